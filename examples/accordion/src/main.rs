@@ -0,0 +1,74 @@
+use iced::{
+    widget::{Button, Column, Text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{accordion::AccordionItem, Accordion};
+
+fn main() -> iced::Result {
+    AccordionExample::run(Settings {
+        fonts: vec![iced_aw::BOOTSTRAP_FONT_BYTES.into()],
+        ..Settings::default()
+    })
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Toggled(usize, bool),
+    Pressed,
+}
+
+#[derive(Default)]
+struct AccordionExample {
+    last_toggled: Option<(usize, bool)>,
+    presses: usize,
+}
+
+impl Sandbox for AccordionExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("Accordion example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Toggled(index, is_open) => self.last_toggled = Some((index, is_open)),
+            Message::Pressed => self.presses += 1,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let accordion = Accordion::new(vec![
+            AccordionItem::new(
+                Text::new("What is an accordion?"),
+                Text::new("A list of sections whose bodies can be expanded and collapsed."),
+            ),
+            AccordionItem::new(
+                Text::new("Can the body be interactive?"),
+                Column::new()
+                    .push(Text::new(format!("Pressed {} times", self.presses)))
+                    .push(Button::new(Text::new("Press me")).on_press(Message::Pressed))
+                    .spacing(10),
+            ),
+            AccordionItem::new(
+                Text::new("Can multiple sections be open?"),
+                Text::new("Only if `allow_multiple` is enabled."),
+            ),
+        ])
+        .on_toggle(Message::Toggled)
+        .width(400);
+
+        Column::new()
+            .push(Text::new(format!("Last toggled: {:?}", self.last_toggled)))
+            .push(accordion)
+            .spacing(20)
+            .padding(40)
+            .width(Length::Fill)
+            .into()
+    }
+}
@@ -0,0 +1,79 @@
+use iced::{
+    widget::{Column, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Autocomplete;
+
+fn main() -> iced::Result {
+    AutocompleteExample::run(Settings::default())
+}
+
+const FRUITS: [&str; 10] = [
+    "Apple",
+    "Apricot",
+    "Banana",
+    "Blackberry",
+    "Cherry",
+    "Grape",
+    "Lemon",
+    "Mango",
+    "Orange",
+    "Pineapple",
+];
+
+#[derive(Clone, Debug)]
+enum Message {
+    Changed(String),
+    Submitted,
+}
+
+struct AutocompleteExample {
+    value: String,
+    submitted: Option<String>,
+}
+
+impl Sandbox for AutocompleteExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            value: String::new(),
+            submitted: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Autocomplete example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Changed(value) => self.value = value,
+            Message::Submitted => self.submitted = Some(self.value.clone()),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let suggestions = FRUITS.iter().map(ToString::to_string).collect();
+
+        Column::new()
+            .push(
+                Autocomplete::new(&self.value, suggestions, Message::Changed)
+                    .placeholder("Type a fruit...")
+                    .max_suggestions(5)
+                    .match_anywhere(true)
+                    .on_submit(Message::Submitted)
+                    .width(300),
+            )
+            .push(Text::new(format!(
+                "Submitted: {}",
+                self.submitted.as_deref().unwrap_or("nothing yet")
+            )))
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
@@ -0,0 +1,92 @@
+use iced::{
+    advanced::image::Handle,
+    widget::{Column, Row, Text},
+    Alignment, Color, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{Avatar, AvatarShape};
+
+fn main() -> iced::Result {
+    AvatarExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Pressed(&'static str),
+}
+
+struct AvatarExample {
+    picture: Handle,
+    last_pressed: Option<&'static str>,
+}
+
+/// Creates a picture with a color gradient.
+fn gradient_picture(size: u32) -> Handle {
+    let pixels = (0..size)
+        .flat_map(|y| (0..size).map(move |x| (x, y)))
+        .flat_map(|(x, y)| {
+            #[allow(clippy::cast_possible_truncation)]
+            let (r, g) = ((x * 255 / size) as u8, (y * 255 / size) as u8);
+            [r, g, 200, 255]
+        })
+        .collect::<Vec<_>>();
+
+    Handle::from_pixels(size, size, pixels)
+}
+
+impl Sandbox for AvatarExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            picture: gradient_picture(64),
+            last_pressed: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Avatar example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Pressed(name) => self.last_pressed = Some(name),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let avatars = Row::new()
+            .push(
+                Avatar::from_image(self.picture.clone())
+                    .size(64.0)
+                    .border_width(2.0)
+                    .on_press(Message::Pressed("Picture")),
+            )
+            .push(
+                Avatar::from_initials("JD")
+                    .size(64.0)
+                    .on_press(Message::Pressed("Jane Doe")),
+            )
+            .push(
+                Avatar::from_initials("Max")
+                    .size(48.0)
+                    .shape(AvatarShape::RoundedSquare(8.0))
+                    .background_color(Color::from_rgb(0.9, 0.6, 0.2))
+                    .on_press(Message::Pressed("Max")),
+            )
+            .spacing(20)
+            .align_items(Alignment::Center);
+
+        Column::new()
+            .push(avatars)
+            .push(Text::new(match self.last_pressed {
+                Some(name) => format!("Pressed: {name}"),
+                None => String::from("Press an avatar"),
+            }))
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
@@ -3,7 +3,10 @@ use iced::{
     Alignment, Element, Length, Sandbox, Settings,
 };
 
-use iced_aw::{helpers::badge, style::BadgeStyles};
+use iced_aw::{
+    helpers::{badge, count_badge},
+    style::BadgeStyles,
+};
 
 const BADGE_TEXT_SIZE: u16 = 15;
 
@@ -79,6 +82,17 @@ impl Sandbox for BadgeExample {
                     .push(badge(Text::new("Light")).style(BadgeStyles::Light))
                     .push(badge(Text::new("Dark")).style(BadgeStyles::Dark))
                     .push(badge(Text::new("White")).style(BadgeStyles::White)),
+            )
+            .push(Text::new("Counts shown on top of other elements:").size(32))
+            .push(
+                Row::new()
+                    .spacing(20)
+                    .push(count_badge(Text::new("Inbox"), 7).style(BadgeStyles::Danger))
+                    .push(
+                        count_badge(Text::new("Updates"), 120)
+                            .max_count(99)
+                            .style(BadgeStyles::Primary),
+                    ),
             );
 
         Container::new(
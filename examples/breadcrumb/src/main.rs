@@ -0,0 +1,68 @@
+use iced::{
+    widget::{Button, Column, Row, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{breadcrumb::BreadcrumbItem, Breadcrumb};
+
+fn main() -> iced::Result {
+    BreadcrumbExample::run(Settings::default())
+}
+
+const FOLDERS: [&str; 4] = ["Documents", "Projects", "Widgets", "Breadcrumb"];
+
+#[derive(Clone, Debug)]
+enum Message {
+    GoTo(usize),
+    Open,
+}
+
+struct BreadcrumbExample {
+    depth: usize,
+}
+
+impl Sandbox for BreadcrumbExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self { depth: 2 }
+    }
+
+    fn title(&self) -> String {
+        String::from("Breadcrumb example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::GoTo(depth) => self.depth = depth,
+            Message::Open => self.depth = (self.depth + 1).min(FOLDERS.len()),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let items = |depth: usize| {
+            std::iter::once("Home")
+                .chain(FOLDERS.iter().copied())
+                .take(depth + 1)
+                .enumerate()
+                .map(|(index, label)| BreadcrumbItem::new(label).on_press(Message::GoTo(index)))
+                .collect::<Vec<_>>()
+        };
+
+        let open = Button::new(Text::new("Open next folder"));
+
+        Column::new()
+            .push(Breadcrumb::new(items(self.depth)))
+            .push(Breadcrumb::new(items(self.depth)).separator(Text::new(" > ")))
+            .push(Row::new().push(if self.depth < FOLDERS.len() {
+                open.on_press(Message::Open)
+            } else {
+                open
+            }))
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
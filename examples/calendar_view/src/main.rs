@@ -0,0 +1,72 @@
+use iced::{
+    widget::{Column, Text},
+    Alignment, Color, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    calendar_view::{Date, HighlightedDate, Weekday},
+    CalendarView,
+};
+
+fn main() -> iced::Result {
+    CalendarViewExample::run(Settings {
+        fonts: vec![iced_aw::BOOTSTRAP_FONT_BYTES.into()],
+        ..Settings::default()
+    })
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Select(Date),
+}
+
+struct CalendarViewExample {
+    today: Date,
+    selected: Option<Date>,
+}
+
+impl Sandbox for CalendarViewExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            today: Date::today(),
+            selected: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("CalendarView example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Select(date) => self.selected = Some(date),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let selected = self
+            .selected
+            .map_or_else(|| String::from("No date selected"), |date| date.to_string());
+
+        let calendar = CalendarView::new(self.selected, Message::Select)
+            .min_date(self.today)
+            .highlight_dates(vec![HighlightedDate::new(
+                self.today,
+                Color::from_rgb(0.9, 0.3, 0.3),
+            )
+            .tooltip("Today")])
+            .show_week_numbers(true)
+            .first_day_of_week(Weekday::Sun);
+
+        Column::new()
+            .push(Text::new(selected))
+            .push(calendar)
+            .spacing(10)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
@@ -0,0 +1,86 @@
+use iced::{
+    widget::{Column, Row, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Chip;
+
+fn main() -> iced::Result {
+    ChipExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Toggle(usize),
+    Delete(usize),
+}
+
+struct ChipExample {
+    tags: Vec<(&'static str, bool)>,
+}
+
+impl Sandbox for ChipExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            tags: vec![
+                ("Rust", true),
+                ("Iced", false),
+                ("Widgets", false),
+                ("GUI", true),
+            ],
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Chip example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Toggle(index) => {
+                if let Some((_, selected)) = self.tags.get_mut(index) {
+                    *selected = !*selected;
+                }
+            }
+            Message::Delete(index) => {
+                if index < self.tags.len() {
+                    let _ = self.tags.remove(index);
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let chips = Row::with_children(self.tags.iter().enumerate().map(
+            |(index, (label, selected))| {
+                Chip::new(*label)
+                    .on_press(Message::Toggle(index))
+                    .on_delete(Message::Delete(index))
+                    .selected(*selected)
+                    .into()
+            },
+        ))
+        .spacing(10);
+
+        let static_chips = Row::new()
+            .push(Chip::new("Read only"))
+            .push(
+                Chip::new("With icon")
+                    .icon(Text::new("★"))
+                    .on_press(Message::Toggle(0)),
+            )
+            .spacing(10);
+
+        Column::new()
+            .push(Text::new("Press a chip to toggle it or × to delete it."))
+            .push(chips)
+            .push(static_chips)
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
@@ -0,0 +1,56 @@
+use iced::{
+    widget::{Column, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{clock_face::Time, ClockFace};
+
+fn main() -> iced::Result {
+    ClockFaceExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Change(Time),
+}
+
+struct ClockFaceExample {
+    time: Time,
+}
+
+impl Sandbox for ClockFaceExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            time: Time::now_hms(false),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("ClockFace example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Change(time) => self.time = time,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        Column::new()
+            .push(Text::new(format!("Time: {}", self.time)))
+            .push(
+                ClockFace::new(self.time, Message::Change)
+                    .show_seconds(true)
+                    .use_12h(true)
+                    .radius(150.0)
+                    .step(5),
+            )
+            .spacing(10)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
@@ -0,0 +1,127 @@
+use iced::{
+    widget::{Button, Column, Container, Text},
+    Color, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{ColorPicker, ColorSwatch};
+
+/// The number of recently picked colors that are kept.
+const RECENT_COLORS: usize = 8;
+
+fn main() -> iced::Result {
+    ColorSwatchExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    ColorSelected(Color),
+    ChooseColor,
+    CancelColor,
+    SubmitColor(Color),
+}
+
+struct ColorSwatchExample {
+    color: Color,
+    recent: Vec<Color>,
+    show_picker: bool,
+}
+
+/// The preset palette of the example.
+fn palette() -> Vec<Color> {
+    [
+        [0.96, 0.26, 0.21],
+        [0.91, 0.12, 0.39],
+        [0.61, 0.15, 0.69],
+        [0.40, 0.23, 0.72],
+        [0.25, 0.32, 0.71],
+        [0.13, 0.59, 0.95],
+        [0.01, 0.66, 0.96],
+        [0.00, 0.74, 0.83],
+        [0.00, 0.59, 0.53],
+        [0.30, 0.69, 0.31],
+        [0.55, 0.76, 0.29],
+        [0.80, 0.86, 0.22],
+        [1.00, 0.92, 0.23],
+        [1.00, 0.76, 0.03],
+        [1.00, 0.60, 0.00],
+        [1.00, 0.34, 0.13],
+    ]
+    .into_iter()
+    .map(Color::from)
+    .collect()
+}
+
+impl Sandbox for ColorSwatchExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            color: Color::from_rgb(0.13, 0.59, 0.95),
+            recent: Vec::new(),
+            show_picker: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Color swatch example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::ColorSelected(color) => self.color = color,
+            Message::ChooseColor => self.show_picker = true,
+            Message::CancelColor => self.show_picker = false,
+            Message::SubmitColor(color) => {
+                self.color = color;
+                self.show_picker = false;
+                self.recent.retain(|recent| *recent != color);
+                self.recent.insert(0, color);
+                self.recent.truncate(RECENT_COLORS);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let palette = ColorSwatch::new(palette(), Some(self.color), Message::ColorSelected)
+            .columns(8)
+            .swatch_size(32.0);
+
+        let picker = ColorPicker::new(
+            self.show_picker,
+            self.color,
+            Button::new(Text::new("Pick a custom color")).on_press(Message::ChooseColor),
+            Message::CancelColor,
+            Message::SubmitColor,
+        );
+
+        let recent = ColorSwatch::new(
+            self.recent.clone(),
+            Some(self.color),
+            Message::ColorSelected,
+        )
+        .swatch_size(20.0)
+        .border_radius(10.0);
+
+        let color = self.color;
+        let preview = Container::new(Text::new(format!("{:?}", self.color)))
+            .padding(10)
+            .style(
+                move |_theme: &iced::Theme| iced::widget::container::Appearance {
+                    background: Some(color.into()),
+                    ..Default::default()
+                },
+            );
+
+        Column::new()
+            .push(Text::new("Palette"))
+            .push(palette)
+            .push(picker)
+            .push(Text::new("Recent colors"))
+            .push(recent)
+            .push(preview)
+            .spacing(10)
+            .padding(20)
+            .width(Length::Fill)
+            .into()
+    }
+}
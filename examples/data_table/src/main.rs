@@ -0,0 +1,111 @@
+use iced::{
+    widget::{Container, Text},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    data_table::{Column, ColumnWidth},
+    DataTable, SortDirection,
+};
+
+fn main() -> iced::Result {
+    DataTableExample::run(Settings {
+        fonts: vec![iced_aw::BOOTSTRAP_FONT_BYTES.into()],
+        ..Settings::default()
+    })
+}
+
+#[derive(Clone, Debug)]
+struct Planet {
+    name: &'static str,
+    moons: u32,
+    radius: f32,
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    SortByName(SortDirection),
+    SortByMoons(SortDirection),
+    SortByRadius(SortDirection),
+}
+
+struct DataTableExample {
+    planets: Vec<Planet>,
+}
+
+impl Sandbox for DataTableExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        let planet = |name, moons, radius| Planet {
+            name,
+            moons,
+            radius,
+        };
+
+        Self {
+            planets: vec![
+                planet("Mercury", 0, 2_439.7),
+                planet("Venus", 0, 6_051.8),
+                planet("Earth", 1, 6_371.0),
+                planet("Mars", 2, 3_389.5),
+                planet("Jupiter", 95, 69_911.0),
+                planet("Saturn", 146, 58_232.0),
+                planet("Uranus", 28, 25_362.0),
+                planet("Neptune", 16, 24_622.0),
+            ],
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Data table example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        let direction = match message {
+            Message::SortByName(direction) => {
+                self.planets.sort_by(|a, b| a.name.cmp(b.name));
+                direction
+            }
+            Message::SortByMoons(direction) => {
+                self.planets.sort_by_key(|planet| planet.moons);
+                direction
+            }
+            Message::SortByRadius(direction) => {
+                self.planets.sort_by(|a, b| a.radius.total_cmp(&b.radius));
+                direction
+            }
+        };
+
+        if direction == SortDirection::Desc {
+            self.planets.reverse();
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let table = DataTable::new(
+            vec![
+                Column::new("Planet", |planet: &Planet| Text::new(planet.name).into())
+                    .on_sort(Message::SortByName),
+                Column::new("Moons", |planet: &Planet| {
+                    Text::new(planet.moons.to_string()).into()
+                })
+                .width(ColumnWidth::Fixed(100.0))
+                .on_sort(Message::SortByMoons),
+                Column::new("Radius (km)", |planet: &Planet| {
+                    Text::new(planet.radius.to_string()).into()
+                })
+                .width(ColumnWidth::Flex(2))
+                .on_sort(Message::SortByRadius),
+            ],
+            self.planets.clone(),
+        )
+        .height(200);
+
+        Container::new(table)
+            .padding(20)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
@@ -1,9 +1,12 @@
 use iced::{
     alignment, font,
     widget::{container, text, Button, Container, Row, Text},
-    Alignment, Application, Command, Element, Length, Settings, Theme,
+    Alignment, Application, Color, Command, Element, Length, Settings, Theme,
+};
+use iced_aw::{
+    date_picker::{Date, HighlightedDate},
+    helpers::date_picker,
 };
-use iced_aw::{date_picker::Date, helpers::date_picker};
 
 fn main() -> iced::Result {
     DatePickerExample::run(Settings::default())
@@ -103,7 +106,14 @@ impl Application for DatePickerExample {
                     but,
                     Message::CancelDate,
                     Message::SubmitDate,
-                );
+                )
+                .show_today_button(true)
+                .highlight_dates([
+                    HighlightedDate::new(Date::today(), Color::from_rgb(0.2, 0.6, 0.2))
+                        .tooltip("Today"),
+                    HighlightedDate::new(Date::today().add_days(7), Color::from_rgb(0.8, 0.2, 0.2))
+                        .tooltip("In one week"),
+                ]);
 
                 let row = Row::new()
                     .align_items(Alignment::Center)
@@ -0,0 +1,131 @@
+use iced::{
+    alignment, font,
+    widget::{container, text, Button, Container, Row, Text},
+    Alignment, Application, Command, Element, Length, Settings, Theme,
+};
+use iced_aw::{
+    date_range_picker::{Date, DateRange},
+    helpers::date_range_picker,
+};
+
+fn main() -> iced::Result {
+    DateRangePickerExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+#[allow(clippy::enum_variant_names)]
+enum Message {
+    ChooseRange,
+    SubmitRange(Date, Date),
+    CancelRange,
+    #[allow(dead_code)]
+    Loaded(Result<(), String>),
+    FontLoaded(Result<(), font::Error>),
+}
+
+enum DateRangePickerExample {
+    Loading,
+    Loaded(State),
+}
+
+struct State {
+    range: DateRange,
+    show_picker: bool,
+}
+
+async fn load() -> Result<(), String> {
+    Ok(())
+}
+
+impl Application for DateRangePickerExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (DateRangePickerExample, Command<Message>) {
+        (
+            DateRangePickerExample::Loading,
+            Command::batch(vec![
+                font::load(iced_aw::BOOTSTRAP_FONT_BYTES).map(Message::FontLoaded),
+                Command::perform(load(), Message::Loaded),
+            ]),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("DateRangePicker example")
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Message> {
+        match self {
+            DateRangePickerExample::Loading => {
+                if let Message::Loaded(_) = message {
+                    *self = DateRangePickerExample::Loaded(State {
+                        range: DateRange::new(Date::today(), Date::today()),
+                        show_picker: false,
+                    })
+                }
+            }
+            DateRangePickerExample::Loaded(state) => match message {
+                Message::ChooseRange => {
+                    state.show_picker = true;
+                }
+                Message::SubmitRange(start, end) => {
+                    state.range = DateRange::new(start, end);
+                    state.show_picker = false;
+                }
+                Message::CancelRange => {
+                    state.show_picker = false;
+                }
+                _ => {}
+            },
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match self {
+            DateRangePickerExample::Loading => container(
+                text("Loading...")
+                    .horizontal_alignment(alignment::Horizontal::Center)
+                    .size(50),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_y()
+            .center_x()
+            .into(),
+            DateRangePickerExample::Loaded(state) => {
+                let but = Button::new(Text::new("Set Dates")).on_press(Message::ChooseRange);
+
+                let datepicker = date_range_picker(
+                    state.show_picker,
+                    state.range,
+                    but,
+                    Message::CancelRange,
+                    Message::SubmitRange,
+                );
+
+                let row = Row::new()
+                    .align_items(Alignment::Center)
+                    .spacing(10)
+                    .push(datepicker)
+                    .push(Text::new(format!(
+                        "Dates: {} - {} ({} days)",
+                        state.range.start,
+                        state.range.end,
+                        state.range.iter().count()
+                    )));
+
+                Container::new(row)
+                    .center_x()
+                    .center_y()
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into()
+            }
+        }
+    }
+}
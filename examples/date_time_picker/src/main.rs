@@ -0,0 +1,127 @@
+use iced::{
+    alignment, font,
+    widget::{container, text, Button, Container, Row, Text},
+    Alignment, Application, Command, Element, Length, Settings, Theme,
+};
+use iced_aw::{
+    date_time_picker::{Date, DateTime, Time},
+    helpers::date_time_picker,
+};
+
+fn main() -> iced::Result {
+    DateTimePickerExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+#[allow(clippy::enum_variant_names)]
+enum Message {
+    ChooseDateTime,
+    SubmitDateTime(Date, Time),
+    CancelDateTime,
+    #[allow(dead_code)]
+    Loaded(Result<(), String>),
+    FontLoaded(Result<(), font::Error>),
+}
+
+enum DateTimePickerExample {
+    Loading,
+    Loaded(State),
+}
+
+struct State {
+    date_time: DateTime,
+    show_picker: bool,
+}
+
+async fn load() -> Result<(), String> {
+    Ok(())
+}
+
+impl Application for DateTimePickerExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (DateTimePickerExample, Command<Message>) {
+        (
+            DateTimePickerExample::Loading,
+            Command::batch(vec![
+                font::load(iced_aw::BOOTSTRAP_FONT_BYTES).map(Message::FontLoaded),
+                Command::perform(load(), Message::Loaded),
+            ]),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("DateTimePicker example")
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Message> {
+        match self {
+            DateTimePickerExample::Loading => {
+                if let Message::Loaded(_) = message {
+                    *self = DateTimePickerExample::Loaded(State {
+                        date_time: DateTime::now(false),
+                        show_picker: false,
+                    })
+                }
+            }
+            DateTimePickerExample::Loaded(state) => match message {
+                Message::ChooseDateTime => {
+                    state.show_picker = true;
+                }
+                Message::SubmitDateTime(date, time) => {
+                    state.date_time = DateTime::new(date, time);
+                    state.show_picker = false;
+                }
+                Message::CancelDateTime => {
+                    state.show_picker = false;
+                }
+                _ => {}
+            },
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match self {
+            DateTimePickerExample::Loading => container(
+                text("Loading...")
+                    .horizontal_alignment(alignment::Horizontal::Center)
+                    .size(50),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_y()
+            .center_x()
+            .into(),
+            DateTimePickerExample::Loaded(state) => {
+                let but =
+                    Button::new(Text::new("Set Date and Time")).on_press(Message::ChooseDateTime);
+
+                let datetimepicker = date_time_picker(
+                    state.show_picker,
+                    state.date_time,
+                    but,
+                    Message::CancelDateTime,
+                    Message::SubmitDateTime,
+                );
+
+                let row = Row::new()
+                    .align_items(Alignment::Center)
+                    .spacing(10)
+                    .push(datetimepicker)
+                    .push(Text::new(format!("Date and time: {}", state.date_time)));
+
+                Container::new(row)
+                    .center_x()
+                    .center_y()
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into()
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use iced::{
+    widget::{Column, Row, Text},
+    Alignment, Color, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Divider;
+
+fn main() -> iced::Result {
+    DividerExample::run(Settings::default())
+}
+
+struct DividerExample;
+
+impl Sandbox for DividerExample {
+    type Message = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn title(&self) -> String {
+        String::from("Divider example")
+    }
+
+    fn update(&mut self, _message: Self::Message) {}
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let columns = Row::new()
+            .push(Text::new("Left"))
+            .push(Divider::vertical())
+            .push(Text::new("Center"))
+            .push(Divider::vertical().thickness(3.0).label("|"))
+            .push(Text::new("Right"))
+            .height(Length::Fixed(100.0))
+            .align_items(Alignment::Center);
+
+        Column::new()
+            .push(Text::new("Above a plain divider"))
+            .push(Divider::horizontal())
+            .push(Text::new("Sign in with your account"))
+            .push(Divider::horizontal().label("or"))
+            .push(Text::new("Continue as a guest"))
+            .push(
+                Divider::horizontal()
+                    .thickness(2.0)
+                    .spacing(20.0)
+                    .color(Color::from_rgb(0.2, 0.5, 0.9)),
+            )
+            .push(columns)
+            .padding(20)
+            .width(Length::Fill)
+            .into()
+    }
+}
@@ -5,13 +5,13 @@ use iced::{
     Element, Length, Sandbox, Settings,
 };
 
-use iced_aw::{drop_down, DropDown};
+use iced_aw::{drop_down, DropDown, MultiSelect, Select};
 
 fn main() -> iced::Result {
     DropDownExample::run(Settings::default())
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 enum Choice {
     #[default]
     Choice1,
@@ -32,12 +32,16 @@ enum Message {
     Select(Choice),
     Dismiss,
     Expand,
+    Pick(Choice),
+    PickMany(Vec<Choice>),
 }
 
 #[derive(Default)]
 struct DropDownExample {
     selected: Choice,
     expanded: bool,
+    picked: Option<Choice>,
+    picked_many: Vec<Choice>,
 }
 
 impl Sandbox for DropDownExample {
@@ -59,6 +63,8 @@ impl Sandbox for DropDownExample {
             }
             Message::Dismiss => self.expanded = false,
             Message::Expand => self.expanded = !self.expanded,
+            Message::Pick(choice) => self.picked = Some(choice),
+            Message::PickMany(choices) => self.picked_many = choices,
         }
     }
 
@@ -79,12 +85,28 @@ impl Sandbox for DropDownExample {
             .on_dismiss(Message::Dismiss)
             .alignment(drop_down::Alignment::Bottom);
 
+        let select = Select::new(CHOICES.to_vec(), self.picked.clone(), Message::Pick)
+            .placeholder("Pick a choice")
+            .width(200);
+
+        let multi_select = MultiSelect::new(
+            CHOICES.to_vec(),
+            self.picked_many.clone(),
+            Message::PickMany,
+        )
+        .placeholder("Pick up to three choices")
+        .max_selected(3)
+        .width(200);
+
         Column::new()
             .padding(20)
+            .spacing(20)
             .width(Length::Fill)
             .height(Length::Fill)
             .align_items(iced::Alignment::Center)
             .push(drop_down)
+            .push(select)
+            .push(multi_select)
             .into()
     }
 }
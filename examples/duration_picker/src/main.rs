@@ -0,0 +1,73 @@
+use iced::{
+    widget::{Column, Text, Toggler},
+    Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{duration_picker::Duration, DurationPicker};
+
+fn main() -> iced::Result {
+    DurationPickerExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    DurationChanged(Duration),
+    ShowSeconds(bool),
+    Submit,
+}
+
+struct DurationPickerExample {
+    duration: Duration,
+    show_seconds: bool,
+    submitted: Option<Duration>,
+}
+
+impl Sandbox for DurationPickerExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            duration: Duration::minutes(25),
+            show_seconds: true,
+            submitted: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Duration picker example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::DurationChanged(duration) => self.duration = duration,
+            Message::ShowSeconds(show_seconds) => self.show_seconds = show_seconds,
+            Message::Submit => self.submitted = Some(self.duration),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let picker = DurationPicker::new(self.duration, Message::DurationChanged)
+            .max_hours(24)
+            .show_seconds(self.show_seconds)
+            .on_submit(Message::Submit);
+
+        let submitted = self.submitted.map_or_else(
+            || String::from("Press enter to start the timer"),
+            |duration| format!("Timer started for {duration}"),
+        );
+
+        Column::new()
+            .push(Text::new("Timer duration"))
+            .push(picker)
+            .push(Toggler::new(
+                String::from("Show seconds"),
+                self.show_seconds,
+                Message::ShowSeconds,
+            ))
+            .push(Text::new(submitted))
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .into()
+    }
+}
@@ -0,0 +1,82 @@
+use iced::{
+    theme,
+    widget::{Button, Column, Container, Row, Scrollable, Text},
+    Element, Length, Sandbox, Settings, Vector,
+};
+
+use iced_aw::{floating_button::Anchor, FloatingButton};
+
+fn main() -> iced::Result {
+    FloatingButtonExample::run(Settings::default())
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Message {
+    Add,
+    Anchor(Anchor),
+}
+
+struct FloatingButtonExample {
+    items: usize,
+    anchor: Anchor,
+}
+
+impl Sandbox for FloatingButtonExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            items: 3,
+            anchor: Anchor::SouthEast,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("FloatingButton example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Add => self.items += 1,
+            Message::Anchor(anchor) => self.anchor = anchor,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let anchors = [
+            ("North West", Anchor::NorthWest),
+            ("North East", Anchor::NorthEast),
+            ("South West", Anchor::SouthWest),
+            ("South East", Anchor::SouthEast),
+        ]
+        .into_iter()
+        .fold(Row::new().spacing(10), |row, (name, anchor)| {
+            row.push(Button::new(Text::new(name)).on_press(Message::Anchor(anchor)))
+        });
+
+        let items = (1..=self.items).fold(Column::new().padding(10), |column, item| {
+            column.push(Text::new(format!("Item {item}")))
+        });
+
+        let list = Container::new(Scrollable::new(items).width(Length::Fill))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .max_width(400)
+            .max_height(600)
+            .style(theme::Container::Box);
+
+        let content = FloatingButton::new(
+            list,
+            Button::new(Text::new("+").size(24)).on_press(Message::Add),
+        )
+        .anchor(self.anchor)
+        .offset(Vector::new(20.0, 20.0));
+
+        Column::new()
+            .push(anchors)
+            .push(content)
+            .spacing(20)
+            .padding(20)
+            .into()
+    }
+}
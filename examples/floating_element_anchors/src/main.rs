@@ -15,7 +15,7 @@ enum Message {
     NextAnchor,
 }
 
-const AVAILABLE_ANCHORS: [Anchor; 8] = [
+const AVAILABLE_ANCHORS: [Anchor; 9] = [
     Anchor::North,
     Anchor::NorthEast,
     Anchor::East,
@@ -24,6 +24,7 @@ const AVAILABLE_ANCHORS: [Anchor; 8] = [
     Anchor::SouthWest,
     Anchor::West,
     Anchor::NorthWest,
+    Anchor::Center,
 ];
 
 impl Sandbox for FloatingElementAnchorsExample {
@@ -56,6 +57,7 @@ impl Sandbox for FloatingElementAnchorsExample {
             Anchor::East => "East",
             Anchor::South => "South",
             Anchor::West => "West",
+            Anchor::Center => "Center",
         };
 
         let content = container(button(text(current_anchor_name)).on_press(Message::NextAnchor))
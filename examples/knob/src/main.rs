@@ -0,0 +1,71 @@
+use iced::{
+    widget::{Column, Row, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Knob;
+
+fn main() -> iced::Result {
+    KnobExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    VolumeChanged(f32),
+    BalanceChanged(f32),
+}
+
+struct KnobExample {
+    volume: f32,
+    balance: f32,
+}
+
+impl Sandbox for KnobExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            volume: 50.0,
+            balance: 0.0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Knob example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::VolumeChanged(volume) => self.volume = volume,
+            Message::BalanceChanged(balance) => self.balance = balance,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let volume = Column::new()
+            .push(Knob::new(self.volume, 0.0..=100.0, Message::VolumeChanged).step(1.0))
+            .push(Text::new(format!("Volume: {}", self.volume)))
+            .spacing(10)
+            .align_items(Alignment::Center);
+
+        let balance = Column::new()
+            .push(
+                Knob::new(self.balance, -1.0..=1.0, Message::BalanceChanged)
+                    .size(80.0)
+                    .snap_to_center(true)
+                    .sensitivity(0.0025),
+            )
+            .push(Text::new(format!("Balance: {:.2}", self.balance)))
+            .spacing(10)
+            .align_items(Alignment::Center);
+
+        Row::new()
+            .push(volume)
+            .push(balance)
+            .spacing(50)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
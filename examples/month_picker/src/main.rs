@@ -0,0 +1,125 @@
+use iced::{
+    alignment, font,
+    widget::{container, text, Button, Container, Row, Text},
+    Alignment, Application, Command, Element, Length, Settings, Theme,
+};
+use iced_aw::helpers::month_picker;
+
+fn main() -> iced::Result {
+    MonthPickerExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+#[allow(clippy::enum_variant_names)]
+enum Message {
+    ChooseMonth,
+    SubmitMonth(i32, u8),
+    CancelMonth,
+    #[allow(dead_code)]
+    Loaded(Result<(), String>),
+    FontLoaded(Result<(), font::Error>),
+}
+
+enum MonthPickerExample {
+    Loading,
+    Loaded(State),
+}
+
+struct State {
+    month: Option<(i32, u8)>,
+    show_picker: bool,
+}
+
+async fn load() -> Result<(), String> {
+    Ok(())
+}
+
+impl Application for MonthPickerExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (MonthPickerExample, Command<Message>) {
+        (
+            MonthPickerExample::Loading,
+            Command::batch(vec![
+                font::load(iced_aw::BOOTSTRAP_FONT_BYTES).map(Message::FontLoaded),
+                Command::perform(load(), Message::Loaded),
+            ]),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("MonthPicker example")
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Message> {
+        match self {
+            MonthPickerExample::Loading => {
+                if let Message::Loaded(_) = message {
+                    *self = MonthPickerExample::Loaded(State {
+                        month: None,
+                        show_picker: false,
+                    })
+                }
+            }
+            MonthPickerExample::Loaded(state) => match message {
+                Message::ChooseMonth => {
+                    state.show_picker = true;
+                }
+                Message::SubmitMonth(year, month) => {
+                    state.month = Some((year, month));
+                    state.show_picker = false;
+                }
+                Message::CancelMonth => {
+                    state.show_picker = false;
+                }
+                _ => {}
+            },
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match self {
+            MonthPickerExample::Loading => container(
+                text("Loading...")
+                    .horizontal_alignment(alignment::Horizontal::Center)
+                    .size(50),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_y()
+            .center_x()
+            .into(),
+            MonthPickerExample::Loaded(state) => {
+                let but = Button::new(Text::new("Set Month")).on_press(Message::ChooseMonth);
+
+                let monthpicker = month_picker(
+                    state.show_picker,
+                    but,
+                    Message::CancelMonth,
+                    Message::SubmitMonth,
+                );
+
+                let row = Row::new()
+                    .align_items(Alignment::Center)
+                    .spacing(10)
+                    .push(monthpicker)
+                    .push(Text::new(match state.month {
+                        Some((year, month)) => format!("Month: {year}-{month:02}"),
+                        None => String::from("No month picked"),
+                    }));
+
+                Container::new(row)
+                    .center_x()
+                    .center_y()
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into()
+            }
+        }
+    }
+}
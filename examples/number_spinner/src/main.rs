@@ -0,0 +1,61 @@
+use iced::{
+    widget::{Button, Column, Row, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::NumberSpinner;
+
+fn main() -> iced::Result {
+    NumberSpinnerExample::run(Settings::default())
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Message {
+    Add(i64),
+    Reset,
+}
+
+struct NumberSpinnerExample {
+    score: i64,
+}
+
+impl Sandbox for NumberSpinnerExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self { score: 0 }
+    }
+
+    fn title(&self) -> String {
+        String::from("NumberSpinner example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Add(points) => self.score += points,
+            Message::Reset => self.score = 0,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let buttons = [-100, -1, 1, 7, 250]
+            .into_iter()
+            .fold(Row::new().spacing(10), |row, points| {
+                row.push(
+                    Button::new(Text::new(format!("{points:+}"))).on_press(Message::Add(points)),
+                )
+            })
+            .push(Button::new(Text::new("Reset")).on_press(Message::Reset));
+
+        Column::new()
+            .push(Text::new("Score"))
+            .push(NumberSpinner::new(self.score).font_size(64.0))
+            .push(NumberSpinner::new(self.score * 3).animation_speed(1.0))
+            .push(buttons)
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
@@ -0,0 +1,65 @@
+use iced::{
+    widget::{Column, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Pagination;
+
+fn main() -> iced::Result {
+    PaginationExample::run(Settings {
+        fonts: vec![iced_aw::BOOTSTRAP_FONT_BYTES.into()],
+        ..Settings::default()
+    })
+}
+
+const TOTAL_PAGES: usize = 20;
+
+#[derive(Clone, Debug)]
+enum Message {
+    PageChanged(usize),
+}
+
+struct PaginationExample {
+    page: usize,
+}
+
+impl Sandbox for PaginationExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self { page: 0 }
+    }
+
+    fn title(&self) -> String {
+        String::from("Pagination example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::PageChanged(page) => self.page = page,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        Column::new()
+            .push(Text::new(format!(
+                "Showing page {} of {TOTAL_PAGES}",
+                self.page + 1
+            )))
+            .push(Pagination::new(
+                TOTAL_PAGES,
+                self.page,
+                Message::PageChanged,
+            ))
+            .push(
+                Pagination::new(TOTAL_PAGES, self.page, Message::PageChanged)
+                    .visible_page_buttons(5)
+                    .show_first_last(false),
+            )
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
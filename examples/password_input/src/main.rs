@@ -0,0 +1,84 @@
+use iced::{
+    widget::{Column, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::PasswordInput;
+
+fn main() -> iced::Result {
+    PasswordInputExample::run(Settings {
+        fonts: vec![iced_aw::BOOTSTRAP_FONT_BYTES.into()],
+        ..Settings::default()
+    })
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    PasswordChanged(String),
+    PinChanged(String),
+    ShowToggled(bool),
+    Submitted,
+}
+
+struct PasswordInputExample {
+    password: String,
+    pin: String,
+    shown: bool,
+    status: String,
+}
+
+impl Sandbox for PasswordInputExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            password: String::new(),
+            pin: String::new(),
+            shown: false,
+            status: String::from("Enter a password and press enter"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Password input example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::PasswordChanged(password) => self.password = password,
+            Message::PinChanged(pin) => self.pin = pin,
+            Message::ShowToggled(shown) => self.shown = shown,
+            Message::Submitted => {
+                self.status = format!("Submitted a password of {} characters", self.password.len());
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        Column::new()
+            .push(
+                PasswordInput::new(&self.password, Message::PasswordChanged)
+                    .placeholder("Password")
+                    .on_submit(Message::Submitted)
+                    .on_show_toggle(Message::ShowToggled)
+                    .width(300),
+            )
+            .push(Text::new(format!(
+                "The password is {}",
+                if self.shown { "shown" } else { "hidden" }
+            )))
+            .push(
+                PasswordInput::new(&self.pin, Message::PinChanged)
+                    .placeholder("PIN")
+                    .visible_icon(Text::new("Hide"))
+                    .hidden_icon(Text::new("Show"))
+                    .width(300),
+            )
+            .push(Text::new(&self.status))
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
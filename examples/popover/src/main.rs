@@ -0,0 +1,84 @@
+use iced::{
+    widget::{Button, Column, Container, Row, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{Popover, PopoverPosition};
+
+fn main() -> iced::Result {
+    PopoverExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Toggle,
+    Dismiss,
+    Position(PopoverPosition),
+}
+
+struct PopoverExample {
+    show: bool,
+    position: PopoverPosition,
+}
+
+impl Sandbox for PopoverExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            show: false,
+            position: PopoverPosition::Bottom,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Popover example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Toggle => self.show = !self.show,
+            Message::Dismiss => self.show = false,
+            Message::Position(position) => self.position = position,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let positions = [
+            ("Top", PopoverPosition::Top),
+            ("Bottom", PopoverPosition::Bottom),
+            ("Left", PopoverPosition::Left),
+            ("Right", PopoverPosition::Right),
+            ("Auto", PopoverPosition::Auto),
+        ]
+        .into_iter()
+        .fold(Row::new().spacing(10), |row, (label, position)| {
+            row.push(Button::new(Text::new(label)).on_press(Message::Position(position)))
+        });
+
+        let popover = Popover::new(
+            Button::new(Text::new("Toggle popover")).on_press(Message::Toggle),
+            Column::new()
+                .spacing(5)
+                .push(Text::new("A popover"))
+                .push(Text::new("Click outside or press escape to close it.")),
+            self.show,
+        )
+        .position(self.position)
+        .show_arrow(true)
+        .on_dismiss(Message::Dismiss);
+
+        let content = Column::new()
+            .align_items(Alignment::Center)
+            .spacing(40)
+            .push(positions)
+            .push(popover);
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
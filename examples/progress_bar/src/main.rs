@@ -0,0 +1,81 @@
+use iced::{
+    widget::{Button, Column, Row, Text, Toggler},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::ProgressBar;
+
+fn main() -> iced::Result {
+    ProgressBarExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Advance,
+    Reset,
+    Completed,
+    Indeterminate(bool),
+}
+
+struct ProgressBarExample {
+    value: f32,
+    completed: u32,
+    indeterminate: bool,
+}
+
+impl Sandbox for ProgressBarExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            value: 0.0,
+            completed: 0,
+            indeterminate: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Progress bar example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Advance => self.value = (self.value + 0.1).min(1.0),
+            Message::Reset => self.value = 0.0,
+            Message::Completed => self.completed += 1,
+            Message::Indeterminate(indeterminate) => self.indeterminate = indeterminate,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        Column::new()
+            .push(Text::new(format!(
+                "Progress: {:.0}% (completed {} times)",
+                self.value * 100.0,
+                self.completed
+            )))
+            .push(
+                ProgressBar::new(self.value)
+                    .height(20.0)
+                    .border_radius(10.0)
+                    .on_complete(Message::Completed),
+            )
+            .push(
+                Row::new()
+                    .push(Button::new("Advance").on_press(Message::Advance))
+                    .push(Button::new("Reset").on_press(Message::Reset))
+                    .spacing(10),
+            )
+            .push(Toggler::new(
+                String::from("Indeterminate"),
+                self.indeterminate,
+                Message::Indeterminate,
+            ))
+            .push(ProgressBar::new(0.0).indeterminate(self.indeterminate))
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
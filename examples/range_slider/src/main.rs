@@ -0,0 +1,79 @@
+use iced::{
+    widget::{Column, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::RangeSlider;
+
+fn main() -> iced::Result {
+    RangeSliderExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    PriceChanged(f32, f32),
+    HoursChanged(f32, f32),
+}
+
+struct RangeSliderExample {
+    price: (f32, f32),
+    hours: (f32, f32),
+}
+
+impl Sandbox for RangeSliderExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            price: (20.0, 80.0),
+            hours: (9.0, 17.0),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Range slider example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::PriceChanged(low, high) => self.price = (low, high),
+            Message::HoursChanged(low, high) => self.hours = (low, high),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        Column::new()
+            .push(Text::new(format!(
+                "Price: {} $ - {} $",
+                self.price.0, self.price.1
+            )))
+            .push(
+                RangeSlider::new(
+                    0.0..=100.0,
+                    self.price.0,
+                    self.price.1,
+                    Message::PriceChanged,
+                )
+                .step(5.0)
+                .width(300),
+            )
+            .push(Text::new(format!(
+                "Opening hours: {}:00 - {}:00",
+                self.hours.0, self.hours.1
+            )))
+            .push(
+                RangeSlider::new(
+                    0.0..=24.0,
+                    self.hours.0,
+                    self.hours.1,
+                    Message::HoursChanged,
+                )
+                .width(300),
+            )
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
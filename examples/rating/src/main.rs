@@ -0,0 +1,65 @@
+use iced::{
+    widget::{Column, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Rating;
+
+fn main() -> iced::Result {
+    RatingExample::run(Settings {
+        fonts: vec![iced_aw::BOOTSTRAP_FONT_BYTES.into()],
+        ..Settings::default()
+    })
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Rated(f32),
+    HalfRated(f32),
+}
+
+struct RatingExample {
+    rating: f32,
+    half_rating: f32,
+}
+
+impl Sandbox for RatingExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            rating: 3.0,
+            half_rating: 2.5,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Rating example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Rated(value) => self.rating = value,
+            Message::HalfRated(value) => self.half_rating = value,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        Column::new()
+            .push(Text::new(format!("Rating: {}", self.rating)))
+            .push(Rating::new(self.rating, 5, Message::Rated))
+            .push(Text::new(format!("Half star rating: {}", self.half_rating)))
+            .push(
+                Rating::new(self.half_rating, 5, Message::HalfRated)
+                    .allow_half(true)
+                    .icon_size(32.0),
+            )
+            .push(Text::new("Read only"))
+            .push(Rating::read_only(4.5, 5))
+            .spacing(10)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
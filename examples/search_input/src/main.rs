@@ -0,0 +1,84 @@
+use iced::{
+    widget::{Column, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+use std::time::Duration;
+
+use iced_aw::SearchInput;
+
+fn main() -> iced::Result {
+    SearchInputExample::run(Settings {
+        fonts: vec![iced_aw::BOOTSTRAP_FONT_BYTES.into()],
+        ..Settings::default()
+    })
+}
+
+const FRUITS: [&str; 8] = [
+    "Apple",
+    "Banana",
+    "Cherry",
+    "Grape",
+    "Lemon",
+    "Mango",
+    "Orange",
+    "Strawberry",
+];
+
+#[derive(Clone, Debug)]
+enum Message {
+    Search(String),
+    Cleared,
+}
+
+struct SearchInputExample {
+    query: String,
+    cleared: u32,
+}
+
+impl Sandbox for SearchInputExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            query: String::new(),
+            cleared: 0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Search input example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Search(query) => self.query = query,
+            Message::Cleared => self.cleared += 1,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let query = self.query.to_lowercase();
+        let results = FRUITS
+            .iter()
+            .filter(|fruit| fruit.to_lowercase().contains(&query))
+            .fold(Column::new().spacing(5), |column, fruit| {
+                column.push(Text::new(*fruit))
+            });
+
+        Column::new()
+            .push(
+                SearchInput::new(&self.query, Message::Search)
+                    .placeholder("Search fruits...")
+                    .debounce(Duration::from_millis(300))
+                    .on_clear(Message::Cleared)
+                    .width(300),
+            )
+            .push(Text::new(format!("Cleared {} times", self.cleared)))
+            .push(results)
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
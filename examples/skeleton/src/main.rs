@@ -0,0 +1,80 @@
+use iced::{
+    widget::{Column, Row, Text, Toggler},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Skeleton;
+
+fn main() -> iced::Result {
+    SkeletonExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Loaded(bool),
+}
+
+struct SkeletonExample {
+    loaded: bool,
+}
+
+impl Sandbox for SkeletonExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self { loaded: false }
+    }
+
+    fn title(&self) -> String {
+        String::from("Skeleton example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Loaded(loaded) => self.loaded = loaded,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let card: Element<'_, Message> = if self.loaded {
+            Column::new()
+                .push(
+                    Row::new()
+                        .push(Text::new("JD").size(30))
+                        .push(Text::new("Jane Doe").size(20))
+                        .spacing(10)
+                        .align_items(Alignment::Center),
+                )
+                .push(Text::new(
+                    "The content has arrived. Until then the skeletons above \
+                     have shown where it is going to appear.",
+                ))
+                .spacing(20)
+                .into()
+        } else {
+            Column::new()
+                .push(
+                    Row::new()
+                        .push(Skeleton::circle(40.0))
+                        .push(Skeleton::new(Length::Fixed(120.0), Length::Fixed(20.0)))
+                        .spacing(10)
+                        .align_items(Alignment::Center),
+                )
+                .push(Skeleton::text(3, 0.6))
+                .spacing(20)
+                .into()
+        };
+
+        Column::new()
+            .push(Toggler::new(
+                String::from("Loaded"),
+                self.loaded,
+                Message::Loaded,
+            ))
+            .push(card)
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .into()
+    }
+}
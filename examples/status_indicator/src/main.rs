@@ -0,0 +1,110 @@
+use iced::{
+    widget::{Column, PickList, Row, Text, Toggler},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{
+    floating_element::Anchor, status_indicator::Status, Avatar, FloatingElement, StatusIndicator,
+};
+
+fn main() -> iced::Result {
+    StatusIndicatorExample::run(Settings::default())
+}
+
+/// The statuses that can be picked.
+const STATUSES: [Status; 4] = [Status::Online, Status::Away, Status::Busy, Status::Offline];
+
+/// A picked status with a name for the pick list.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Choice(Status);
+
+impl std::fmt::Display for Choice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self.0 {
+            Status::Online => "Online",
+            Status::Away => "Away",
+            Status::Busy => "Busy",
+            Status::Offline => "Offline",
+            Status::Custom(_) => "Custom",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    StatusPicked(Choice),
+    Pulse(bool),
+}
+
+struct StatusIndicatorExample {
+    status: Status,
+    pulse: bool,
+}
+
+impl Sandbox for StatusIndicatorExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            status: Status::Online,
+            pulse: true,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Status indicator example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::StatusPicked(Choice(status)) => self.status = status,
+            Message::Pulse(pulse) => self.pulse = pulse,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let avatar = FloatingElement::new(
+            Avatar::from_initials("JD").size(64.0),
+            StatusIndicator::new(self.status)
+                .size(16.0)
+                .pulse(self.pulse),
+        )
+        .anchor(Anchor::SouthEast);
+
+        let statuses = Row::new()
+            .push(StatusIndicator::new(Status::Online))
+            .push(Text::new("Online"))
+            .push(StatusIndicator::new(Status::Away))
+            .push(Text::new("Away"))
+            .push(StatusIndicator::new(Status::Busy))
+            .push(Text::new("Busy"))
+            .push(StatusIndicator::new(Status::Offline))
+            .push(Text::new("Offline"))
+            .push(StatusIndicator::new(Status::Custom(iced::Color::from_rgb(
+                0.5, 0.3, 0.9,
+            ))))
+            .push(Text::new("Custom"))
+            .spacing(10)
+            .align_items(Alignment::Center);
+
+        Column::new()
+            .push(avatar)
+            .push(PickList::new(
+                STATUSES.map(Choice).to_vec(),
+                Some(Choice(self.status)),
+                Message::StatusPicked,
+            ))
+            .push(Toggler::new(
+                String::from("Pulse while online"),
+                self.pulse,
+                Message::Pulse,
+            ))
+            .push(statuses)
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
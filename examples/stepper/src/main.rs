@@ -0,0 +1,89 @@
+use iced::{
+    widget::{Button, Column, Row, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{StepLabel, Stepper, StepperOrientation};
+
+fn main() -> iced::Result {
+    StepperExample::run(Settings {
+        fonts: vec![iced_aw::BOOTSTRAP_FONT_BYTES.into()],
+        ..Settings::default()
+    })
+}
+
+const STEPS: [&str; 4] = ["Cart", "Shipping", "Payment", "Confirmation"];
+
+#[derive(Clone, Debug)]
+enum Message {
+    GoTo(usize),
+    Back,
+    Next,
+    ToggleError,
+}
+
+struct StepperExample {
+    current: usize,
+    payment_failed: bool,
+}
+
+impl StepperExample {
+    fn steps(&self) -> Vec<StepLabel> {
+        STEPS
+            .iter()
+            .map(|step| StepLabel::new(*step).error(self.payment_failed && *step == "Payment"))
+            .collect()
+    }
+}
+
+impl Sandbox for StepperExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            current: 1,
+            payment_failed: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Stepper example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::GoTo(step) => self.current = step,
+            Message::Back => self.current = self.current.saturating_sub(1),
+            Message::Next => self.current = (self.current + 1).min(STEPS.len()),
+            Message::ToggleError => self.payment_failed = !self.payment_failed,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        Column::new()
+            .push(Text::new(format!(
+                "Step {} of {}",
+                (self.current + 1).min(STEPS.len()),
+                STEPS.len()
+            )))
+            .push(Stepper::new(self.steps(), self.current).on_step_click(Message::GoTo))
+            .push(
+                Row::new()
+                    .push(Button::new("Back").on_press(Message::Back))
+                    .push(Button::new("Next").on_press(Message::Next))
+                    .push(Button::new("Toggle payment error").on_press(Message::ToggleError))
+                    .spacing(10),
+            )
+            .push(Text::new("Vertical"))
+            .push(
+                Stepper::new(self.steps(), self.current)
+                    .orientation(StepperOrientation::Vertical)
+                    .on_step_click(Message::GoTo),
+            )
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
@@ -183,6 +183,8 @@ impl Application for TabBarExample {
                     .tab_bar_position(match position {
                         TabBarPosition::Top => iced_aw::TabBarPosition::Top,
                         TabBarPosition::Bottom => iced_aw::TabBarPosition::Bottom,
+                        TabBarPosition::Left => iced_aw::TabBarPosition::Left,
+                        TabBarPosition::Right => iced_aw::TabBarPosition::Right,
                     })
                     .into()
             }
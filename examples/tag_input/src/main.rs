@@ -0,0 +1,62 @@
+use iced::{
+    widget::{Column, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::TagInput;
+
+fn main() -> iced::Result {
+    TagInputExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    TagsChanged(Vec<String>),
+    Submitted,
+}
+
+struct TagInputExample {
+    tags: Vec<String>,
+    submitted: usize,
+}
+
+impl Sandbox for TagInputExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            tags: vec![String::from("rust"), String::from("iced")],
+            submitted: 0,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Tag input example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::TagsChanged(tags) => self.tags = tags,
+            Message::Submitted => self.submitted += 1,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        Column::new()
+            .push(
+                TagInput::new(self.tags.clone(), Message::TagsChanged)
+                    .placeholder("Add a tag...")
+                    .max_tags(8)
+                    .allowed_chars(|c| c.is_alphanumeric() || c == '-')
+                    .on_submit(Message::Submitted)
+                    .width(400),
+            )
+            .push(Text::new(format!("Tags: {}", self.tags.join(", "))))
+            .push(Text::new(format!("Submitted {} times", self.submitted)))
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
@@ -106,7 +106,7 @@ impl Application for TimePickerExample {
                     Message::CancelTime,
                     Message::SubmitTime,
                 )
-                //.show_seconds()
+                //.show_seconds(true)
                 .use_24h();
 
                 let row = Row::new()
@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use iced::{
+    widget::{Button, Column, Row, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{Toast, ToastContainer, ToastPosition, ToastStyles};
+
+fn main() -> iced::Result {
+    ToastExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Add,
+    Close(usize),
+    Undo(usize),
+    Position(ToastPosition),
+}
+
+#[derive(Default)]
+struct ToastExample {
+    toasts: Vec<usize>,
+    next: usize,
+    last_undo: Option<usize>,
+    position: ToastPosition,
+}
+
+impl Sandbox for ToastExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("Toast example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Add => {
+                self.toasts.push(self.next);
+                self.next += 1;
+            }
+            Message::Close(index) => {
+                self.toasts.remove(index);
+            }
+            Message::Undo(id) => {
+                self.last_undo = Some(id);
+                self.toasts.retain(|toast| *toast != id);
+            }
+            Message::Position(position) => self.position = position,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let positions = [
+            ("Top left", ToastPosition::TopLeft),
+            ("Top center", ToastPosition::TopCenter),
+            ("Top right", ToastPosition::TopRight),
+            ("Bottom left", ToastPosition::BottomLeft),
+            ("Bottom center", ToastPosition::BottomCenter),
+            ("Bottom right", ToastPosition::BottomRight),
+        ];
+
+        let position_row =
+            positions
+                .into_iter()
+                .fold(Row::new().spacing(10), |row, (label, position)| {
+                    row.push(Button::new(Text::new(label)).on_press(Message::Position(position)))
+                });
+
+        let content = Column::new()
+            .padding(20)
+            .spacing(10)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_items(Alignment::Center)
+            .push(Button::new(Text::new("Add toast")).on_press(Message::Add))
+            .push(position_row)
+            .push(Text::new(match self.last_undo {
+                Some(id) => format!("Undone: {id}"),
+                None => String::from("Nothing undone yet"),
+            }));
+
+        let toasts = self
+            .toasts
+            .iter()
+            .map(|id| {
+                Toast::new(Text::new(format!("Toast #{id}")))
+                    .id(id)
+                    .timeout(Duration::from_secs(5))
+                    .action("Undo", Message::Undo(*id))
+                    .style(if id % 2 == 0 {
+                        ToastStyles::Default
+                    } else {
+                        ToastStyles::Primary
+                    })
+            })
+            .collect();
+
+        ToastContainer::new(content, toasts, Message::Close)
+            .position(self.position)
+            .into()
+    }
+}
@@ -0,0 +1,67 @@
+use iced::{
+    widget::{Column, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::Toggle;
+
+fn main() -> iced::Result {
+    ToggleExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    WifiToggled(bool),
+    BluetoothToggled(bool),
+}
+
+struct ToggleExample {
+    wifi: bool,
+    bluetooth: bool,
+}
+
+impl Sandbox for ToggleExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            wifi: true,
+            bluetooth: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Toggle example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::WifiToggled(is_on) => self.wifi = is_on,
+            Message::BluetoothToggled(is_on) => self.bluetooth = is_on,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        Column::new()
+            .push(Toggle::new(self.wifi, Message::WifiToggled).label("Wi-Fi"))
+            .push(
+                Toggle::new(self.bluetooth, Message::BluetoothToggled)
+                    .label("Bluetooth")
+                    .width(60.0),
+            )
+            .push(
+                Toggle::new(self.wifi, Message::WifiToggled)
+                    .label("Airplane mode (disabled)")
+                    .disabled(true),
+            )
+            .push(Text::new(format!(
+                "Wi-Fi: {}, Bluetooth: {}",
+                self.wifi, self.bluetooth
+            )))
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use iced::{
+    widget::{Button, Column, Row, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{Tooltip, TooltipPosition};
+
+fn main() -> iced::Result {
+    TooltipExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Pressed(TooltipPosition),
+}
+
+#[derive(Default)]
+struct TooltipExample {
+    last_pressed: Option<TooltipPosition>,
+}
+
+impl Sandbox for TooltipExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("Tooltip example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Pressed(position) => self.last_pressed = Some(position),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let buttons = Row::with_children(
+            [
+                TooltipPosition::Top,
+                TooltipPosition::Bottom,
+                TooltipPosition::Left,
+                TooltipPosition::Right,
+                TooltipPosition::FollowCursor,
+            ]
+            .map(|position| {
+                Tooltip::new(
+                    Button::new(Text::new(format!("{position:?}")))
+                        .on_press(Message::Pressed(position)),
+                    Text::new(format!("A tooltip at {position:?}")),
+                )
+                .position(position)
+                .delay(Duration::from_millis(300))
+                .into()
+            }),
+        )
+        .spacing(20);
+
+        Column::new()
+            .push(Text::new(format!("Last pressed: {:?}", self.last_pressed)))
+            .push(buttons)
+            .spacing(40)
+            .padding(40)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
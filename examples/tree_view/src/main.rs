@@ -0,0 +1,106 @@
+use iced::{
+    widget::{Button, Column, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::{NodeId, TreeNode, TreeView};
+
+fn main() -> iced::Result {
+    TreeViewExample::run(Settings {
+        fonts: vec![iced_aw::BOOTSTRAP_FONT_BYTES.into()],
+        ..Settings::default()
+    })
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Expand(NodeId),
+    Select(NodeId),
+    FinishLoading,
+}
+
+struct TreeViewExample {
+    root: TreeNode<String>,
+    loading: Vec<NodeId>,
+    selected: Option<String>,
+}
+
+/// A folder whose content is loaded once it's expanded.
+fn folder(name: &str) -> TreeNode<String> {
+    TreeNode::new(name.to_owned()).loading(true)
+}
+
+impl Sandbox for TreeViewExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            root: TreeNode::new(String::from("Home")).children(vec![
+                folder("Documents"),
+                folder("Pictures"),
+                TreeNode::new(String::from("notes.txt")),
+            ]),
+            loading: Vec::new(),
+            selected: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Tree view example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Expand(id) => {
+                let is_loading = self
+                    .root
+                    .get(&id)
+                    .is_some_and(|node| node.is_loading && node.children.is_empty());
+
+                if is_loading && !self.loading.contains(&id) {
+                    self.loading.push(id);
+                }
+            }
+            Message::Select(id) => {
+                self.selected = self.root.get(&id).map(|node| node.value.clone());
+            }
+            Message::FinishLoading => {
+                for id in self.loading.drain(..) {
+                    if let Some(node) = self.root.get_mut(&id) {
+                        node.children = vec![
+                            folder(&format!("{} 1", node.value)),
+                            TreeNode::new(format!("{} 2.txt", node.value)),
+                        ];
+                        node.is_loading = false;
+                    }
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let finish = Button::new(Text::new("Finish loading"));
+
+        Column::new()
+            .push(
+                TreeView::new(self.root.clone())
+                    .on_expand(Message::Expand)
+                    .on_select(Message::Select)
+                    .render_node(|value| Text::new(value.clone()).into()),
+            )
+            .push(Text::new(format!(
+                "Selected: {}",
+                self.selected.as_deref().unwrap_or("nothing")
+            )))
+            .push(if self.loading.is_empty() {
+                finish
+            } else {
+                finish.on_press(Message::FinishLoading)
+            })
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
@@ -0,0 +1,59 @@
+use iced::{
+    widget::{Column, Container, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+
+use iced_aw::VirtualList;
+
+fn main() -> iced::Result {
+    VirtualListExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+enum Message {
+    Scrolled(f32),
+}
+
+struct VirtualListExample {
+    offset: f32,
+}
+
+impl Sandbox for VirtualListExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self { offset: 0.0 }
+    }
+
+    fn title(&self) -> String {
+        String::from("Virtual list example")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::Scrolled(offset) => self.offset = offset,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let list = VirtualList::new(1_000_000, 30.0, |index| {
+            Container::new(Text::new(format!("Item {index}")))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .center_y()
+                .into()
+        })
+        .padding(10)
+        .on_scroll(Message::Scrolled)
+        .height(400);
+
+        Column::new()
+            .push(Text::new(format!("Scrolled: {:.0}px", self.offset)))
+            .push(list)
+            .spacing(20)
+            .padding(20)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
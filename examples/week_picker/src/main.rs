@@ -0,0 +1,125 @@
+use iced::{
+    alignment, font,
+    widget::{container, text, Button, Container, Row, Text},
+    Alignment, Application, Command, Element, Length, Settings, Theme,
+};
+use iced_aw::helpers::week_picker;
+
+fn main() -> iced::Result {
+    WeekPickerExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+#[allow(clippy::enum_variant_names)]
+enum Message {
+    ChooseWeek,
+    SubmitWeek(i32, u8),
+    CancelWeek,
+    #[allow(dead_code)]
+    Loaded(Result<(), String>),
+    FontLoaded(Result<(), font::Error>),
+}
+
+enum WeekPickerExample {
+    Loading,
+    Loaded(State),
+}
+
+struct State {
+    week: Option<(i32, u8)>,
+    show_picker: bool,
+}
+
+async fn load() -> Result<(), String> {
+    Ok(())
+}
+
+impl Application for WeekPickerExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (WeekPickerExample, Command<Message>) {
+        (
+            WeekPickerExample::Loading,
+            Command::batch(vec![
+                font::load(iced_aw::BOOTSTRAP_FONT_BYTES).map(Message::FontLoaded),
+                Command::perform(load(), Message::Loaded),
+            ]),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("WeekPicker example")
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Message> {
+        match self {
+            WeekPickerExample::Loading => {
+                if let Message::Loaded(_) = message {
+                    *self = WeekPickerExample::Loaded(State {
+                        week: None,
+                        show_picker: false,
+                    })
+                }
+            }
+            WeekPickerExample::Loaded(state) => match message {
+                Message::ChooseWeek => {
+                    state.show_picker = true;
+                }
+                Message::SubmitWeek(year, week) => {
+                    state.week = Some((year, week));
+                    state.show_picker = false;
+                }
+                Message::CancelWeek => {
+                    state.show_picker = false;
+                }
+                _ => {}
+            },
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match self {
+            WeekPickerExample::Loading => container(
+                text("Loading...")
+                    .horizontal_alignment(alignment::Horizontal::Center)
+                    .size(50),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_y()
+            .center_x()
+            .into(),
+            WeekPickerExample::Loaded(state) => {
+                let but = Button::new(Text::new("Set Week")).on_press(Message::ChooseWeek);
+
+                let weekpicker = week_picker(
+                    state.show_picker,
+                    but,
+                    Message::CancelWeek,
+                    Message::SubmitWeek,
+                );
+
+                let row = Row::new()
+                    .align_items(Alignment::Center)
+                    .spacing(10)
+                    .push(weekpicker)
+                    .push(Text::new(match state.week {
+                        Some((year, week)) => format!("Week: {year}-W{week:02}"),
+                        None => String::from("No week picked"),
+                    }));
+
+                Container::new(row)
+                    .center_x()
+                    .center_y()
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into()
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use iced::{
+    alignment, font,
+    widget::{container, text, Button, Container, Row, Text},
+    Alignment, Application, Command, Element, Length, Settings, Theme,
+};
+use iced_aw::helpers::year_picker;
+
+fn main() -> iced::Result {
+    YearPickerExample::run(Settings::default())
+}
+
+#[derive(Clone, Debug)]
+#[allow(clippy::enum_variant_names)]
+enum Message {
+    ChooseYear,
+    SubmitYear(i32),
+    CancelYear,
+    #[allow(dead_code)]
+    Loaded(Result<(), String>),
+    FontLoaded(Result<(), font::Error>),
+}
+
+enum YearPickerExample {
+    Loading,
+    Loaded(State),
+}
+
+struct State {
+    year: Option<i32>,
+    show_picker: bool,
+}
+
+async fn load() -> Result<(), String> {
+    Ok(())
+}
+
+impl Application for YearPickerExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (YearPickerExample, Command<Message>) {
+        (
+            YearPickerExample::Loading,
+            Command::batch(vec![
+                font::load(iced_aw::BOOTSTRAP_FONT_BYTES).map(Message::FontLoaded),
+                Command::perform(load(), Message::Loaded),
+            ]),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("YearPicker example")
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Message> {
+        match self {
+            YearPickerExample::Loading => {
+                if let Message::Loaded(_) = message {
+                    *self = YearPickerExample::Loaded(State {
+                        year: None,
+                        show_picker: false,
+                    })
+                }
+            }
+            YearPickerExample::Loaded(state) => match message {
+                Message::ChooseYear => {
+                    state.show_picker = true;
+                }
+                Message::SubmitYear(year) => {
+                    state.year = Some(year);
+                    state.show_picker = false;
+                }
+                Message::CancelYear => {
+                    state.show_picker = false;
+                }
+                _ => {}
+            },
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match self {
+            YearPickerExample::Loading => container(
+                text("Loading...")
+                    .horizontal_alignment(alignment::Horizontal::Center)
+                    .size(50),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_y()
+            .center_x()
+            .into(),
+            YearPickerExample::Loaded(state) => {
+                let but = Button::new(Text::new("Set Year")).on_press(Message::ChooseYear);
+
+                let yearpicker = year_picker(
+                    state.show_picker,
+                    but,
+                    Message::CancelYear,
+                    Message::SubmitYear,
+                );
+
+                let row = Row::new()
+                    .align_items(Alignment::Center)
+                    .spacing(10)
+                    .push(yearpicker)
+                    .push(Text::new(match state.year {
+                        Some(year) => format!("Year: {year}"),
+                        None => String::from("No year picked"),
+                    }));
+
+                Container::new(row)
+                    .center_x()
+                    .center_y()
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into()
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Derive macros for implementing the style sheets of the widgets of `iced_aw`.
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, Path, Type};
+
+/// Implements a style sheet of a widget for a struct holding an appearance for
+/// each method of the style sheet.
+///
+/// The style sheet is given by the `#[style_sheet(...)]` attribute on the
+/// struct. Every named field implements the method of the same name by
+/// returning a clone of its value, unless the field is marked with
+/// `#[style_sheet(skip)]`. Methods without a field keep their default
+/// implementation. The `Style` of the style sheet is [`iced::Theme`] unless
+/// another one is given by `#[style_sheet(style = ...)]`.
+///
+/// ```ignore
+/// use iced_aw::style::chip::{Appearance, StyleSheet};
+///
+/// #[derive(iced_aw::StyleSheet)]
+/// #[style_sheet(StyleSheet)]
+/// struct ChipStyle {
+///     active: Appearance,
+///     selected: Appearance,
+///     hovered: Appearance,
+///     disabled: Appearance,
+/// }
+/// ```
+#[proc_macro_derive(StyleSheet, attributes(style_sheet))]
+pub fn derive_style_sheet(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    style_sheet(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Generates the implementation of the style sheet for the input.
+fn style_sheet(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut style_sheet: Option<Path> = None;
+    let mut style: Option<Type> = None;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("style_sheet"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("style") {
+                style = Some(meta.value()?.parse()?);
+            } else {
+                style_sheet = Some(meta.path);
+            }
+            Ok(())
+        })?;
+    }
+
+    let style_sheet = style_sheet.ok_or_else(|| {
+        Error::new(
+            input.ident.span(),
+            "the style sheet is missing, e.g. `#[style_sheet(iced_aw::style::chip::StyleSheet)]`",
+        )
+    })?;
+    let style = style.unwrap_or_else(|| syn::parse_quote!(::iced::Theme));
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "a style sheet can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            data.fields.span(),
+            "a style sheet can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut methods = Vec::new();
+    for field in &fields.named {
+        let mut skip = false;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("style_sheet"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip`"))
+                }
+            })?;
+        }
+
+        if skip {
+            continue;
+        }
+
+        let name = &field.ident;
+        let ty = &field.ty;
+        methods.push(quote! {
+            fn #name(&self, _style: &Self::Style) -> #ty {
+                ::core::clone::Clone::clone(&self.#name)
+            }
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #style_sheet for #ident #ty_generics #where_clause {
+            type Style = #style;
+
+            #(#methods)*
+        }
+    })
+}
@@ -4,6 +4,12 @@ use cfg_if::cfg_if;
 #[cfg(feature = "date_picker")]
 pub mod date;
 
+#[cfg(feature = "date_time_picker")]
+pub mod date_time;
+
+#[cfg(any(feature = "date_time_picker", feature = "duration_picker"))]
+pub mod duration;
+
 #[cfg(feature = "time_picker")]
 pub mod clock;
 
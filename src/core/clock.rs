@@ -121,11 +121,60 @@ pub fn nearest_radius(
     distance_vec[0].1.clone()
 }
 
+/// Snaps the minute or second `value` to the nearest multiple of `step` on
+/// the clock face.
+#[must_use]
+pub fn snap_to_step(value: u32, step: u32) -> u32 {
+    let step = step.clamp(1, 60);
+    let lower = value / step * step;
+    let upper = lower + step;
+
+    // The upper multiple wraps around the clock face to zero.
+    let upper = if upper >= 60 { 0 } else { upper };
+    let upper_distance = if upper == 0 {
+        60 - value
+    } else {
+        upper - value
+    };
+    if upper_distance < value - lower {
+        upper
+    } else {
+        lower
+    }
+}
+
+/// Moves the minute or second `value` to the next or previous multiple of
+/// `step` on the clock face.
+///
+/// Returns the new value and the carry (-1, 0 or 1) for the next larger unit.
+#[must_use]
+pub fn step_value(value: u32, step: u32, forward: bool) -> (u32, i64) {
+    let step = step.clamp(1, 60);
+    let lower = value / step * step;
+
+    if forward {
+        let next = lower + step;
+        if next < 60 {
+            (next, 0)
+        } else {
+            (0, 1)
+        }
+    } else if value != lower {
+        (lower, 0)
+    } else if lower >= step {
+        (lower - step, 0)
+    } else {
+        (59 / step * step, -1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use iced::{Point, Vector};
 
-    use super::{circle_points, nearest_point, nearest_radius, NearestRadius};
+    use super::{
+        circle_points, nearest_point, nearest_radius, snap_to_step, step_value, NearestRadius,
+    };
 
     #[test]
     fn circle_points_test() {
@@ -215,4 +264,28 @@ mod tests {
         result = nearest_point(&points, cursor_position);
         assert_eq!(index, result);
     }
+
+    #[test]
+    fn snap_to_step_test() {
+        assert_eq!(snap_to_step(7, 1), 7);
+        assert_eq!(snap_to_step(7, 15), 0);
+        assert_eq!(snap_to_step(8, 15), 15);
+        assert_eq!(snap_to_step(53, 15), 0);
+        assert_eq!(snap_to_step(59, 25), 0);
+        assert_eq!(snap_to_step(40, 25), 50);
+        assert_eq!(snap_to_step(36, 25), 25);
+    }
+
+    #[test]
+    fn step_value_test() {
+        assert_eq!(step_value(7, 1, true), (8, 0));
+        assert_eq!(step_value(59, 1, true), (0, 1));
+        assert_eq!(step_value(0, 1, false), (59, -1));
+        assert_eq!(step_value(7, 15, true), (15, 0));
+        assert_eq!(step_value(7, 15, false), (0, 0));
+        assert_eq!(step_value(15, 15, false), (0, 0));
+        assert_eq!(step_value(45, 15, true), (0, 1));
+        assert_eq!(step_value(0, 15, false), (45, -1));
+        assert_eq!(step_value(56, 7, true), (0, 1));
+    }
 }
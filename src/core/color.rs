@@ -25,6 +25,29 @@ impl Hsv {
     }
 }
 
+/// A color in the HSL color space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl {
+    /// HSL hue.
+    pub(crate) hue: u16,
+    /// HSL saturation.
+    pub(crate) saturation: f32,
+    /// HSL lightness.
+    pub(crate) lightness: f32,
+}
+
+impl Hsl {
+    /// Creates a [`Hsl`] from its HSL components.
+    #[must_use]
+    pub const fn from_hsl(hue: u16, saturation: f32, lightness: f32) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+}
+
 /// Creates a string of hexadecimal characters.
 pub trait HexString {
     /// Turns self into a string of hexadecimal characters.
@@ -43,6 +66,31 @@ impl HexString for Color {
     }
 }
 
+/// Parses a color from a `#RRGGBB` or `#RRGGBBAA` string of hexadecimal characters.
+///
+/// The leading `#` is optional. Returns `None` if the string is not a valid color.
+#[must_use]
+pub fn color_from_hex(hex: &str) -> Option<Color> {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+    if !(hex.len() == 6 || hex.len() == 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |i: usize| {
+        u8::from_str_radix(&hex[i..i + 2], 16)
+            .ok()
+            .map(|value| f32::from(value) / 255.0)
+    };
+
+    Some(Color::from_rgba(
+        channel(0)?,
+        channel(2)?,
+        channel(4)?,
+        if hex.len() == 8 { channel(6)? } else { 1.0 },
+    ))
+}
+
 impl From<Color> for Hsv {
     // https://de.wikipedia.org/wiki/HSV-Farbraum#Umrechnung_RGB_in_HSV/HSL
     fn from(color: Color) -> Self {
@@ -97,11 +145,114 @@ impl From<Hsv> for Color {
     }
 }
 
+impl From<Color> for Hsl {
+    // https://de.wikipedia.org/wiki/HSV-Farbraum#Umrechnung_RGB_in_HSV/HSL
+    fn from(color: Color) -> Self {
+        let max = color.r.max(color.g.max(color.b));
+        let min = color.r.min(color.g.min(color.b));
+
+        let hue = Hsv::from(color).hue;
+
+        let lightness = f32::midpoint(max, min);
+
+        let saturation = if (max - min).abs() < f32::EPSILON {
+            0.0
+        } else {
+            (max - min) / (1.0 - 2.0f32.mul_add(lightness, -1.0).abs())
+        };
+
+        Self {
+            hue,
+            saturation: saturation.clamp(0.0, 1.0),
+            lightness,
+        }
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        // https://de.wikipedia.org/wiki/HSV-Farbraum#Umrechnung_HSL_in_RGB
+        let chroma = (1.0 - 2.0f32.mul_add(hsl.lightness, -1.0).abs()) * hsl.saturation;
+        let h = f32::from(hsl.hue % 360) / 60.0;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let m = hsl.lightness - chroma / 2.0;
+
+        let (red, green, blue) = match h as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        Self::from_rgb(red + m, green + m, blue + m)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use iced::Color;
 
-    use super::Hsv;
+    use super::{color_from_hex, HexString, Hsl, Hsv};
+
+    #[test]
+    fn rgb_to_hsl() {
+        let red_rgb = Color::from_rgb(1.0, 0.0, 0.0);
+        assert_eq!(Hsl::from_hsl(0, 1.0, 0.5), red_rgb.into());
+
+        let dark_green_rgb = Color::from_rgb(0.0, 0.5, 0.0);
+        assert_eq!(Hsl::from_hsl(120, 1.0, 0.25), dark_green_rgb.into());
+
+        let light_blue_rgb = Color::from_rgb(0.5, 0.5, 1.0);
+        assert_eq!(Hsl::from_hsl(240, 1.0, 0.75), light_blue_rgb.into());
+
+        let gray_rgb = Color::from_rgb(0.5, 0.5, 0.5);
+        assert_eq!(Hsl::from_hsl(0, 0.0, 0.5), gray_rgb.into());
+
+        let white_rgb = Color::from_rgb(1.0, 1.0, 1.0);
+        assert_eq!(Hsl::from_hsl(0, 0.0, 1.0), white_rgb.into());
+    }
+
+    #[test]
+    fn hsl_to_rgb() {
+        let red_hsl = Hsl::from_hsl(0, 1.0, 0.5);
+        assert_eq!(Color::from_rgb(1.0, 0.0, 0.0), red_hsl.into());
+
+        let dark_green_hsl = Hsl::from_hsl(120, 1.0, 0.25);
+        assert_eq!(Color::from_rgb(0.0, 0.5, 0.0), dark_green_hsl.into());
+
+        let light_blue_hsl = Hsl::from_hsl(240, 1.0, 0.75);
+        assert_eq!(Color::from_rgb(0.5, 0.5, 1.0), light_blue_hsl.into());
+
+        let black_hsl = Hsl::from_hsl(0, 0.0, 0.0);
+        assert_eq!(Color::from_rgb(0.0, 0.0, 0.0), black_hsl.into());
+
+        let wrapped_hsl = Hsl::from_hsl(360, 1.0, 0.5);
+        assert_eq!(Color::from_rgb(1.0, 0.0, 0.0), wrapped_hsl.into());
+    }
+
+    #[test]
+    fn hex_to_rgb() {
+        assert_eq!(
+            color_from_hex("#FF8000"),
+            Some(Color::from_rgb8(0xFF, 0x80, 0x00))
+        );
+        assert_eq!(
+            color_from_hex("ff800080"),
+            Some(Color::from_rgba8(
+                0xFF,
+                0x80,
+                0x00,
+                f32::from(0x80u8) / 255.0
+            ))
+        );
+        assert_eq!(color_from_hex("#FF80"), None);
+        assert_eq!(color_from_hex("#GG8000"), None);
+
+        let color = Color::from_rgba8(0x12, 0x34, 0x56, f32::from(0x78u8) / 255.0);
+        assert_eq!(color_from_hex(&color.as_hex_string()), Some(color));
+    }
 
     #[allow(clippy::cognitive_complexity)]
     #[test]
@@ -2,10 +2,20 @@
 
 use chrono::{Datelike, Duration, Local, NaiveDate};
 use once_cell::sync::Lazy;
-use std::fmt::Display;
+use std::{
+    fmt::{Display, Write},
+    str::FromStr,
+};
 
 /// The date value
-#[derive(Clone, Copy, Debug, Default)]
+///
+/// Dates are ordered by their position in the calendar.
+///
+/// With the `serde` feature a date is serialized as a map of its `year`, `month`
+/// and `day` and can be deserialized from such a map or, in a human-readable
+/// format, from a `YYYY-MM-DD` string.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Date {
     /// The year value of the date.
     pub year: i32,
@@ -28,6 +38,172 @@ impl Date {
     pub const fn from_ymd(year: i32, month: u32, day: u32) -> Self {
         Self { year, month, day }
     }
+
+    /// Creates a new date from the given [`NaiveDate`].
+    ///
+    /// Every [`NaiveDate`] is in the range of a [`Date`], so this currently
+    /// always returns `Some`.
+    #[must_use]
+    pub fn from_naive_date(date: NaiveDate) -> Option<Self> {
+        Some(date.into())
+    }
+
+    /// Converts the date into a [`NaiveDate`].
+    ///
+    /// Returns `None` if year, month or day doesnt exist instead of panicking
+    /// like the [`From`] conversion.
+    #[must_use]
+    pub fn to_naive_date(self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, self.month, self.day)
+    }
+
+    /// # Panics
+    /// Gets the day of the week of the date.
+    /// panics if year, month or day doesnt exist.
+    #[must_use]
+    pub fn weekday(self) -> Weekday {
+        NaiveDate::from(self).weekday().into()
+    }
+
+    /// # Panics
+    /// Gets the ISO 8601 week number of the date.
+    /// panics if year, month or day doesnt exist.
+    #[must_use]
+    pub fn week_number(self) -> u8 {
+        NaiveDate::from(self).iso_week().week() as u8
+    }
+
+    /// # Panics
+    /// Calculates the date the given number of days after this date.
+    /// Negative numbers calculate a date before this date.
+    /// panics if year, month or day doesnt exist.
+    #[must_use]
+    pub fn add_days(self, n: i32) -> Self {
+        (NaiveDate::from(self) + Duration::days(i64::from(n))).into()
+    }
+
+    /// # Panics
+    /// Calculates the date the given number of days before this date.
+    /// panics if year, month or day doesnt exist.
+    #[must_use]
+    pub fn sub_days(self, n: u32) -> Self {
+        (NaiveDate::from(self) - Duration::days(i64::from(n))).into()
+    }
+
+    /// # Panics
+    /// Formats the date with the given format string.
+    ///
+    /// The following tokens are replaced, everything else is copied as is:
+    ///     * `%Y`: the year (at least four digits).
+    ///     * `%m`: the month (`01` - `12`).
+    ///     * `%d`: the day (`01` - `31`).
+    ///     * `%b` / `%B`: the abbreviated / full English month name.
+    ///     * `%a` / `%A`: the abbreviated / full English weekday name.
+    ///     * `%%`: a literal `%`.
+    ///
+    /// panics if the weekday is formatted and year, month or day doesnt exist.
+    #[must_use]
+    pub fn format(self, fmt: &str) -> String {
+        let mut formatted = String::with_capacity(fmt.len());
+        let mut chars = fmt.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                formatted.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                // Writing to a `String` cannot fail.
+                Some('Y') => _ = write!(formatted, "{:04}", self.year),
+                Some('m') => _ = write!(formatted, "{:02}", self.month),
+                Some('d') => _ = write!(formatted, "{:02}", self.day),
+                Some('b') => formatted.push_str(&month_name(self.month)[..3]),
+                Some('B') => formatted.push_str(month_name(self.month)),
+                Some('a') => formatted.push_str(&self.weekday().name()[..3]),
+                Some('A') => formatted.push_str(self.weekday().name()),
+                Some('%') | None => formatted.push('%'),
+                Some(other) => {
+                    formatted.push('%');
+                    formatted.push(other);
+                }
+            }
+        }
+
+        formatted
+    }
+
+    /// Parses a date from the given string with the given format string.
+    ///
+    /// The format string supports the same tokens as [`Date::format`]. The year
+    /// is parsed from up to four digits, the month and day from up to two digits.
+    /// Month and weekday names are matched case-insensitively and a parsed
+    /// weekday has to match the parsed date.
+    ///
+    /// # Errors
+    /// Returns a [`ParseError`] if the string does not match the format, if the
+    /// format misses the year, month or day or if the date does not exist.
+    pub fn parse(s: &str, fmt: &str) -> Result<Self, ParseError> {
+        let (mut year, mut month, mut day, mut weekday) = (None, None, None, None);
+        let mut input = s;
+        let mut chars = fmt.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                input = input.strip_prefix(c).ok_or(ParseError::Mismatch)?;
+                continue;
+            }
+
+            match chars.next() {
+                Some('Y') => {
+                    let sign = usize::from(input.starts_with(['-', '+']));
+                    let (value, rest) = split_number(input, sign, 4)?;
+                    year = Some(value);
+                    input = rest;
+                }
+                Some('m') => {
+                    let (value, rest) = split_number(input, 0, 2)?;
+                    month = Some(value);
+                    input = rest;
+                }
+                Some('d') => {
+                    let (value, rest) = split_number(input, 0, 2)?;
+                    day = Some(value);
+                    input = rest;
+                }
+                Some(token @ ('b' | 'B')) => {
+                    let (index, rest) = split_name(input, &MONTH_NAMES, token == 'b')?;
+                    month = Some(index as u32 + 1);
+                    input = rest;
+                }
+                Some(token @ ('a' | 'A')) => {
+                    let (index, rest) = split_name(input, &WEEKDAY_NAMES, token == 'a')?;
+                    weekday = Some(Weekday::ALL[index]);
+                    input = rest;
+                }
+                Some('%') | None => {
+                    input = input.strip_prefix('%').ok_or(ParseError::Mismatch)?;
+                }
+                Some(token) => return Err(ParseError::UnknownToken(token)),
+            }
+        }
+
+        if !input.is_empty() {
+            return Err(ParseError::TrailingInput);
+        }
+
+        let (Some(year), Some(month), Some(day)) = (year, month, day) else {
+            return Err(ParseError::Incomplete);
+        };
+        let date = NaiveDate::from_ymd_opt(year, month, day)
+            .map(Self::from)
+            .ok_or(ParseError::InvalidDate)?;
+
+        match weekday {
+            Some(weekday) if weekday != date.weekday() => Err(ParseError::WeekdayMismatch),
+            _ => Ok(date),
+        }
+    }
 }
 
 impl Display for Date {
@@ -36,6 +212,221 @@ impl Display for Date {
     }
 }
 
+/// The error returned if a [`Date`] cannot be parsed with [`Date::parse`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// The string does not match the format.
+    Mismatch,
+    /// The string contains more characters than the format.
+    TrailingInput,
+    /// The format contains an unsupported token.
+    UnknownToken(char),
+    /// The format does not contain the year, the month or the day.
+    Incomplete,
+    /// The parsed date does not exist.
+    InvalidDate,
+    /// The parsed weekday does not match the parsed date.
+    WeekdayMismatch,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mismatch => f.write_str("the date does not match the format"),
+            Self::TrailingInput => f.write_str("the date contains trailing characters"),
+            Self::UnknownToken(token) => write!(f, "unsupported format token %{token}"),
+            Self::Incomplete => f.write_str("the format misses the year, month or day"),
+            Self::InvalidDate => f.write_str("the date does not exist"),
+            Self::WeekdayMismatch => f.write_str("the weekday does not match the date"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The English names of the months.
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The English names of the weekdays starting with Monday.
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Gets the English name of the given month (1 - 12).
+fn month_name(month: u32) -> &'static str {
+    MONTH_NAMES[(month.clamp(1, 12) - 1) as usize]
+}
+
+/// Gets the abbreviated English name of the given month (1 - 12).
+#[must_use]
+pub fn month_label(month: u32) -> &'static str {
+    &month_name(month)[..3]
+}
+
+/// Splits and parses a number of up to `max_digits` digits following a sign of
+/// `sign_len` characters from the start of the input.
+fn split_number<T: FromStr>(
+    input: &str,
+    sign_len: usize,
+    max_digits: usize,
+) -> Result<(T, &str), ParseError> {
+    let digits = input[sign_len..]
+        .bytes()
+        .take(max_digits)
+        .take_while(u8::is_ascii_digit)
+        .count();
+    let (number, rest) = input.split_at(sign_len + digits);
+
+    number
+        .parse()
+        .ok()
+        .filter(|_| digits > 0)
+        .map(|number| (number, rest))
+        .ok_or(ParseError::Mismatch)
+}
+
+/// Splits one of the given names, or its first three characters if abbreviated,
+/// case-insensitively from the start of the input and returns its index.
+fn split_name<'a>(
+    input: &'a str,
+    names: &[&str],
+    abbreviated: bool,
+) -> Result<(usize, &'a str), ParseError> {
+    names
+        .iter()
+        .enumerate()
+        .find_map(|(index, name)| {
+            let name = if abbreviated { &name[..3] } else { name };
+            input
+                .get(..name.len())
+                .filter(|prefix| prefix.eq_ignore_ascii_case(name))
+                .map(|_| (index, &input[name.len()..]))
+        })
+        .ok_or(ParseError::Mismatch)
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Date {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // Formats that aren't self-describing only read the struct written by `Serialize`.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(DateVisitor)
+        } else {
+            deserializer.deserialize_struct("Date", DATE_FIELDS, DateVisitor)
+        }
+    }
+}
+
+/// The names of the fields of a serialized [`Date`].
+#[cfg(feature = "serde")]
+const DATE_FIELDS: &[&str] = &["year", "month", "day"];
+
+/// The fields of a serialized [`Date`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum DateField {
+    /// The year of the date.
+    Year,
+    /// The month of the date.
+    Month,
+    /// The day of the date.
+    Day,
+}
+
+/// Deserializes a [`Date`] from a `YYYY-MM-DD` string or a `year`, `month`
+/// and `day` map or sequence.
+#[cfg(feature = "serde")]
+struct DateVisitor;
+
+#[cfg(feature = "serde")]
+impl DateVisitor {
+    /// Creates the date checking that it exists.
+    fn date<E: serde::de::Error>(year: i32, month: u32, day: u32) -> Result<Date, E> {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .map(Date::from)
+            .ok_or_else(|| E::custom(format!("invalid date {year:04}-{month:02}-{day:02}")))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for DateVisitor {
+    type Value = Date;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a date string like \"2024-06-15\" or a map of year, month and day")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map(Date::from)
+            .map_err(E::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let year = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+        let month = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+        let day = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(2, &self))?;
+
+        Self::date(year, month, day)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let (mut year, mut month, mut day) = (None, None, None);
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                DateField::Year => year = Some(map.next_value()?),
+                DateField::Month => month = Some(map.next_value()?),
+                DateField::Day => day = Some(map.next_value()?),
+            }
+        }
+
+        Self::date(
+            year.ok_or_else(|| serde::de::Error::missing_field("year"))?,
+            month.ok_or_else(|| serde::de::Error::missing_field("month"))?,
+            day.ok_or_else(|| serde::de::Error::missing_field("day"))?,
+        )
+    }
+}
+
 impl From<Date> for NaiveDate {
     fn from(date: Date) -> Self {
         Self::from_ymd_opt(date.year, date.month, date.day)
@@ -49,6 +440,148 @@ impl From<NaiveDate> for Date {
     }
 }
 
+/// A day of the week.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    /// Monday.
+    #[default]
+    Mon,
+    /// Tuesday.
+    Tue,
+    /// Wednesday.
+    Wed,
+    /// Thursday.
+    Thu,
+    /// Friday.
+    Fri,
+    /// Saturday.
+    Sat,
+    /// Sunday.
+    Sun,
+}
+
+impl Weekday {
+    /// All weekdays starting with Monday.
+    const ALL: [Self; 7] = [
+        Self::Mon,
+        Self::Tue,
+        Self::Wed,
+        Self::Thu,
+        Self::Fri,
+        Self::Sat,
+        Self::Sun,
+    ];
+
+    /// Gets the full English name of the weekday.
+    fn name(self) -> &'static str {
+        WEEKDAY_NAMES[chrono::Weekday::from(self).num_days_from_monday() as usize]
+    }
+}
+
+impl From<chrono::Weekday> for Weekday {
+    fn from(weekday: chrono::Weekday) -> Self {
+        match weekday {
+            chrono::Weekday::Mon => Self::Mon,
+            chrono::Weekday::Tue => Self::Tue,
+            chrono::Weekday::Wed => Self::Wed,
+            chrono::Weekday::Thu => Self::Thu,
+            chrono::Weekday::Fri => Self::Fri,
+            chrono::Weekday::Sat => Self::Sat,
+            chrono::Weekday::Sun => Self::Sun,
+        }
+    }
+}
+
+impl From<Weekday> for chrono::Weekday {
+    fn from(weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Mon => Self::Mon,
+            Weekday::Tue => Self::Tue,
+            Weekday::Wed => Self::Wed,
+            Weekday::Thu => Self::Thu,
+            Weekday::Fri => Self::Fri,
+            Weekday::Sat => Self::Sat,
+            Weekday::Sun => Self::Sun,
+        }
+    }
+}
+
+/// A range of dates including its start and end date.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DateRange {
+    /// The first date of the range.
+    pub start: Date,
+    /// The last date of the range.
+    pub end: Date,
+}
+
+impl DateRange {
+    /// Creates a new range between the given dates.
+    ///
+    /// The dates are swapped if the end lays before the start.
+    #[must_use]
+    pub fn new(start: impl Into<Date>, end: impl Into<Date>) -> Self {
+        let (start, end) = (start.into(), end.into());
+
+        Self {
+            start: start.min(end),
+            end: start.max(end),
+        }
+    }
+
+    /// Checks if the given date lays inside of the range.
+    #[must_use]
+    pub fn contains(&self, date: impl Into<Date>) -> bool {
+        (self.start..=self.end).contains(&date.into())
+    }
+
+    /// Returns an iterator yielding every day of the range.
+    #[must_use]
+    pub fn iter(&self) -> DateRangeIter {
+        DateRangeIter {
+            next: Some(self.start.into()),
+            end: self.end.into(),
+        }
+    }
+}
+
+impl IntoIterator for DateRange {
+    type Item = Date;
+    type IntoIter = DateRangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &DateRange {
+    type Item = Date;
+    type IntoIter = DateRangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the days of a [`DateRange`].
+#[derive(Clone, Debug)]
+pub struct DateRangeIter {
+    /// The next day to yield.
+    next: Option<NaiveDate>,
+    /// The last day to yield.
+    end: NaiveDate,
+}
+
+impl Iterator for DateRangeIter {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let date = self.next.filter(|date| *date <= self.end)?;
+        self.next = date.succ_opt();
+        Some(date.into())
+    }
+}
+
 /// # Panics
 /// Creates a date with the previous month based on the given date.
 /// panics if year, month or day doesnt exist.
@@ -133,6 +666,14 @@ pub fn succ_day(date: NaiveDate) -> NaiveDate {
     date + Duration::days(1)
 }
 
+/// Calculates the range from the Monday to the Sunday of the ISO week of the given date.
+#[must_use]
+pub fn week_range(date: NaiveDate) -> DateRange {
+    let monday = date - Duration::days(i64::from(date.weekday().num_days_from_monday()));
+
+    DateRange::new(monday, monday + Duration::days(6))
+}
+
 /// Specifies if the calculated day lays in the previous, same or next month of
 /// the date.
 
@@ -154,10 +695,27 @@ pub enum IsInMonth {
 /// panics if year, month or day does not exist.
 #[must_use]
 pub fn position_to_day(x: usize, y: usize, year: i32, month: u32) -> (usize, IsInMonth) {
+    position_to_day_from(x, y, year, month, Weekday::Mon)
+}
+
+/// # Panics
+/// Calculates the day number at the given position in the calendar table based
+/// on the given year and month, where the columns start with the given weekday.
+/// panics if year, month or day does not exist.
+#[must_use]
+pub fn position_to_day_from(
+    x: usize,
+    y: usize,
+    year: i32,
+    month: u32,
+    first_day_of_week: Weekday,
+) -> (usize, IsInMonth) {
     let (x, y) = (x as isize, y as isize);
     let first_day =
         NaiveDate::from_ymd_opt(year, month, 1).expect("Year, Month or Day doesnt Exist");
-    let day_of_week = first_day.weekday().num_days_from_monday() as isize;
+    let first_column = chrono::Weekday::from(first_day_of_week).num_days_from_monday();
+    let day_of_week =
+        ((first_day.weekday().num_days_from_monday() + 7 - first_column) % 7) as isize;
     let day_of_week = if day_of_week == 0 { 7 } else { day_of_week };
 
     let day = (x + 7 * y) + 1 - day_of_week;
@@ -190,7 +748,7 @@ const fn is_leap_year(year: i32) -> bool {
 
 /// Gets the number of days the given month in the year has.
 
-const fn num_days_of_month(year: i32, month: u32) -> u32 {
+pub(crate) const fn num_days_of_month(year: i32, month: u32) -> u32 {
     match month {
         4 | 6 | 9 | 11 => 30,
         2 => {
@@ -272,8 +830,9 @@ mod tests {
     use chrono::NaiveDate;
 
     use super::{
-        is_leap_year, num_days_of_month, position_to_day, pred_month, pred_year, succ_month,
-        succ_year, IsInMonth,
+        is_leap_year, num_days_of_month, position_to_day, position_to_day_from, pred_month,
+        pred_year, succ_month, succ_year, week_range, Date, DateRange, IsInMonth, ParseError,
+        Weekday,
     };
 
     #[test]
@@ -412,6 +971,31 @@ mod tests {
         assert_eq!(is_in_month, IsInMonth::Next);
     }
 
+    #[test]
+    fn position_to_day_from_test() {
+        // December 2020 starts on a Tuesday
+        assert_eq!(
+            position_to_day_from(0, 0, 2020, 12, Weekday::Sun),
+            (29, IsInMonth::Previous)
+        );
+        assert_eq!(
+            position_to_day_from(2, 0, 2020, 12, Weekday::Sun),
+            (1, IsInMonth::Same)
+        );
+        assert_eq!(
+            position_to_day_from(0, 0, 2020, 12, Weekday::Tue),
+            (24, IsInMonth::Previous)
+        );
+        assert_eq!(
+            position_to_day_from(0, 1, 2020, 12, Weekday::Tue),
+            (1, IsInMonth::Same)
+        );
+        assert_eq!(
+            position_to_day_from(3, 4, 2020, 12, Weekday::Mon),
+            position_to_day(3, 4, 2020, 12)
+        );
+    }
+
     #[test]
     fn is_leap_year_test() {
         assert!(is_leap_year(2020));
@@ -437,4 +1021,177 @@ mod tests {
         assert_eq!(num_days_of_month(2020, 11), 30);
         assert_eq!(num_days_of_month(2020, 12), 31);
     }
+
+    #[test]
+    fn date_ord_test() {
+        assert!(Date::from_ymd(2020, 12, 31) < Date::from_ymd(2021, 1, 1));
+        assert!(Date::from_ymd(2021, 2, 1) > Date::from_ymd(2021, 1, 31));
+        assert!(Date::from_ymd(2021, 2, 3) > Date::from_ymd(2021, 2, 2));
+        assert_eq!(Date::from_ymd(2021, 2, 3), Date::from_ymd(2021, 2, 3));
+    }
+
+    #[test]
+    fn date_add_sub_days_test() {
+        let date = Date::from_ymd(2020, 2, 28);
+        assert_eq!(date.add_days(1), Date::from_ymd(2020, 2, 29));
+        assert_eq!(date.add_days(2), Date::from_ymd(2020, 3, 1));
+        assert_eq!(date.add_days(-59), Date::from_ymd(2019, 12, 31));
+        assert_eq!(date.add_days(7), Date::from_ymd(2020, 3, 6));
+        assert_eq!(date.sub_days(28), Date::from_ymd(2020, 1, 31));
+        assert_eq!(date.sub_days(0), date);
+    }
+
+    #[test]
+    fn weekday_test() {
+        assert_eq!(Date::from_ymd(2020, 6, 1).weekday(), Weekday::Mon);
+        assert_eq!(Date::from_ymd(2020, 6, 7).weekday(), Weekday::Sun);
+        assert_eq!(Date::from_ymd(2024, 2, 29).weekday(), Weekday::Thu);
+    }
+
+    #[test]
+    fn week_number_test() {
+        assert_eq!(Date::from_ymd(2020, 6, 1).week_number(), 23);
+        assert_eq!(Date::from_ymd(2021, 1, 3).week_number(), 53);
+        assert_eq!(Date::from_ymd(2021, 1, 4).week_number(), 1);
+        assert_eq!(Date::from_ymd(2024, 12, 30).week_number(), 1);
+    }
+
+    #[test]
+    fn week_range_test() {
+        let dates = (30..=31)
+            .map(|day| NaiveDate::from_ymd_opt(2024, 12, day))
+            .chain((1..=5).map(|day| NaiveDate::from_ymd_opt(2025, 1, day)));
+
+        for date in dates {
+            let range = week_range(date.expect("Date should exist"));
+            assert_eq!(range.start, Date::from_ymd(2024, 12, 30));
+            assert_eq!(range.end, Date::from_ymd(2025, 1, 5));
+        }
+    }
+
+    #[test]
+    fn date_naive_date_test() {
+        let naive = NaiveDate::from_ymd_opt(2024, 2, 29).expect("Date should exist");
+        assert_eq!(
+            Date::from_naive_date(naive),
+            Some(Date::from_ymd(2024, 2, 29))
+        );
+        assert_eq!(Date::from_ymd(2024, 2, 29).to_naive_date(), Some(naive));
+        assert_eq!(Date::from_ymd(2023, 2, 29).to_naive_date(), None);
+    }
+
+    #[test]
+    fn date_format_test() {
+        let date = Date::from_ymd(2024, 6, 5);
+        assert_eq!(date.to_string(), "2024-06-05");
+        assert_eq!(date.format("%d.%m.%Y"), "05.06.2024");
+        assert_eq!(date.format("%a, %d %b %Y"), "Wed, 05 Jun 2024");
+        assert_eq!(date.format("%A, %B %d"), "Wednesday, June 05");
+        assert_eq!(date.format("100%% %q"), "100% %q");
+    }
+
+    #[test]
+    fn date_parse_test() {
+        assert_eq!(
+            Date::parse("2024-06-05", "%Y-%m-%d"),
+            Ok(Date::from_ymd(2024, 6, 5))
+        );
+        assert_eq!(
+            Date::parse("5.6.2024", "%d.%m.%Y"),
+            Ok(Date::from_ymd(2024, 6, 5))
+        );
+        assert_eq!(
+            Date::parse("20240605", "%Y%m%d"),
+            Ok(Date::from_ymd(2024, 6, 5))
+        );
+        assert_eq!(
+            Date::parse("wed, 05 JUN 2024", "%a, %d %b %Y"),
+            Ok(Date::from_ymd(2024, 6, 5))
+        );
+        assert_eq!(
+            Date::parse("June 5 2024", "%B %d %Y"),
+            Ok(Date::from_ymd(2024, 6, 5))
+        );
+        assert_eq!(
+            Date::parse("Thu 2024-06-05", "%a %Y-%m-%d"),
+            Err(ParseError::WeekdayMismatch)
+        );
+        assert_eq!(
+            Date::parse("2023-02-29", "%Y-%m-%d"),
+            Err(ParseError::InvalidDate)
+        );
+        assert_eq!(
+            Date::parse("2024/06/05", "%Y-%m-%d"),
+            Err(ParseError::Mismatch)
+        );
+        assert_eq!(
+            Date::parse("2024-06-05x", "%Y-%m-%d"),
+            Err(ParseError::TrailingInput)
+        );
+        assert_eq!(Date::parse("2024-06", "%Y-%m"), Err(ParseError::Incomplete));
+        assert_eq!(
+            Date::parse("2024-06-05", "%Y-%m-%q"),
+            Err(ParseError::UnknownToken('q'))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn date_deserialize_test() {
+        use serde::de::{
+            value::{Error, MapDeserializer, StrDeserializer},
+            Deserialize, IntoDeserializer,
+        };
+
+        let deserializer: StrDeserializer<Error> = "2024-06-15".into_deserializer();
+        assert_eq!(
+            Date::deserialize(deserializer),
+            Ok(Date::from_ymd(2024, 6, 15))
+        );
+
+        let deserializer: StrDeserializer<Error> = "2023-02-29".into_deserializer();
+        assert!(Date::deserialize(deserializer).is_err());
+
+        let deserializer: MapDeserializer<_, Error> =
+            MapDeserializer::new([("year", 2024), ("month", 6), ("day", 15)].into_iter());
+        assert_eq!(
+            Date::deserialize(deserializer),
+            Ok(Date::from_ymd(2024, 6, 15))
+        );
+
+        let deserializer: MapDeserializer<_, Error> =
+            MapDeserializer::new([("year", 2024), ("month", 6)].into_iter());
+        assert!(Date::deserialize(deserializer).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn date_bincode_test() {
+        let date = Date::from_ymd(2024, 6, 15);
+        let bytes = bincode::serialize(&date).expect("Date should be serialized");
+
+        assert_eq!(bincode::deserialize::<Date>(&bytes).ok(), Some(date));
+    }
+
+    #[test]
+    fn date_range_test() {
+        let range = DateRange::new(Date::from_ymd(2020, 3, 2), Date::from_ymd(2020, 2, 27));
+        let days: Vec<(i32, u32, u32)> = range.iter().map(|d| (d.year, d.month, d.day)).collect();
+        assert_eq!(
+            days,
+            vec![
+                (2020, 2, 27),
+                (2020, 2, 28),
+                (2020, 2, 29),
+                (2020, 3, 1),
+                (2020, 3, 2)
+            ]
+        );
+
+        assert!(range.contains(Date::from_ymd(2020, 2, 29)));
+        assert!(!range.contains(Date::from_ymd(2020, 3, 3)));
+
+        let range = DateRange::new(Date::from_ymd(2021, 1, 1), Date::from_ymd(2021, 1, 1));
+        assert_eq!(range.iter().count(), 1);
+    }
 }
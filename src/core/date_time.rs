@@ -0,0 +1,274 @@
+//! Use a date time picker as an input element for picking a date and a time.
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker`*
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::core::{date::Date, duration::Duration, time::Time};
+
+/// The date time value.
+///
+/// Date times are ordered by their date and then by their time of day,
+/// regardless of the period the time is displayed in.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateTime {
+    /// The date value of the date time.
+    pub date: Date,
+    /// The time value of the date time.
+    pub time: Time,
+}
+
+impl DateTime {
+    /// Creates a new date time from the given date and time.
+    #[must_use]
+    pub const fn new(date: Date, time: Time) -> Self {
+        Self { date, time }
+    }
+
+    /// Creates a new date time (hours, minutes, seconds) from the current timestamp.
+    #[must_use]
+    pub fn now(use_24h: bool) -> Self {
+        Self {
+            date: Date::today(),
+            time: Time::now_hms(use_24h),
+        }
+    }
+
+    /// Moves the date time forward by the given duration, keeping the format
+    /// of its time.
+    ///
+    /// Seconds are dropped from the result if the time has no seconds.
+    ///
+    /// # Panics
+    /// Panics if the date or the time is out of range or if the result
+    /// overflows. See [`DateTime::checked_add_duration`].
+    #[must_use]
+    pub fn add_duration(self, duration: Duration) -> Self {
+        self.checked_add_duration(duration)
+            .expect("Date time should be in range after adding the duration")
+    }
+
+    /// Moves the date time back by the given duration, keeping the format
+    /// of its time.
+    ///
+    /// Seconds are dropped from the result if the time has no seconds.
+    ///
+    /// # Panics
+    /// Panics if the date or the time is out of range or if the result
+    /// overflows. See [`DateTime::checked_sub_duration`].
+    #[must_use]
+    pub fn sub_duration(self, duration: Duration) -> Self {
+        self.checked_sub_duration(duration)
+            .expect("Date time should be in range after subtracting the duration")
+    }
+
+    /// Moves the date time forward by the given duration like
+    /// [`DateTime::add_duration`], or returns `None` if the date or the time is
+    /// out of range or if the result overflows.
+    #[must_use]
+    pub fn checked_add_duration(self, duration: Duration) -> Option<Self> {
+        let (hour, minute, second) = self.time.hms();
+        let date_time = NaiveDateTime::new(
+            NaiveDate::from_ymd_opt(self.date.year, self.date.month, self.date.day)?,
+            NaiveTime::from_hms_opt(hour, minute, second)?,
+        )
+        .checked_add_signed(chrono::Duration::try_seconds(duration.num_seconds())?)?;
+
+        Some(Self {
+            date: date_time.date().into(),
+            time: self.time.with_naive_time(date_time.time()),
+        })
+    }
+
+    /// Moves the date time back by the given duration like
+    /// [`DateTime::sub_duration`], or returns `None` if the date or the time is
+    /// out of range or if the result overflows.
+    #[must_use]
+    pub fn checked_sub_duration(self, duration: Duration) -> Option<Self> {
+        self.checked_add_duration(Duration::seconds(duration.num_seconds().checked_neg()?))
+    }
+}
+
+impl PartialEq for DateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for DateTime {}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.date
+            .cmp(&other.date)
+            .then_with(|| self.time.cmp(&other.time))
+    }
+}
+
+impl std::hash::Hash for DateTime {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.date.hash(state);
+        self.time.hash(state);
+    }
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (hour, minute, second) = self.time.hms();
+        write!(f, "{}T{hour:02}:{minute:02}:{second:02}", self.date)
+    }
+}
+
+impl From<DateTime> for NaiveDateTime {
+    fn from(date_time: DateTime) -> Self {
+        Self::new(date_time.date.into(), date_time.time.into())
+    }
+}
+
+impl From<NaiveDateTime> for DateTime {
+    fn from(date_time: NaiveDateTime) -> Self {
+        Self {
+            date: date_time.date().into(),
+            time: date_time.time().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+    use super::DateTime;
+    use crate::core::{
+        date::Date,
+        duration::Duration,
+        time::{Period, Time},
+    };
+
+    #[test]
+    fn date_time_duration_test() {
+        let date_time = DateTime::new(
+            Date::from_ymd(2024, 12, 31),
+            Time::Hm {
+                hour: 11,
+                minute: 30,
+                period: Period::Pm,
+            },
+        );
+
+        let later = date_time.add_duration(Duration::minutes(45));
+        assert_eq!(later.date, Date::from_ymd(2025, 1, 1));
+        assert_eq!(later.to_string(), "2025-01-01T00:15:00");
+        assert!(matches!(
+            later.time,
+            Time::Hm {
+                hour: 12,
+                minute: 15,
+                period: Period::Am,
+            }
+        ));
+
+        assert_eq!(later.sub_duration(Duration::minutes(45)), date_time);
+    }
+
+    #[test]
+    fn date_time_ord_test() {
+        let morning = DateTime::new(
+            Date::from_ymd(2024, 6, 15),
+            Time::Hm {
+                hour: 8,
+                minute: 30,
+                period: Period::Am,
+            },
+        );
+        let evening = DateTime::new(
+            Date::from_ymd(2024, 6, 15),
+            Time::Hm {
+                hour: 8,
+                minute: 30,
+                period: Period::Pm,
+            },
+        );
+        let next_day = DateTime::new(
+            Date::from_ymd(2024, 6, 16),
+            Time::Hm {
+                hour: 0,
+                minute: 0,
+                period: Period::H24,
+            },
+        );
+
+        assert!(morning < evening);
+        assert!(evening < next_day);
+        assert_eq!(
+            evening,
+            DateTime::new(
+                Date::from_ymd(2024, 6, 15),
+                Time::Hms {
+                    hour: 20,
+                    minute: 30,
+                    second: 0,
+                    period: Period::H24,
+                },
+            )
+        );
+    }
+
+    #[test]
+    fn date_time_naive_test() {
+        let naive = NaiveDateTime::new(
+            NaiveDate::from_ymd_opt(2024, 6, 15).expect("Year, Month or Day doesnt Exist"),
+            NaiveTime::from_hms_opt(20, 30, 15).expect("Hour, Minute or Second doesnt Exist"),
+        );
+
+        let date_time = DateTime::from(naive);
+        assert_eq!(date_time.date, Date::from_ymd(2024, 6, 15));
+        assert_eq!(NaiveDateTime::from(date_time), naive);
+    }
+
+    #[test]
+    fn date_time_out_of_range_test() {
+        let invalid = DateTime::new(
+            Date::from_ymd(2024, 6, 15),
+            Time::Hm {
+                hour: 25,
+                minute: 0,
+                period: Period::H24,
+            },
+        );
+
+        assert_eq!(invalid, invalid);
+        assert!(invalid > DateTime::new(Date::from_ymd(2024, 6, 15), Time::midnight()));
+        assert_eq!(invalid.to_string(), "2024-06-15T25:00:00");
+        assert_eq!(invalid.checked_add_duration(Duration::minutes(1)), None);
+
+        let date_time = DateTime::new(Date::from_ymd(2024, 6, 15), Time::midnight());
+        assert_eq!(
+            date_time.checked_add_duration(Duration::seconds(i64::MAX)),
+            None
+        );
+        assert_eq!(
+            date_time.checked_sub_duration(Duration::seconds(i64::MIN)),
+            None
+        );
+        assert_eq!(
+            date_time.checked_sub_duration(Duration::hours(1)),
+            Some(DateTime::new(
+                Date::from_ymd(2024, 6, 14),
+                Time::Hm {
+                    hour: 23,
+                    minute: 0,
+                    period: Period::H24,
+                },
+            ))
+        );
+    }
+}
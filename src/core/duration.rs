@@ -0,0 +1,95 @@
+//! A duration for moving a [`DateTime`](crate::core::date_time::DateTime).
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker` or `duration_picker`*
+
+use std::{
+    fmt::Display,
+    ops::{Add, Neg, Sub},
+};
+
+/// A signed duration with a precision of one second.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Duration {
+    /// The number of seconds of the duration.
+    seconds: i64,
+}
+
+impl Duration {
+    /// The duration of zero seconds.
+    pub const ZERO: Self = Self::seconds(0);
+
+    /// Creates a new duration of the given number of seconds.
+    #[must_use]
+    pub const fn seconds(seconds: i64) -> Self {
+        Self { seconds }
+    }
+
+    /// Creates a new duration of the given number of minutes.
+    #[must_use]
+    pub const fn minutes(minutes: i64) -> Self {
+        Self::seconds(minutes * 60)
+    }
+
+    /// Creates a new duration of the given number of hours.
+    #[must_use]
+    pub const fn hours(hours: i64) -> Self {
+        Self::minutes(hours * 60)
+    }
+
+    /// Creates a new duration of the given number of days.
+    #[must_use]
+    pub const fn days(days: i64) -> Self {
+        Self::hours(days * 24)
+    }
+
+    /// The total number of seconds of the duration.
+    #[must_use]
+    pub const fn num_seconds(self) -> i64 {
+        self.seconds
+    }
+}
+
+impl Add for Duration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::seconds(self.seconds + rhs.seconds)
+    }
+}
+
+impl Sub for Duration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::seconds(self.seconds - rhs.seconds)
+    }
+}
+
+impl Neg for Duration {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::seconds(-self.seconds)
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}s", self.seconds)
+    }
+}
+
+#[cfg(feature = "date_time_picker")]
+impl From<Duration> for chrono::Duration {
+    fn from(duration: Duration) -> Self {
+        Self::seconds(duration.seconds)
+    }
+}
+
+#[cfg(feature = "date_time_picker")]
+impl From<chrono::Duration> for Duration {
+    fn from(duration: chrono::Duration) -> Self {
+        Self::seconds(duration.num_seconds())
+    }
+}
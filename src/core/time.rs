@@ -2,11 +2,15 @@
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
 
-use chrono::{Local, Timelike};
-use std::fmt::Display;
+use chrono::{Local, NaiveTime, Timelike};
+use std::{cmp::Ordering, fmt::Display};
 
 /// The time value
+///
+/// Times are compared by their time of day, regardless of the period they are
+/// displayed in, so `12:30 PM` equals `12:30` in 24 hour format.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Time {
     /// The time value containing hour, minute and period.
     Hm {
@@ -32,6 +36,68 @@ pub enum Time {
 }
 
 impl Time {
+    /// Creates a new time (hours, minutes, seconds) in 24 hour format from the
+    /// current timestamp.
+    #[must_use]
+    pub fn now() -> Self {
+        Self::now_hms(true)
+    }
+
+    /// Creates a new time in 24 hour format from the given hour (0 - 23),
+    /// minute (0 - 59) and second (0 - 59).
+    ///
+    /// # Errors
+    /// Returns a [`TimeError`] naming the first value that is out of range.
+    pub fn from_hms(hour: u32, minute: u32, second: u32) -> Result<Self, TimeError> {
+        if hour > 23 {
+            Err(TimeError::Hour(hour))
+        } else if minute > 59 {
+            Err(TimeError::Minute(minute))
+        } else if second > 59 {
+            Err(TimeError::Second(second))
+        } else {
+            Ok(Self::Hms {
+                hour,
+                minute,
+                second,
+                period: Period::H24,
+            })
+        }
+    }
+
+    /// The time `00:00:00` in 24 hour format.
+    #[must_use]
+    pub const fn midnight() -> Self {
+        Self::default_hms(Period::H24)
+    }
+
+    /// Creates a new time of the given time of day, keeping the seconds
+    /// and the period of this time.
+    #[cfg(feature = "date_time_picker")]
+    pub(crate) fn with_naive_time(self, time: NaiveTime) -> Self {
+        let (hour, period) = match self {
+            Self::Hm { period, .. } | Self::Hms { period, .. } if period != Period::H24 => {
+                let (pm, hour) = time.hour12();
+                (hour, if pm { Period::Pm } else { Period::Am })
+            }
+            _ => (time.hour(), Period::H24),
+        };
+
+        match self {
+            Self::Hm { .. } => Self::Hm {
+                hour,
+                minute: time.minute(),
+                period,
+            },
+            Self::Hms { .. } => Self::Hms {
+                hour,
+                minute: time.minute(),
+                second: time.second(),
+                period,
+            },
+        }
+    }
+
     /// Creates a new time (hours, minutes) from the current timestamp.
     #[must_use]
     pub fn now_hm(use_24h: bool) -> Self {
@@ -73,6 +139,35 @@ impl Time {
         }
     }
 
+    /// The hour in 24 hour format, the minute and the second of the time.
+    ///
+    /// Values out of range, e.g. of a deserialized time, are kept as they are,
+    /// so the time can still be compared without panicking.
+    pub(crate) fn hms(self) -> (u32, u32, u32) {
+        let (hour, minute, second, period) = match self {
+            Self::Hm {
+                hour,
+                minute,
+                period,
+            } => (hour, minute, 0, period),
+            Self::Hms {
+                hour,
+                minute,
+                second,
+                period,
+            } => (hour, minute, second, period),
+        };
+
+        let hour = match period {
+            Period::Am if hour == 12 => 0,
+            Period::H24 | Period::Am => hour,
+            Period::Pm if hour == 12 => 12,
+            Period::Pm => hour.saturating_add(12),
+        };
+
+        (hour, minute, second)
+    }
+
     /// The default time `00:00` with the given period.
     #[must_use]
     pub const fn default_hm(period: Period) -> Self {
@@ -117,8 +212,58 @@ impl Display for Time {
     }
 }
 
+impl PartialEq for Time {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Time {}
+
+impl PartialOrd for Time {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Time {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.hms().cmp(&other.hms())
+    }
+}
+
+impl std::hash::Hash for Time {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.hms().hash(state);
+    }
+}
+
+/// The error returned if a [`Time`] cannot be created with [`Time::from_hms`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeError {
+    /// The hour is larger than 23.
+    Hour(u32),
+    /// The minute is larger than 59.
+    Minute(u32),
+    /// The second is larger than 59.
+    Second(u32),
+}
+
+impl Display for TimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Hour(hour) => write!(f, "the hour {hour} is out of range"),
+            Self::Minute(minute) => write!(f, "the minute {minute} is out of range"),
+            Self::Second(second) => write!(f, "the second {second} is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for TimeError {}
+
 /// The current period of the clock
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Period {
     /// No period - using 24 hour format.
     H24,
@@ -142,35 +287,16 @@ impl Display for Period {
     }
 }
 
-impl From<Time> for chrono::NaiveTime {
+impl From<Time> for NaiveTime {
     fn from(time: Time) -> Self {
-        let (h, m, s, p) = match time {
-            Time::Hm {
-                hour,
-                minute,
-                period,
-            } => (hour, minute, 0, period),
-            Time::Hms {
-                hour,
-                minute,
-                second,
-                period,
-            } => (hour, minute, second, period),
-        };
-
-        let h = if h == 12 && p != Period::H24 { 0 } else { h };
-
-        let h = match p {
-            Period::H24 | Period::Am => h,
-            Period::Pm => (h + 12) % 24,
-        };
+        let (h, m, s) = time.hms();
 
         Self::from_hms_opt(h, m, s).expect("Time Conversion failed. H, M, or S was too large.")
     }
 }
 
-impl From<chrono::NaiveTime> for Time {
-    fn from(time: chrono::NaiveTime) -> Self {
+impl From<NaiveTime> for Time {
+    fn from(time: NaiveTime) -> Self {
         Self::Hms {
             hour: time.hour(),
             minute: time.minute(),
@@ -185,7 +311,52 @@ impl From<chrono::NaiveTime> for Time {
 mod tests {
     use chrono::NaiveTime;
 
-    use super::{Period, Time};
+    use super::{Period, Time, TimeError};
+
+    #[test]
+    fn from_hms() {
+        assert_eq!(
+            Time::from_hms(23, 59, 59),
+            Ok(Time::Hms {
+                hour: 23,
+                minute: 59,
+                second: 59,
+                period: Period::H24,
+            })
+        );
+        assert_eq!(Time::from_hms(24, 0, 0), Err(TimeError::Hour(24)));
+        assert_eq!(Time::from_hms(0, 60, 0), Err(TimeError::Minute(60)));
+        assert_eq!(Time::from_hms(0, 0, 60), Err(TimeError::Second(60)));
+        assert_eq!(Time::midnight().to_string(), "00:00:00");
+    }
+
+    #[test]
+    fn compare_periods() {
+        let noon = Time::Hm {
+            hour: 12,
+            minute: 30,
+            period: Period::Pm,
+        };
+
+        assert_eq!(
+            noon,
+            Time::from_hms(12, 30, 0).expect("Time should be valid")
+        );
+        assert!(Time::midnight() < noon);
+    }
+
+    #[test]
+    fn compare_out_of_range() {
+        let invalid = Time::Hm {
+            hour: 25,
+            minute: 0,
+            period: Period::H24,
+        };
+
+        assert_ne!(invalid, Time::midnight());
+        assert!(invalid > Time::from_hms(23, 59, 59).expect("Time should be valid"));
+        assert_eq!(invalid, invalid);
+    }
 
     #[test]
     fn time_to_naive() {
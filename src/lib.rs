@@ -1,4 +1,10 @@
 //! Additional widgets for the Iced GUI library.
+//!
+//! Every widget is hidden behind a feature gate and exported at the root of
+//! the crate once its feature is enabled, e.g. [`DatePicker`] with the feature
+//! `date_picker` and [`TimePicker`] with the feature `time_picker`. The types
+//! belonging to a widget are found in its module, like [`date_picker::Date`].
+//! These import paths don't depend on any other enabled feature.
 #![deny(missing_docs)]
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
@@ -53,6 +59,9 @@ pub mod style;
 
 pub use iced::Element;
 
+#[cfg(feature = "derive")]
+pub use iced_aw_macros::StyleSheet;
+
 /// Exports for all platforms that are not WASM32.
 mod platform {
     pub use crate::widgets::helpers;
@@ -70,7 +79,11 @@ mod platform {
 
     #[doc(no_inline)]
     #[cfg(feature = "badge")]
-    pub use {crate::style::BadgeStyles, crate::widgets::badge, badge::Badge};
+    pub use {
+        crate::style::BadgeStyles,
+        crate::widgets::badge,
+        badge::{Badge, CountBadge},
+    };
 
     #[doc(no_inline)]
     #[cfg(feature = "card")]
@@ -84,6 +97,30 @@ mod platform {
     #[cfg(feature = "date_picker")]
     pub use {crate::widgets::date_picker, date_picker::DatePicker};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "date_picker")]
+    pub use {crate::widgets::calendar_view, calendar_view::CalendarView};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "date_picker")]
+    pub use {crate::widgets::date_range_picker, date_range_picker::DateRangePicker};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "date_picker")]
+    pub use {crate::widgets::week_picker, week_picker::WeekPicker};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "date_picker")]
+    pub use {crate::widgets::month_picker, month_picker::MonthPicker};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "date_picker")]
+    pub use {crate::widgets::year_picker, year_picker::YearPicker};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "date_time_picker")]
+    pub use {crate::widgets::date_time_picker, date_time_picker::DateTimePicker};
+
     #[doc(no_inline)]
     #[cfg(feature = "floating_element")]
     pub use {crate::widgets::floating_element, floating_element::FloatingElement};
@@ -96,6 +133,165 @@ mod platform {
     #[cfg(feature = "modal")]
     pub use {crate::style::ModalStyles, crate::widgets::modal, modal::Modal};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "chip")]
+    pub use {crate::style::ChipStyles, crate::widgets::chip, chip::Chip};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "rating")]
+    pub use {crate::style::RatingStyles, crate::widgets::rating, rating::Rating};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tooltip")]
+    pub use {
+        crate::style::TooltipStyles,
+        crate::widgets::tooltip,
+        tooltip::{Tooltip, TooltipPosition},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "accordion")]
+    pub use {
+        crate::style::AccordionStyles,
+        crate::widgets::accordion,
+        accordion::{Accordion, AccordionItem},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "stepper")]
+    pub use {
+        crate::style::StepperStyles,
+        crate::widgets::stepper,
+        stepper::{StepLabel, StepState, Stepper, StepperOrientation},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "progress_bar")]
+    pub use {
+        crate::style::ProgressBarStyles, crate::widgets::progress_bar, progress_bar::ProgressBar,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "toggle")]
+    pub use {crate::style::ToggleStyles, crate::widgets::toggle, toggle::Toggle};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "search_input")]
+    pub use {crate::widgets::search_input, search_input::SearchInput};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "password_input")]
+    pub use {crate::widgets::password_input, password_input::PasswordInput};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "range_slider")]
+    pub use {
+        crate::style::RangeSliderStyles, crate::widgets::range_slider, range_slider::RangeSlider,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "knob")]
+    pub use {crate::style::KnobStyles, crate::widgets::knob, knob::Knob};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "pagination")]
+    pub use {crate::style::PaginationStyles, crate::widgets::pagination, pagination::Pagination};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "breadcrumb")]
+    pub use {
+        crate::style::BreadcrumbStyles,
+        crate::widgets::breadcrumb,
+        breadcrumb::{Breadcrumb, BreadcrumbItem},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tree_view")]
+    pub use {
+        crate::widgets::tree_view,
+        tree_view::{NodeId, TreeNode, TreeView},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "data_table")]
+    pub use {
+        crate::widgets::data_table,
+        data_table::{ColumnWidth, DataTable, SortDirection},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "virtual_list")]
+    pub use {crate::widgets::virtual_list, virtual_list::VirtualList};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "popover")]
+    pub use {
+        crate::widgets::popover,
+        popover::{Popover, PopoverPosition},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "autocomplete")]
+    pub use {
+        crate::style::AutocompleteStyles, crate::widgets::autocomplete, autocomplete::Autocomplete,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tag_input")]
+    pub use {crate::widgets::tag_input, tag_input::TagInput};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "skeleton")]
+    pub use {crate::style::SkeletonStyles, crate::widgets::skeleton, skeleton::Skeleton};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "avatar")]
+    pub use {
+        crate::style::AvatarStyles,
+        crate::widgets::avatar,
+        avatar::{Avatar, AvatarContent, AvatarShape},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "status_indicator")]
+    pub use {
+        crate::style::StatusIndicatorStyles, crate::widgets::status_indicator,
+        status_indicator::StatusIndicator,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "divider")]
+    pub use {crate::style::DividerStyles, crate::widgets::divider, divider::Divider};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "color_swatch")]
+    pub use {
+        crate::style::ColorSwatchStyles, crate::widgets::color_swatch, color_swatch::ColorSwatch,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "duration_picker")]
+    pub use {crate::widgets::duration_picker, duration_picker::DurationPicker};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "number_spinner")]
+    pub use {
+        crate::style::NumberSpinnerStyles, crate::widgets::number_spinner,
+        number_spinner::NumberSpinner,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "floating_button")]
+    pub use {crate::widgets::floating_button, floating_button::FloatingButton};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "toast")]
+    pub use {
+        crate::style::ToastStyles,
+        crate::widgets::toast,
+        toast::{Toast, ToastContainer, ToastPosition},
+    };
+
     #[doc(no_inline)]
     #[cfg(feature = "tab_bar")]
     pub use {
@@ -115,6 +311,10 @@ mod platform {
     #[cfg(feature = "time_picker")]
     pub use {crate::widgets::time_picker, time_picker::TimePicker};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "time_picker")]
+    pub use {crate::widgets::clock_face, clock_face::ClockFace};
+
     #[doc(no_inline)]
     #[cfg(feature = "wrap")]
     pub use {crate::widgets::wrap, wrap::direction, wrap::Wrap};
@@ -160,7 +360,11 @@ mod platform {
 
     #[doc(no_inline)]
     #[cfg(feature = "drop_down")]
-    pub use {crate::widgets::drop_down, drop_down::DropDown};
+    pub use {
+        crate::style::DropDownStyles,
+        crate::widgets::drop_down,
+        drop_down::{DropDown, MultiSelect, Select},
+    };
 }
 
 #[doc(no_inline)]
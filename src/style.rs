@@ -3,6 +3,32 @@
 pub mod colors;
 pub mod style_state;
 
+#[cfg(any(
+    feature = "color_picker",
+    feature = "date_picker",
+    feature = "time_picker"
+))]
+pub mod style_fn;
+#[cfg(any(
+    feature = "color_picker",
+    feature = "date_picker",
+    feature = "time_picker"
+))]
+pub use style_fn::StyleFn;
+
+#[cfg(any(
+    feature = "color_picker",
+    feature = "date_picker",
+    feature = "time_picker"
+))]
+pub mod default_style_sheet;
+#[cfg(any(
+    feature = "color_picker",
+    feature = "date_picker",
+    feature = "time_picker"
+))]
+pub use default_style_sheet::DefaultStyleSheet;
+
 #[cfg(feature = "badge")]
 pub mod badge;
 #[cfg(feature = "badge")]
@@ -28,6 +54,71 @@ pub mod modal;
 #[cfg(feature = "modal")]
 pub use modal::ModalStyles;
 
+#[cfg(feature = "drop_down")]
+pub mod drop_down;
+#[cfg(feature = "drop_down")]
+pub use drop_down::DropDownStyles;
+
+#[cfg(feature = "chip")]
+pub mod chip;
+#[cfg(feature = "chip")]
+pub use chip::ChipStyles;
+
+#[cfg(feature = "rating")]
+pub mod rating;
+#[cfg(feature = "rating")]
+pub use rating::RatingStyles;
+
+#[cfg(feature = "tooltip")]
+pub mod tooltip;
+#[cfg(feature = "tooltip")]
+pub use tooltip::TooltipStyles;
+
+#[cfg(feature = "accordion")]
+pub mod accordion;
+#[cfg(feature = "accordion")]
+pub use accordion::AccordionStyles;
+
+#[cfg(feature = "stepper")]
+pub mod stepper;
+#[cfg(feature = "stepper")]
+pub use stepper::StepperStyles;
+
+#[cfg(feature = "progress_bar")]
+pub mod progress_bar;
+#[cfg(feature = "progress_bar")]
+pub use progress_bar::ProgressBarStyles;
+
+#[cfg(feature = "toggle")]
+pub mod toggle;
+#[cfg(feature = "toggle")]
+pub use toggle::ToggleStyles;
+
+#[cfg(feature = "range_slider")]
+pub mod range_slider;
+#[cfg(feature = "range_slider")]
+pub use range_slider::RangeSliderStyles;
+
+#[cfg(feature = "knob")]
+pub mod knob;
+#[cfg(feature = "knob")]
+pub use knob::KnobStyles;
+
+#[cfg(feature = "pagination")]
+pub mod pagination;
+#[cfg(feature = "pagination")]
+pub use pagination::PaginationStyles;
+
+#[cfg(feature = "breadcrumb")]
+pub mod breadcrumb;
+#[cfg(feature = "breadcrumb")]
+pub use breadcrumb::BreadcrumbStyles;
+
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toast")]
+pub use toast::ToastStyles;
+
 #[cfg(feature = "tab_bar")]
 pub mod tab_bar;
 #[cfg(feature = "tab_bar")]
@@ -72,3 +163,43 @@ pub use context_menu::ContextMenuStyle;
 pub mod segmented_button;
 #[cfg(feature = "segmented_button")]
 pub use segmented_button::SegmentedButton;
+
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "popover")]
+pub use popover::PopoverStyles;
+
+#[cfg(feature = "autocomplete")]
+pub mod autocomplete;
+#[cfg(feature = "autocomplete")]
+pub use autocomplete::AutocompleteStyles;
+
+#[cfg(feature = "skeleton")]
+pub mod skeleton;
+#[cfg(feature = "skeleton")]
+pub use skeleton::SkeletonStyles;
+
+#[cfg(feature = "avatar")]
+pub mod avatar;
+#[cfg(feature = "avatar")]
+pub use avatar::AvatarStyles;
+
+#[cfg(feature = "status_indicator")]
+pub mod status_indicator;
+#[cfg(feature = "status_indicator")]
+pub use status_indicator::StatusIndicatorStyles;
+
+#[cfg(feature = "divider")]
+pub mod divider;
+#[cfg(feature = "divider")]
+pub use divider::DividerStyles;
+
+#[cfg(feature = "color_swatch")]
+pub mod color_swatch;
+#[cfg(feature = "color_swatch")]
+pub use color_swatch::ColorSwatchStyles;
+
+#[cfg(feature = "number_spinner")]
+pub mod number_spinner;
+#[cfg(feature = "number_spinner")]
+pub use number_spinner::NumberSpinnerStyles;
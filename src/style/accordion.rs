@@ -0,0 +1,114 @@
+//! Use an accordion for showing sections whose bodies can be expanded and collapsed.
+//!
+//! *This API requires the following crate features to be activated: `accordion`*
+use std::rc::Rc;
+
+use iced::{Background, Color, Theme};
+
+/// The appearance of an [`Accordion`](crate::widgets::accordion::Accordion).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the headers of the [`Accordion`](crate::widgets::accordion::Accordion).
+    pub header_background: Background,
+
+    /// The text color of the headers of the [`Accordion`](crate::widgets::accordion::Accordion).
+    pub header_text_color: Color,
+
+    /// The background of the bodies of the [`Accordion`](crate::widgets::accordion::Accordion).
+    pub body_background: Background,
+
+    /// The text color of the bodies of the [`Accordion`](crate::widgets::accordion::Accordion).
+    pub body_text_color: Color,
+
+    /// The border radius of the sections of the [`Accordion`](crate::widgets::accordion::Accordion).
+    pub border_radius: f32,
+
+    /// The border width of the sections of the [`Accordion`](crate::widgets::accordion::Accordion).
+    pub border_width: f32,
+
+    /// The border color of the sections of the [`Accordion`](crate::widgets::accordion::Accordion).
+    pub border_color: Color,
+
+    /// The color of the expand icon of the [`Accordion`](crate::widgets::accordion::Accordion).
+    pub icon_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            header_background: Background::Color([0.87, 0.87, 0.87].into()),
+            header_text_color: Color::BLACK,
+            body_background: Background::Color(Color::WHITE),
+            body_text_color: Color::BLACK,
+            border_radius: 4.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            icon_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of an [`Accordion`](crate::widgets::accordion::Accordion).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a section of an [`Accordion`](crate::widgets::accordion::Accordion).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a section of an [`Accordion`](crate::widgets::accordion::Accordion)
+    /// with a hovered header.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of an [`Accordion`](crate::widgets::accordion::Accordion).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum AccordionStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl AccordionStyles {
+    /// Creates a custom [`AccordionStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = AccordionStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let AccordionStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            header_background: palette.background.weak.color.into(),
+            header_text_color: palette.background.weak.text,
+            body_background: palette.background.base.color.into(),
+            body_text_color: palette.background.base.text,
+            border_color: palette.background.strong.color,
+            icon_color: palette.background.weak.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let AccordionStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            header_background: palette.background.strong.color.into(),
+            header_text_color: palette.background.strong.text,
+            icon_color: palette.primary.base.color,
+            ..self.active(style)
+        }
+    }
+}
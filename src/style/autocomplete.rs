@@ -0,0 +1,84 @@
+//! Use an autocomplete for entering text with suggestions.
+//!
+//! *This API requires the following crate features to be activated: `autocomplete`*
+use std::rc::Rc;
+
+use iced::{Background, Color, Theme};
+
+/// The appearance of the suggestions of an [`Autocomplete`](crate::widgets::autocomplete::Autocomplete).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the list of suggestions.
+    pub background: Background,
+    /// The border color of the list of suggestions.
+    pub border_color: Color,
+    /// The border width of the list of suggestions.
+    pub border_width: f32,
+    /// The border radius of the list of suggestions.
+    pub border_radius: f32,
+    /// The text color of the suggestions.
+    pub text_color: Color,
+    /// The background of the hovered suggestion.
+    pub hovered_background: Background,
+    /// The text color of the hovered suggestion.
+    pub hovered_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_color: [0.8, 0.8, 0.8].into(),
+            border_width: 1.0,
+            border_radius: 2.0,
+            text_color: Color::BLACK,
+            hovered_background: Color::from_rgb(0.0, 0.5, 1.0).into(),
+            hovered_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of the suggestions of an [`Autocomplete`](crate::widgets::autocomplete::Autocomplete).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of the suggestions of an [`Autocomplete`](crate::widgets::autocomplete::Autocomplete).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of an [`Autocomplete`](crate::widgets::autocomplete::Autocomplete).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum AutocompleteStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl AutocompleteStyles {
+    /// Creates a custom [`AutocompleteStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = AutocompleteStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let AutocompleteStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            hovered_background: palette.primary.weak.color.into(),
+            hovered_text_color: palette.primary.weak.text,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Use an avatar for showing the picture or the initials of a user.
+//!
+//! *This API requires the following crate features to be activated: `avatar`*
+use std::rc::Rc;
+
+use iced::{Color, Theme};
+
+/// The appearance of an [`Avatar`](crate::widgets::avatar::Avatar).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background behind the initials of the [`Avatar`](crate::widgets::avatar::Avatar).
+    pub background_color: Color,
+
+    /// The color of the initials of the [`Avatar`](crate::widgets::avatar::Avatar).
+    pub text_color: Color,
+
+    /// The border width of the [`Avatar`](crate::widgets::avatar::Avatar).
+    pub border_width: f32,
+
+    /// The border color of the [`Avatar`](crate::widgets::avatar::Avatar).
+    pub border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background_color: [0.87, 0.87, 0.87].into(),
+            text_color: Color::BLACK,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+}
+
+/// The appearance of an [`Avatar`](crate::widgets::avatar::Avatar).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of an [`Avatar`](crate::widgets::avatar::Avatar).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of an [`Avatar`](crate::widgets::avatar::Avatar).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum AvatarStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl AvatarStyles {
+    /// Creates a custom [`AvatarStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = AvatarStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let AvatarStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background_color: palette.primary.weak.color,
+            text_color: palette.primary.weak.text,
+            border_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
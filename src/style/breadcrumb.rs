@@ -0,0 +1,71 @@
+//! Use a breadcrumb for navigating back up a hierarchy.
+//!
+//! *This API requires the following crate features to be activated: `breadcrumb`*
+use std::rc::Rc;
+
+use iced::{Color, Theme};
+
+/// The appearance of a [`Breadcrumb`](crate::widgets::breadcrumb::Breadcrumb).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the items that can be pressed.
+    pub active_link_color: Color,
+
+    /// The color of a hovered item that can be pressed.
+    pub hovered_link_color: Color,
+
+    /// The color of the last item and the items without a message.
+    pub inactive_link_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            active_link_color: [0.0, 0.4, 0.8].into(),
+            hovered_link_color: [0.0, 0.3, 0.6].into(),
+            inactive_link_color: [0.4, 0.4, 0.4].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Breadcrumb`](crate::widgets::breadcrumb::Breadcrumb).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Breadcrumb`](crate::widgets::breadcrumb::Breadcrumb).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Breadcrumb`](crate::widgets::breadcrumb::Breadcrumb).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum BreadcrumbStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl BreadcrumbStyles {
+    /// Creates a custom [`BreadcrumbStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = BreadcrumbStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let BreadcrumbStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            active_link_color: palette.primary.base.color,
+            hovered_link_color: palette.primary.strong.color,
+            inactive_link_color: palette.background.base.text,
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Use a chip for showing a compact, optionally interactive label.
+//!
+//! *This API requires the following crate features to be activated: `chip`*
+use std::rc::Rc;
+
+use iced::{Background, Color, Theme};
+
+/// The appearance of a [`Chip`](crate::widgets::chip::Chip).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`Chip`](crate::widgets::chip::Chip).
+    pub background: Background,
+
+    /// The border radius of the [`Chip`](crate::widgets::chip::Chip).
+    /// If no radius is specified the chip is drawn as a pill.
+    pub border_radius: Option<f32>,
+
+    /// The border width of the [`Chip`](crate::widgets::chip::Chip).
+    pub border_width: f32,
+
+    /// The border color of the [`Chip`](crate::widgets::chip::Chip).
+    pub border_color: Color,
+
+    /// The text color of the label and the delete button of the
+    /// [`Chip`](crate::widgets::chip::Chip).
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color([0.87, 0.87, 0.87].into()),
+            border_radius: None,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Chip`](crate::widgets::chip::Chip).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Chip`](crate::widgets::chip::Chip).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance when the [`Chip`](crate::widgets::chip::Chip) is selected.
+    fn selected(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance when the [`Chip`](crate::widgets::chip::Chip) is hovered.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance when the [`Chip`](crate::widgets::chip::Chip) can neither be
+    /// pressed nor deleted.
+    fn disabled(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Chip`](crate::widgets::chip::Chip).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ChipStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ChipStyles {
+    /// Creates a custom [`ChipStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ChipStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let ChipStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.weak.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn selected(&self, style: &Self::Style) -> Appearance {
+        if let ChipStyles::Custom(custom) = style {
+            return custom.selected(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.primary.weak.color.into(),
+            border_color: palette.primary.strong.color,
+            text_color: palette.primary.weak.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let ChipStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.strong.color.into(),
+            text_color: palette.background.strong.text,
+            ..self.active(style)
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        if let ChipStyles::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let active = self.active(style);
+
+        Appearance {
+            text_color: Color {
+                a: active.text_color.a * 0.5,
+                ..active.text_color
+            },
+            ..active
+        }
+    }
+}
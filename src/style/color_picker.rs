@@ -4,6 +4,8 @@
 
 use std::rc::Rc;
 
+use super::{style_fn::StyleFn, style_state::StyleState};
+
 use iced::{Background, Color, Theme};
 
 /// The appearance of a [`ColorPicker`](crate::native::ColorPicker).
@@ -65,6 +67,12 @@ impl ColorPickerStyles {
     pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
         Self::Custom(Rc::new(style_sheet))
     }
+
+    /// Creates a custom [`ColorPickerStyles`] style variant from a closure returning the
+    /// [`Appearance`] for each [`StyleState`].
+    pub fn from_fn(style: impl Fn(&Theme, StyleState) -> Appearance + 'static) -> Self {
+        Self::custom(StyleFn::new(style))
+    }
 }
 
 impl StyleSheet for Theme {
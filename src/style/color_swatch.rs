@@ -0,0 +1,76 @@
+//! Use a color swatch for selecting a color from a palette of preset colors.
+//!
+//! *This API requires the following crate features to be activated: `color_swatch`*
+use std::rc::Rc;
+
+use iced::{Color, Theme};
+
+/// The appearance of a [`ColorSwatch`](crate::widgets::color_swatch::ColorSwatch).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The width of the border around each swatch.
+    pub border_width: f32,
+
+    /// The color of the border around each swatch.
+    pub border_color: Color,
+
+    /// The color of the border around the hovered swatch.
+    pub hovered_border_color: Color,
+
+    /// The color of the ring around the selected swatch.
+    pub selected_ring_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            hovered_border_color: [0.4, 0.4, 0.4].into(),
+            selected_ring_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`ColorSwatch`](crate::widgets::color_swatch::ColorSwatch).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`ColorSwatch`](crate::widgets::color_swatch::ColorSwatch).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`ColorSwatch`](crate::widgets::color_swatch::ColorSwatch).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ColorSwatchStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ColorSwatchStyles {
+    /// Creates a custom [`ColorSwatchStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ColorSwatchStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let ColorSwatchStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_width: 1.0,
+            border_color: palette.background.strong.color,
+            hovered_border_color: palette.background.base.text,
+            selected_ring_color: palette.primary.strong.color,
+        }
+    }
+}
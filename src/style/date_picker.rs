@@ -4,56 +4,174 @@
 
 use std::rc::Rc;
 
+use super::{style_fn::StyleFn, style_state::StyleState};
+
 use iced::{Background, Color, Theme};
 
-/// The appearance of a [`DatePicker`](crate::native::DatePicker).
+/// The appearance of a [`DatePicker`](crate::widgets::DatePicker).
 #[derive(Clone, Copy, Debug)]
 pub struct Appearance {
-    /// The background of the [`DatePicker`](crate::native::DatePicker).
+    /// The background of the [`DatePicker`](crate::widgets::DatePicker).
     pub background: Background,
 
-    /// The border radius of the [`DatePicker`](crate::native::DatePicker).
+    /// The border radius of the [`DatePicker`](crate::widgets::DatePicker).
     pub border_radius: f32,
 
-    /// The border with of the [`DatePicker`](crate::native::DatePicker).
+    /// The border width of the [`DatePicker`](crate::widgets::DatePicker).
     pub border_width: f32,
 
-    /// The border color of the [`DatePicker`](crate::native::DatePicker).
+    /// The border color of the [`DatePicker`](crate::widgets::DatePicker).
     pub border_color: Color,
 
-    /// The text color of the [`DatePicker`](crate::native::DatePicker).
+    /// The text color of the [`DatePicker`](crate::widgets::DatePicker).
     pub text_color: Color,
 
     /// The attenuated color of the days which are not in the selected month
-    /// of the [`DatePicker`](crate::native::DatePicker).
+    /// of the [`DatePicker`](crate::widgets::DatePicker).
     pub text_attenuated_color: Color,
 
     /// The background of the days in the calender of the
-    /// [`DatePicker`](crate::native::DatePicker).
+    /// [`DatePicker`](crate::widgets::DatePicker).
     pub day_background: Background,
+
+    /// The color of the week numbers in the calender of the
+    /// [`DatePicker`](crate::widgets::DatePicker).
+    pub week_number_color: Color,
+
+    /// The background of the row of the selected week in the calender of the
+    /// [`WeekPicker`](crate::widgets::WeekPicker).
+    pub week_selected_background: Color,
+
+    /// The background of the header showing the month and the year of the
+    /// [`DatePicker`](crate::widgets::DatePicker).
+    pub header_background: Background,
+
+    /// The color of the month and the year in the header of the
+    /// [`DatePicker`](crate::widgets::DatePicker).
+    pub header_text_color: Color,
+
+    /// The color of the arrows in the header of the
+    /// [`DatePicker`](crate::widgets::DatePicker).
+    pub header_icon_color: Color,
+
+    /// The color of a hovered arrow in the header of the
+    /// [`DatePicker`](crate::widgets::DatePicker).
+    pub header_icon_hovered_color: Color,
+}
+
+/// The appearance of a day cell in the calendar of a [`DatePicker`](crate::widgets::DatePicker).
+#[derive(Clone, Copy, Debug)]
+pub struct DayAppearance {
+    /// The background of the day cell.
+    pub background: Background,
+
+    /// The text color of the day cell.
+    pub text_color: Color,
+
+    /// The border radius of the day cell. It is limited to half of the height
+    /// of the cell, so [`f32::INFINITY`] draws a circle.
+    pub border_radius: f32,
 }
 
-/// The appearance of a [`DatePicker`](crate::native::DatePicker).
+impl DayAppearance {
+    /// Creates the [`DayAppearance`] of the days of the given [`Appearance`].
+    fn from_appearance(appearance: &Appearance, text_color: Color) -> Self {
+        Self {
+            background: appearance.day_background,
+            text_color,
+            border_radius: f32::INFINITY,
+        }
+    }
+}
+
+/// The appearance of a [`DatePicker`](crate::widgets::DatePicker).
 pub trait StyleSheet {
     /// The style type of this stylesheet
     type Style: Default + Clone;
-    /// The normal appearance of a [`DatePicker`](crate::native::DatePicker).
+    /// The normal appearance of a [`DatePicker`](crate::widgets::DatePicker).
     fn active(&self, style: &Self::Style) -> Appearance;
 
     /// The appearance when something is selected of the
-    /// [`DatePicker`](crate::native::DatePicker).
+    /// [`DatePicker`](crate::widgets::DatePicker).
     fn selected(&self, style: &Self::Style) -> Appearance;
 
     /// The appearance when something is hovered of the
-    /// [`DatePicker`](crate::native::DatePicker).
+    /// [`DatePicker`](crate::widgets::DatePicker).
     fn hovered(&self, style: &Self::Style) -> Appearance;
 
     /// The appearance when something is focused of the
-    /// [`DatePicker`](crate::native::DatePicker).
+    /// [`DatePicker`](crate::widgets::DatePicker).
     fn focused(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of the days that can not be selected in the
+    /// [`DatePicker`](crate::widgets::DatePicker).
+    ///
+    /// By default the active appearance with the text at half of its opacity.
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        let active = self.active(style);
+
+        Appearance {
+            text_color: Color {
+                a: active.text_color.a * 0.5,
+                ..active.text_color
+            },
+            text_attenuated_color: Color {
+                a: active.text_attenuated_color.a * 0.5,
+                ..active.text_attenuated_color
+            },
+            ..active
+        }
+    }
+
+    /// The normal appearance of the days of the shown month.
+    fn day_active(&self, style: &Self::Style) -> DayAppearance {
+        let active = self.active(style);
+        DayAppearance::from_appearance(&active, active.text_color)
+    }
+
+    /// The appearance of a hovered day.
+    fn day_hovered(&self, style: &Self::Style) -> DayAppearance {
+        let hovered = self.hovered(style);
+        DayAppearance::from_appearance(&hovered, hovered.text_color)
+    }
+
+    /// The appearance of the selected day or of the days of the selected date range.
+    fn day_selected(&self, style: &Self::Style) -> DayAppearance {
+        let selected = self.selected(style);
+        DayAppearance::from_appearance(&selected, selected.text_color)
+    }
+
+    /// The appearance of the current day.
+    fn day_today(&self, style: &Self::Style) -> DayAppearance {
+        self.day_active(style)
+    }
+
+    /// The appearance of the days of the previous and next month.
+    fn day_outside_month(&self, style: &Self::Style) -> DayAppearance {
+        let active = self.active(style);
+        DayAppearance::from_appearance(&active, active.text_attenuated_color)
+    }
+
+    /// The appearance of the days that can not be selected.
+    fn day_disabled(&self, style: &Self::Style) -> DayAppearance {
+        let disabled = self.disabled(style);
+        DayAppearance::from_appearance(&disabled, disabled.text_color)
+    }
+
+    /// The appearance of the selected month of the
+    /// [`MonthPicker`](crate::widgets::MonthPicker).
+    fn month_selected(&self, style: &Self::Style) -> DayAppearance {
+        self.day_selected(style)
+    }
+
+    /// The appearance of a hovered month of the
+    /// [`MonthPicker`](crate::widgets::MonthPicker).
+    fn month_hovered(&self, style: &Self::Style) -> DayAppearance {
+        self.day_hovered(style)
+    }
 }
 
-/// The default appearance of the [`DatePicker`](crate::native::DatePicker).
+/// The default appearance of the [`DatePicker`](crate::widgets::DatePicker).
 #[derive(Clone, Default)]
 #[allow(missing_docs, clippy::missing_docs_in_private_items)]
 pub enum DatePickerStyle {
@@ -67,6 +185,12 @@ impl DatePickerStyle {
     pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
         Self::Custom(Rc::new(style_sheet))
     }
+
+    /// Creates a custom [`DatePickerStyle`] style variant from a closure returning the
+    /// [`Appearance`] for each [`StyleState`].
+    pub fn from_fn(style: impl Fn(&Theme, StyleState) -> Appearance + 'static) -> Self {
+        Self::custom(StyleFn::new(style))
+    }
 }
 
 impl StyleSheet for Theme {
@@ -91,6 +215,12 @@ impl StyleSheet for Theme {
                 ..foreground.text
             },
             day_background: palette.background.base.color.into(),
+            week_number_color: palette.primary.base.color,
+            week_selected_background: palette.primary.strong.color,
+            header_background: palette.background.base.color.into(),
+            header_text_color: foreground.text,
+            header_icon_color: foreground.text,
+            header_icon_hovered_color: palette.primary.base.color,
         }
     }
 
@@ -132,4 +262,98 @@ impl StyleSheet for Theme {
             ..self.active(style)
         }
     }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let foreground = self.palette();
+
+        Appearance {
+            text_color: Color {
+                a: foreground.text.a * 0.25,
+                ..foreground.text
+            },
+            text_attenuated_color: Color {
+                a: foreground.text.a * 0.15,
+                ..foreground.text
+            },
+            ..self.active(style)
+        }
+    }
+
+    fn day_active(&self, style: &Self::Style) -> DayAppearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.day_active(self);
+        }
+
+        let active = self.active(style);
+        DayAppearance::from_appearance(&active, active.text_color)
+    }
+
+    fn day_hovered(&self, style: &Self::Style) -> DayAppearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.day_hovered(self);
+        }
+
+        let hovered = self.hovered(style);
+        DayAppearance::from_appearance(&hovered, hovered.text_color)
+    }
+
+    fn day_selected(&self, style: &Self::Style) -> DayAppearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.day_selected(self);
+        }
+
+        let selected = self.selected(style);
+        DayAppearance::from_appearance(&selected, selected.text_color)
+    }
+
+    fn day_today(&self, style: &Self::Style) -> DayAppearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.day_today(self);
+        }
+
+        let palette = self.extended_palette();
+
+        DayAppearance {
+            text_color: palette.primary.base.color,
+            ..self.day_active(style)
+        }
+    }
+
+    fn day_outside_month(&self, style: &Self::Style) -> DayAppearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.day_outside_month(self);
+        }
+
+        let active = self.active(style);
+        DayAppearance::from_appearance(&active, active.text_attenuated_color)
+    }
+
+    fn day_disabled(&self, style: &Self::Style) -> DayAppearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.day_disabled(self);
+        }
+
+        let disabled = self.disabled(style);
+        DayAppearance::from_appearance(&disabled, disabled.text_color)
+    }
+
+    fn month_selected(&self, style: &Self::Style) -> DayAppearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.month_selected(self);
+        }
+
+        self.day_selected(style)
+    }
+
+    fn month_hovered(&self, style: &Self::Style) -> DayAppearance {
+        if let DatePickerStyle::Custom(custom) = style {
+            return custom.month_hovered(self);
+        }
+
+        self.day_hovered(style)
+    }
 }
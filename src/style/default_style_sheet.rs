@@ -0,0 +1,85 @@
+//! Style widgets with a fixed appearance for each state instead of a type
+//! implementing their style sheet.
+
+use iced::Theme;
+
+use super::{style_fn::impl_style_sheets, style_state::StyleState};
+
+/// A style sheet holding the appearance of a widget for each [`StyleState`].
+///
+/// It implements the style sheet of every widget whose appearance depends on
+/// these states by returning the appearance of the matching field.
+///
+/// ```ignore
+/// # use iced_aw::style::{date_picker::Appearance, DatePickerStyles, DefaultStyleSheet};
+/// let mut style_sheet = DefaultStyleSheet::new(Appearance::default());
+/// style_sheet.hovered.day_background = iced::Color::from_rgb(0.9, 0.9, 0.6).into();
+///
+/// let style = DatePickerStyles::custom(style_sheet);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DefaultStyleSheet<T> {
+    /// The appearance of the active state.
+    pub active: T,
+    /// The appearance of the selected state.
+    pub selected: T,
+    /// The appearance of the hovered state.
+    pub hovered: T,
+    /// The appearance of the focused state.
+    pub focused: T,
+    /// The appearance of the disabled state.
+    pub disabled: T,
+}
+
+impl<T: Clone> DefaultStyleSheet<T> {
+    /// Creates a new [`DefaultStyleSheet`] using the given appearance for every state.
+    pub fn new(appearance: T) -> Self {
+        Self {
+            active: appearance.clone(),
+            selected: appearance.clone(),
+            hovered: appearance.clone(),
+            focused: appearance.clone(),
+            disabled: appearance,
+        }
+    }
+
+    /// The appearance for the given state.
+    fn appearance(&self, _theme: &Theme, state: StyleState) -> T {
+        match state {
+            StyleState::Active => self.active.clone(),
+            StyleState::Selected => self.selected.clone(),
+            StyleState::Hovered => self.hovered.clone(),
+            StyleState::Focused => self.focused.clone(),
+            StyleState::Disabled => self.disabled.clone(),
+        }
+    }
+}
+
+impl_style_sheets!(DefaultStyleSheet);
+
+#[cfg(test)]
+mod tests {
+    use super::{DefaultStyleSheet, StyleState};
+    use iced::Theme;
+
+    #[test]
+    fn appearance_test() {
+        let mut style_sheet = DefaultStyleSheet::new(0);
+        style_sheet.hovered = 1;
+        style_sheet.disabled = 2;
+
+        assert_eq!(style_sheet.appearance(&Theme::Light, StyleState::Active), 0);
+        assert_eq!(
+            style_sheet.appearance(&Theme::Light, StyleState::Focused),
+            0
+        );
+        assert_eq!(
+            style_sheet.appearance(&Theme::Light, StyleState::Hovered),
+            1
+        );
+        assert_eq!(
+            style_sheet.appearance(&Theme::Light, StyleState::Disabled),
+            2
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! Use a divider for separating content by a horizontal or a vertical line.
+//!
+//! *This API requires the following crate features to be activated: `divider`*
+use std::rc::Rc;
+
+use iced::{Color, Theme};
+
+/// The appearance of a [`Divider`](crate::widgets::divider::Divider).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the line of the [`Divider`](crate::widgets::divider::Divider).
+    pub color: Color,
+
+    /// The color of the label of the [`Divider`](crate::widgets::divider::Divider).
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Divider`](crate::widgets::divider::Divider).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Divider`](crate::widgets::divider::Divider).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Divider`](crate::widgets::divider::Divider).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum DividerStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl DividerStyles {
+    /// Creates a custom [`DividerStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = DividerStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let DividerStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Use a select or a multi select for picking options of a drop down list.
+//!
+//! *This API requires the following crate features to be activated: `drop_down`*
+use std::rc::Rc;
+
+use iced::{Background, Color, Theme};
+
+/// The appearance of a [`Select`](crate::widgets::drop_down::Select).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the field of the [`Select`](crate::widgets::drop_down::Select).
+    pub background: Background,
+    /// The border color of the field and the list.
+    pub border_color: Color,
+    /// The border width of the field and the list.
+    pub border_width: f32,
+    /// The border radius of the field and the list.
+    pub border_radius: f32,
+    /// The text color of the selected option and the options of the list.
+    pub text_color: Color,
+    /// The text color of the placeholder.
+    pub placeholder_color: Color,
+    /// The background of the list.
+    pub menu_background: Background,
+    /// The background of the hovered option of the list.
+    pub hovered_option_background: Background,
+    /// The text color of the hovered option of the list.
+    pub hovered_option_text_color: Color,
+    /// The background of the chips of the selected options of a
+    /// [`MultiSelect`](crate::widgets::drop_down::MultiSelect).
+    pub chip_background: Background,
+    /// The text color of the chips of the selected options of a
+    /// [`MultiSelect`](crate::widgets::drop_down::MultiSelect).
+    pub chip_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_color: [0.8, 0.8, 0.8].into(),
+            border_width: 1.0,
+            border_radius: 2.0,
+            text_color: Color::BLACK,
+            placeholder_color: [0.6, 0.6, 0.6].into(),
+            menu_background: Color::WHITE.into(),
+            hovered_option_background: Color::from_rgb(0.0, 0.5, 1.0).into(),
+            hovered_option_text_color: Color::WHITE,
+            chip_background: Color::from_rgb(0.85, 0.9, 1.0).into(),
+            chip_text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Select`](crate::widgets::drop_down::Select).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Select`](crate::widgets::drop_down::Select).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance when the [`Select`](crate::widgets::drop_down::Select) is hovered or open.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Select`](crate::widgets::drop_down::Select).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum DropDownStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl DropDownStyles {
+    /// Creates a custom [`DropDownStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = DropDownStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let DropDownStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.weak.text,
+            placeholder_color: palette.background.strong.color,
+            menu_background: palette.background.weak.color.into(),
+            hovered_option_background: palette.primary.strong.color.into(),
+            hovered_option_text_color: palette.primary.strong.text,
+            chip_background: palette.primary.weak.color.into(),
+            chip_text_color: palette.primary.weak.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let DropDownStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+}
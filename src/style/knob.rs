@@ -0,0 +1,123 @@
+//! Use a knob for selecting a value from a range by turning it.
+//!
+//! *This API requires the following crate features to be activated: `knob`*
+use std::rc::Rc;
+
+use iced::{Color, Theme};
+
+/// The appearance of a [`Knob`](crate::widgets::knob::Knob).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the unfilled portion of the arc.
+    pub arc_color: Color,
+
+    /// The color of the filled portion of the arc up to the value.
+    pub fill_color: Color,
+
+    /// The width of the arc.
+    pub arc_width: f32,
+
+    /// The color of the knob.
+    pub knob_color: Color,
+
+    /// The border color of the knob.
+    pub knob_border: Color,
+
+    /// The border width of the knob.
+    pub knob_border_width: f32,
+
+    /// The color of the mark on the knob pointing at the value.
+    pub indicator_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            arc_color: [0.8, 0.8, 0.8].into(),
+            fill_color: [0.0, 0.5, 1.0].into(),
+            arc_width: 4.0,
+            knob_color: Color::WHITE,
+            knob_border: [0.6, 0.6, 0.6].into(),
+            knob_border_width: 1.0,
+            indicator_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Knob`](crate::widgets::knob::Knob).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Knob`](crate::widgets::knob::Knob).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a hovered [`Knob`](crate::widgets::knob::Knob).
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a [`Knob`](crate::widgets::knob::Knob) while it is dragged.
+    fn dragging(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Knob`](crate::widgets::knob::Knob).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum KnobStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl KnobStyles {
+    /// Creates a custom [`KnobStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = KnobStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let KnobStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            arc_color: palette.background.strong.color,
+            fill_color: palette.primary.base.color,
+            knob_color: palette.background.base.color,
+            knob_border: palette.background.strong.color,
+            indicator_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let KnobStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            knob_border: palette.primary.base.color,
+            ..self.active(style)
+        }
+    }
+
+    fn dragging(&self, style: &Self::Style) -> Appearance {
+        if let KnobStyles::Custom(custom) = style {
+            return custom.dragging(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            fill_color: palette.primary.strong.color,
+            knob_border: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+}
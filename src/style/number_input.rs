@@ -11,6 +11,8 @@ pub struct Appearance {
     pub button_background: Option<Background>,
     /// The Color of the arrows of [`NumberInput`](crate::native::number_input::NumberInput).
     pub icon_color: Color,
+    /// The Color of the border drawn around the text field of the [`NumberInput`](crate::native::number_input::NumberInput), if any.
+    pub input_border_color: Option<Color>,
 }
 
 impl Default for Appearance {
@@ -18,6 +20,7 @@ impl Default for Appearance {
         Self {
             button_background: None,
             icon_color: Color::BLACK,
+            input_border_color: None,
         }
     }
 }
@@ -34,6 +37,16 @@ pub trait StyleSheet {
 
     /// The appearance when the [`NumberInput`](crate::native::number_input::NumberInput) is disabled.
     fn disabled(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance when the typed input of the [`NumberInput`](crate::native::number_input::NumberInput) is invalid.
+    ///
+    /// By default, this is the active appearance with a red border around the text field.
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        Appearance {
+            input_border_color: Some(Color::from_rgb(0.8, 0.2, 0.2)),
+            ..self.active(style)
+        }
+    }
 }
 
 #[derive(Default)]
@@ -65,6 +78,7 @@ impl StyleSheet for Theme {
         Appearance {
             button_background: Some(palette.primary.strong.color.into()),
             icon_color: palette.primary.strong.text,
+            input_border_color: None,
         }
     }
 
@@ -95,6 +109,21 @@ impl StyleSheet for Theme {
                 a: active.icon_color.a * 0.5,
                 ..active.icon_color
             },
+            input_border_color: active.input_border_color,
+        }
+    }
+
+    /// The appearance when the typed input of the [`NumberInput`](crate::native::number_input::NumberInput) is invalid.
+    fn invalid(&self, style: &Self::Style) -> Appearance {
+        if let NumberInputStyles::Custom(custom) = style {
+            return custom.invalid(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            input_border_color: Some(palette.danger.base.color),
+            ..self.active(style)
         }
     }
 }
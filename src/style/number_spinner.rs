@@ -0,0 +1,59 @@
+//! Use a number spinner for showing a number whose digits roll to a new value.
+//!
+//! *This API requires the following crate features to be activated: `number_spinner`*
+use std::rc::Rc;
+
+use iced::{Color, Theme};
+
+/// The appearance of a [`NumberSpinner`](crate::widgets::number_spinner::NumberSpinner).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the digits of the [`NumberSpinner`](crate::widgets::number_spinner::NumberSpinner).
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`NumberSpinner`](crate::widgets::number_spinner::NumberSpinner).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`NumberSpinner`](crate::widgets::number_spinner::NumberSpinner).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`NumberSpinner`](crate::widgets::number_spinner::NumberSpinner).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum NumberSpinnerStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl NumberSpinnerStyles {
+    /// Creates a custom [`NumberSpinnerStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = NumberSpinnerStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let NumberSpinnerStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        Appearance {
+            text_color: self.extended_palette().background.base.text,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Use a pagination for navigating between the pages of a content.
+//!
+//! *This API requires the following crate features to be activated: `pagination`*
+use std::rc::Rc;
+
+use iced::{Background, Color, Theme};
+
+/// The appearance of a page button of a [`Pagination`](crate::widgets::pagination::Pagination).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the button.
+    pub background: Background,
+
+    /// The border radius of the button.
+    pub border_radius: f32,
+
+    /// The border width of the button.
+    pub border_width: f32,
+
+    /// The border color of the button.
+    pub border_color: Color,
+
+    /// The color of the page number or icon of the button.
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color(Color::TRANSPARENT),
+            border_radius: 4.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Pagination`](crate::widgets::pagination::Pagination).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The appearance of the button of the current page.
+    fn page_active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of the buttons of the other pages.
+    fn page_inactive(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a hovered button.
+    fn page_hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a button that can't be pressed, like the button of the
+    /// previous page on the first page.
+    fn page_disabled(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Pagination`](crate::widgets::pagination::Pagination).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum PaginationStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl PaginationStyles {
+    /// Creates a custom [`PaginationStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = PaginationStyles;
+
+    fn page_active(&self, style: &Self::Style) -> Appearance {
+        if let PaginationStyles::Custom(custom) = style {
+            return custom.page_active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.primary.base.color.into(),
+            border_color: palette.primary.base.color,
+            text_color: palette.primary.base.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn page_inactive(&self, style: &Self::Style) -> Appearance {
+        if let PaginationStyles::Custom(custom) = style {
+            return custom.page_inactive(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn page_hovered(&self, style: &Self::Style) -> Appearance {
+        if let PaginationStyles::Custom(custom) = style {
+            return custom.page_hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.primary.weak.color.into(),
+            border_color: palette.primary.base.color,
+            text_color: palette.primary.weak.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn page_disabled(&self, style: &Self::Style) -> Appearance {
+        if let PaginationStyles::Custom(custom) = style {
+            return custom.page_disabled(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.weak.color,
+            text_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Use a popover for showing floating content anchored to an element.
+//!
+//! *This API requires the following crate features to be activated: `popover`*
+use std::rc::Rc;
+
+use iced::{Color, Theme};
+
+/// The appearance of a [`Popover`](crate::widgets::popover::Popover).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background color of the [`Popover`](crate::widgets::popover::Popover)
+    /// and its arrow.
+    pub background: Color,
+
+    /// The border radius of the [`Popover`](crate::widgets::popover::Popover).
+    pub border_radius: f32,
+
+    /// The border width of the [`Popover`](crate::widgets::popover::Popover)
+    /// and its arrow.
+    pub border_width: f32,
+
+    /// The border color of the [`Popover`](crate::widgets::popover::Popover)
+    /// and its arrow.
+    pub border_color: Color,
+
+    /// The default text color of the [`Popover`](crate::widgets::popover::Popover).
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE,
+            border_radius: 6.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Popover`](crate::widgets::popover::Popover).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Popover`](crate::widgets::popover::Popover).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Popover`](crate::widgets::popover::Popover).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum PopoverStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl PopoverStyles {
+    /// Creates a custom [`PopoverStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = PopoverStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let PopoverStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color,
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+}
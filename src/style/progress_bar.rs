@@ -0,0 +1,75 @@
+//! Use a progress bar for showing the progress of a task, or that a task of unknown length is running.
+//!
+//! *This API requires the following crate features to be activated: `progress_bar`*
+use std::rc::Rc;
+
+use iced::{Background, Color, Theme};
+
+/// The appearance of a [`ProgressBar`](crate::widgets::progress_bar::ProgressBar).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the track of the [`ProgressBar`](crate::widgets::progress_bar::ProgressBar).
+    pub background: Background,
+
+    /// The color of the filled part or of the sliding block of the [`ProgressBar`](crate::widgets::progress_bar::ProgressBar).
+    pub bar_color: Color,
+
+    /// The border color of the [`ProgressBar`](crate::widgets::progress_bar::ProgressBar).
+    pub border_color: Color,
+
+    /// The border width of the [`ProgressBar`](crate::widgets::progress_bar::ProgressBar).
+    pub border_width: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color([0.87, 0.87, 0.87].into()),
+            bar_color: [0.0, 0.5, 1.0].into(),
+            border_color: Color::TRANSPARENT,
+            border_width: 0.0,
+        }
+    }
+}
+
+/// The appearance of a [`ProgressBar`](crate::widgets::progress_bar::ProgressBar).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`ProgressBar`](crate::widgets::progress_bar::ProgressBar).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`ProgressBar`](crate::widgets::progress_bar::ProgressBar).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ProgressBarStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ProgressBarStyles {
+    /// Creates a custom [`ProgressBarStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ProgressBarStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let ProgressBarStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.strong.color.into(),
+            bar_color: palette.primary.base.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Use a range slider for selecting an interval from a range of values.
+//!
+//! *This API requires the following crate features to be activated: `range_slider`*
+use std::rc::Rc;
+
+use iced::{Color, Theme};
+
+/// The appearance of a [`RangeSlider`](crate::widgets::range_slider::RangeSlider).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the track outside of the selected interval.
+    pub track_color: Color,
+
+    /// The color of the track between the handles.
+    pub fill_color: Color,
+
+    /// The height of the track.
+    pub track_height: f32,
+
+    /// The color of the handles.
+    pub handle_color: Color,
+
+    /// The radius of the handles.
+    pub handle_radius: f32,
+
+    /// The border color of the handles.
+    pub handle_border_color: Color,
+
+    /// The border width of the handles.
+    pub handle_border_width: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            track_color: [0.8, 0.8, 0.8].into(),
+            fill_color: [0.0, 0.5, 1.0].into(),
+            track_height: 4.0,
+            handle_color: Color::WHITE,
+            handle_radius: 8.0,
+            handle_border_color: [0.0, 0.5, 1.0].into(),
+            handle_border_width: 2.0,
+        }
+    }
+}
+
+/// The appearance of a [`RangeSlider`](crate::widgets::range_slider::RangeSlider).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`RangeSlider`](crate::widgets::range_slider::RangeSlider).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a hovered [`RangeSlider`](crate::widgets::range_slider::RangeSlider).
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a [`RangeSlider`](crate::widgets::range_slider::RangeSlider)
+    /// while a handle is dragged.
+    fn dragging(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`RangeSlider`](crate::widgets::range_slider::RangeSlider).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum RangeSliderStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl RangeSliderStyles {
+    /// Creates a custom [`RangeSliderStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = RangeSliderStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let RangeSliderStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            track_color: palette.background.strong.color,
+            fill_color: palette.primary.base.color,
+            handle_color: palette.background.base.color,
+            handle_border_color: palette.primary.base.color,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let RangeSliderStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            handle_border_color: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+
+    fn dragging(&self, style: &Self::Style) -> Appearance {
+        if let RangeSliderStyles::Custom(custom) = style {
+            return custom.dragging(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            handle_color: palette.primary.weak.color,
+            handle_border_color: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+}
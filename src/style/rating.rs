@@ -0,0 +1,74 @@
+//! Use a rating for showing or picking a value as a row of stars.
+//!
+//! *This API requires the following crate features to be activated: `rating`*
+use std::rc::Rc;
+
+use iced::{Color, Theme};
+
+/// The color of the filled stars of the default style.
+const GOLD: Color = Color::from_rgb(1.0, 0.76, 0.03);
+
+/// The appearance of a [`Rating`](crate::widgets::rating::Rating).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the filled part of the stars.
+    pub filled_color: Color,
+
+    /// The color of the empty part of the stars.
+    pub empty_color: Color,
+
+    /// The color of the filled part of the stars while previewing a hovered value.
+    pub hovered_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            filled_color: GOLD,
+            empty_color: [0.8, 0.8, 0.8].into(),
+            hovered_color: Color::from_rgb(1.0, 0.6, 0.0),
+        }
+    }
+}
+
+/// The appearance of a [`Rating`](crate::widgets::rating::Rating).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Rating`](crate::widgets::rating::Rating).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Rating`](crate::widgets::rating::Rating).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum RatingStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl RatingStyles {
+    /// Creates a custom [`RatingStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = RatingStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let RatingStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            empty_color: palette.background.strong.color,
+            hovered_color: palette.primary.base.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Use a skeleton as a placeholder for content that is still loading.
+//!
+//! *This API requires the following crate features to be activated: `skeleton`*
+use std::rc::Rc;
+
+use iced::{Color, Theme};
+
+/// The appearance of a [`Skeleton`](crate::widgets::skeleton::Skeleton).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the [`Skeleton`](crate::widgets::skeleton::Skeleton).
+    pub base_color: Color,
+
+    /// The color of the shimmer sweeping across the [`Skeleton`](crate::widgets::skeleton::Skeleton).
+    pub shimmer_color: Color,
+
+    /// The speed of the shimmer in sweeps per second, or 0.0 for no shimmer.
+    pub animation_speed: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            base_color: [0.87, 0.87, 0.87].into(),
+            shimmer_color: [0.95, 0.95, 0.95].into(),
+            animation_speed: 0.75,
+        }
+    }
+}
+
+/// The appearance of a [`Skeleton`](crate::widgets::skeleton::Skeleton).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Skeleton`](crate::widgets::skeleton::Skeleton).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Skeleton`](crate::widgets::skeleton::Skeleton).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SkeletonStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl SkeletonStyles {
+    /// Creates a custom [`SkeletonStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = SkeletonStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let SkeletonStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            base_color: palette.background.strong.color,
+            shimmer_color: palette.background.weak.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! *This API requires the following crate features to be activated: spinner*
 
-use iced::Theme;
+use iced::{Color, Theme};
 
 /// The style of a [`Spinner`](crate::native::spinner::Spinner).
 #[derive(Default)]
@@ -17,7 +17,12 @@ pub enum SpinnerStyle {
 
 /// The appearance of a [`Spinner`](crate::native::spinner::Spinner).
 #[derive(Clone, Copy, Debug)]
-pub struct Appearance {}
+pub struct Appearance {
+    /// The color of the spinning circle.
+    pub color: Color,
+    /// The color of the track the circle is spinning on.
+    pub background: Color,
+}
 
 /// A set of rules that dictate the style of a [`Spinner`](crate::native::spinner::Spinner).
 pub trait StyleSheet {
@@ -30,7 +35,14 @@ pub trait StyleSheet {
 impl StyleSheet for Theme {
     type Style = SpinnerStyle;
 
-    fn appearance(&self, _style: &Self::Style) -> Appearance {
-        Appearance {}
+    fn appearance(&self, style: &Self::Style) -> Appearance {
+        if let SpinnerStyle::Custom(custom) = style {
+            return custom.appearance(self);
+        }
+
+        Appearance {
+            color: self.palette().text,
+            background: Color::TRANSPARENT,
+        }
     }
 }
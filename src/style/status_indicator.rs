@@ -0,0 +1,86 @@
+//! Use a status indicator for showing whether a user is online.
+//!
+//! *This API requires the following crate features to be activated: `status_indicator`*
+use std::rc::Rc;
+
+use iced::{Color, Theme};
+
+/// The appearance of a [`StatusIndicator`](crate::widgets::status_indicator::StatusIndicator).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the [`Online`](crate::widgets::status_indicator::Status::Online) status.
+    pub online_color: Color,
+
+    /// The color of the [`Away`](crate::widgets::status_indicator::Status::Away) status.
+    pub away_color: Color,
+
+    /// The color of the [`Busy`](crate::widgets::status_indicator::Status::Busy) status.
+    pub busy_color: Color,
+
+    /// The color of the [`Offline`](crate::widgets::status_indicator::Status::Offline) status.
+    pub offline_color: Color,
+
+    /// The border width of the [`StatusIndicator`](crate::widgets::status_indicator::StatusIndicator).
+    pub border_width: f32,
+
+    /// The border color of the [`StatusIndicator`](crate::widgets::status_indicator::StatusIndicator),
+    /// separating it from an avatar below it.
+    pub border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            online_color: [0.2, 0.75, 0.35].into(),
+            away_color: [0.95, 0.7, 0.1].into(),
+            busy_color: [0.85, 0.2, 0.2].into(),
+            offline_color: [0.6, 0.6, 0.6].into(),
+            border_width: 2.0,
+            border_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`StatusIndicator`](crate::widgets::status_indicator::StatusIndicator).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`StatusIndicator`](crate::widgets::status_indicator::StatusIndicator).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`StatusIndicator`](crate::widgets::status_indicator::StatusIndicator).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum StatusIndicatorStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl StatusIndicatorStyles {
+    /// Creates a custom [`StatusIndicatorStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = StatusIndicatorStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let StatusIndicatorStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            online_color: palette.success.base.color,
+            busy_color: palette.danger.base.color,
+            offline_color: palette.background.strong.color,
+            border_color: palette.background.base.color,
+            ..Appearance::default()
+        }
+    }
+}
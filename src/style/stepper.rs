@@ -0,0 +1,163 @@
+//! Use a stepper for showing the progress through a sequence of steps.
+//!
+//! *This API requires the following crate features to be activated: `stepper`*
+use std::rc::Rc;
+
+use iced::{Background, Color, Theme};
+
+/// The state of a step of a [`Stepper`](crate::widgets::stepper::Stepper).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StepState {
+    /// The step comes after the current step.
+    Pending,
+    /// The step is the current step.
+    Active,
+    /// The step comes before the current step.
+    Completed,
+    /// The step has failed.
+    Error,
+}
+
+/// The appearance of a step of a [`Stepper`](crate::widgets::stepper::Stepper).
+#[derive(Clone, Copy, Debug)]
+pub struct StepAppearance {
+    /// The background of the circle of the step.
+    pub circle_background: Background,
+
+    /// The border color of the circle of the step.
+    pub circle_border_color: Color,
+
+    /// The border width of the circle of the step.
+    pub circle_border_width: f32,
+
+    /// The color of the number or icon inside the circle of the step.
+    pub circle_text_color: Color,
+
+    /// The color of the label of the step.
+    pub label_color: Color,
+
+    /// The color of the line connecting the step to the next one.
+    pub connector_color: Color,
+}
+
+impl Default for StepAppearance {
+    fn default() -> Self {
+        Self {
+            circle_background: Background::Color(Color::WHITE),
+            circle_border_color: [0.8, 0.8, 0.8].into(),
+            circle_border_width: 2.0,
+            circle_text_color: Color::BLACK,
+            label_color: Color::BLACK,
+            connector_color: [0.8, 0.8, 0.8].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Stepper`](crate::widgets::stepper::Stepper).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The appearance of a step that comes after the current step.
+    fn pending(&self, style: &Self::Style) -> StepAppearance;
+
+    /// The appearance of the current step.
+    fn active(&self, style: &Self::Style) -> StepAppearance;
+
+    /// The appearance of a step that comes before the current step.
+    fn completed(&self, style: &Self::Style) -> StepAppearance;
+
+    /// The appearance of a failed step.
+    fn error(&self, style: &Self::Style) -> StepAppearance;
+
+    /// The appearance of a step in the given [`StepState`].
+    fn step(&self, style: &Self::Style, state: StepState) -> StepAppearance {
+        match state {
+            StepState::Pending => self.pending(style),
+            StepState::Active => self.active(style),
+            StepState::Completed => self.completed(style),
+            StepState::Error => self.error(style),
+        }
+    }
+}
+
+/// The default appearance of a [`Stepper`](crate::widgets::stepper::Stepper).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum StepperStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl StepperStyles {
+    /// Creates a custom [`StepperStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = StepperStyles;
+
+    fn pending(&self, style: &Self::Style) -> StepAppearance {
+        if let StepperStyles::Custom(custom) = style {
+            return custom.pending(self);
+        }
+
+        let palette = self.extended_palette();
+
+        StepAppearance {
+            circle_background: palette.background.base.color.into(),
+            circle_border_color: palette.background.strong.color,
+            circle_text_color: palette.background.strong.color,
+            label_color: palette.background.strong.color,
+            connector_color: palette.background.strong.color,
+            ..StepAppearance::default()
+        }
+    }
+
+    fn active(&self, style: &Self::Style) -> StepAppearance {
+        if let StepperStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        StepAppearance {
+            circle_background: palette.primary.base.color.into(),
+            circle_border_color: palette.primary.base.color,
+            circle_text_color: palette.primary.base.text,
+            label_color: palette.background.base.text,
+            ..self.pending(style)
+        }
+    }
+
+    fn completed(&self, style: &Self::Style) -> StepAppearance {
+        if let StepperStyles::Custom(custom) = style {
+            return custom.completed(self);
+        }
+
+        let palette = self.extended_palette();
+
+        StepAppearance {
+            connector_color: palette.primary.base.color,
+            ..self.active(style)
+        }
+    }
+
+    fn error(&self, style: &Self::Style) -> StepAppearance {
+        if let StepperStyles::Custom(custom) = style {
+            return custom.error(self);
+        }
+
+        let palette = self.extended_palette();
+
+        StepAppearance {
+            circle_background: palette.danger.base.color.into(),
+            circle_border_color: palette.danger.base.color,
+            circle_text_color: palette.danger.base.text,
+            label_color: palette.danger.base.color,
+            ..self.pending(style)
+        }
+    }
+}
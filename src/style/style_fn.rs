@@ -0,0 +1,144 @@
+//! Style widgets with a closure instead of a type implementing their style sheet.
+
+use iced::Theme;
+
+use super::style_state::StyleState;
+
+/// A style sheet created from a closure returning the appearance for each
+/// [`StyleState`] of a widget.
+///
+/// ```ignore
+/// # use iced_aw::style::{style_state::StyleState, DatePickerStyle};
+/// let style = DatePickerStyle::from_fn(|theme, state| {
+///     let mut appearance = theme.active(&DatePickerStyle::Default);
+///     if state == StyleState::Hovered {
+///         appearance.day_background = iced::Color::from_rgb(0.9, 0.9, 0.6).into();
+///     }
+///     appearance
+/// });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct StyleFn<'a, T>(Appearances<'a, T>);
+
+/// The closure returning the appearance for each [`StyleState`].
+type Appearances<'a, T> = Box<dyn Fn(&Theme, StyleState) -> T + 'a>;
+
+impl<'a, T> StyleFn<'a, T> {
+    /// Creates a new [`StyleFn`] from the given closure.
+    pub fn new(style: impl Fn(&Theme, StyleState) -> T + 'a) -> Self {
+        Self(Box::new(style))
+    }
+
+    /// The appearance for the given theme and state.
+    fn appearance(&self, theme: &Theme, state: StyleState) -> T {
+        (self.0)(theme, state)
+    }
+}
+
+impl<'a, T, F> From<F> for StyleFn<'a, T>
+where
+    F: Fn(&Theme, StyleState) -> T + 'a,
+{
+    fn from(style: F) -> Self {
+        Self::new(style)
+    }
+}
+
+/// Implements the style sheets of the widgets with a state-based appearance
+/// for a type with an `appearance(&self, &Theme, StyleState)` method.
+macro_rules! impl_style_sheets {
+    ($name:ident $(<$lifetime:lifetime>)?) => {
+        #[cfg(feature = "color_picker")]
+        impl $crate::style::color_picker::StyleSheet
+            for $name<$($lifetime,)? $crate::style::color_picker::Appearance>
+        {
+            type Style = iced::Theme;
+
+            fn active(&self, style: &Self::Style) -> $crate::style::color_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Active)
+            }
+
+            fn selected(&self, style: &Self::Style) -> $crate::style::color_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Selected)
+            }
+
+            fn hovered(&self, style: &Self::Style) -> $crate::style::color_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Hovered)
+            }
+
+            fn focused(&self, style: &Self::Style) -> $crate::style::color_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Focused)
+            }
+        }
+
+        #[cfg(feature = "date_picker")]
+        impl $crate::style::date_picker::StyleSheet
+            for $name<$($lifetime,)? $crate::style::date_picker::Appearance>
+        {
+            type Style = iced::Theme;
+
+            fn active(&self, style: &Self::Style) -> $crate::style::date_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Active)
+            }
+
+            fn selected(&self, style: &Self::Style) -> $crate::style::date_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Selected)
+            }
+
+            fn hovered(&self, style: &Self::Style) -> $crate::style::date_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Hovered)
+            }
+
+            fn focused(&self, style: &Self::Style) -> $crate::style::date_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Focused)
+            }
+
+            fn disabled(&self, style: &Self::Style) -> $crate::style::date_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Disabled)
+            }
+        }
+
+        // The AM/PM buttons use the appearance of the active and the selected state.
+        #[cfg(feature = "time_picker")]
+        impl $crate::style::time_picker::StyleSheet
+            for $name<$($lifetime,)? $crate::style::time_picker::Appearance>
+        {
+            type Style = iced::Theme;
+
+            fn active(&self, style: &Self::Style) -> $crate::style::time_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Active)
+            }
+
+            fn selected(&self, style: &Self::Style) -> $crate::style::time_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Selected)
+            }
+
+            fn hovered(&self, style: &Self::Style) -> $crate::style::time_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Hovered)
+            }
+
+            fn focused(&self, style: &Self::Style) -> $crate::style::time_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Focused)
+            }
+
+            fn disabled(&self, style: &Self::Style) -> $crate::style::time_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Disabled)
+            }
+
+            fn am_pm_button_active(&self, style: &Self::Style) -> $crate::style::time_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Active)
+            }
+
+            fn am_pm_button_selected(
+                &self,
+                style: &Self::Style,
+            ) -> $crate::style::time_picker::Appearance {
+                self.appearance(style, $crate::style::style_state::StyleState::Selected)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_style_sheets;
+
+impl_style_sheets!(StyleFn<'_>);
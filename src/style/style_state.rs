@@ -1,7 +1,7 @@
 //! Helper enum for the state of the style
 
 /// The state of the style
-#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum StyleState {
     /// Use the active style
     Active,
@@ -11,4 +11,6 @@ pub enum StyleState {
     Hovered,
     /// Use the focused style
     Focused,
+    /// Use the disabled style
+    Disabled,
 }
@@ -4,68 +4,114 @@
 #![allow(clippy::doc_markdown)]
 use std::rc::Rc;
 
+use super::{style_fn::StyleFn, style_state::StyleState};
+
 use iced::{Background, Color, Theme};
 
-/// The appearance of a [`TimePicker`](crate::native::TimePicker).
+/// The appearance of a [`TimePicker`](crate::widgets::TimePicker).
 #[derive(Clone, Copy, Debug)]
 pub struct Appearance {
-    /// The background of the [`TimePicker`](crate::native::TimePicker).
+    /// The background of the [`TimePicker`](crate::widgets::TimePicker).
     pub background: Background,
 
-    /// The border radius of the [`TimePicker`](crate::native::TimePicker).
+    /// The border radius of the [`TimePicker`](crate::widgets::TimePicker).
     pub border_radius: f32,
 
-    /// The border width of the [`TimePicker`](crate::native::TimePicker).
+    /// The border width of the [`TimePicker`](crate::widgets::TimePicker).
     pub border_width: f32,
 
-    /// The border color of the [`TimePicker`](crate::native::TimePicker).
+    /// The border color of the [`TimePicker`](crate::widgets::TimePicker).
     pub border_color: Color,
 
-    /// The text color of the [`TimePicker`](crate::native::TimePicker).
+    /// The text color of the [`TimePicker`](crate::widgets::TimePicker).
     pub text_color: Color,
 
     /// The color of the clock numbers of the
-    /// [`TimePicker`](crate::native::TimePicker).
+    /// [`TimePicker`](crate::widgets::TimePicker).
     pub clock_number_color: Color,
 
     /// The background of the clock numbers of the
-    /// [`TimePicker`](crate::native::TimePicker).
+    /// [`TimePicker`](crate::widgets::TimePicker).
     pub clock_number_background: Color,
 
     /// The color of the dots on the clock of the
-    /// [`TimePicker`](crate::native::TimePicker).
+    /// [`TimePicker`](crate::widgets::TimePicker).
     pub clock_dots_color: Color,
 
     /// The color of the hands of the clock of the
-    /// [`TimePicker`](crate::native::TimePicker).
+    /// [`TimePicker`](crate::widgets::TimePicker).
     pub clock_hand_color: Color,
 
+    /// The color of the seconds hand of the clock of the
+    /// [`TimePicker`](crate::widgets::TimePicker).
+    pub clock_seconds_hand_color: Color,
+
     /// The with of the hands of the clock of the
-    /// [`TimePicker](crate::native::TimePicker).
+    /// [`TimePicker`](crate::widgets::TimePicker).
     pub clock_hand_width: f32,
 }
 
-/// The appearance of a [`TimePicker`](crate::native::TimePicker).
+/// The appearance of a [`TimePicker`](crate::widgets::TimePicker).
 pub trait StyleSheet {
     /// The style type of this stylesheet
     type Style: Default + Clone;
-    /// The normal appearance of a [`TimePicker`](crate::native::TimePicker).
+    /// The normal appearance of a [`TimePicker`](crate::widgets::TimePicker).
     fn active(&self, style: &Self::Style) -> Appearance;
 
     /// The appearance when something is selected of the
-    /// [`TimePicker`](crate::native::TimePicker)
+    /// [`TimePicker`](crate::widgets::TimePicker)
     fn selected(&self, style: &Self::Style) -> Appearance;
 
     /// The appearance when something is hovered of the
-    /// [`TimePicker`](crate::native::TimePicker).
+    /// [`TimePicker`](crate::widgets::TimePicker).
     fn hovered(&self, style: &Self::Style) -> Appearance;
 
     /// The appearance when something is focused of the
-    /// [`TimePicker`](crate::native::TimePicker).
+    /// [`TimePicker`](crate::widgets::TimePicker).
     fn focused(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a disabled [`TimePicker`](crate::widgets::TimePicker).
+    ///
+    /// By default the active appearance with the text and the clock numbers at
+    /// half of their opacity.
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        let active = self.active(style);
+
+        Appearance {
+            text_color: Color {
+                a: active.text_color.a * 0.5,
+                ..active.text_color
+            },
+            clock_number_color: Color {
+                a: active.clock_number_color.a * 0.5,
+                ..active.clock_number_color
+            },
+            ..active
+        }
+    }
+
+    /// The appearance of the unselected AM/PM button of the
+    /// [`TimePicker`](crate::widgets::TimePicker).
+    ///
+    /// The buttons are only shown on the 12 hour clock and use the `background`,
+    /// `text_color` and the border fields of the [`Appearance`]. By default the
+    /// active appearance.
+    fn am_pm_button_active(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of the selected AM/PM button of the
+    /// [`TimePicker`](crate::widgets::TimePicker).
+    ///
+    /// The buttons are only shown on the 12 hour clock and use the `background`,
+    /// `text_color` and the border fields of the [`Appearance`]. By default the
+    /// selected appearance.
+    fn am_pm_button_selected(&self, style: &Self::Style) -> Appearance {
+        self.selected(style)
+    }
 }
 
-/// The style appearance of the [`TimePicker`](crate::native::TimePicker)
+/// The style appearance of the [`TimePicker`](crate::widgets::TimePicker)
 #[derive(Clone, Default)]
 #[allow(missing_docs, clippy::missing_docs_in_private_items)]
 pub enum TimePickerStyle {
@@ -79,6 +125,12 @@ impl TimePickerStyle {
     pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
         Self::Custom(Rc::new(style_sheet))
     }
+
+    /// Creates a custom [`TimePickerStyle`] style variant from a closure returning the
+    /// [`Appearance`] for each [`StyleState`].
+    pub fn from_fn(style: impl Fn(&Theme, StyleState) -> Appearance + 'static) -> Self {
+        Self::custom(StyleFn::new(style))
+    }
 }
 
 impl StyleSheet for Theme {
@@ -102,6 +154,7 @@ impl StyleSheet for Theme {
             clock_number_background: palette.background.base.color,
             clock_dots_color: [0.87, 0.87, 0.87].into(),
             clock_hand_color: [0.87, 0.87, 0.87].into(),
+            clock_seconds_hand_color: palette.primary.base.color,
             clock_hand_width: 3.0,
         }
     }
@@ -144,4 +197,55 @@ impl StyleSheet for Theme {
             ..self.active(style)
         }
     }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        if let TimePickerStyle::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let active = self.active(style);
+
+        Appearance {
+            text_color: Color {
+                a: active.text_color.a * 0.5,
+                ..active.text_color
+            },
+            clock_number_color: Color {
+                a: active.clock_number_color.a * 0.5,
+                ..active.clock_number_color
+            },
+            ..active
+        }
+    }
+
+    fn am_pm_button_active(&self, style: &Self::Style) -> Appearance {
+        if let TimePickerStyle::Custom(custom) = style {
+            return custom.am_pm_button_active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            border_radius: 5.0,
+            text_color: palette.background.weak.text,
+            ..self.active(style)
+        }
+    }
+
+    fn am_pm_button_selected(&self, style: &Self::Style) -> Appearance {
+        if let TimePickerStyle::Custom(custom) = style {
+            return custom.am_pm_button_selected(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.primary.strong.color.into(),
+            border_radius: 5.0,
+            border_color: palette.primary.strong.color,
+            text_color: palette.primary.strong.text,
+            ..self.active(style)
+        }
+    }
 }
@@ -0,0 +1,87 @@
+//! Use toasts for showing short notifications on top of the other elements.
+//!
+//! *This API requires the following crate features to be activated: toast*
+use std::rc::Rc;
+
+use iced::{Background, Color, Theme};
+
+/// The appearance of a [`Toast`](crate::widgets::toast::Toast).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`Toast`](crate::widgets::toast::Toast).
+    pub background: Background,
+
+    /// The border radius of the [`Toast`](crate::widgets::toast::Toast).
+    pub border_radius: f32,
+
+    /// The border width of the [`Toast`](crate::widgets::toast::Toast).
+    pub border_width: f32,
+
+    /// The border color of the [`Toast`](crate::widgets::toast::Toast).
+    pub border_color: Color,
+
+    /// The default text color of the [`Toast`](crate::widgets::toast::Toast).
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color([0.87, 0.87, 0.87].into()),
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Toast`](crate::widgets::toast::Toast).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Toast`](crate::widgets::toast::Toast).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Toast`](crate::widgets::toast::Toast).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ToastStyles {
+    #[default]
+    Default,
+    Primary,
+    Success,
+    Danger,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ToastStyles {
+    /// Creates a custom [`ToastStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ToastStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
+        let pair = match style {
+            ToastStyles::Default => palette.background.weak,
+            ToastStyles::Primary => palette.primary.strong,
+            ToastStyles::Success => palette.success.strong,
+            ToastStyles::Danger => palette.danger.strong,
+            ToastStyles::Custom(custom) => return custom.active(self),
+        };
+
+        Appearance {
+            background: pair.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: pair.text,
+            ..Appearance::default()
+        }
+    }
+}
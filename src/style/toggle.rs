@@ -0,0 +1,131 @@
+//! Use a toggle for switching an option on and off.
+//!
+//! *This API requires the following crate features to be activated: `toggle`*
+use std::rc::Rc;
+
+use iced::{Background, Color, Theme};
+
+/// The appearance of a [`Toggle`](crate::widgets::toggle::Toggle).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the track of the [`Toggle`](crate::widgets::toggle::Toggle).
+    pub background: Background,
+
+    /// The color of the knob of the [`Toggle`](crate::widgets::toggle::Toggle).
+    pub knob_color: Color,
+
+    /// The border color of the track of the [`Toggle`](crate::widgets::toggle::Toggle).
+    pub border_color: Color,
+
+    /// The border width of the track of the [`Toggle`](crate::widgets::toggle::Toggle).
+    pub border_width: f32,
+
+    /// The color of the label of the [`Toggle`](crate::widgets::toggle::Toggle).
+    pub label_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color([0.87, 0.87, 0.87].into()),
+            knob_color: Color::WHITE,
+            border_color: [0.8, 0.8, 0.8].into(),
+            border_width: 1.0,
+            label_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Toggle`](crate::widgets::toggle::Toggle).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The appearance of a [`Toggle`](crate::widgets::toggle::Toggle) that is switched on.
+    fn on_appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a [`Toggle`](crate::widgets::toggle::Toggle) that is switched off.
+    fn off_appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a disabled [`Toggle`](crate::widgets::toggle::Toggle)
+    /// that is switched on or off.
+    fn disabled_appearance(&self, style: &Self::Style, is_on: bool) -> Appearance;
+}
+
+/// The default appearance of a [`Toggle`](crate::widgets::toggle::Toggle).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ToggleStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ToggleStyles {
+    /// Creates a custom [`ToggleStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ToggleStyles;
+
+    fn on_appearance(&self, style: &Self::Style) -> Appearance {
+        if let ToggleStyles::Custom(custom) = style {
+            return custom.on_appearance(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.primary.base.color.into(),
+            knob_color: palette.primary.base.text,
+            border_color: palette.primary.base.color,
+            label_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn off_appearance(&self, style: &Self::Style) -> Appearance {
+        if let ToggleStyles::Custom(custom) = style {
+            return custom.off_appearance(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.strong.color.into(),
+            knob_color: palette.background.base.color,
+            border_color: palette.background.strong.color,
+            label_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn disabled_appearance(&self, style: &Self::Style, is_on: bool) -> Appearance {
+        if let ToggleStyles::Custom(custom) = style {
+            return custom.disabled_appearance(self, is_on);
+        }
+
+        let appearance = if is_on {
+            self.on_appearance(style)
+        } else {
+            self.off_appearance(style)
+        };
+        let fade = |color: Color| Color {
+            a: color.a * 0.5,
+            ..color
+        };
+
+        Appearance {
+            background: match appearance.background {
+                Background::Color(color) => fade(color).into(),
+                background @ Background::Gradient(_) => background,
+            },
+            knob_color: fade(appearance.knob_color),
+            border_color: fade(appearance.border_color),
+            label_color: fade(appearance.label_color),
+            ..appearance
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Use a tooltip for showing a hint next to an element after hovering it for a while.
+//!
+//! *This API requires the following crate features to be activated: `tooltip`*
+use std::rc::Rc;
+
+use iced::{Background, Color, Theme};
+
+/// The appearance of a [`Tooltip`](crate::widgets::tooltip::Tooltip).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the [`Tooltip`](crate::widgets::tooltip::Tooltip).
+    pub background: Background,
+
+    /// The border radius of the [`Tooltip`](crate::widgets::tooltip::Tooltip).
+    pub border_radius: f32,
+
+    /// The border width of the [`Tooltip`](crate::widgets::tooltip::Tooltip).
+    pub border_width: f32,
+
+    /// The border color of the [`Tooltip`](crate::widgets::tooltip::Tooltip).
+    pub border_color: Color,
+
+    /// The default text color of the [`Tooltip`](crate::widgets::tooltip::Tooltip).
+    pub text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color([0.87, 0.87, 0.87].into()),
+            border_radius: 4.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`Tooltip`](crate::widgets::tooltip::Tooltip).
+pub trait StyleSheet {
+    ///Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Tooltip`](crate::widgets::tooltip::Tooltip).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Tooltip`](crate::widgets::tooltip::Tooltip).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TooltipStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl TooltipStyles {
+    /// Creates a custom [`TooltipStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = TooltipStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let TooltipStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+}
@@ -15,6 +15,9 @@ pub mod badge;
 #[cfg(feature = "badge")]
 /// A badge for color highlighting small information.
 pub type Badge<'a, Message, Theme, Renderer> = badge::Badge<'a, Message, Theme, Renderer>;
+#[cfg(feature = "badge")]
+/// A count shown in a corner of another element.
+pub type CountBadge<'a, Message, Theme, Renderer> = badge::CountBadge<'a, Message, Theme, Renderer>;
 
 #[cfg(feature = "number_input")]
 pub mod number_input;
@@ -40,11 +43,41 @@ pub mod cupertino;
 #[cfg(feature = "cupertino")]
 pub use crate::widgets::cupertino::cupertino_spinner::CupertinoSpinner;
 
+#[cfg(feature = "date_picker")]
+pub mod calendar_view;
+#[cfg(feature = "date_picker")]
+pub use calendar_view::CalendarView;
+
 #[cfg(feature = "date_picker")]
 pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePicker;
 
+#[cfg(feature = "date_picker")]
+pub mod date_range_picker;
+#[cfg(feature = "date_picker")]
+pub use date_range_picker::DateRangePicker;
+
+#[cfg(feature = "date_picker")]
+pub mod week_picker;
+#[cfg(feature = "date_picker")]
+pub use week_picker::WeekPicker;
+
+#[cfg(feature = "date_picker")]
+pub mod month_picker;
+#[cfg(feature = "date_picker")]
+pub use month_picker::MonthPicker;
+
+#[cfg(feature = "date_picker")]
+pub mod year_picker;
+#[cfg(feature = "date_picker")]
+pub use year_picker::YearPicker;
+
+#[cfg(feature = "date_time_picker")]
+pub mod date_time_picker;
+#[cfg(feature = "date_time_picker")]
+pub use date_time_picker::DateTimePicker;
+
 #[cfg(feature = "selection_list")]
 pub mod selection_list;
 #[cfg(feature = "selection_list")]
@@ -75,6 +108,189 @@ pub mod modal;
 /// A modal content as an overlay.
 pub type Modal<'a, Message, Theme, Renderer> = modal::Modal<'a, Message, Theme, Renderer>;
 
+#[cfg(feature = "chip")]
+pub mod chip;
+#[cfg(feature = "chip")]
+/// A compact, optionally interactive label.
+pub type Chip<'a, Message, Theme, Renderer> = chip::Chip<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "rating")]
+pub mod rating;
+#[cfg(feature = "rating")]
+/// A row of stars for showing or picking a value.
+pub type Rating<'a, Message, Theme> = rating::Rating<'a, Message, Theme>;
+
+#[cfg(feature = "tooltip")]
+pub mod tooltip;
+#[cfg(feature = "tooltip")]
+/// A hint shown next to an element after hovering it for a while.
+pub type Tooltip<'a, Message, Theme, Renderer> = tooltip::Tooltip<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "accordion")]
+pub mod accordion;
+#[cfg(feature = "accordion")]
+/// A list of sections whose bodies can be expanded and collapsed.
+pub type Accordion<'a, Message, Theme, Renderer> =
+    accordion::Accordion<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "stepper")]
+pub mod stepper;
+#[cfg(feature = "stepper")]
+/// A sequence of step circles showing the progress through the steps.
+pub type Stepper<'a, Message, Theme> = stepper::Stepper<'a, Message, Theme>;
+
+#[cfg(feature = "progress_bar")]
+pub mod progress_bar;
+#[cfg(feature = "progress_bar")]
+/// A bar showing the progress of a task, or that a task of unknown length is running.
+pub type ProgressBar<Message, Theme> = progress_bar::ProgressBar<Message, Theme>;
+
+#[cfg(feature = "toggle")]
+pub mod toggle;
+#[cfg(feature = "toggle")]
+/// A pill-shaped switch for turning an option on and off.
+pub type Toggle<'a, Message, Theme> = toggle::Toggle<'a, Message, Theme>;
+
+#[cfg(feature = "search_input")]
+pub mod search_input;
+#[cfg(feature = "search_input")]
+/// A text input for a search query with a button for clearing it.
+pub type SearchInput<'a, Message, Theme, Renderer> =
+    search_input::SearchInput<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "password_input")]
+pub mod password_input;
+#[cfg(feature = "password_input")]
+/// A text input for a password with a button for showing and hiding it.
+pub type PasswordInput<'a, Message, Theme, Renderer> =
+    password_input::PasswordInput<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "range_slider")]
+pub mod range_slider;
+#[cfg(feature = "range_slider")]
+/// A slider with two handles for selecting an interval of values.
+pub type RangeSlider<'a, Message, Theme> = range_slider::RangeSlider<'a, Message, Theme>;
+
+#[cfg(feature = "knob")]
+pub mod knob;
+#[cfg(feature = "knob")]
+/// A circular control for selecting a value by turning it.
+pub type Knob<'a, Message, Theme> = knob::Knob<'a, Message, Theme>;
+
+#[cfg(feature = "pagination")]
+pub mod pagination;
+#[cfg(feature = "pagination")]
+/// A row of buttons for navigating between pages.
+pub type Pagination<'a, Message, Theme> = pagination::Pagination<'a, Message, Theme>;
+
+#[cfg(feature = "breadcrumb")]
+pub mod breadcrumb;
+#[cfg(feature = "breadcrumb")]
+/// A trail of links to the levels of a hierarchy leading to the current one.
+pub type Breadcrumb<'a, Message, Theme, Renderer> =
+    breadcrumb::Breadcrumb<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "tree_view")]
+pub mod tree_view;
+#[cfg(feature = "tree_view")]
+/// A hierarchy of nodes that can be expanded and collapsed.
+pub type TreeView<'a, T, Message, Theme, Renderer> =
+    tree_view::TreeView<'a, T, Message, Theme, Renderer>;
+
+#[cfg(feature = "data_table")]
+pub mod data_table;
+#[cfg(feature = "data_table")]
+/// A grid of rows of data with sortable columns and a fixed header.
+pub type DataTable<'a, Message, Theme, Renderer> =
+    data_table::DataTable<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "virtual_list")]
+pub mod virtual_list;
+#[cfg(feature = "virtual_list")]
+/// A scrollable list that only creates the items in its viewport.
+pub type VirtualList<'a, Message, Theme, Renderer> =
+    virtual_list::VirtualList<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "popover")]
+/// Floating content anchored to an element.
+pub type Popover<'a, Message, Theme> = popover::Popover<'a, Message, Theme>;
+
+#[cfg(feature = "autocomplete")]
+pub mod autocomplete;
+#[cfg(feature = "autocomplete")]
+/// A text input showing suggestions matching its value.
+pub type Autocomplete<'a, Message, Theme, Renderer> =
+    autocomplete::Autocomplete<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "tag_input")]
+pub mod tag_input;
+#[cfg(feature = "tag_input")]
+/// A text input for entering several freeform tags.
+pub type TagInput<'a, Message, Theme, Renderer> = tag_input::TagInput<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "skeleton")]
+pub mod skeleton;
+#[cfg(feature = "skeleton")]
+/// A placeholder for content that is still loading.
+pub type Skeleton<Theme = iced::Theme> = skeleton::Skeleton<Theme>;
+
+#[cfg(feature = "avatar")]
+pub mod avatar;
+#[cfg(feature = "avatar")]
+/// The picture or the initials of a user.
+pub type Avatar<Message, Theme, Renderer> = avatar::Avatar<Message, Theme, Renderer>;
+
+#[cfg(feature = "status_indicator")]
+pub mod status_indicator;
+#[cfg(feature = "status_indicator")]
+/// A small dot showing the status of a user.
+pub type StatusIndicator<Theme = iced::Theme> = status_indicator::StatusIndicator<Theme>;
+
+#[cfg(feature = "divider")]
+pub mod divider;
+#[cfg(feature = "divider")]
+/// A horizontal or vertical line separating content.
+pub type Divider<Theme, Renderer> = divider::Divider<Theme, Renderer>;
+
+#[cfg(feature = "color_swatch")]
+pub mod color_swatch;
+#[cfg(feature = "color_swatch")]
+/// A grid of colored squares for picking a color from a preset palette.
+pub type ColorSwatch<'a, Message, Theme, Renderer> =
+    color_swatch::ColorSwatch<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "duration_picker")]
+pub mod duration_picker;
+#[cfg(feature = "duration_picker")]
+/// A set of fields for entering the hours, minutes and seconds of a duration.
+pub type DurationPicker<'a, Message, Theme, Renderer> =
+    duration_picker::DurationPicker<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "number_spinner")]
+pub mod number_spinner;
+#[cfg(feature = "number_spinner")]
+/// A number whose digits roll to a new value.
+pub type NumberSpinner<Theme, Renderer> = number_spinner::NumberSpinner<Theme, Renderer>;
+
+#[cfg(feature = "floating_button")]
+pub mod floating_button;
+#[cfg(feature = "floating_button")]
+/// A button pinned to a corner of some content.
+pub type FloatingButton<'a, Message, Theme, Renderer> =
+    floating_button::FloatingButton<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toast")]
+/// A single notification of a [`ToastContainer`].
+pub type Toast<'a, Message, Theme, Renderer> = toast::Toast<'a, Message, Theme, Renderer>;
+#[cfg(feature = "toast")]
+/// A stack of notifications as an overlay.
+pub type ToastContainer<'a, Message, Theme, Renderer> =
+    toast::ToastContainer<'a, Message, Theme, Renderer>;
+
 #[cfg(feature = "split")]
 pub mod split;
 #[cfg(feature = "split")]
@@ -100,6 +316,11 @@ pub type Tabs<'a, Message, TabId, Theme, Renderer> =
 #[cfg(feature = "tabs")]
 pub use tabs::TabBarPosition;
 
+#[cfg(feature = "time_picker")]
+pub mod clock_face;
+#[cfg(feature = "time_picker")]
+pub use clock_face::ClockFace;
+
 #[cfg(feature = "time_picker")]
 pub mod time_picker;
 #[cfg(feature = "time_picker")]
@@ -123,7 +344,7 @@ pub mod spinner;
 
 #[cfg(feature = "spinner")]
 /// A spinner widget, a circle spinning around the center of the widget.
-pub type Spinner = spinner::Spinner;
+pub type Spinner<Theme = iced::Theme> = spinner::Spinner<Theme>;
 
 #[cfg(feature = "context_menu")]
 pub mod context_menu;
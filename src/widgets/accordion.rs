@@ -0,0 +1,744 @@
+//! Use an accordion for showing sections whose bodies can be expanded and collapsed.
+//!
+//! *This API requires the following crate features to be activated: `accordion`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay::{self, Group},
+        renderer, text,
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch, window, Border, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shadow, Size,
+    Vector,
+};
+use std::time::{Duration, Instant};
+
+use crate::core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT};
+pub use crate::style::accordion::{Appearance, StyleSheet};
+
+/// A section of an [`Accordion`] with a header that is always shown and a
+/// body that can be expanded and collapsed.
+#[allow(missing_debug_implementations)]
+pub struct AccordionItem<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The header of the section.
+    header: Element<'a, Message, Theme, Renderer>,
+    /// The body of the section.
+    body: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> AccordionItem<'a, Message, Theme, Renderer> {
+    /// Creates a new [`AccordionItem`] with the given header and body.
+    pub fn new<H, B>(header: H, body: B) -> Self
+    where
+        H: Into<Element<'a, Message, Theme, Renderer>>,
+        B: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self {
+            header: header.into(),
+            body: body.into(),
+        }
+    }
+}
+
+/// A list of sections whose bodies are expanded and collapsed by clicking
+/// on their headers.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::{accordion::AccordionItem, Accordion};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(usize, bool),
+/// }
+///
+/// let accordion = Accordion::new(vec![
+///     AccordionItem::new(Text::new("First"), Text::new("The first section")),
+///     AccordionItem::new(Text::new("Second"), Text::new("The second section")),
+/// ])
+/// .allow_multiple(true)
+/// .on_toggle(Message::Toggled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Accordion<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// The sections of the [`Accordion`].
+    items: Vec<AccordionItem<'a, Message, Theme, Renderer>>,
+    /// Whether multiple sections can be expanded at the same time.
+    allow_multiple: bool,
+    /// The function that produces a message when a section is expanded or collapsed.
+    on_toggle: Option<Box<dyn Fn(usize, bool) -> Message + 'a>>,
+    /// The width of the [`Accordion`].
+    width: Length,
+    /// The spacing between the sections.
+    spacing: f32,
+    /// The padding around the headers and the bodies.
+    padding: Padding,
+    /// The size of the expand icon.
+    icon_size: f32,
+    /// The time it takes to expand or collapse a section.
+    animation_duration: Duration,
+    /// The style of the [`Accordion`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Accordion<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Accordion`] with the given sections, all collapsed.
+    #[must_use]
+    pub fn new(items: Vec<AccordionItem<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            items,
+            allow_multiple: false,
+            on_toggle: None,
+            width: Length::Fill,
+            spacing: 5.0,
+            padding: Padding::new(10.0),
+            icon_size: 16.0,
+            animation_duration: Duration::from_millis(200),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets whether multiple sections of the [`Accordion`] can be expanded at the same time.
+    ///
+    /// Otherwise expanding a section collapses the other ones.
+    #[must_use]
+    pub fn allow_multiple(mut self, allow_multiple: bool) -> Self {
+        self.allow_multiple = allow_multiple;
+        self
+    }
+
+    /// Sets the function that will be called with the index of a section and
+    /// whether it is expanded when it is expanded or collapsed.
+    #[must_use]
+    pub fn on_toggle<F>(mut self, on_toggle: F) -> Self
+    where
+        F: 'a + Fn(usize, bool) -> Message,
+    {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the width of the [`Accordion`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the sections of the [`Accordion`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding around the headers and the bodies of the [`Accordion`].
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the size of the expand icon of the [`Accordion`].
+    #[must_use]
+    pub fn icon_size(mut self, icon_size: f32) -> Self {
+        self.icon_size = icon_size;
+        self
+    }
+
+    /// Sets the time it takes to expand or collapse a section of the [`Accordion`].
+    #[must_use]
+    pub fn animation_duration(mut self, animation_duration: Duration) -> Self {
+        self.animation_duration = animation_duration;
+        self
+    }
+
+    /// Sets the style of the [`Accordion`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Expands or collapses the section with the given index.
+    fn toggle(&self, state: &mut State, index: usize, shell: &mut Shell<'_, Message>) {
+        let is_open = !state.sections[index].is_open;
+        state.sections[index].open(is_open);
+
+        if let Some(on_toggle) = &self.on_toggle {
+            shell.publish(on_toggle(index, is_open));
+        }
+
+        if is_open && !self.allow_multiple {
+            for (other, section) in state.sections.iter_mut().enumerate() {
+                if other != index && section.is_open {
+                    section.open(false);
+
+                    if let Some(on_toggle) = &self.on_toggle {
+                        shell.publish(on_toggle(other, false));
+                    }
+                }
+            }
+        }
+
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+}
+
+/// The animation of expanding or collapsing a section of an [`Accordion`].
+#[derive(Clone, Copy, Debug, Default)]
+struct AnimationState {
+    /// How far the section is expanded, from 0.0 for collapsed to 1.0 for expanded.
+    progress: f32,
+    /// Whether the section is expanded or being expanded.
+    is_open: bool,
+    /// The time of the previous animation step or `None` if the animation did not start yet.
+    last_update: Option<Instant>,
+}
+
+impl AnimationState {
+    /// Starts expanding or collapsing the section.
+    fn open(&mut self, is_open: bool) {
+        self.is_open = is_open;
+        self.last_update = None;
+    }
+
+    /// The progress the section is animated to.
+    fn target(&self) -> f32 {
+        if self.is_open {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Checks if the section is still expanding or collapsing.
+    fn is_animating(&self) -> bool {
+        (self.progress - self.target()).abs() > f32::EPSILON
+    }
+
+    /// Moves the progress towards its target for the time passed since the
+    /// previous step and returns `true` if the progress changed.
+    fn step(&mut self, now: Instant, duration: Duration) -> bool {
+        if !self.is_animating() {
+            self.progress = self.target();
+            self.last_update = None;
+            return false;
+        }
+
+        let elapsed = self.last_update.map_or(Duration::ZERO, |last_update| {
+            now.saturating_duration_since(last_update)
+        });
+        self.last_update = Some(now);
+
+        let delta = if duration.is_zero() {
+            1.0
+        } else {
+            elapsed.as_secs_f32() / duration.as_secs_f32()
+        };
+
+        let previous = self.progress;
+        self.progress = if self.is_open {
+            (self.progress + delta).min(1.0)
+        } else {
+            (self.progress - delta).max(0.0)
+        };
+
+        (self.progress - previous).abs() > f32::EPSILON
+    }
+}
+
+/// The state of an [`Accordion`].
+#[derive(Debug, Default)]
+struct State {
+    /// The animations of the sections.
+    sections: Vec<AnimationState>,
+}
+
+/// Gets the header and body layouts of each section.
+fn section_layouts(layout: Layout<'_>) -> impl Iterator<Item = (Layout<'_>, Layout<'_>)> {
+    let mut children = layout.children();
+    std::iter::from_fn(move || Some((children.next()?, children.next()?)))
+}
+
+/// Gets the layout of the content of a header or a body.
+fn content_layout(layout: Layout<'_>) -> Layout<'_> {
+    layout
+        .children()
+        .next()
+        .expect("Native: Layout should have a content layout")
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Accordion<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            sections: vec![AnimationState::default(); self.items.len()],
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.items
+            .iter()
+            .flat_map(|item| [Tree::new(&item.header), Tree::new(&item.body)])
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state
+            .downcast_mut::<State>()
+            .sections
+            .resize(self.items.len(), AnimationState::default());
+
+        let children: Vec<_> = self
+            .items
+            .iter()
+            .flat_map(|item| [&item.header, &item.body])
+            .collect();
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_ref::<State>();
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let icon_space = self.icon_size + self.padding.right;
+        let header_limits = limits
+            .shrink(self.padding)
+            .shrink(Size::new(icon_space, 0.0));
+        let body_limits = limits.shrink(self.padding);
+
+        let contents: Vec<_> = self
+            .items
+            .iter()
+            .zip(tree.children.chunks_exact_mut(2))
+            .map(|(item, trees)| {
+                (
+                    item.header
+                        .as_widget()
+                        .layout(&mut trees[0], renderer, &header_limits),
+                    item.body
+                        .as_widget()
+                        .layout(&mut trees[1], renderer, &body_limits),
+                )
+            })
+            .collect();
+
+        let content_width = contents
+            .iter()
+            .map(|(header, body)| (header.size().width + icon_space).max(body.size().width))
+            .fold(0.0, f32::max);
+        let width = limits
+            .resolve(
+                self.width,
+                Length::Shrink,
+                Size::new(content_width + self.padding.horizontal(), 0.0),
+            )
+            .width;
+
+        let mut y = 0.0;
+        let mut children = Vec::with_capacity(2 * contents.len());
+
+        for (index, ((header, body), section)) in
+            contents.into_iter().zip(&state.sections).enumerate()
+        {
+            if index > 0 {
+                y += self.spacing;
+            }
+
+            let content_position = Point::new(self.padding.left, self.padding.top);
+
+            let header_height = header.size().height + self.padding.vertical();
+            children.push(
+                Node::with_children(
+                    Size::new(width, header_height),
+                    vec![header.move_to(content_position)],
+                )
+                .move_to(Point::new(0.0, y)),
+            );
+            y += header_height;
+
+            let body_height = (body.size().height + self.padding.vertical()) * section.progress;
+            children.push(
+                Node::with_children(
+                    Size::new(width, body_height),
+                    vec![body.move_to(content_position)],
+                )
+                .move_to(Point::new(0.0, y)),
+            );
+            y += body_height;
+        }
+
+        Node::with_children(Size::new(width, y), children)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            let mut changed = false;
+            for section in &mut state.sections {
+                changed |= section.step(now, self.animation_duration);
+            }
+
+            if changed {
+                shell.invalidate_layout();
+            }
+            if state.sections.iter().any(AnimationState::is_animating) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        let mut status = event::Status::Ignored;
+        let mut toggled = None;
+
+        for (index, ((item, trees), (header_layout, body_layout))) in self
+            .items
+            .iter_mut()
+            .zip(tree.children.chunks_exact_mut(2))
+            .zip(section_layouts(layout))
+            .enumerate()
+        {
+            let header_status = item.header.as_widget_mut().on_event(
+                &mut trees[0],
+                event.clone(),
+                content_layout(header_layout),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            let body_status = if state.sections[index].progress > 0.0 {
+                item.body.as_widget_mut().on_event(
+                    &mut trees[1],
+                    event.clone(),
+                    content_layout(body_layout),
+                    if cursor.is_over(body_layout.bounds()) {
+                        cursor
+                    } else {
+                        Cursor::Unavailable
+                    },
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            } else {
+                event::Status::Ignored
+            };
+
+            let toggle_status = match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                    if header_status == event::Status::Ignored
+                        && cursor.is_over(header_layout.bounds()) =>
+                {
+                    toggled = Some(index);
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            };
+
+            status = status
+                .merge(header_status)
+                .merge(body_status)
+                .merge(toggle_status);
+        }
+
+        if let Some(index) = toggled {
+            self.toggle(state, index, shell);
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        self.items
+            .iter()
+            .zip(tree.children.chunks_exact(2))
+            .zip(section_layouts(layout))
+            .zip(&state.sections)
+            .map(|(((item, trees), (header_layout, body_layout)), section)| {
+                let header_interaction = item.header.as_widget().mouse_interaction(
+                    &trees[0],
+                    content_layout(header_layout),
+                    cursor,
+                    viewport,
+                    renderer,
+                );
+                let toggle_interaction = if cursor.is_over(header_layout.bounds()) {
+                    mouse::Interaction::Pointer
+                } else {
+                    mouse::Interaction::default()
+                };
+                let body_interaction =
+                    if section.progress > 0.0 && cursor.is_over(body_layout.bounds()) {
+                        item.body.as_widget().mouse_interaction(
+                            &trees[1],
+                            content_layout(body_layout),
+                            cursor,
+                            viewport,
+                            renderer,
+                        )
+                    } else {
+                        mouse::Interaction::default()
+                    };
+
+                header_interaction
+                    .max(toggle_interaction)
+                    .max(body_interaction)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        for (((item, trees), (header_layout, body_layout)), section) in self
+            .items
+            .iter()
+            .zip(tree.children.chunks_exact(2))
+            .zip(section_layouts(layout))
+            .zip(&state.sections)
+        {
+            let header_bounds = header_layout.bounds();
+            let body_bounds = body_layout.bounds();
+
+            let appearance = if cursor.is_over(header_bounds) {
+                theme.hovered(&self.style)
+            } else {
+                theme.active(&self.style)
+            };
+            let border = Border {
+                radius: appearance.border_radius.into(),
+                width: appearance.border_width,
+                color: appearance.border_color,
+            };
+
+            // Body
+            if section.progress > 0.0 {
+                renderer.with_layer(body_bounds, |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: body_bounds,
+                            border,
+                            shadow: Shadow::default(),
+                        },
+                        appearance.body_background,
+                    );
+
+                    item.body.as_widget().draw(
+                        &trees[1],
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: appearance.body_text_color,
+                        },
+                        content_layout(body_layout),
+                        cursor,
+                        &body_bounds,
+                    );
+                });
+            }
+
+            // Header
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: header_bounds,
+                    border,
+                    shadow: Shadow::default(),
+                },
+                appearance.header_background,
+            );
+
+            item.header.as_widget().draw(
+                &trees[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: appearance.header_text_color,
+                },
+                content_layout(header_layout),
+                cursor,
+                viewport,
+            );
+
+            let icon_bounds = Rectangle {
+                x: header_bounds.x + header_bounds.width - self.padding.right - self.icon_size,
+                y: header_bounds.y,
+                width: self.icon_size,
+                height: header_bounds.height,
+            };
+            let icon = if section.is_open {
+                Bootstrap::ChevronDown
+            } else {
+                Bootstrap::ChevronRight
+            };
+
+            renderer.fill_text(
+                text::Text {
+                    content: &icon_to_string(icon),
+                    bounds: icon_bounds.size(),
+                    size: Pixels(self.icon_size),
+                    line_height: text::LineHeight::Relative(1.0),
+                    font: BOOTSTRAP_FONT,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                },
+                icon_bounds.center(),
+                appearance.icon_color,
+                icon_bounds,
+            );
+        }
+    }
+
+    fn operate<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((item, trees), (header_layout, body_layout)) in self
+                .items
+                .iter()
+                .zip(tree.children.chunks_exact_mut(2))
+                .zip(section_layouts(layout))
+            {
+                item.header.as_widget().operate(
+                    &mut trees[0],
+                    content_layout(header_layout),
+                    renderer,
+                    operation,
+                );
+                item.body.as_widget().operate(
+                    &mut trees[1],
+                    content_layout(body_layout),
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let expanded: Vec<bool> = tree
+            .state
+            .downcast_ref::<State>()
+            .sections
+            .iter()
+            .map(|section| section.progress > 0.0)
+            .collect();
+
+        let mut overlays = Vec::new();
+
+        for (((item, trees), (header_layout, body_layout)), expanded) in self
+            .items
+            .iter_mut()
+            .zip(tree.children.chunks_exact_mut(2))
+            .zip(section_layouts(layout))
+            .zip(expanded)
+        {
+            let (header_tree, body_tree) = trees.split_at_mut(1);
+
+            overlays.extend(item.header.as_widget_mut().overlay(
+                &mut header_tree[0],
+                content_layout(header_layout),
+                renderer,
+                translation,
+            ));
+
+            if expanded {
+                overlays.extend(item.body.as_widget_mut().overlay(
+                    &mut body_tree[0],
+                    content_layout(body_layout),
+                    renderer,
+                    translation,
+                ));
+            }
+        }
+
+        (!overlays.is_empty()).then(|| Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Accordion<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(accordion: Accordion<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(accordion)
+    }
+}
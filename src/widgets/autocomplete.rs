@@ -0,0 +1,829 @@
+//! Use an autocomplete for entering text with suggestions matching the input.
+//!
+//! *This API requires the following crate features to be activated: `autocomplete`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay, renderer, text,
+        widget::{
+            operation,
+            tree::{self, Tag},
+            Id, Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor},
+    touch,
+    widget::{text_input, TextInput},
+    Border, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::any::Any;
+
+pub use crate::style::autocomplete::{Appearance, StyleSheet};
+
+/// The default padding of an [`Autocomplete`].
+const DEFAULT_PADDING: f32 = 5.0;
+
+/// The default maximum number of suggestions shown by an [`Autocomplete`].
+const DEFAULT_MAX_SUGGESTIONS: usize = 10;
+
+/// A text input showing the suggestions matching its value in a list below it.
+///
+/// The suggestions can be picked with the mouse or with the arrow keys and enter.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::Autocomplete;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Changed(String),
+///     Submitted,
+/// }
+///
+/// let suggestions = vec![String::from("Apple"), String::from("Banana")];
+/// let autocomplete = Autocomplete::new("", suggestions, Message::Changed)
+///     .max_suggestions(5)
+///     .match_anywhere(true)
+///     .on_submit(Message::Submitted);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Autocomplete<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+    Theme: text_input::StyleSheet + StyleSheet,
+{
+    /// The current value.
+    value: String,
+    /// The suggestions for the value.
+    suggestions: Vec<String>,
+    /// The function that produces a message when the value changes.
+    on_change: Box<dyn Fn(String) -> Message + 'a>,
+    /// The message that is produced when the value is submitted with enter.
+    on_submit: Option<Message>,
+    /// The maximum number of suggestions shown at once.
+    max_suggestions: usize,
+    /// Whether the suggestions are matched case sensitive.
+    case_sensitive: bool,
+    /// Whether the value may appear anywhere in a suggestion instead of only
+    /// at its start.
+    match_anywhere: bool,
+    /// The text shown while the value is empty.
+    placeholder: String,
+    /// The width of the [`Autocomplete`].
+    width: Length,
+    /// The padding of the [`Autocomplete`] and its suggestions.
+    padding: Padding,
+    /// The text size of the [`Autocomplete`], or the default size of the renderer.
+    size: Option<Pixels>,
+    /// The font of the [`Autocomplete`], or the default font of the renderer.
+    font: Option<Renderer::Font>,
+    /// The style of the suggestions of the [`Autocomplete`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Autocomplete<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+    Theme: text_input::StyleSheet + StyleSheet,
+{
+    /// Creates a new [`Autocomplete`].
+    ///
+    /// It expects:
+    ///     * the current value.
+    ///     * the suggestions for the value.
+    ///     * a function that will be called with the new value when it changes.
+    pub fn new<F>(value: &str, suggestions: Vec<String>, on_change: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        Self {
+            value: value.to_owned(),
+            suggestions,
+            on_change: Box::new(on_change),
+            on_submit: None,
+            max_suggestions: DEFAULT_MAX_SUGGESTIONS,
+            case_sensitive: false,
+            match_anywhere: false,
+            placeholder: String::new(),
+            width: Length::Fill,
+            padding: Padding::from(DEFAULT_PADDING),
+            size: None,
+            font: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the maximum number of suggestions shown by the [`Autocomplete`].
+    #[must_use]
+    pub fn max_suggestions(mut self, max_suggestions: usize) -> Self {
+        self.max_suggestions = max_suggestions;
+        self
+    }
+
+    /// Sets the message that is produced when enter is pressed while no
+    /// suggestion is selected.
+    #[must_use]
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets whether the suggestions are matched case sensitive.
+    #[must_use]
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Sets whether the value may appear anywhere in a suggestion instead of
+    /// only at its start.
+    #[must_use]
+    pub fn match_anywhere(mut self, match_anywhere: bool) -> Self {
+        self.match_anywhere = match_anywhere;
+        self
+    }
+
+    /// Sets the text shown while the value of the [`Autocomplete`] is empty.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the width of the [`Autocomplete`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the [`Autocomplete`] and its suggestions.
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Autocomplete`].
+    #[must_use]
+    pub fn size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`Autocomplete`].
+    #[must_use]
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the style of the suggestions of the [`Autocomplete`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The underlying text input.
+    fn input(&self) -> TextInput<'_, InputMessage, Theme, Renderer> {
+        let input = TextInput::new(&self.placeholder, &self.value)
+            .on_input(InputMessage::Change)
+            .width(self.width)
+            .padding(self.padding);
+        let input = match self.size {
+            Some(size) => input.size(size),
+            None => input,
+        };
+        let input = match self.font {
+            Some(font) => input.font(font),
+            None => input,
+        };
+
+        if self.on_submit.is_some() {
+            input.on_submit(InputMessage::Submit)
+        } else {
+            input
+        }
+    }
+
+    /// The suggestions matching the current value.
+    fn matches(&self) -> Vec<&str> {
+        matches(
+            &self.suggestions,
+            &self.value,
+            self.case_sensitive,
+            self.match_anywhere,
+            self.max_suggestions,
+        )
+    }
+}
+
+/// The suggestions matching the value, leaving out a suggestion equal to it.
+fn matches<'s>(
+    suggestions: &'s [String],
+    value: &str,
+    case_sensitive: bool,
+    match_anywhere: bool,
+    max_suggestions: usize,
+) -> Vec<&'s str> {
+    if value.is_empty() {
+        return Vec::new();
+    }
+
+    let fold = |text: &str| {
+        if case_sensitive {
+            text.to_owned()
+        } else {
+            text.to_lowercase()
+        }
+    };
+    let value_folded = fold(value);
+
+    suggestions
+        .iter()
+        .filter(|suggestion| suggestion.as_str() != value)
+        .filter(|suggestion| {
+            let suggestion = fold(suggestion);
+            if match_anywhere {
+                suggestion.contains(&value_folded)
+            } else {
+                suggestion.starts_with(&value_folded)
+            }
+        })
+        .take(max_suggestions)
+        .map(String::as_str)
+        .collect()
+}
+
+/// The messages of the underlying text input of an [`Autocomplete`].
+#[derive(Clone, Debug)]
+enum InputMessage {
+    /// The value of the text input changed.
+    Change(String),
+    /// Enter was pressed in the text input.
+    Submit,
+}
+
+/// The state of an [`Autocomplete`].
+#[derive(Debug, Default)]
+struct State {
+    /// The index of the selected suggestion.
+    hovered: Option<usize>,
+    /// Whether the suggestions were closed with escape.
+    dismissed: bool,
+}
+
+/// Whether the underlying text input of an [`Autocomplete`] is focused.
+fn is_focused<Renderer: text::Renderer>(tree: &Tree) -> bool {
+    tree.children[0]
+        .state
+        .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+        .is_focused()
+}
+
+/// Picks a suggestion, reporting it as the new value and moving the cursor
+/// of the text input behind it.
+fn pick<Message, Renderer: text::Renderer>(
+    tree: &mut Tree,
+    suggestion: &str,
+    on_change: &dyn Fn(String) -> Message,
+    shell: &mut Shell<'_, Message>,
+) {
+    let state = tree.state.downcast_mut::<State>();
+    state.hovered = None;
+
+    tree.children[0]
+        .state
+        .downcast_mut::<text_input::State<Renderer::Paragraph>>()
+        .move_cursor_to_end();
+
+    shell.publish(on_change(suggestion.to_owned()));
+}
+
+/// Forwards the operations on the underlying text input of an [`Autocomplete`],
+/// which produces its own messages instead of the messages of the application.
+struct InputOperation<'b, T>(&'b mut dyn Operation<T>);
+
+impl<T> Operation<InputMessage> for InputOperation<'_, T> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<InputMessage>),
+    ) {
+        self.0.container(id, bounds, &mut |operation| {
+            operate_on_children(&mut InputOperation(operation));
+        });
+    }
+
+    fn focusable(&mut self, state: &mut dyn operation::Focusable, id: Option<&Id>) {
+        self.0.focusable(state, id);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.0.scrollable(state, id, bounds, translation);
+    }
+
+    fn text_input(&mut self, state: &mut dyn operation::TextInput, id: Option<&Id>) {
+        self.0.text_input(state, id);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        self.0.custom(state, id);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Autocomplete<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + text_input::StyleSheet + StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.input() as &dyn Widget<InputMessage, Theme, Renderer>
+        )]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.input() as &dyn Widget<InputMessage, Theme, Renderer>]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        Widget::<InputMessage, Theme, Renderer>::layout(
+            &self.input(),
+            &mut tree.children[0],
+            renderer,
+            limits,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        Widget::<InputMessage, Theme, Renderer>::operate(
+            &self.input(),
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut InputOperation(operation),
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let matches = self.matches();
+        let state = tree.state.downcast_mut::<State>();
+        let shown = !state.dismissed && !matches.is_empty();
+
+        if shown && is_focused::<Renderer>(tree) {
+            if let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) = &event
+            {
+                let state = tree.state.downcast_mut::<State>();
+                let last = matches.len() - 1;
+
+                match key {
+                    Named::ArrowDown => {
+                        state.hovered = Some(state.hovered.map_or(0, |hovered| {
+                            if hovered >= last {
+                                0
+                            } else {
+                                hovered + 1
+                            }
+                        }));
+                        return event::Status::Captured;
+                    }
+                    Named::ArrowUp => {
+                        state.hovered = Some(
+                            state
+                                .hovered
+                                .map_or(last, |hovered| hovered.checked_sub(1).unwrap_or(last)),
+                        );
+                        return event::Status::Captured;
+                    }
+                    Named::Enter => {
+                        if let Some(suggestion) =
+                            state.hovered.and_then(|hovered| matches.get(hovered))
+                        {
+                            pick::<Message, Renderer>(tree, suggestion, &self.on_change, shell);
+                            return event::Status::Captured;
+                        }
+                    }
+                    Named::Escape => {
+                        state.dismissed = true;
+                        state.hovered = None;
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut messages = Vec::new();
+        let mut input_shell = Shell::new(&mut messages);
+        let status = Widget::<InputMessage, Theme, Renderer>::on_event(
+            &mut self.input(),
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            viewport,
+        );
+
+        if input_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if input_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+        if let Some(redraw_request) = input_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        for message in messages {
+            match message {
+                InputMessage::Change(value) => {
+                    let state = tree.state.downcast_mut::<State>();
+                    state.hovered = None;
+                    state.dismissed = false;
+                    shell.publish((self.on_change)(value));
+                }
+                InputMessage::Submit => {
+                    if let Some(on_submit) = &self.on_submit {
+                        shell.publish(on_submit.clone());
+                    }
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::<InputMessage, Theme, Renderer>::mouse_interaction(
+            &self.input(),
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        Widget::<InputMessage, Theme, Renderer>::draw(
+            &self.input(),
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let this: &'b Self = self;
+        let matches = this.matches();
+
+        if matches.is_empty()
+            || tree.state.downcast_ref::<State>().dismissed
+            || !is_focused::<Renderer>(tree)
+        {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(SuggestionList {
+            tree,
+            matches,
+            on_change: &this.on_change,
+            input_bounds: layout.bounds() + translation,
+            padding: this.padding,
+            text_size: this.size.unwrap_or_else(|| renderer.default_size()),
+            font: this.font.unwrap_or_else(|| renderer.default_font()),
+            style: &this.style,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Autocomplete<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + text_input::StyleSheet + StyleSheet,
+{
+    fn from(autocomplete: Autocomplete<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(autocomplete)
+    }
+}
+
+/// The list of suggestions shown below the text input of an [`Autocomplete`].
+struct SuggestionList<'b, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// The tree of the [`Autocomplete`].
+    tree: &'b mut Tree,
+    /// The suggestions matching the value.
+    matches: Vec<&'b str>,
+    /// The function that produces a message when a suggestion is picked.
+    on_change: &'b dyn Fn(String) -> Message,
+    /// The bounds of the text input.
+    input_bounds: Rectangle,
+    /// The padding of each suggestion.
+    padding: Padding,
+    /// The text size of the suggestions.
+    text_size: Pixels,
+    /// The font of the suggestions.
+    font: Renderer::Font,
+    /// The style of the list.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<Message, Theme, Renderer> SuggestionList<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// The height of a single suggestion.
+    fn suggestion_height(&self) -> f32 {
+        text::LineHeight::default().to_absolute(self.text_size).0 + self.padding.vertical()
+    }
+
+    /// The index of the suggestion under the cursor.
+    fn suggestion_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<usize> {
+        let position = cursor.position_over(bounds)?;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let index = ((position.y - bounds.y) / self.suggestion_height()) as usize;
+
+        (index < self.matches.len()).then_some(index)
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for SuggestionList<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        #[allow(clippy::cast_precision_loss)]
+        let height = self.matches.len() as f32 * self.suggestion_height();
+
+        let below = self.input_bounds.y + self.input_bounds.height;
+        let y = if below + height > bounds.height && self.input_bounds.y >= height {
+            self.input_bounds.y - height
+        } else {
+            below
+        };
+
+        Node::new(Size::new(self.input_bounds.width, height))
+            .move_to(Point::new(self.input_bounds.x, y))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(index) = self.suggestion_at(bounds, cursor) {
+                    self.tree.state.downcast_mut::<State>().hovered = Some(index);
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match self.suggestion_at(bounds, cursor) {
+                    Some(index) => {
+                        pick::<Message, Renderer>(
+                            self.tree,
+                            self.matches[index],
+                            self.on_change,
+                            shell,
+                        );
+
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.suggestion_at(layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(self.style);
+        let suggestion_height = self.suggestion_height();
+        let hovered = self.tree.state.downcast_ref::<State>().hovered;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        for (index, suggestion) in self.matches.iter().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let suggestion_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + index as f32 * suggestion_height,
+                width: bounds.width,
+                height: suggestion_height,
+            };
+
+            let color = if hovered == Some(index) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: suggestion_bounds,
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.hovered_background,
+                );
+
+                appearance.hovered_text_color
+            } else {
+                appearance.text_color
+            };
+
+            let text_bounds = Rectangle {
+                x: suggestion_bounds.x + self.padding.left,
+                y: suggestion_bounds.y + self.padding.top,
+                width: (suggestion_bounds.width - self.padding.horizontal()).max(0.0),
+                height: (suggestion_bounds.height - self.padding.vertical()).max(0.0),
+            };
+
+            renderer.fill_text(
+                iced::advanced::Text {
+                    content: suggestion,
+                    bounds: text_bounds.size(),
+                    size: self.text_size,
+                    line_height: text::LineHeight::default(),
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                },
+                Point::new(text_bounds.x, text_bounds.center_y()),
+                color,
+                bounds,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches;
+
+    fn suggestions() -> Vec<String> {
+        ["Apple", "Pineapple", "apricot", "Banana"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn matches_prefix_ignoring_case() {
+        let suggestions = suggestions();
+        assert_eq!(
+            matches(&suggestions, "ap", false, false, 10),
+            ["Apple", "apricot"]
+        );
+    }
+
+    #[test]
+    fn matches_case_sensitive() {
+        let suggestions = suggestions();
+        assert_eq!(matches(&suggestions, "Ap", true, false, 10), ["Apple"]);
+    }
+
+    #[test]
+    fn matches_anywhere() {
+        let suggestions = suggestions();
+        assert_eq!(
+            matches(&suggestions, "apple", false, true, 10),
+            ["Apple", "Pineapple"]
+        );
+    }
+
+    #[test]
+    fn matches_at_most_max_suggestions() {
+        let suggestions = suggestions();
+        assert_eq!(matches(&suggestions, "a", false, true, 2).len(), 2);
+    }
+
+    #[test]
+    fn matches_nothing_for_empty_or_equal_value() {
+        let suggestions = suggestions();
+        assert!(matches(&suggestions, "", false, true, 10).is_empty());
+        assert!(matches(&suggestions, "Banana", false, false, 10).is_empty());
+    }
+}
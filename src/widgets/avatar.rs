@@ -0,0 +1,430 @@
+//! Use an avatar for showing the picture or the initials of a user.
+//!
+//! *This API requires the following crate features to be activated: `avatar`*
+
+use iced::{
+    advanced::{
+        image,
+        layout::{Limits, Node},
+        renderer,
+        text::{self, Text},
+        widget::Tree,
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+pub use crate::style::avatar::{Appearance, StyleSheet};
+
+/// The size of the initials relative to the size of an [`Avatar`].
+const TEXT_SIZE: f32 = 0.4;
+
+/// The content shown by an [`Avatar`].
+#[derive(Clone, Debug)]
+pub enum AvatarContent {
+    /// A picture of the user.
+    Image(image::Handle),
+    /// The initials of the user, used when no picture is available.
+    Initials(String),
+}
+
+impl From<image::Handle> for AvatarContent {
+    fn from(handle: image::Handle) -> Self {
+        Self::Image(handle)
+    }
+}
+
+impl From<&str> for AvatarContent {
+    fn from(initials: &str) -> Self {
+        Self::Initials(initials.to_owned())
+    }
+}
+
+impl From<String> for AvatarContent {
+    fn from(initials: String) -> Self {
+        Self::Initials(initials)
+    }
+}
+
+/// The shape of an [`Avatar`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AvatarShape {
+    /// A circle.
+    #[default]
+    Circle,
+    /// A square with corners rounded by the given radius.
+    RoundedSquare(f32),
+}
+
+impl AvatarShape {
+    /// The border radius of an [`Avatar`] of the given size.
+    fn radius(self, size: f32) -> f32 {
+        match self {
+            Self::Circle => size / 2.0,
+            Self::RoundedSquare(radius) => radius.clamp(0.0, size / 2.0),
+        }
+    }
+
+    /// Whether the given point is inside of an [`Avatar`] with this shape in the given bounds.
+    fn contains(self, bounds: Rectangle, point: Point) -> bool {
+        if !bounds.contains(point) {
+            return false;
+        }
+
+        // Outside of the corners the point has to be within the radius of the
+        // closest corner center.
+        let radius = self.radius(bounds.width.min(bounds.height));
+        let closest = Point::new(
+            point
+                .x
+                .clamp(bounds.x + radius, bounds.x + bounds.width - radius),
+            point
+                .y
+                .clamp(bounds.y + radius, bounds.y + bounds.height - radius),
+        );
+
+        closest.distance(point) <= radius
+    }
+}
+
+/// The picture of a user framed by a circle or a rounded square, or their
+/// initials if no picture is available.
+///
+/// The picture covers the bounds of the [`Avatar`] and is clipped to them, its
+/// corners are not rounded.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::Avatar;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     OpenProfile,
+/// }
+///
+/// let avatar = Avatar::from_initials("JD")
+///     .size(48.0)
+///     .on_press(Message::OpenProfile);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Avatar<Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// The content of the [`Avatar`].
+    content: AvatarContent,
+    /// The shape of the [`Avatar`].
+    shape: AvatarShape,
+    /// The width and the height of the [`Avatar`].
+    size: f32,
+    /// The border color of the [`Avatar`] or `None` for the color of the style.
+    border_color: Option<Color>,
+    /// The border width of the [`Avatar`] or `None` for the width of the style.
+    border_width: Option<f32>,
+    /// The background behind the initials or `None` for the color of the style.
+    background_color: Option<Color>,
+    /// The font of the initials or `None` for the default.
+    font: Option<Renderer::Font>,
+    /// The message produced when the [`Avatar`] is pressed.
+    on_press: Option<Message>,
+    /// The style of the [`Avatar`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Message, Theme, Renderer> Avatar<Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Avatar`] showing the given picture or initials.
+    pub fn new(content: impl Into<AvatarContent>) -> Self {
+        Self {
+            content: content.into(),
+            shape: AvatarShape::default(),
+            size: 40.0,
+            border_color: None,
+            border_width: None,
+            background_color: None,
+            font: None,
+            on_press: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Creates a new [`Avatar`] showing the given picture.
+    #[must_use]
+    pub fn from_image(handle: image::Handle) -> Self {
+        Self::new(AvatarContent::Image(handle))
+    }
+
+    /// Creates a new [`Avatar`] showing the first one or two characters of
+    /// the given initials.
+    pub fn from_initials(initials: impl Into<String>) -> Self {
+        Self::new(AvatarContent::Initials(initials.into()))
+    }
+
+    /// Sets the shape of the [`Avatar`].
+    #[must_use]
+    pub fn shape(mut self, shape: AvatarShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the width and the height of the [`Avatar`].
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the border color of the [`Avatar`].
+    #[must_use]
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.border_color = Some(color);
+        self
+    }
+
+    /// Sets the border width of the [`Avatar`].
+    #[must_use]
+    pub fn border_width(mut self, width: f32) -> Self {
+        self.border_width = Some(width);
+        self
+    }
+
+    /// Sets the background behind the initials of the [`Avatar`].
+    #[must_use]
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    /// Sets the font of the initials of the [`Avatar`].
+    #[must_use]
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the message produced when the [`Avatar`] is pressed.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Avatar`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// The first one or two characters of the given initials.
+fn initials(initials: &str) -> String {
+    initials
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .take(2)
+        .collect()
+}
+
+/// The bounds of a picture of the given size covering the given bounds.
+#[allow(clippy::cast_precision_loss)]
+fn cover(bounds: Rectangle, image: Size<u32>) -> Rectangle {
+    let image = Size::new(image.width as f32, image.height as f32);
+    let scale = (bounds.width / image.width).max(bounds.height / image.height);
+    let size = Size::new(image.width * scale, image.height * scale);
+
+    Rectangle::new(
+        Point::new(
+            bounds.center_x() - size.width / 2.0,
+            bounds.center_y() - size.height / 2.0,
+        ),
+        size,
+    )
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Avatar<Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer + image::Renderer<Handle = image::Handle>,
+    Theme: StyleSheet,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.size), Length::Fixed(self.size))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(self.size, self.size, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(on_press) = &self.on_press else {
+            return event::Status::Ignored;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor
+                    .position()
+                    .is_some_and(|position| self.shape.contains(layout.bounds(), position)) =>
+            {
+                shell.publish(on_press.clone());
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let hovered = cursor
+            .position()
+            .is_some_and(|position| self.shape.contains(layout.bounds(), position));
+
+        if self.on_press.is_some() && hovered {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        if !bounds.intersects(viewport) {
+            return;
+        }
+
+        let appearance = theme.active(&self.style);
+        let border = Border {
+            radius: self.shape.radius(bounds.width.min(bounds.height)).into(),
+            width: self.border_width.unwrap_or(appearance.border_width),
+            color: self.border_color.unwrap_or(appearance.border_color),
+        };
+
+        match &self.content {
+            AvatarContent::Image(handle) => {
+                // Images can't be clipped to rounded corners, so they are only
+                // clipped to the bounds with the border drawn on top.
+                let image_bounds = cover(bounds, renderer.dimensions(handle));
+                renderer.with_layer(bounds, |renderer| {
+                    image::Renderer::draw(
+                        renderer,
+                        handle.clone(),
+                        image::FilterMethod::default(),
+                        image_bounds,
+                    );
+                });
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border,
+                        shadow: Shadow::default(),
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+            AvatarContent::Initials(content) => {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border,
+                        shadow: Shadow::default(),
+                    },
+                    self.background_color.unwrap_or(appearance.background_color),
+                );
+
+                renderer.fill_text(
+                    Text {
+                        content: &initials(content),
+                        bounds: bounds.size(),
+                        size: Pixels(bounds.height * TEXT_SIZE),
+                        line_height: text::LineHeight::default(),
+                        font: self.font.unwrap_or_else(|| renderer.default_font()),
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        shaping: text::Shaping::Advanced,
+                    },
+                    bounds.center(),
+                    appearance.text_color,
+                    bounds,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Avatar<Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer + image::Renderer<Handle = image::Handle>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(avatar: Avatar<Message, Theme, Renderer>) -> Self {
+        Element::new(avatar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{initials, AvatarShape};
+    use iced::{Point, Rectangle, Size};
+
+    #[test]
+    fn initials_test() {
+        assert_eq!(initials("JD"), "JD");
+        assert_eq!(initials("John"), "Jo");
+        assert_eq!(initials(" J D "), "JD");
+        assert_eq!(initials("J"), "J");
+        assert_eq!(initials(""), "");
+    }
+
+    #[test]
+    fn contains_test() {
+        let bounds = Rectangle::new(Point::new(10.0, 10.0), Size::new(40.0, 40.0));
+
+        // The corners of the bounds are outside of a circle
+        assert!(AvatarShape::Circle.contains(bounds, Point::new(30.0, 30.0)));
+        assert!(AvatarShape::Circle.contains(bounds, Point::new(30.0, 11.0)));
+        assert!(!AvatarShape::Circle.contains(bounds, Point::new(12.0, 12.0)));
+        assert!(!AvatarShape::Circle.contains(bounds, Point::new(5.0, 30.0)));
+
+        let square = AvatarShape::RoundedSquare(5.0);
+        assert!(square.contains(bounds, Point::new(12.0, 16.0)));
+        assert!(!square.contains(bounds, Point::new(10.5, 10.5)));
+        assert!(AvatarShape::RoundedSquare(0.0).contains(bounds, Point::new(10.5, 10.5)));
+    }
+}
@@ -6,12 +6,15 @@ use iced::{
     advanced::{
         layout::{Limits, Node},
         renderer,
-        widget::Tree,
+        text::{self, Paragraph as _},
+        widget::{Operation, Tree},
         Clipboard, Layout, Shell, Widget,
     },
+    alignment::{Horizontal, Vertical},
     event,
     mouse::{self, Cursor},
-    Alignment, Border, Color, Element, Event, Length, Padding, Point, Rectangle, Shadow, Size,
+    Alignment, Background, Border, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Size,
 };
 
 pub use crate::style::badge::{Appearance, StyleSheet};
@@ -275,3 +278,302 @@ where
         Self::new(badge)
     }
 }
+
+/// The corner of the child element a [`CountBadge`] is placed at.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BadgePosition {
+    /// The badge is placed at the top right corner.
+    #[default]
+    TopRight,
+    /// The badge is placed at the top left corner.
+    TopLeft,
+    /// The badge is placed at the bottom right corner.
+    BottomRight,
+    /// The badge is placed at the bottom left corner.
+    BottomLeft,
+}
+
+/// The text size of the count of a [`CountBadge`].
+const COUNT_TEXT_SIZE: f32 = 12.0;
+
+/// The padding between the count and the border of a [`CountBadge`].
+const COUNT_PADDING: f32 = 3.0;
+
+/// A small circle showing a count in a corner of a child element.
+///
+/// The circle is drawn on top of the child and does not affect its layout.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::{badge::BadgePosition, BadgeStyles, CountBadge};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+/// }
+///
+/// let badge = CountBadge::<Message>::new(Text::new("Inbox"), 120)
+///     .max_count(99)
+///     .position(BadgePosition::TopRight)
+///     .style(BadgeStyles::Danger);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct CountBadge<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The child [`Element`] of the [`CountBadge`].
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The count shown by the [`CountBadge`].
+    count: u32,
+    /// The highest count that is shown before the count is cut off.
+    max_count: Option<u32>,
+    /// The corner of the child the [`CountBadge`] is placed at.
+    position: BadgePosition,
+    /// The color overriding the background of the style.
+    color: Option<Color>,
+    /// The color overriding the text color of the style.
+    text_color: Option<Color>,
+    /// The style of the [`CountBadge`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> CountBadge<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`CountBadge`] showing the count on top of the given child.
+    ///
+    /// It expects:
+    ///     * the child [`Element`] the count is shown on.
+    ///     * the count to show.
+    pub fn new<T>(content: T, count: u32) -> Self
+    where
+        T: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        CountBadge {
+            content: content.into(),
+            count,
+            max_count: None,
+            position: BadgePosition::default(),
+            color: None,
+            text_color: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the highest count of the [`CountBadge`].
+    ///
+    /// Higher counts are shown as the maximum followed by a `+`, e.g. `99+`.
+    #[must_use]
+    pub fn max_count(mut self, max_count: u32) -> Self {
+        self.max_count = Some(max_count);
+        self
+    }
+
+    /// Sets the corner of the child the [`CountBadge`] is placed at.
+    #[must_use]
+    pub fn position(mut self, position: BadgePosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the background color of the [`CountBadge`], overriding the style.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the text color of the [`CountBadge`], overriding the style.
+    #[must_use]
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+
+    /// Sets the style of the [`CountBadge`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The text shown by the [`CountBadge`].
+    fn label(&self) -> String {
+        match self.max_count {
+            Some(max_count) if self.count > max_count => format!("{max_count}+"),
+            _ => self.count.to_string(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CountBadge<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let bounds = layout.bounds();
+        let style_sheet = theme.active(&self.style);
+        let label = self.label();
+
+        let text = iced::advanced::Text {
+            content: &label,
+            bounds: Size::INFINITY,
+            size: Pixels(COUNT_TEXT_SIZE),
+            font: renderer.default_font(),
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Basic,
+        };
+        let text_size = Renderer::Paragraph::with_text(text).min_bounds();
+
+        let height = text_size.height + 2.0 * COUNT_PADDING;
+        let width = (text_size.width + 2.0 * COUNT_PADDING).max(height);
+
+        let center = match self.position {
+            BadgePosition::TopRight => Point::new(bounds.x + bounds.width, bounds.y),
+            BadgePosition::TopLeft => Point::new(bounds.x, bounds.y),
+            BadgePosition::BottomRight => {
+                Point::new(bounds.x + bounds.width, bounds.y + bounds.height)
+            }
+            BadgePosition::BottomLeft => Point::new(bounds.x, bounds.y + bounds.height),
+        };
+        let badge_bounds = Rectangle {
+            x: center.x - width / 2.0,
+            y: center.y - height / 2.0,
+            width,
+            height,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: badge_bounds,
+                border: Border {
+                    radius: style_sheet.border_radius.unwrap_or(height / 2.0).into(),
+                    width: style_sheet.border_width,
+                    color: style_sheet.border_color.unwrap_or(Color::BLACK),
+                },
+                shadow: Shadow::default(),
+            },
+            self.color.map_or(style_sheet.background, Background::Color),
+        );
+
+        renderer.fill_text(
+            iced::advanced::Text {
+                bounds: badge_bounds.size(),
+                ..text
+            },
+            badge_bounds.center(),
+            self.text_color.unwrap_or(style_sheet.text_color),
+            badge_bounds,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CountBadge<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(badge: CountBadge<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(badge)
+    }
+}
@@ -0,0 +1,348 @@
+//! Use a breadcrumb for navigating back up a hierarchy.
+//!
+//! *This API requires the following crate features to be activated: `breadcrumb`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::{self, Paragraph as _},
+        widget::Tree,
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::Text,
+    Element, Event, Length, Pixels, Point, Rectangle, Size,
+};
+
+pub use crate::style::breadcrumb::{Appearance, StyleSheet};
+
+/// An item of a [`Breadcrumb`].
+#[derive(Clone, Debug)]
+pub struct BreadcrumbItem<Message> {
+    /// The text of the item.
+    label: String,
+    /// The message produced when the item is pressed.
+    on_press: Option<Message>,
+}
+
+impl<Message> BreadcrumbItem<Message> {
+    /// Creates a new [`BreadcrumbItem`] with the given text.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            on_press: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the [`BreadcrumbItem`] is pressed.
+    ///
+    /// The last item of a [`Breadcrumb`] can't be pressed.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+}
+
+/// A trail of links to the levels of a hierarchy leading to the current one.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{breadcrumb::BreadcrumbItem, Breadcrumb};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Home,
+///     Documents,
+/// }
+///
+/// let breadcrumb = Breadcrumb::new(vec![
+///     BreadcrumbItem::new("Home").on_press(Message::Home),
+///     BreadcrumbItem::new("Documents").on_press(Message::Documents),
+///     BreadcrumbItem::new("Report.pdf"),
+/// ]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Breadcrumb<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// The items of the [`Breadcrumb`].
+    items: Vec<BreadcrumbItem<Message>>,
+    /// The element shown between the items.
+    separator: Element<'a, Message, Theme, Renderer>,
+    /// The space between the items and the separators.
+    spacing: f32,
+    /// The text size of the items, or the default size of the renderer.
+    text_size: Option<Pixels>,
+    /// The style of the [`Breadcrumb`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Breadcrumb<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet + iced::widget::text::StyleSheet,
+{
+    /// Creates a new [`Breadcrumb`] with the given items, from the top of the
+    /// hierarchy down to the current level.
+    #[must_use]
+    pub fn new(items: Vec<BreadcrumbItem<Message>>) -> Self {
+        Self {
+            items,
+            separator: Text::new(" / ").into(),
+            spacing: 4.0,
+            text_size: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the element shown between the items of the [`Breadcrumb`].
+    #[must_use]
+    pub fn separator(
+        mut self,
+        separator: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.separator = separator.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Breadcrumb<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// Sets the space between the items and the separators of the [`Breadcrumb`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the items of the [`Breadcrumb`].
+    #[must_use]
+    pub fn text_size<P: Into<Pixels>>(mut self, text_size: P) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the style of the [`Breadcrumb`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The message of the item that can be pressed at the cursor.
+    fn hovered_message(&self, layout: Layout<'_>, cursor: Cursor) -> Option<&Message> {
+        let last = self.items.len().saturating_sub(1);
+
+        self.items
+            .iter()
+            .zip(layout.children().step_by(2))
+            .take(last)
+            .find(|(_, layout)| cursor.is_over(layout.bounds()))
+            .and_then(|(item, _)| item.on_press.as_ref())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Breadcrumb<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.separator)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.separator));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let max_width = limits.max().width;
+
+        let separator = self.separator.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, limits.max()),
+        );
+
+        // Items and separators alternate, wrapping to a new row before an item
+        // that doesn't fit.
+        let mut nodes: Vec<Node> = Vec::with_capacity(2 * self.items.len());
+        let mut rows: Vec<(usize, f32)> = Vec::new();
+        let (mut x, mut row_start, mut row_height, mut width) = (0.0_f32, 0, 0.0_f32, 0.0_f32);
+
+        for (index, item) in self.items.iter().enumerate() {
+            if index > 0 {
+                nodes.push(separator.clone().move_to(Point::new(x, 0.0)));
+                x += separator.size().width + self.spacing;
+                row_height = row_height.max(separator.size().height);
+            }
+
+            let size = Renderer::Paragraph::with_text(text::Text {
+                content: &item.label,
+                bounds: Size::INFINITY,
+                size: text_size,
+                line_height: text::LineHeight::default(),
+                font: renderer.default_font(),
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            })
+            .min_bounds();
+
+            if x > 0.0 && x + size.width > max_width {
+                rows.push((row_start, row_height));
+                width = width.max(x - self.spacing);
+                (x, row_start, row_height) = (0.0, nodes.len(), 0.0);
+            }
+
+            nodes.push(Node::new(size).move_to(Point::new(x, 0.0)));
+            x += size.width + self.spacing;
+            row_height = row_height.max(size.height);
+        }
+        rows.push((row_start, row_height));
+        width = width.max(x - self.spacing);
+
+        let mut y = 0.0;
+        for (row, &(start, height)) in rows.iter().enumerate() {
+            let end = rows.get(row + 1).map_or(nodes.len(), |(end, _)| *end);
+
+            for node in &mut nodes[start..end] {
+                let offset = (height - node.size().height) / 2.0;
+                node.move_to_mut(Point::new(node.bounds().x, y + offset));
+            }
+
+            y += height + self.spacing;
+        }
+
+        let size = Size::new(width.max(0.0), (y - self.spacing).max(0.0));
+
+        Node::with_children(limits.resolve(Length::Shrink, Length::Shrink, size), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        _tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(message) = self.hovered_message(layout, cursor) {
+                    shell.publish(message.clone());
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.hovered_message(layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let appearance = theme.active(&self.style);
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let last = self.items.len().saturating_sub(1);
+
+        for (index, layout) in layout.children().enumerate() {
+            if index % 2 == 1 {
+                self.separator.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor,
+                    viewport,
+                );
+                continue;
+            }
+
+            let item = &self.items[index / 2];
+            let bounds = layout.bounds();
+            let color = if index / 2 == last || item.on_press.is_none() {
+                appearance.inactive_link_color
+            } else if cursor.is_over(bounds) {
+                appearance.hovered_link_color
+            } else {
+                appearance.active_link_color
+            };
+
+            renderer.fill_text(
+                text::Text {
+                    content: &item.label,
+                    bounds: bounds.size(),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                },
+                Point::new(bounds.x, bounds.center_y()),
+                color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Breadcrumb<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(breadcrumb: Breadcrumb<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(breadcrumb)
+    }
+}
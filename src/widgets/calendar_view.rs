@@ -0,0 +1,435 @@
+//! Use a calendar view for showing a month and picking a date from it.
+//!
+//! *This API requires the following crate features to be activated: `date_picker`*
+
+use super::overlay::date_picker::{
+    self, appearances, calendar, days, highlight_tooltip, hovered_day, month_year,
+    month_year_interaction, on_event_month_year, CalendarOptions, DayStyles, Focus,
+};
+
+use crate::{core::date::DateRange, style::style_state::StyleState};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::Renderer as _,
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Renderer as _, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::{container, text},
+    Border,
+    Element,
+    Event,
+    Length,
+    Padding,
+    Pixels,
+    Point,
+    Rectangle,
+    Renderer, // the actual type
+    Shadow,
+    Size,
+};
+
+pub use crate::{
+    core::date::{Date, Weekday},
+    style::date_picker::{Appearance, StyleSheet},
+    widgets::date_picker::HighlightedDate,
+};
+
+/// An always visible calendar showing a month with arrows for navigating
+/// to the other months and years.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{calendar_view, CalendarView};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Select(calendar_view::Date),
+/// }
+///
+/// let calendar_view = CalendarView::new(Some(calendar_view::Date::today()), Message::Select)
+///     .show_week_numbers(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct CalendarView<'a, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The selected date or `None` if no date is selected.
+    selected: Option<Date>,
+    /// The function that produces a message when a day is clicked.
+    on_select: Box<dyn Fn(Date) -> Message + 'a>,
+    /// The options of the calendar table.
+    options: CalendarOptions,
+    /// The dates that are marked in the calendar.
+    highlights: Vec<HighlightedDate>,
+    /// The font size of text and icons in the [`CalendarView`].
+    font_size: Option<Pixels>,
+    /// The padding between the border and the calendar.
+    padding: Padding,
+    /// The style of the [`CalendarView`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> CalendarView<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`CalendarView`].
+    ///
+    /// It expects:
+    ///     * the selected date or `None` if no date is selected.
+    ///     * a function that will be called with the clicked date.
+    pub fn new<F>(selected: Option<Date>, on_select: F) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        Self {
+            selected,
+            on_select: Box::new(on_select),
+            options: CalendarOptions::default(),
+            highlights: Vec::new(),
+            font_size: None,
+            padding: Padding::from(date_picker::DEFAULT_PADDING),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the earliest date that can be picked in the [`CalendarView`].
+    ///
+    /// Days before this date are shown as disabled.
+    #[must_use]
+    pub fn min_date(mut self, date: impl Into<Date>) -> Self {
+        self.options.date_bounds.min = Some(date.into().into());
+        self
+    }
+
+    /// Sets the latest date that can be picked in the [`CalendarView`].
+    ///
+    /// Days after this date are shown as disabled.
+    #[must_use]
+    pub fn max_date(mut self, date: impl Into<Date>) -> Self {
+        self.options.date_bounds.max = Some(date.into().into());
+        self
+    }
+
+    /// Marks the given dates in the [`CalendarView`] with a colored dot.
+    ///
+    /// Hovering a marked day shows its tooltip, if any.
+    #[must_use]
+    pub fn highlight_dates(mut self, highlights: impl Into<Vec<HighlightedDate>>) -> Self {
+        self.highlights = highlights.into();
+        self
+    }
+
+    /// Shows a column with the ISO 8601 week numbers in front of the days.
+    #[must_use]
+    pub fn show_week_numbers(mut self, show_week_numbers: bool) -> Self {
+        self.options.show_week_numbers = show_week_numbers;
+        self
+    }
+
+    /// Sets the weekday shown in the first column of the [`CalendarView`].
+    #[must_use]
+    pub fn first_day_of_week(mut self, first_day_of_week: Weekday) -> Self {
+        self.options.first_day_of_week = first_day_of_week;
+        self
+    }
+
+    /// Sets the font size of the [`CalendarView`].
+    #[must_use]
+    pub fn font_size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.font_size = Some(size.into());
+        self
+    }
+
+    /// Sets the padding between the border and the calendar.
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`CalendarView`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// The state of a [`CalendarView`].
+#[derive(Debug)]
+struct State {
+    /// The state of the shown month.
+    calendar: date_picker::State,
+    /// The selected date the shown month was last moved to.
+    selected: Option<Date>,
+}
+
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for CalendarView<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet + text::StyleSheet + container::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let calendar = self.selected.map_or_else(
+            || {
+                let state = date_picker::State::default();
+                date_picker::State::new(self.options.date_bounds.clamp(state.date))
+            },
+            |date| date_picker::State::new(date.into()),
+        );
+
+        tree::State::new(State {
+            calendar,
+            selected: self.selected,
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Show the month of a newly selected date.
+        if state.selected != self.selected {
+            state.selected = self.selected;
+            if let Some(date) = self.selected {
+                state.calendar.date = date.into();
+            }
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let font_size = self.font_size.unwrap_or_else(|| renderer.default_size());
+        let limits = limits
+            .shrink(self.padding)
+            .width(Length::Shrink)
+            .height(Length::Shrink);
+
+        let element: Element<Message, Theme, Renderer> =
+            calendar(font_size, self.options.show_week_numbers, Length::Shrink).into();
+        let mut element_tree = Tree::new(&element);
+
+        let col = element
+            .as_widget()
+            .layout(&mut element_tree, renderer, &limits)
+            .move_to(Point::new(self.padding.left, self.padding.top));
+
+        Node::with_children(col.size().expand(self.padding), vec![col])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let mut children = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a calendar layout")
+            .children();
+
+        let month_year_layout = children
+            .next()
+            .expect("Native: Layout should have a month/year layout");
+        let month_year_status = on_event_month_year(
+            &mut state.calendar,
+            &self.options,
+            &event,
+            month_year_layout,
+            cursor,
+        );
+
+        let days_layout = children
+            .next()
+            .expect("Native: Layout should have a days table parent")
+            .children()
+            .next()
+            .expect("Native: Layout should have a days table layout");
+
+        let days_status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                hovered_day(state.calendar.date, &self.options, days_layout, cursor).map_or(
+                    event::Status::Ignored,
+                    |date| {
+                        state.calendar.date = date;
+                        shell.publish((self.on_select)(date.into()));
+                        event::Status::Captured
+                    },
+                )
+            }
+            _ => event::Status::Ignored,
+        };
+
+        month_year_status.merge(days_status)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout
+            .children()
+            .next()
+            .expect("Graphics: Layout should have a calendar layout")
+            .children();
+
+        let month_year_mouse_interaction = month_year_interaction(
+            children
+                .next()
+                .expect("Graphics: Layout should have a month/year layout"),
+            cursor,
+        );
+
+        let days_layout = children
+            .next()
+            .expect("Graphics: Layout should have a days layout parent")
+            .children()
+            .next()
+            .expect("Graphics: Layout should have a days layout");
+
+        let table_mouse_interaction =
+            if hovered_day(state.calendar.date, &self.options, days_layout, cursor).is_some() {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
+
+        month_year_mouse_interaction.max(table_mouse_interaction)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let font_size = self.font_size.unwrap_or_else(|| renderer.default_size());
+
+        let style_sheet = appearances(theme, &self.style);
+        let day_styles = DayStyles::new(theme, &self.style);
+
+        let style_state = if cursor.is_over(bounds) {
+            StyleState::Hovered
+        } else {
+            StyleState::Active
+        };
+
+        // Background
+        if (bounds.width > 0.) && (bounds.height > 0.) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: style_sheet[&style_state].border_radius.into(),
+                        width: style_sheet[&style_state].border_width,
+                        color: style_sheet[&style_state].border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                style_sheet[&style_state].background,
+            );
+        }
+
+        let mut children = layout
+            .children()
+            .next()
+            .expect("Graphics: Layout should have a calendar layout")
+            .children();
+
+        // ----------- Year/Month----------------------
+        month_year(
+            renderer,
+            children
+                .next()
+                .expect("Graphics: Layout should have a month/year layout"),
+            &crate::core::date::date_as_string(state.calendar.date),
+            cursor.position().unwrap_or_default(),
+            &style_sheet,
+            Focus::None,
+            font_size,
+        );
+
+        // ----------- Days ---------------------------
+        let days_layout = children
+            .next()
+            .expect("Graphics: Layout should have a days layout parent")
+            .children()
+            .next()
+            .expect("Graphics: Layout should have a days layout");
+
+        let hovered_tooltip = days(
+            renderer,
+            days_layout,
+            state.calendar.date,
+            state.calendar.date,
+            self.selected.map(|date| DateRange::new(date, date)),
+            &self.options,
+            &self.highlights,
+            cursor.position().unwrap_or_default(),
+            &style_sheet,
+            &day_styles,
+            Focus::None,
+            font_size,
+        );
+
+        if let Some((cell_bounds, tooltip)) = hovered_tooltip {
+            highlight_tooltip(
+                renderer,
+                cell_bounds,
+                tooltip,
+                bounds,
+                &style_sheet[&StyleState::Active],
+                font_size,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<CalendarView<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet + text::StyleSheet + container::StyleSheet,
+{
+    fn from(calendar_view: CalendarView<'a, Message, Theme>) -> Self {
+        Element::new(calendar_view)
+    }
+}
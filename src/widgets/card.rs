@@ -76,6 +76,8 @@ where
     foot: Option<Element<'a, Message, Theme, Renderer>>,
     /// The style of the [`Card`].
     style: <Theme as StyleSheet>::Style,
+    /// The optional border radius of the [`Card`] overriding the one of the style.
+    border_radius: Option<f32>,
 }
 
 impl<'a, Message, Theme, Renderer> Card<'a, Message, Theme, Renderer>
@@ -107,6 +109,7 @@ where
             body: body.into(),
             foot: None,
             style: <Theme as StyleSheet>::Style::default(),
+            border_radius: None,
         }
     }
 
@@ -120,6 +123,13 @@ where
         self
     }
 
+    /// Sets the border radius of the [`Card`], overriding the border radius of its style.
+    #[must_use]
+    pub fn border_radius(mut self, border_radius: f32) -> Self {
+        self.border_radius = Some(border_radius);
+        self
+    }
+
     /// Sets the size of the close icon of the [`Card`].
     #[must_use]
     pub fn close_size(mut self, size: f32) -> Self {
@@ -501,6 +511,7 @@ where
         let bounds = layout.bounds();
         let mut children = layout.children();
         let style_sheet = theme.active(&self.style);
+        let border_radius = self.border_radius.unwrap_or(style_sheet.border_radius);
 
         if bounds.intersects(viewport) {
             // Background
@@ -508,7 +519,7 @@ where
                 renderer::Quad {
                     bounds,
                     border: Border {
-                        radius: style_sheet.border_radius.into(),
+                        radius: border_radius.into(),
                         width: style_sheet.border_width,
                         color: style_sheet.border_color,
                     },
@@ -523,7 +534,7 @@ where
                 renderer::Quad {
                     bounds,
                     border: Border {
-                        radius: style_sheet.border_radius.into(),
+                        radius: border_radius.into(),
                         width: style_sheet.border_width,
                         color: style_sheet.border_color,
                     },
@@ -547,6 +558,7 @@ where
             theme,
             &self.style,
             self.close_size,
+            self.border_radius,
         );
 
         // ----------- Body ----------------------
@@ -577,6 +589,7 @@ where
             viewport,
             theme,
             &self.style,
+            self.border_radius,
         );
     }
 
@@ -750,6 +763,7 @@ fn draw_head<Message, Theme, Renderer>(
     theme: &Theme,
     style: &<Theme as StyleSheet>::Style,
     close_size: Option<f32>,
+    border_radius: Option<f32>,
 ) where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: StyleSheet,
@@ -757,7 +771,7 @@ fn draw_head<Message, Theme, Renderer>(
     let mut head_children = layout.children();
     let style_sheet = theme.active(style);
     let bounds = layout.bounds();
-    let border_radius = style_sheet.border_radius;
+    let border_radius = border_radius.unwrap_or(style_sheet.border_radius);
 
     // Head background
     if bounds.intersects(viewport) {
@@ -897,6 +911,7 @@ fn draw_foot<Message, Theme, Renderer>(
     viewport: &Rectangle,
     theme: &Theme,
     style: &<Theme as StyleSheet>::Style,
+    border_radius: Option<f32>,
 ) where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: StyleSheet,
@@ -911,7 +926,7 @@ fn draw_foot<Message, Theme, Renderer>(
             renderer::Quad {
                 bounds,
                 border: Border {
-                    radius: style_sheet.border_radius.into(),
+                    radius: border_radius.unwrap_or(style_sheet.border_radius).into(),
                     width: 0.0,
                     color: Color::TRANSPARENT,
                 },
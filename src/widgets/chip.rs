@@ -0,0 +1,401 @@
+//! Use a chip for showing a compact, optionally interactive label.
+//!
+//! *This API requires the following crate features to be activated: `chip`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::{self, Paragraph as _},
+        widget::Tree,
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch, Border, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+pub use crate::style::chip::{Appearance, StyleSheet};
+
+/// The mark of the button deleting a [`Chip`].
+const DELETE_MARK: &str = "×";
+
+/// A compact, pill-shaped label that can be pressed, selected or deleted.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::Chip;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggle,
+///     Delete,
+/// }
+///
+/// let chip = Chip::new("Rust")
+///     .on_press(Message::Toggle)
+///     .on_delete(Message::Delete)
+///     .selected(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Chip<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// The label of the [`Chip`].
+    label: String,
+    /// The icon shown in front of the label of the [`Chip`].
+    icon: Option<Element<'a, Message, Theme, Renderer>>,
+    /// The message produced when the [`Chip`] is pressed.
+    on_press: Option<Message>,
+    /// The message produced when the delete button of the [`Chip`] is pressed.
+    on_delete: Option<Message>,
+    /// Whether the [`Chip`] is selected.
+    selected: bool,
+    /// The padding of the [`Chip`].
+    padding: Padding,
+    /// The spacing between the icon, the label and the delete button.
+    spacing: f32,
+    /// The text size of the [`Chip`] or `None` for the default.
+    text_size: Option<Pixels>,
+    /// The font of the [`Chip`] or `None` for the default.
+    font: Option<Renderer::Font>,
+    /// The style of the [`Chip`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Chip<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Chip`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Chip {
+            label: label.into(),
+            icon: None,
+            on_press: None,
+            on_delete: None,
+            selected: false,
+            padding: Padding::from([4, 10]),
+            spacing: 4.0,
+            text_size: None,
+            font: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message produced when the [`Chip`] is pressed.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the delete button of the [`Chip`] is pressed.
+    ///
+    /// The delete button is only shown if this is set.
+    #[must_use]
+    pub fn on_delete(mut self, message: Message) -> Self {
+        self.on_delete = Some(message);
+        self
+    }
+
+    /// Sets whether the [`Chip`] is selected.
+    #[must_use]
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the icon shown in front of the label of the [`Chip`].
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the padding of the [`Chip`].
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the icon, the label and the delete button of the [`Chip`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the [`Chip`].
+    #[must_use]
+    pub fn text_size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`Chip`].
+    #[must_use]
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Chip`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The text of the label or the delete button.
+    fn text<'b>(&self, content: &'b str, renderer: &Renderer) -> text::Text<'b, Renderer::Font> {
+        text::Text {
+            content,
+            bounds: Size::INFINITY,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        }
+    }
+
+    /// The height of the label.
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        text::LineHeight::default()
+            .to_absolute(self.text_size.unwrap_or_else(|| renderer.default_size()))
+            .0
+    }
+
+    /// The bounds of the delete button, if the [`Chip`] has one.
+    fn delete_bounds(&self, bounds: Rectangle, renderer: &Renderer) -> Option<Rectangle> {
+        let size = self.line_height(renderer);
+
+        self.on_delete.as_ref().map(|_| Rectangle {
+            x: bounds.x + bounds.width - self.padding.right - size,
+            y: bounds.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        })
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Chip<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.icon.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(self.icon.as_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let line_height = self.line_height(renderer);
+        let label_width =
+            Renderer::Paragraph::with_text(self.text(&self.label, renderer)).min_width();
+
+        let mut icon = self.icon.as_ref().map(|icon| {
+            icon.as_widget().layout(
+                &mut tree.children[0],
+                renderer,
+                &Limits::new(Size::ZERO, Size::new(f32::INFINITY, line_height)),
+            )
+        });
+
+        let icon_size = icon.as_ref().map_or(Size::ZERO, Node::size);
+        let mut width = label_width;
+        if icon.is_some() {
+            width += icon_size.width + self.spacing;
+        }
+        if self.on_delete.is_some() {
+            width += self.spacing + line_height;
+        }
+
+        let size = limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(width, line_height.max(icon_size.height)).expand(self.padding),
+        );
+
+        if let Some(icon) = &mut icon {
+            icon.move_to_mut(Point::new(
+                self.padding.left,
+                (size.height - icon_size.height) / 2.0,
+            ));
+        }
+
+        Node::with_children(size, icon.into_iter().collect())
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(bounds) =>
+            {
+                let message = match self.delete_bounds(bounds, renderer) {
+                    Some(delete_bounds) if cursor.is_over(delete_bounds) => self.on_delete.clone(),
+                    _ => self.on_press.clone(),
+                };
+
+                message.map_or(event::Status::Ignored, |message| {
+                    shell.publish(message);
+                    event::Status::Captured
+                })
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let over_delete = self
+            .delete_bounds(bounds, renderer)
+            .is_some_and(|delete_bounds| cursor.is_over(delete_bounds));
+
+        if over_delete || (self.on_press.is_some() && cursor.is_over(bounds)) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let appearance = if self.on_press.is_none() && self.on_delete.is_none() {
+            theme.disabled(&self.style)
+        } else if self.selected {
+            theme.selected(&self.style)
+        } else if self.on_press.is_some() && cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        if bounds.intersects(viewport) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: appearance
+                            .border_radius
+                            .unwrap_or(bounds.height / 2.0)
+                            .into(),
+                        width: appearance.border_width,
+                        color: appearance.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.background,
+            );
+        }
+
+        let mut label_x = bounds.x + self.padding.left;
+
+        if let (Some(icon), Some(icon_layout)) = (&self.icon, layout.children().next()) {
+            icon.as_widget().draw(
+                &state.children[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: appearance.text_color,
+                },
+                icon_layout,
+                cursor,
+                viewport,
+            );
+            label_x += icon_layout.bounds().width + self.spacing;
+        }
+
+        let delete_bounds = self.delete_bounds(bounds, renderer);
+        let label_bounds = Rectangle {
+            x: label_x,
+            y: bounds.y,
+            width: delete_bounds.map_or(
+                bounds.x + bounds.width - self.padding.right,
+                |delete_bounds| delete_bounds.x - self.spacing,
+            ) - label_x,
+            height: bounds.height,
+        };
+
+        renderer.fill_text(
+            text::Text {
+                bounds: label_bounds.size(),
+                ..self.text(&self.label, renderer)
+            },
+            Point::new(label_bounds.x, label_bounds.center_y()),
+            appearance.text_color,
+            label_bounds,
+        );
+
+        if let Some(delete_bounds) = delete_bounds {
+            renderer.fill_text(
+                text::Text {
+                    bounds: delete_bounds.size(),
+                    horizontal_alignment: Horizontal::Center,
+                    ..self.text(DELETE_MARK, renderer)
+                },
+                delete_bounds.center(),
+                appearance.text_color,
+                delete_bounds,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Chip<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(chip: Chip<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(chip)
+    }
+}
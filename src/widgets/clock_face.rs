@@ -0,0 +1,244 @@
+//! Use a clock face for showing a time and picking it by dragging the hands.
+//!
+//! *This API requires the following crate features to be activated: `time_picker`*
+
+use super::overlay::time_picker::{self, appearances, draw_clock, on_event_clock};
+
+use chrono::NaiveTime;
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    Element,
+    Event,
+    Length,
+    Rectangle,
+    Renderer, // the actual type
+    Size,
+};
+
+pub use crate::{
+    core::time::Time,
+    style::time_picker::{Appearance, StyleSheet},
+};
+
+/// An always visible analog clock whose hands can be dragged for picking a time.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{clock_face, ClockFace};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Change(clock_face::Time),
+/// }
+///
+/// let clock_face = ClockFace::new(clock_face::Time::now_hm(true), Message::Change)
+///     .show_seconds(true)
+///     .step(5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ClockFace<'a, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The shown time.
+    time: Time,
+    /// The function that produces a message when a hand of the clock is moved.
+    on_change: Box<dyn Fn(Time) -> Message + 'a>,
+    /// Show the seconds hand.
+    show_seconds: bool,
+    /// Show the hours from 1 to 12 with AM and PM instead of from 0 to 23.
+    use_12h: bool,
+    /// The radius of the clock.
+    radius: f32,
+    /// The interval the minutes and seconds snap to.
+    step: u32,
+    /// The style of the [`ClockFace`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> ClockFace<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`ClockFace`].
+    ///
+    /// It expects:
+    ///     * the shown time.
+    ///     * a function that will be called with the time when a hand of the clock is moved.
+    pub fn new<F>(time: impl Into<Time>, on_change: F) -> Self
+    where
+        F: 'a + Fn(Time) -> Message,
+    {
+        Self {
+            time: time.into(),
+            on_change: Box::new(on_change),
+            show_seconds: false,
+            use_12h: false,
+            radius: 100.0,
+            step: 1,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Shows the seconds hand of the [`ClockFace`].
+    #[must_use]
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    /// Shows the hours of the [`ClockFace`] from 1 to 12 with AM and PM
+    /// instead of from 0 to 23.
+    #[must_use]
+    pub fn use_12h(mut self, use_12h: bool) -> Self {
+        self.use_12h = use_12h;
+        self
+    }
+
+    /// Sets the radius of the [`ClockFace`].
+    #[must_use]
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Sets the interval the minutes and seconds of the [`ClockFace`] snap to.
+    #[must_use]
+    pub fn step(mut self, step: u8) -> Self {
+        self.step = u32::from(step.clamp(1, 60));
+        self
+    }
+
+    /// Sets the style of the [`ClockFace`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for ClockFace<'_, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<time_picker::State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(time_picker::State::new(
+            self.time,
+            !self.use_12h,
+            self.show_seconds,
+        ))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<time_picker::State>();
+        let time = NaiveTime::from(self.time);
+
+        if state.time != time
+            || state.use_24h == self.use_12h
+            || state.show_seconds != self.show_seconds
+        {
+            state.time = time;
+            state.use_24h = !self.use_12h;
+            state.show_seconds = self.show_seconds;
+            state.clock_cache.clear();
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let diameter = 2.0 * self.radius;
+
+        Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(diameter, diameter),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<time_picker::State>();
+        let previous_time = state.time;
+
+        let status = on_event_clock(state, self.step, &event, layout, cursor);
+
+        if state.time != previous_time {
+            shell.publish((self.on_change)(state.selected_time()));
+        }
+
+        // Releasing the mouse somewhere else only stops dragging the hands.
+        if cursor.is_over(layout.bounds()) {
+            status
+        } else {
+            event::Status::Ignored
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<time_picker::State>();
+        let style_sheet = appearances(theme, &self.style);
+
+        draw_clock(renderer, state, self.step, layout, cursor, &style_sheet);
+    }
+}
+
+impl<'a, Message, Theme> From<ClockFace<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(clock_face: ClockFace<'a, Message, Theme>) -> Self {
+        Element::new(clock_face)
+    }
+}
@@ -75,6 +75,10 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The buttons of the overlay.
     overlay_state: Element<'a, Message, Theme, Renderer>,
+    /// Toggle the use of the HSL bars instead of the RGB bars.
+    use_hsl: bool,
+    /// Toggle the alpha bar and the alpha channel of the hex text.
+    show_alpha: bool,
 }
 
 impl<'a, Message, Theme> ColorPicker<'a, Message, Theme>
@@ -112,6 +116,8 @@ where
             on_submit: Box::new(on_submit),
             style: <Theme as StyleSheet>::Style::default(),
             overlay_state: ColorPickerOverlayButtons::default().into(),
+            use_hsl: false,
+            show_alpha: true,
         }
     }
 
@@ -121,6 +127,22 @@ where
         self.style = style;
         self
     }
+
+    /// Shows hue, saturation and lightness bars instead of the red, green and blue bars.
+    #[must_use]
+    pub fn use_hsl(mut self, use_hsl: bool) -> Self {
+        self.use_hsl = use_hsl;
+        self
+    }
+
+    /// Toggles the alpha bar and the alpha channel of the hex text.
+    ///
+    /// It is shown by default. If hidden, the alpha channel of the color is left as is.
+    #[must_use]
+    pub fn show_alpha(mut self, show_alpha: bool) -> Self {
+        self.show_alpha = show_alpha;
+        self
+    }
 }
 
 /// The state of the [`ColorPicker`].
@@ -274,6 +296,8 @@ where
                 self.style.clone(),
                 &mut state.children[1],
             )
+            .use_hsl(self.use_hsl)
+            .show_alpha(self.show_alpha)
             .overlay(),
         )
     }
@@ -0,0 +1,534 @@
+//! Use a color swatch for selecting a color from a palette of preset colors.
+//!
+//! *This API requires the following crate features to be activated: `color_swatch`*
+
+use super::{overlay::tooltip::TooltipOverlay, tooltip::TooltipPosition};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay, renderer, text,
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::Text,
+    window, Border, Color, Element, Event, Length, Padding, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::time::{Duration, Instant};
+
+pub use crate::style::color_swatch::{Appearance, StyleSheet};
+
+/// The time a swatch has to be hovered before its hex code is shown.
+const TOOLTIP_DELAY: Duration = Duration::from_millis(500);
+
+/// The gap between the hex code tooltip and the hovered swatch.
+const TOOLTIP_GAP: f32 = 5.0;
+
+/// The padding between the border and the hex code of the tooltip.
+const TOOLTIP_PADDING: f32 = 5.0;
+
+/// The width of the ring around the selected swatch.
+const RING_WIDTH: f32 = 2.0;
+
+/// The space between the ring and the color of the selected swatch.
+const RING_OFFSET: f32 = 2.0;
+
+/// A grid of colored squares for picking a color from a preset palette.
+///
+/// Hovering a swatch shows its hex code in a tooltip. It can be used on its
+/// own or next to a [`ColorPicker`](crate::widgets::ColorPicker), e.g. as a row
+/// of recently picked colors.
+///
+/// # Example
+/// ```ignore
+/// # use iced::Color;
+/// # use iced_aw::ColorSwatch;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     ColorSelected(Color),
+/// }
+///
+/// let swatch = ColorSwatch::new(
+///     vec![Color::BLACK, Color::WHITE, Color::from_rgb(1.0, 0.0, 0.0)],
+///     Some(Color::BLACK),
+///     Message::ColorSelected,
+/// )
+/// .columns(8);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ColorSwatch<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet + crate::style::tooltip::StyleSheet,
+{
+    /// The colors of the swatches.
+    colors: Vec<Color>,
+    /// The selected color, if any.
+    selected: Option<Color>,
+    /// The function producing the message when a swatch is clicked.
+    on_select: Box<dyn Fn(Color) -> Message + 'a>,
+    /// The hex codes of the colors shown as the tooltips.
+    hints: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// The width and height of each swatch.
+    swatch_size: f32,
+    /// The gap between the swatches.
+    gap: f32,
+    /// The maximum number of swatches in a row, or `None` for a single row.
+    columns: Option<usize>,
+    /// The border radius of each swatch.
+    border_radius: f32,
+    /// The style of the [`ColorSwatch`].
+    style: <Theme as StyleSheet>::Style,
+    /// The style of the hex code tooltip.
+    tooltip_style: <Theme as crate::style::tooltip::StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> ColorSwatch<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet + crate::style::tooltip::StyleSheet + iced::widget::text::StyleSheet,
+{
+    /// Creates a new [`ColorSwatch`].
+    ///
+    /// It expects:
+    ///     * the colors of the swatches.
+    ///     * the selected color, if any.
+    ///     * the function producing the message when a swatch is clicked.
+    pub fn new<F>(colors: Vec<Color>, selected: Option<Color>, on_select: F) -> Self
+    where
+        F: 'a + Fn(Color) -> Message,
+    {
+        let hints = colors
+            .iter()
+            .map(|color| Text::new(hex(*color)).into())
+            .collect();
+
+        Self {
+            colors,
+            selected,
+            on_select: Box::new(on_select),
+            hints,
+            swatch_size: 24.0,
+            gap: 4.0,
+            columns: None,
+            border_radius: 4.0,
+            style: <Theme as StyleSheet>::Style::default(),
+            tooltip_style: <Theme as crate::style::tooltip::StyleSheet>::Style::default(),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> ColorSwatch<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet + crate::style::tooltip::StyleSheet,
+{
+    /// Sets the width and height of each swatch of the [`ColorSwatch`].
+    #[must_use]
+    pub fn swatch_size(mut self, size: f32) -> Self {
+        self.swatch_size = size;
+        self
+    }
+
+    /// Sets the gap between the swatches of the [`ColorSwatch`].
+    #[must_use]
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the maximum number of swatches in a row of the [`ColorSwatch`].
+    ///
+    /// The swatches are wrapped into the next row once a row is full.
+    #[must_use]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns.max(1));
+        self
+    }
+
+    /// Sets the border radius of each swatch of the [`ColorSwatch`].
+    #[must_use]
+    pub fn border_radius(mut self, radius: f32) -> Self {
+        self.border_radius = radius;
+        self
+    }
+
+    /// Sets the style of the [`ColorSwatch`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the tooltip showing the hex code of the hovered swatch.
+    #[must_use]
+    pub fn tooltip_style(
+        mut self,
+        style: <Theme as crate::style::tooltip::StyleSheet>::Style,
+    ) -> Self {
+        self.tooltip_style = style;
+        self
+    }
+
+    /// The grid of the swatches.
+    fn grid(&self) -> Grid {
+        Grid {
+            count: self.colors.len(),
+            columns: self.columns.unwrap_or(self.colors.len()).max(1),
+            size: self.swatch_size,
+            gap: self.gap,
+        }
+    }
+}
+
+/// The placement of the swatches of a [`ColorSwatch`] in rows and columns.
+#[derive(Clone, Copy, Debug)]
+struct Grid {
+    /// The number of swatches.
+    count: usize,
+    /// The maximum number of swatches in a row.
+    columns: usize,
+    /// The width and height of each swatch.
+    size: f32,
+    /// The gap between the swatches.
+    gap: f32,
+}
+
+impl Grid {
+    /// The size of all swatches including the gaps between them.
+    #[allow(clippy::cast_precision_loss)]
+    fn size(self) -> Size {
+        if self.count == 0 {
+            return Size::ZERO;
+        }
+
+        let columns = self.columns.min(self.count);
+        let rows = self.count.div_ceil(self.columns);
+        let extent = |n: usize| n as f32 * self.size + n.saturating_sub(1) as f32 * self.gap;
+
+        Size::new(extent(columns), extent(rows))
+    }
+
+    /// The bounds of the swatch at the index, relative to the origin of the grid.
+    #[allow(clippy::cast_precision_loss)]
+    fn bounds(self, index: usize) -> Rectangle {
+        let step = self.size + self.gap;
+
+        Rectangle {
+            x: (index % self.columns) as f32 * step,
+            y: (index / self.columns) as f32 * step,
+            width: self.size,
+            height: self.size,
+        }
+    }
+
+    /// The index of the swatch at the position relative to the origin of the grid.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn index_at(self, position: Point) -> Option<usize> {
+        let step = self.size + self.gap;
+        if position.x < 0.0 || position.y < 0.0 || step <= 0.0 {
+            return None;
+        }
+
+        let column = (position.x / step) as usize;
+        let row = (position.y / step) as usize;
+        let index = row * self.columns + column;
+
+        (column < self.columns && index < self.count && self.bounds(index).contains(position))
+            .then_some(index)
+    }
+}
+
+/// Formats the color as `#RRGGBB`, or as `#RRGGBBAA` if it's translucent.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn hex(color: Color) -> String {
+    let channel = |c: f32| (c * 255.0) as u8;
+    let rgb = format!(
+        "#{:02X}{:02X}{:02X}",
+        channel(color.r),
+        channel(color.g),
+        channel(color.b)
+    );
+
+    if color.a < 1.0 {
+        format!("{rgb}{:02X}", channel(color.a))
+    } else {
+        rgb
+    }
+}
+
+/// The state of a [`ColorSwatch`].
+#[derive(Debug, Default)]
+struct State {
+    /// The index of the hovered swatch.
+    hovered: Option<usize>,
+    /// The time the hovered swatch has been hovered since.
+    hovered_since: Option<Instant>,
+    /// Whether the tooltip of the hovered swatch is shown.
+    is_visible: bool,
+    /// The last position of the cursor over the hovered swatch.
+    cursor_position: Point,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorSwatch<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Theme: StyleSheet + crate::style::tooltip::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.hints.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.hints);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(Length::Shrink, Length::Shrink, self.grid().size()))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let grid = self.grid();
+
+        let hovered = cursor.position_over(bounds).and_then(|position| {
+            grid.index_at(position - Vector::new(bounds.x, bounds.y))
+                .map(|index| (index, position))
+        });
+
+        let Some((index, position)) = hovered else {
+            *state = State::default();
+            return event::Status::Ignored;
+        };
+
+        state.cursor_position = position;
+
+        if state.hovered != Some(index) {
+            let now = Instant::now();
+            state.hovered = Some(index);
+            state.hovered_since = Some(now);
+            state.is_visible = false;
+            shell.request_redraw(window::RedrawRequest::At(now + TOOLTIP_DELAY));
+        }
+
+        match event {
+            Event::Window(_id, window::Event::RedrawRequested(now)) if !state.is_visible => {
+                if let Some(since) = state.hovered_since {
+                    let deadline = since + TOOLTIP_DELAY;
+
+                    if now >= deadline {
+                        state.is_visible = true;
+                    } else {
+                        shell.request_redraw(window::RedrawRequest::At(deadline));
+                    }
+                }
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                shell.publish((self.on_select)(self.colors[index]));
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        cursor
+            .position_over(bounds)
+            .and_then(|position| {
+                self.grid()
+                    .index_at(position - Vector::new(bounds.x, bounds.y))
+            })
+            .map_or_else(mouse::Interaction::default, |_| mouse::Interaction::Pointer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        if !bounds.intersects(viewport) {
+            return;
+        }
+
+        let appearance = StyleSheet::active(theme, &self.style);
+        let hovered = tree.state.downcast_ref::<State>().hovered;
+        let grid = self.grid();
+
+        for (index, color) in self.colors.iter().enumerate() {
+            let swatch = grid.bounds(index) + Vector::new(bounds.x, bounds.y);
+            let is_selected = self.selected == Some(*color);
+
+            // The selected swatch shrinks its color to make room for the ring.
+            let fill = if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: swatch,
+                        border: Border {
+                            radius: self.border_radius.into(),
+                            width: RING_WIDTH,
+                            color: appearance.selected_ring_color,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    Color::TRANSPARENT,
+                );
+
+                let inset = RING_WIDTH + RING_OFFSET;
+                Rectangle {
+                    x: swatch.x + inset,
+                    y: swatch.y + inset,
+                    width: (swatch.width - 2.0 * inset).max(0.0),
+                    height: (swatch.height - 2.0 * inset).max(0.0),
+                }
+            } else {
+                swatch
+            };
+
+            let border_color = if hovered == Some(index) {
+                appearance.hovered_border_color
+            } else {
+                appearance.border_color
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: fill,
+                    border: Border {
+                        radius: (self.border_radius - swatch.width + fill.width)
+                            .max(0.0)
+                            .into(),
+                        width: appearance.border_width,
+                        color: border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                *color,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+        if !state.is_visible {
+            return None;
+        }
+
+        let index = state.hovered?;
+        let cursor_position = state.cursor_position + translation;
+        let bounds = layout.bounds();
+        let swatch = self.grid().bounds(index) + Vector::new(bounds.x, bounds.y);
+
+        Some(overlay::Element::new(Box::new(TooltipOverlay::new(
+            &mut tree.children[index],
+            &self.hints[index],
+            TooltipPosition::Top,
+            swatch + translation,
+            cursor_position,
+            TOOLTIP_GAP,
+            Padding::new(TOOLTIP_PADDING),
+            &self.tooltip_style,
+        ))))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ColorSwatch<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet + crate::style::tooltip::StyleSheet,
+{
+    fn from(color_swatch: ColorSwatch<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(color_swatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hex, Grid};
+    use iced::{Color, Point, Size};
+
+    #[test]
+    fn hex_test() {
+        assert_eq!(hex(Color::from_rgb(1.0, 0.5, 0.0)), "#FF7F00");
+        assert_eq!(hex(Color::from_rgba(0.0, 0.0, 0.0, 0.5)), "#0000007F");
+    }
+
+    #[test]
+    fn grid_test() {
+        let grid = Grid {
+            count: 5,
+            columns: 3,
+            size: 10.0,
+            gap: 2.0,
+        };
+
+        assert_eq!(grid.size(), Size::new(34.0, 22.0));
+        assert_eq!(grid.index_at(Point::new(5.0, 5.0)), Some(0));
+        assert_eq!(grid.index_at(Point::new(15.0, 15.0)), Some(4));
+        // Between two swatches.
+        assert_eq!(grid.index_at(Point::new(11.0, 5.0)), None);
+        // The last row isn't full.
+        assert_eq!(grid.index_at(Point::new(30.0, 15.0)), None);
+    }
+}
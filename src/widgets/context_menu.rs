@@ -49,6 +49,8 @@ where
     overlay: Overlay,
     /// The style of the [`ContextMenu`].
     style: <Theme as StyleSheet>::Style,
+    /// The optional message that will be send when the [`ContextMenuOverlay`] is dismissed.
+    on_dismiss: Option<Message>,
 }
 
 impl<'a, Overlay, Message, Theme, Renderer> ContextMenu<'a, Overlay, Message, Theme, Renderer>
@@ -71,9 +73,18 @@ where
             underlay: underlay.into(),
             overlay,
             style: <Theme as StyleSheet>::Style::default(),
+            on_dismiss: None,
         }
     }
 
+    /// Sets the message that will be produced when the [`ContextMenu`] is
+    /// dismissed by clicking outside of it or by pressing Escape.
+    #[must_use]
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
     /// Sets the style of the [`ContextMenu`].
     #[must_use]
     pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
@@ -238,6 +249,7 @@ where
                 &mut state.children[1],
                 content,
                 self.style.clone(),
+                self.on_dismiss.clone(),
                 s,
             )
             .overlay(),
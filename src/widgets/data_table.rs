@@ -0,0 +1,594 @@
+//! Use a data table for showing rows of data in columns that can be sorted.
+//!
+//! *This API requires the following crate features to be activated: `data_table`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay, renderer,
+        text::{self, Paragraph as _},
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event, font,
+    mouse::{self, Cursor},
+    touch,
+    widget::{container, scrollable, Container, Row, Scrollable},
+    Border, Color, Element, Event, Length, Padding, Point, Rectangle, Shadow, Size, Vector,
+};
+
+use crate::core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT};
+
+/// The padding of the header and the cells.
+const CELL_PADDING: Padding = Padding {
+    top: 4.0,
+    right: 8.0,
+    bottom: 4.0,
+    left: 8.0,
+};
+
+/// The thickness of the line below the header.
+const DIVIDER_WIDTH: f32 = 1.0;
+
+/// The direction in which a column of a [`DataTable`] is sorted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    /// From the smallest to the largest value.
+    #[default]
+    Asc,
+    /// From the largest to the smallest value.
+    Desc,
+}
+
+impl SortDirection {
+    /// The opposite direction.
+    #[must_use]
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Asc => Self::Desc,
+            Self::Desc => Self::Asc,
+        }
+    }
+}
+
+/// The width of a column of a [`DataTable`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColumnWidth {
+    /// A fixed width in pixels.
+    Fixed(f32),
+    /// A portion of the width left by the fixed columns.
+    Flex(u16),
+}
+
+impl Default for ColumnWidth {
+    fn default() -> Self {
+        Self::Flex(1)
+    }
+}
+
+impl From<ColumnWidth> for Length {
+    fn from(width: ColumnWidth) -> Self {
+        match width {
+            ColumnWidth::Fixed(width) => Self::Fixed(width),
+            ColumnWidth::Flex(portion) => Self::FillPortion(portion),
+        }
+    }
+}
+
+/// The function creating the cell of a [`Column`] for a row.
+type RenderCell<'a, T, Message, Theme, Renderer> =
+    Box<dyn Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+/// A column of a [`DataTable`].
+#[allow(missing_debug_implementations)]
+pub struct Column<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The label of the header of the column.
+    header: String,
+    /// The function creating the cell of the column for a row.
+    render_cell: RenderCell<'a, T, Message, Theme, Renderer>,
+    /// The width of the column.
+    width: ColumnWidth,
+    /// The function that produces a message when the header is clicked.
+    on_sort: Option<Box<dyn Fn(SortDirection) -> Message + 'a>>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Column<'a, T, Message, Theme, Renderer> {
+    /// Creates a new [`Column`] with the label of its header and the function
+    /// creating its cell for a row.
+    pub fn new<F>(header: impl Into<String>, render_cell: F) -> Self
+    where
+        F: 'a + Fn(&T) -> Element<'a, Message, Theme, Renderer>,
+    {
+        Self {
+            header: header.into(),
+            render_cell: Box::new(render_cell),
+            width: ColumnWidth::default(),
+            on_sort: None,
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    #[must_use]
+    pub fn width(mut self, width: ColumnWidth) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the function that will be called with the new [`SortDirection`]
+    /// when the header of the [`Column`] is clicked.
+    ///
+    /// The first click sorts in the [`SortDirection::Asc`] direction, and every
+    /// further click toggles the direction.
+    #[must_use]
+    pub fn on_sort<F>(mut self, on_sort: F) -> Self
+    where
+        F: 'a + Fn(SortDirection) -> Message,
+    {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+}
+
+/// The header of a column of a [`DataTable`], kept after its cells are created.
+struct Header<'a, Message> {
+    /// The label of the header.
+    label: String,
+    /// The width of the column.
+    width: ColumnWidth,
+    /// The function that produces a message when the header is clicked.
+    on_sort: Option<Box<dyn Fn(SortDirection) -> Message + 'a>>,
+}
+
+/// A grid of rows of data with a header that stays visible while the rows are scrolled.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::{data_table::{Column, SortDirection}, DataTable};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     SortByName(SortDirection),
+/// }
+///
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// let data_table = DataTable::new(
+///     vec![
+///         Column::new("Name", |person: &Person| Text::new(person.name.clone()).into())
+///             .on_sort(Message::SortByName),
+///         Column::new("Age", |person: &Person| Text::new(person.age.to_string()).into()),
+///     ],
+///     people,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DataTable<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// The headers of the columns.
+    headers: Vec<Header<'a, Message>>,
+    /// The scrollable rows.
+    body: Element<'a, Message, Theme, Renderer>,
+    /// The width of the [`DataTable`].
+    width: Length,
+    /// The height of the [`DataTable`].
+    height: Length,
+}
+
+impl<'a, Message, Theme, Renderer> DataTable<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + container::StyleSheet + scrollable::StyleSheet,
+{
+    /// Creates a new [`DataTable`] with the given columns, creating the cells
+    /// of every row.
+    #[must_use]
+    pub fn new<T>(columns: Vec<Column<'a, T, Message, Theme, Renderer>>, rows: Vec<T>) -> Self {
+        let body = iced::widget::Column::with_children(rows.into_iter().map(|row| {
+            Row::with_children(columns.iter().map(|column| {
+                Container::new((column.render_cell)(&row))
+                    .width(column.width)
+                    .padding(CELL_PADDING)
+                    .into()
+            }))
+            .width(Length::Fill)
+            .into()
+        }))
+        .width(Length::Fill);
+
+        Self {
+            headers: columns
+                .into_iter()
+                .map(|column| Header {
+                    label: column.header,
+                    width: column.width,
+                    on_sort: column.on_sort,
+                })
+                .collect(),
+            body: Scrollable::new(body)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .into(),
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> DataTable<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Sets the width of the [`DataTable`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`DataTable`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// The index of the header that sorts its column at the cursor.
+    fn hovered_header(&self, layout: Layout<'_>, cursor: Cursor) -> Option<usize> {
+        layout.children().next().and_then(|header| {
+            header
+                .children()
+                .zip(&self.headers)
+                .position(|(cell, header)| {
+                    header.on_sort.is_some() && cursor.is_over(cell.bounds())
+                })
+        })
+    }
+}
+
+/// The state of a [`DataTable`].
+#[derive(Debug, Default)]
+struct State {
+    /// The index of the sorted column and its direction.
+    sorted: Option<(usize, SortDirection)>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DataTable<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.body)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.body));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max = limits.max();
+
+        let label_height = self
+            .headers
+            .iter()
+            .map(|header| {
+                Renderer::Paragraph::with_text(header_text(&header.label, renderer))
+                    .min_bounds()
+                    .height
+            })
+            .fold(0.0, f32::max);
+        let header_height = label_height + CELL_PADDING.vertical() + DIVIDER_WIDTH;
+
+        let body = self
+            .body
+            .as_widget()
+            .layout(
+                &mut tree.children[0],
+                renderer,
+                &Limits::new(
+                    Size::ZERO,
+                    Size::new(max.width, (max.height - header_height).max(0.0)),
+                ),
+            )
+            .move_to(Point::new(0.0, header_height));
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(body.size().width, header_height + body.size().height),
+        );
+
+        // The headers are distributed like the cells in the rows of the body.
+        let fixed: f32 = self
+            .headers
+            .iter()
+            .filter_map(|header| match header.width {
+                ColumnWidth::Fixed(width) => Some(width),
+                ColumnWidth::Flex(_) => None,
+            })
+            .sum();
+        let portions: f32 = self
+            .headers
+            .iter()
+            .filter_map(|header| match header.width {
+                ColumnWidth::Fixed(_) => None,
+                ColumnWidth::Flex(portion) => Some(f32::from(portion)),
+            })
+            .sum();
+        let remaining = (size.width - fixed).max(0.0);
+
+        let mut x = 0.0;
+        let cells = self
+            .headers
+            .iter()
+            .map(|header| {
+                let width = match header.width {
+                    ColumnWidth::Fixed(width) => width,
+                    ColumnWidth::Flex(portion) if portions > 0.0 => {
+                        remaining * f32::from(portion) / portions
+                    }
+                    ColumnWidth::Flex(_) => 0.0,
+                };
+                let cell = Node::new(Size::new(width, header_height - DIVIDER_WIDTH))
+                    .move_to(Point::new(x, 0.0));
+                x += width;
+
+                cell
+            })
+            .collect();
+
+        Node::with_children(
+            size,
+            vec![
+                Node::with_children(Size::new(size.width, header_height), cells),
+                body,
+            ],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let on_sort = self
+                .hovered_header(layout, cursor)
+                .and_then(|index| Some((index, self.headers[index].on_sort.as_ref()?)));
+
+            if let Some((index, on_sort)) = on_sort {
+                let state = tree.state.downcast_mut::<State>();
+                let direction = match state.sorted {
+                    Some((sorted, direction)) if sorted == index => direction.toggle(),
+                    _ => SortDirection::Asc,
+                };
+
+                state.sorted = Some((index, direction));
+                shell.publish(on_sort(direction));
+
+                return event::Status::Captured;
+            }
+        }
+
+        self.body.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            body_layout(layout),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.hovered_header(layout, cursor).is_some() {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.body.as_widget().mouse_interaction(
+            &tree.children[0],
+            body_layout(layout),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.body.as_widget().operate(
+            &mut tree.children[0],
+            body_layout(layout),
+            renderer,
+            operation,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.body.as_widget_mut().overlay(
+            &mut tree.children[0],
+            body_layout(layout),
+            renderer,
+            translation,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(header) = layout.children().next() {
+            let bounds = header.bounds();
+
+            for (index, (cell, header)) in header.children().zip(&self.headers).enumerate() {
+                let cell_bounds = cell.bounds();
+                let bounds = Rectangle {
+                    x: cell_bounds.x + CELL_PADDING.left,
+                    y: cell_bounds.y + CELL_PADDING.top,
+                    width: (cell_bounds.width - CELL_PADDING.horizontal()).max(0.0),
+                    height: (cell_bounds.height - CELL_PADDING.vertical()).max(0.0),
+                };
+
+                renderer.fill_text(
+                    text::Text {
+                        bounds: bounds.size(),
+                        ..header_text(&header.label, renderer)
+                    },
+                    Point::new(bounds.x, bounds.center_y()),
+                    style.text_color,
+                    bounds,
+                );
+
+                if let Some((_, direction)) = state.sorted.filter(|(sorted, _)| *sorted == index) {
+                    let icon = match direction {
+                        SortDirection::Asc => Bootstrap::CaretUpFill,
+                        SortDirection::Desc => Bootstrap::CaretDownFill,
+                    };
+
+                    renderer.fill_text(
+                        text::Text {
+                            content: &icon_to_string(icon),
+                            bounds: bounds.size(),
+                            size: renderer.default_size(),
+                            line_height: text::LineHeight::default(),
+                            font: BOOTSTRAP_FONT,
+                            horizontal_alignment: Horizontal::Right,
+                            vertical_alignment: Vertical::Center,
+                            shaping: text::Shaping::Basic,
+                        },
+                        Point::new(bounds.x + bounds.width, bounds.center_y()),
+                        style.text_color,
+                        bounds,
+                    );
+                }
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x,
+                        y: bounds.y + bounds.height - DIVIDER_WIDTH,
+                        width: bounds.width,
+                        height: DIVIDER_WIDTH,
+                    },
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                Color {
+                    a: 0.3,
+                    ..style.text_color
+                },
+            );
+        }
+
+        self.body.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            body_layout(layout),
+            cursor,
+            viewport,
+        );
+    }
+}
+
+/// The layout of the scrollable rows of a [`DataTable`].
+fn body_layout(layout: Layout<'_>) -> Layout<'_> {
+    layout
+        .children()
+        .nth(1)
+        .expect("Native: Layout should have a body layout")
+}
+
+/// The text of the label of a header, in the bold default font.
+fn header_text<'a, Renderer>(label: &'a str, renderer: &Renderer) -> text::Text<'a, iced::Font>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    text::Text {
+        content: label,
+        bounds: Size::INFINITY,
+        size: renderer.default_size(),
+        line_height: text::LineHeight::default(),
+        font: iced::Font {
+            weight: font::Weight::Bold,
+            ..renderer.default_font()
+        },
+        horizontal_alignment: Horizontal::Left,
+        vertical_alignment: Vertical::Center,
+        shaping: text::Shaping::Advanced,
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DataTable<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a,
+{
+    fn from(data_table: DataTable<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(data_table)
+    }
+}
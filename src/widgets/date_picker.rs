@@ -2,46 +2,64 @@
 //!
 //! *This API requires the following crate features to be activated: `date_picker`*
 
-use super::overlay::date_picker::{self, DatePickerOverlay, DatePickerOverlayButtons};
+use super::overlay::date_picker::{
+    self, DatePickerOverlay, DatePickerOverlayButtons, Focus, DEFAULT_MARGIN, DEFAULT_PADDING,
+    DEFAULT_SWIPE_THRESHOLD,
+};
 
 use chrono::Local;
+use futures_timer::Delay;
 use iced::{
     advanced::{
+        graphics::futures::{boxed_stream, BoxStream, MaybeSend},
         layout::{Limits, Node},
         renderer,
+        subscription::{EventStream, Recipe},
         text::Renderer as _,
         widget::{
             self,
+            operation::{focusable, TextInput},
             tree::{Tag, Tree},
+            Operation,
         },
-        Clipboard, Layout, Shell, Widget,
+        Clipboard, Hasher, Layout, Shell, Widget,
     },
     event,
+    futures::{
+        future::{self, Either},
+        stream::{self, StreamExt},
+    },
+    keyboard::{self, key::Named},
     mouse::{self, Cursor},
+    time::Duration,
+    touch,
     widget::button,
+    Color,
     Element,
     Event,
     Length,
+    Padding,
     Pixels,
     Point,
     Rectangle,
     Renderer, // the actual type
     Size,
+    Subscription,
     Vector,
 };
+use std::{any::TypeId, hash::Hash};
 
 pub use crate::{
-    core::date::Date,
-    style::date_picker::{Appearance, StyleSheet},
+    core::date::{Date, ParseError, Weekday},
+    style::date_picker::{Appearance, DayAppearance, StyleSheet},
 };
 
-//TODO: Remove ignore when Null is updated. Temp fix for Test runs
 /// An input element for picking dates.
 ///
 /// # Example
-/// ```ignore
-/// # use iced_aw::DatePicker;
-/// # use iced::widget::{button, Button, Text};
+/// ```
+/// # use iced_aw::{date_picker, DatePicker};
+/// # use iced::widget::{Button, Text};
 /// #
 /// #[derive(Clone, Debug)]
 /// enum Message {
@@ -50,7 +68,7 @@ pub use crate::{
 ///     Submit(date_picker::Date),
 /// }
 ///
-/// let date_picker = DatePicker::new(
+/// let date_picker: DatePicker<'_, Message, iced::Theme> = DatePicker::new(
 ///     true,
 ///     date_picker::Date::today(),
 ///     Button::new(Text::new("Pick date"))
@@ -59,20 +77,23 @@ pub use crate::{
 ///     Message::Submit,
 /// );
 /// ```
-#[allow(missing_debug_implementations)]
+#[allow(missing_debug_implementations, clippy::struct_excessive_bools)]
 pub struct DatePicker<'a, Message, Theme>
 where
-    Message: Clone,
     Theme: StyleSheet + button::StyleSheet,
 {
-    /// Show the picker.
-    show_picker: bool,
+    /// Show the picker, or `None` if the [`State`] keeps track of it.
+    show_picker: Option<bool>,
     /// The date to show.
     date: Date,
+    /// The date whose month is shown at first without selecting it or `None`
+    /// to show the selected date.
+    view_date: Option<Date>,
     /// The underlying element.
     underlay: Element<'a, Message, Theme, Renderer>,
-    /// The message that is send if the cancel button of the [`DatePickerOverlay`] is pressed.
-    on_cancel: Message,
+    /// The function that produces a message when the cancel button of the [`DatePickerOverlay`]
+    /// is pressed.
+    on_cancel: Box<dyn Fn() -> Message + 'a>,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`] is pressed.
     on_submit: Box<dyn Fn(Date) -> Message>,
     /// The style of the [`DatePickerOverlay`].
@@ -82,6 +103,48 @@ where
     //button_style: <Renderer as button::Renderer>::Style, // clone not satisfied
     /// The font and icon size of the [`DatePickerOverlay`] or `None` for the default
     font_size: Option<Pixels>,
+    /// The earliest date that can be picked or `None` if there is no lower limit.
+    min_date: Option<Date>,
+    /// The latest date that can be picked or `None` if there is no upper limit.
+    max_date: Option<Date>,
+    /// Show the week numbers in the [`DatePickerOverlay`].
+    show_week_numbers: bool,
+    /// Show the days of the previous and next month in the [`DatePickerOverlay`].
+    show_outside_days: bool,
+    /// The weekday of the first column of the [`DatePickerOverlay`].
+    first_day_of_week: Weekday,
+    /// Show a button jumping to the current date in the [`DatePickerOverlay`].
+    show_today_button: bool,
+    /// The label of the button jumping to the current date.
+    today_label: String,
+    /// The label of the cancel button or `None` for the default icon.
+    cancel_label: Option<String>,
+    /// The label of the submit button or `None` for the default icon.
+    submit_label: Option<String>,
+    /// The minimal distance between the [`DatePickerOverlay`] and the edges of the window.
+    margin: f32,
+    /// The horizontal distance of a swipe over the days changing the month.
+    swipe_threshold: f32,
+    /// The dates that are marked in the [`DatePickerOverlay`].
+    highlights: Vec<HighlightedDate>,
+    /// The padding between the border and the elements of the [`DatePickerOverlay`].
+    padding: Padding,
+    /// The border radius of the [`DatePickerOverlay`] or `None` for the radius of the style.
+    border_radius: Option<f32>,
+    /// The border width of the [`DatePickerOverlay`] or `None` for the width of the style.
+    border_width: Option<f32>,
+    /// The width of the [`DatePickerOverlay`].
+    overlay_width: Length,
+    /// The maximum width of the [`DatePickerOverlay`].
+    overlay_max_width: f32,
+    /// The function that produces a message when the [`DatePickerOverlay`] is opened.
+    on_open: Option<Box<dyn Fn() -> Message + 'a>>,
+    /// The function that produces a message when the [`DatePickerOverlay`] is cancelled or
+    /// submitted.
+    on_close: Option<Box<dyn Fn() -> Message + 'a>>,
+    /// The id of the widget in the underlay that is focused when the [`DatePickerOverlay`]
+    /// is closed.
+    underlay_id: Option<widget::Id>,
 }
 
 impl<'a, Message, Theme> DatePicker<'a, Message, Theme>
@@ -95,6 +158,12 @@ where
 {
     /// Creates a new [`DatePicker`] wrapping around the given underlay.
     ///
+    /// Whether the overlay is shown is read from `show_picker` and not kept in the
+    /// [`State`], so the application opens and closes the picker, usually on the
+    /// messages of the underlay and of the cancel and submit buttons.
+    /// Use [`DatePicker::stateful`] for a picker that opens and closes itself and
+    /// [`DatePicker::new_with`] for a message that is not [`Clone`].
+    ///
     /// It expects:
     ///     * if the overlay of the date picker is visible.
     ///     * the initial date to show.
@@ -115,16 +184,122 @@ where
         U: Into<Element<'a, Message, Theme, Renderer>>,
         F: 'static + Fn(Date) -> Message,
     {
-        Self {
+        Self::new_with(
             show_picker,
+            date,
+            underlay,
+            move || on_cancel.clone(),
+            on_submit,
+        )
+    }
+
+    /// Creates a new [`DatePicker`] wrapping around the given underlay that keeps
+    /// track of whether its overlay is shown in its [`State`].
+    ///
+    /// The overlay is opened by clicking the underlay, or by pressing enter or space
+    /// while a focusable widget of the underlay is focused, and closed when the cancel
+    /// or the submit button is pressed. It shows the current date at first.
+    ///
+    /// It expects:
+    ///     * the underlay [`Element`] on which this [`DatePicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DatePicker`]
+    ///         is pressed.
+    ///     * a function that will be called when the submit button of the [`DatePicker`]
+    ///         is pressed, which takes the picked [`Date`](crate::date_picker::Date) value.
+    pub fn stateful<U, F>(underlay: U, on_cancel: Message, on_submit: F) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        F: 'static + Fn(Date) -> Message,
+    {
+        Self::stateful_with(underlay, move || on_cancel.clone(), on_submit)
+    }
+
+    /// Sets the message that is send when the [`DatePicker`] is opened.
+    ///
+    /// It is also send when enter, or space unless a text input is focused, is pressed
+    /// while a focusable widget of the underlay is focused, so the application can open
+    /// a picker created with [`DatePicker::new`] from the keyboard.
+    #[must_use]
+    pub fn on_open(self, on_open: Message) -> Self {
+        self.on_open_with(move || on_open.clone())
+    }
+
+    /// Sets the message that is send when the [`DatePicker`] is cancelled or submitted.
+    #[must_use]
+    pub fn on_close(self, on_close: Message) -> Self {
+        self.on_close_with(move || on_close.clone())
+    }
+}
+
+impl<'a, Message, Theme> DatePicker<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + iced::widget::text::StyleSheet
+        + iced::widget::container::StyleSheet,
+{
+    /// Creates a new [`DatePicker`] like [`DatePicker::new`], producing the
+    /// cancel message with a function, so the message does not need to be [`Clone`].
+    pub fn new_with<U, C, F>(
+        show_picker: bool,
+        date: impl Into<Date>,
+        underlay: U,
+        on_cancel: C,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        C: 'a + Fn() -> Message,
+        F: 'static + Fn(Date) -> Message,
+    {
+        Self {
+            show_picker: Some(show_picker),
             date: date.into(),
+            view_date: None,
             underlay: underlay.into(),
-            on_cancel,
+            on_cancel: Box::new(on_cancel),
             on_submit: Box::new(on_submit),
             style: <Theme as StyleSheet>::Style::default(),
             overlay_state: DatePickerOverlayButtons::default().into(),
             //button_style: <Renderer as button::Renderer>::Style::default(),
             font_size: None,
+            min_date: None,
+            max_date: None,
+            show_week_numbers: false,
+            show_outside_days: true,
+            first_day_of_week: Weekday::Mon,
+            show_today_button: false,
+            today_label: String::from("Today"),
+            cancel_label: None,
+            submit_label: None,
+            margin: DEFAULT_MARGIN,
+            swipe_threshold: DEFAULT_SWIPE_THRESHOLD,
+            highlights: Vec::new(),
+            padding: Padding::from(DEFAULT_PADDING),
+            border_radius: None,
+            border_width: None,
+            overlay_width: Length::Shrink,
+            overlay_max_width: f32::INFINITY,
+            on_open: None,
+            on_close: None,
+            underlay_id: None,
+        }
+    }
+
+    /// Creates a new [`DatePicker`] like [`DatePicker::stateful`], producing the
+    /// cancel message with a function, so the message does not need to be [`Clone`].
+    pub fn stateful_with<U, C, F>(underlay: U, on_cancel: C, on_submit: F) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        C: 'a + Fn() -> Message,
+        F: 'static + Fn(Date) -> Message,
+    {
+        Self {
+            show_picker: None,
+            ..Self::new_with(false, Date::today(), underlay, on_cancel, on_submit)
         }
     }
 
@@ -142,13 +317,389 @@ where
         self.font_size = Some(size.into());
         self
     }
+
+    /// Sets the minimal distance between the [`DatePickerOverlay`] and the edges of the window.
+    ///
+    /// The overlay is moved away from the edges if it would get closer. The default is 8.
+    #[must_use]
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the horizontal distance of a swipe over the days of the [`DatePicker`]
+    /// that changes the month.
+    ///
+    /// Swiping to the left shows the next month, swiping to the right the previous
+    /// month. The default is 40.
+    #[must_use]
+    pub fn swipe_threshold(mut self, swipe_threshold: f32) -> Self {
+        self.swipe_threshold = swipe_threshold;
+        self
+    }
+
+    /// Sets the padding between the border and the elements of the [`DatePicker`].
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the border radius of the [`DatePicker`], overriding the radius of the style.
+    #[must_use]
+    pub fn border_radius(mut self, border_radius: f32) -> Self {
+        self.border_radius = Some(border_radius);
+        self
+    }
+
+    /// Sets the border width of the [`DatePicker`], overriding the width of the style.
+    #[must_use]
+    pub fn border_width(mut self, border_width: f32) -> Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Sets the width of the [`DatePickerOverlay`], e.g. to match the width of the underlay.
+    ///
+    /// The calendar is stretched to the width unless it is [`Length::Shrink`], the default.
+    #[must_use]
+    pub fn overlay_width(mut self, width: impl Into<Length>) -> Self {
+        self.overlay_width = width.into();
+        self
+    }
+
+    /// Sets the maximum width of the [`DatePickerOverlay`].
+    #[must_use]
+    pub fn overlay_max_width(mut self, max_width: f32) -> Self {
+        self.overlay_max_width = max_width;
+        self
+    }
+
+    /// Sets the dates that are marked with a colored dot in the [`DatePicker`].
+    ///
+    /// Hovering a marked day shows its tooltip, if any.
+    #[must_use]
+    pub fn highlight_dates(mut self, highlights: impl Into<Vec<HighlightedDate>>) -> Self {
+        self.highlights = highlights.into();
+        self
+    }
+
+    /// Sets the date whose month is shown when the [`DatePicker`] is opened
+    /// for the first time, without selecting it.
+    #[must_use]
+    pub fn view_date(mut self, date: impl Into<Date>) -> Self {
+        self.view_date = Some(date.into());
+        self
+    }
+
+    /// Sets the earliest date that can be picked in the [`DatePicker`].
+    ///
+    /// Days before this date are shown as disabled.
+    #[must_use]
+    pub fn min_date(mut self, date: impl Into<Date>) -> Self {
+        self.min_date = Some(date.into());
+        self
+    }
+
+    /// Sets the latest date that can be picked in the [`DatePicker`].
+    ///
+    /// Days after this date are shown as disabled.
+    #[must_use]
+    pub fn max_date(mut self, date: impl Into<Date>) -> Self {
+        self.max_date = Some(date.into());
+        self
+    }
+
+    /// Shows a column with the ISO 8601 week numbers in front of the days
+    /// of the [`DatePicker`].
+    #[must_use]
+    pub fn show_week_numbers(mut self, show_week_numbers: bool) -> Self {
+        self.show_week_numbers = show_week_numbers;
+        self
+    }
+
+    /// Sets the weekday of the first column of the calendar of the [`DatePicker`].
+    ///
+    /// The default is Monday.
+    #[must_use]
+    pub fn first_day_of_week(mut self, first_day_of_week: Weekday) -> Self {
+        self.first_day_of_week = first_day_of_week;
+        self
+    }
+
+    /// Shows the days of the previous and next month in the empty day cells
+    /// of the [`DatePicker`].
+    ///
+    /// Picking such a day switches to its month. The days are shown by default.
+    #[must_use]
+    pub fn show_outside_days(mut self, show_outside_days: bool) -> Self {
+        self.show_outside_days = show_outside_days;
+        self
+    }
+
+    /// Shows a button below the days of the [`DatePicker`] that jumps to the
+    /// current date without submitting it.
+    #[must_use]
+    pub fn show_today_button(mut self, show_today_button: bool) -> Self {
+        self.show_today_button = show_today_button;
+        self
+    }
+
+    /// Sets the label of the button jumping to the current date of the [`DatePicker`].
+    ///
+    /// The label defaults to "Today".
+    #[must_use]
+    pub fn today_label(mut self, label: impl Into<String>) -> Self {
+        self.today_label = label.into();
+        self
+    }
+
+    /// Sets the label of the cancel button of the [`DatePicker`].
+    ///
+    /// The button shows a cross icon if no label is set.
+    #[must_use]
+    pub fn cancel_label(mut self, label: impl Into<String>) -> Self {
+        self.cancel_label = Some(label.into());
+        self
+    }
+
+    /// Sets the label of the submit button of the [`DatePicker`].
+    ///
+    /// The button shows a check mark icon if no label is set.
+    #[must_use]
+    pub fn submit_label(mut self, label: impl Into<String>) -> Self {
+        self.submit_label = Some(label.into());
+        self
+    }
+
+    /// Sets the function that produces the message that is send when the
+    /// [`DatePicker`] is opened, like [`DatePicker::on_open`].
+    #[must_use]
+    pub fn on_open_with(mut self, on_open: impl Fn() -> Message + 'a) -> Self {
+        self.on_open = Some(Box::new(on_open));
+        self
+    }
+
+    /// Sets the function that produces the message that is send when the
+    /// [`DatePicker`] is cancelled or submitted, like [`DatePicker::on_close`].
+    #[must_use]
+    pub fn on_close_with(mut self, on_close: impl Fn() -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the id of the focusable widget in the underlay, e.g. a text input, that gets the
+    /// focus back when the [`DatePicker`] is closed.
+    #[must_use]
+    pub fn underlay_id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.underlay_id = Some(id.into());
+        self
+    }
+
+    /// Whether the overlay is shown, read from the [`State`] if the
+    /// [`DatePicker`] was created with [`DatePicker::stateful`].
+    fn is_shown(&self, state: &State) -> bool {
+        self.show_picker.unwrap_or_else(|| state.is_shown())
+    }
+}
+
+/// Finds out whether a focusable widget, e.g. a text input, is focused.
+#[derive(Default)]
+struct HasFocus {
+    /// Whether a focusable widget is focused.
+    focused: bool,
+    /// Whether the focusable widget that was operated on last is focused.
+    last_focused: bool,
+    /// Whether the focused widget is a text input.
+    text_input: bool,
+}
+
+impl<T> Operation<T> for HasFocus {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn focusable::Focusable, _id: Option<&widget::Id>) {
+        self.last_focused = state.is_focused();
+        self.focused |= self.last_focused;
+    }
+
+    fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&widget::Id>) {
+        // A text input is operated on as a focusable widget right before.
+        self.text_input |= self.last_focused;
+    }
+}
+
+impl<Message, Theme> DatePicker<'_, Message, Theme>
+where
+    Message: 'static + Clone + MaybeSend,
+    Theme: StyleSheet + button::StyleSheet,
+{
+    /// Creates a [`Subscription`] that publishes `on_cancel` when the user did not
+    /// interact with the application for the given duration, closing the
+    /// [`DatePicker`] like a session timeout.
+    ///
+    /// Any mouse, keyboard or touch event restarts the timeout. After `on_cancel`
+    /// is published, the timeout only starts again with the next interaction.
+    ///
+    /// The `id` tells the subscriptions of different pickers apart, which would
+    /// otherwise be merged into one if they have the same duration. The application
+    /// should only subscribe while the [`DatePicker`] is shown, e.g.:
+    ///
+    /// ```ignore
+    /// fn subscription(&self) -> Subscription<Message> {
+    ///     if self.show_picker {
+    ///         DatePicker::<Message, Theme>::auto_close_after(
+    ///             "birthday",
+    ///             Duration::from_secs(30),
+    ///             Message::Cancel,
+    ///         )
+    ///     } else {
+    ///         Subscription::none()
+    ///     }
+    /// }
+    /// ```
+    pub fn auto_close_after<I>(
+        id: I,
+        duration: Duration,
+        on_cancel: Message,
+    ) -> Subscription<Message>
+    where
+        I: 'static + Hash,
+    {
+        Subscription::from_recipe(AutoClose {
+            id,
+            duration,
+            on_cancel,
+        })
+    }
+}
+
+/// Publishes the cancel message of a [`DatePicker`] after a duration without
+/// interaction.
+struct AutoClose<I, Message> {
+    /// The identity of the subscription.
+    id: I,
+    /// The duration without interaction after which `on_cancel` is published.
+    duration: Duration,
+    /// The message that is published.
+    on_cancel: Message,
+}
+
+impl<I, Message> Recipe for AutoClose<I, Message>
+where
+    I: 'static + Hash,
+    Message: 'static + Clone + MaybeSend,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut Hasher) {
+        TypeId::of::<Self>().hash(state);
+        self.id.hash(state);
+        self.duration.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Self::Output> {
+        let Self {
+            duration,
+            on_cancel,
+            ..
+        } = *self;
+
+        let interactions = input.filter(|(event, _status)| {
+            future::ready(matches!(
+                event,
+                Event::Mouse(_) | Event::Keyboard(_) | Event::Touch(_)
+            ))
+        });
+
+        boxed_stream(stream::unfold(
+            (interactions, Some(Delay::new(duration))),
+            move |(mut interactions, mut timeout)| {
+                let on_cancel = on_cancel.clone();
+
+                async move {
+                    loop {
+                        if let Some(delay) = timeout.as_mut() {
+                            match future::select(interactions.next(), delay).await {
+                                Either::Left((Some(_), delay)) => delay.reset(duration),
+                                Either::Left((None, _)) => return None,
+                                Either::Right(((), _)) => {
+                                    return Some((on_cancel, (interactions, None)));
+                                }
+                            }
+                        } else {
+                            // The timeout starts again with the next interaction.
+                            let _interaction = interactions.next().await?;
+                            timeout = Some(Delay::new(duration));
+                        }
+                    }
+                }
+            },
+        ))
+    }
+}
+
+/// A date that is marked in the calendar of a [`DatePicker`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct HighlightedDate {
+    /// The marked date.
+    pub date: Date,
+    /// The color of the dot marking the date.
+    pub color: Color,
+    /// The tooltip shown when hovering the date or `None` for no tooltip.
+    pub tooltip: Option<String>,
+}
+
+impl HighlightedDate {
+    /// Creates a new [`HighlightedDate`] marking the given date with the given color.
+    #[must_use]
+    pub fn new(date: impl Into<Date>, color: Color) -> Self {
+        Self {
+            date: date.into(),
+            color,
+            tooltip: None,
+        }
+    }
+
+    /// Sets the tooltip shown when hovering the [`HighlightedDate`].
+    #[must_use]
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
 }
 
 /// The state of the [`DatePicker`] / [`DatePickerOverlay`].
+///
+/// The [`State`] is kept in the widget tree. A [`DatePicker`] creates it from the
+/// `date` given to [`DatePicker::new`] (or the date set with [`DatePicker::view_date`]),
+/// so an application pre-populating the picker passes the date there.
+///
+/// A parent widget wrapping a [`DatePicker`] reaches the [`State`] through the tree of
+/// its child:
+///
+/// ```ignore
+/// let state = tree.children[0].state.downcast_mut::<date_picker::State>();
+/// state.set_date(date);
+/// ```
 #[derive(Debug)]
+#[allow(clippy::struct_field_names)]
 pub struct State {
     /// The state of the overlay.
     pub(crate) overlay_state: date_picker::State,
+    /// Whether the picker was shown during the last event.
+    pub(crate) was_shown: bool,
+    /// The id of the widget in the underlay that is focused when the picker closes.
+    pub(crate) focus_target: Option<widget::Id>,
+    /// Whether opening the picker was requested by the keyboard and the open
+    /// message is already published.
+    pub(crate) open_requested: bool,
 }
 
 impl State {
@@ -157,6 +708,9 @@ impl State {
     pub fn now() -> Self {
         Self {
             overlay_state: date_picker::State::default(),
+            was_shown: false,
+            focus_target: None,
+            open_requested: false,
         }
     }
 
@@ -165,6 +719,29 @@ impl State {
     pub fn new(date: Date) -> Self {
         Self {
             overlay_state: date_picker::State::new(date.into()),
+            was_shown: false,
+            focus_target: None,
+            open_requested: false,
+        }
+    }
+
+    /// Creates a new [`State`] with the given date.
+    ///
+    /// This is the same as [`State::new`].
+    #[must_use]
+    pub fn with_date(date: Date) -> Self {
+        Self::new(date)
+    }
+
+    /// Creates a new [`State`] showing the month of the given date without
+    /// selecting it.
+    #[must_use]
+    pub fn viewing(date: Date) -> Self {
+        Self {
+            overlay_state: date_picker::State::viewing(date.into()),
+            was_shown: false,
+            focus_target: None,
+            open_requested: false,
         }
     }
 
@@ -172,11 +749,43 @@ impl State {
     pub fn reset(&mut self) {
         self.overlay_state.date = Local::now().naive_local().date();
     }
+
+    /// Sets the selected date of the state and shows its month.
+    pub fn set_date(&mut self, date: Date) {
+        self.overlay_state.date = date.into();
+        self.overlay_state.focused_day = None;
+        self.overlay_state.is_selected = true;
+    }
+
+    /// Gets the currently selected date that is not submitted yet.
+    #[must_use]
+    pub fn current_date(&self) -> Date {
+        self.overlay_state.date.into()
+    }
+
+    /// Shows or hides the overlay of a [`DatePicker`] created with
+    /// [`DatePicker::stateful`].
+    pub fn show(&mut self, show: bool) {
+        self.overlay_state.is_shown = show;
+    }
+
+    /// Whether the overlay of a [`DatePicker`] created with
+    /// [`DatePicker::stateful`] is shown.
+    ///
+    /// This lets a parent widget draw something depending on the overlay, like
+    /// a backdrop, without keeping track of it in the application. The parent
+    /// widget reads it from the [`State`] in the tree of its [`DatePicker`] child.
+    /// An application showing the picker with [`DatePicker::new`] already knows
+    /// whether it is shown.
+    #[must_use]
+    pub fn is_shown(&self) -> bool {
+        self.overlay_state.is_shown
+    }
 }
 
 impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for DatePicker<'a, Message, Theme>
 where
-    Message: 'static + Clone,
+    Message: 'static,
     Theme: StyleSheet
         + button::StyleSheet
         + iced::widget::text::StyleSheet
@@ -187,7 +796,10 @@ where
     }
 
     fn state(&self) -> widget::tree::State {
-        widget::tree::State::new(State::new(self.date))
+        widget::tree::State::new(
+            self.view_date
+                .map_or_else(|| State::new(self.date), State::viewing),
+        )
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -219,6 +831,80 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        let picker_state: &mut State = state.state.downcast_mut();
+
+        // A stateful picker opens when its underlay is clicked.
+        if self.show_picker.is_none() && !picker_state.is_shown() {
+            if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) = event
+            {
+                if cursor.is_over(layout.bounds()) {
+                    picker_state.show(true);
+                }
+            }
+        }
+
+        // The application did not open the picker on the open message.
+        if !self.is_shown(picker_state) {
+            picker_state.open_requested = false;
+        }
+
+        // The picker opens on enter or space while the underlay is focused, like on a click.
+        // Space is left to a focused text input. The key is only captured if the picker is
+        // opened or asked to open, otherwise the underlay receives it.
+        if !self.is_shown(picker_state) && (self.show_picker.is_none() || self.on_open.is_some()) {
+            if let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key @ (Named::Enter | Named::Space)),
+                ..
+            }) = event
+            {
+                let mut has_focus = HasFocus::default();
+                self.underlay.as_widget().operate(
+                    &mut state.children[0],
+                    layout,
+                    renderer,
+                    &mut has_focus,
+                );
+
+                if has_focus.focused && (key == Named::Enter || !has_focus.text_input) {
+                    if self.show_picker.is_none() {
+                        picker_state.show(true);
+                        picker_state.overlay_state.focus = Focus::Day;
+                    } else if let Some(on_open) = &self.on_open {
+                        shell.publish(on_open());
+                        picker_state.open_requested = true;
+                    }
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        let show_picker = self.is_shown(picker_state);
+        if show_picker && !picker_state.was_shown {
+            if let Some(on_open) = &self.on_open {
+                if !picker_state.open_requested {
+                    shell.publish(on_open());
+                }
+            }
+            // The keys of the overlay work right away when it is opened by the keyboard.
+            if picker_state.open_requested {
+                picker_state.overlay_state.focus = Focus::Day;
+            }
+            picker_state.open_requested = false;
+            picker_state.focus_target.clone_from(&self.underlay_id);
+        }
+        if !show_picker && picker_state.was_shown {
+            if let Some(id) = picker_state.focus_target.take() {
+                self.underlay.as_widget().operate(
+                    &mut state.children[0],
+                    layout,
+                    renderer,
+                    &mut focusable::focus(id),
+                );
+            }
+        }
+        picker_state.was_shown = show_picker;
+
         self.underlay.as_widget_mut().on_event(
             &mut state.children[0],
             event,
@@ -278,7 +964,7 @@ where
     ) -> Option<iced::overlay::Element<'b, Message, Theme, Renderer>> {
         let picker_state: &mut State = state.state.downcast_mut();
 
-        if !self.show_picker {
+        if !self.is_shown(picker_state) {
             return self.underlay.as_widget_mut().overlay(
                 &mut state.children[0],
                 layout,
@@ -287,19 +973,38 @@ where
             );
         }
 
-        let bounds = layout.bounds();
+        // The translation moves the overlay along with an underlay in a scrolled
+        // `Scrollable`, as the layout is relative to the content of the scrollable.
+        let bounds = layout.bounds() + translation;
         let position = Point::new(bounds.center_x(), bounds.center_y());
 
         Some(
             DatePickerOverlay::new(
                 picker_state,
-                self.on_cancel.clone(),
+                &self.on_cancel,
                 &self.on_submit,
                 position,
                 self.style.clone(),
                 &mut state.children[1],
                 self.font_size.unwrap_or_else(|| renderer.default_size()),
             )
+            .min_date(self.min_date)
+            .max_date(self.max_date)
+            .show_week_numbers(self.show_week_numbers)
+            .show_outside_days(self.show_outside_days)
+            .first_day_of_week(self.first_day_of_week)
+            .cancel_label(self.cancel_label.clone())
+            .submit_label(self.submit_label.clone())
+            .today_button(self.show_today_button.then(|| self.today_label.clone()))
+            .on_close(self.on_close.as_deref())
+            .margin(self.margin)
+            .swipe_threshold(self.swipe_threshold)
+            .highlight_dates(&self.highlights)
+            .padding(self.padding)
+            .border_radius(self.border_radius)
+            .border_width(self.border_width)
+            .width(self.overlay_width)
+            .max_width(self.overlay_max_width)
             .overlay(),
         )
     }
@@ -308,7 +1013,7 @@ where
 impl<'a, Message, Theme> From<DatePicker<'a, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Message: 'static + Clone,
+    Message: 'static,
     Theme: 'a
         + StyleSheet
         + button::StyleSheet
@@ -319,3 +1024,338 @@ where
         Element::new(date_picker)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced::{
+        advanced::{
+            clipboard,
+            layout::Limits,
+            subscription::Recipe,
+            text,
+            widget::{Operation, Tree},
+            Hasher, Layout, Shell, Widget,
+        },
+        event,
+        futures::{executor, stream, StreamExt},
+        keyboard::{self, key::Named},
+        mouse::{self, Cursor},
+        time::{Duration, Instant},
+        widget::{text_input, Button, Text, TextInput},
+        Element, Event, Font, Length, Pixels, Point, Rectangle, Renderer, Size, Theme,
+    };
+
+    use futures_timer::Delay;
+    use std::hash::Hasher as _;
+
+    use super::{AutoClose, Date, DatePicker, Focus, HasFocus, State};
+
+    #[derive(Clone, Debug)]
+    enum Message {
+        Cancel,
+        Submit,
+        Open,
+        Input(String),
+        Entered,
+    }
+
+    type Paragraph = <Renderer as text::Renderer>::Paragraph;
+
+    /// A text input as underlay of a [`DatePicker`].
+    fn input() -> TextInput<'static, Message, Theme, Renderer> {
+        TextInput::new("Date", "")
+            .on_input(Message::Input)
+            .on_submit(Message::Entered)
+    }
+
+    /// Sends the event to the picker and returns the status of the event and the
+    /// published messages.
+    fn send(
+        element: &mut Element<'_, Message, Theme, Renderer>,
+        tree: &mut Tree,
+        event: Event,
+    ) -> (event::Status, Vec<Message>) {
+        let renderer = Renderer::TinySkia(iced_tiny_skia::Renderer::new(
+            iced_tiny_skia::Backend::new(),
+            Font::default(),
+            Pixels(16.0),
+        ));
+        let node = element.as_widget().layout(
+            tree,
+            &renderer,
+            &Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+
+        let mut messages = Vec::new();
+        let status = element.as_widget_mut().on_event(
+            tree,
+            event,
+            Layout::new(&node),
+            Cursor::Unavailable,
+            &renderer,
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(Size::INFINITY),
+        );
+
+        (status, messages)
+    }
+
+    /// Presses the key while the text input of the underlay is focused and returns
+    /// the status of the event, the published messages and the tree of the picker.
+    fn press(
+        picker: DatePicker<'_, Message, Theme>,
+        key: Named,
+        text: Option<&str>,
+    ) -> (event::Status, Vec<Message>, Tree) {
+        let mut element = Element::from(picker);
+        let mut tree = Tree::new(&element);
+
+        tree.children[0]
+            .state
+            .downcast_mut::<text_input::State<Paragraph>>()
+            .focus();
+
+        let (status, messages) = send(
+            &mut element,
+            &mut tree,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::empty(),
+                text: text.map(Into::into),
+            }),
+        );
+
+        (status, messages, tree)
+    }
+
+    /// Gets the [`State`] of the picker from its tree.
+    fn state(tree: &Tree) -> &State {
+        tree.state.downcast_ref::<State>()
+    }
+
+    #[test]
+    fn size_test() {
+        let underlay = Button::new(Text::new("Pick"))
+            .width(Length::Fixed(120.0))
+            .height(Length::Fixed(30.0));
+        let picker: DatePicker<'_, Message, Theme> = DatePicker::new(
+            false,
+            Date::from_ymd(2024, 6, 5),
+            underlay,
+            Message::Cancel,
+            |_| Message::Submit,
+        );
+
+        let size = Widget::<Message, Theme, Renderer>::size(&picker);
+        assert_eq!(size.width, Length::Fixed(120.0));
+        assert_eq!(size.height, Length::Fixed(30.0));
+    }
+
+    #[test]
+    fn state_date_test() {
+        let mut state = State::with_date(Date::from_ymd(2024, 6, 5));
+        assert_eq!(state.current_date(), Date::from_ymd(2024, 6, 5));
+        assert!(state.overlay_state.is_selected);
+
+        state.set_date(Date::from_ymd(2023, 2, 14));
+        assert_eq!(state.current_date(), Date::from_ymd(2023, 2, 14));
+    }
+
+    #[test]
+    fn view_date_test() {
+        let picker: DatePicker<'_, Message, Theme> = DatePicker::new(
+            false,
+            Date::from_ymd(2024, 6, 5),
+            Text::new("Pick"),
+            Message::Cancel,
+            |_| Message::Submit,
+        )
+        .view_date(Date::from_ymd(2024, 9, 1));
+
+        let tree_state = Widget::<Message, Theme, Renderer>::state(&picker);
+        let state = tree_state.downcast_ref::<State>();
+        assert_eq!(state.current_date(), Date::from_ymd(2024, 9, 1));
+        assert!(!state.overlay_state.is_selected);
+    }
+
+    #[test]
+    fn stateful_test() {
+        let picker: DatePicker<'_, Message, Theme> =
+            DatePicker::stateful(Text::new("Pick"), Message::Cancel, |_| Message::Submit);
+
+        let mut tree_state = Widget::<Message, Theme, Renderer>::state(&picker);
+        let state = tree_state.downcast_mut::<State>();
+        assert!(!picker.is_shown(state));
+        assert_eq!(state.overlay_state.focus, Focus::Day);
+
+        state.show(true);
+        assert!(picker.is_shown(state));
+    }
+
+    #[test]
+    fn new_with_test() {
+        #[derive(Debug)]
+        enum Unclonable {
+            Cancel,
+            Submit,
+            Open,
+        }
+
+        let picker: DatePicker<'_, Unclonable, Theme> = DatePicker::new_with(
+            false,
+            Date::from_ymd(2024, 6, 5),
+            Text::new("Pick"),
+            || Unclonable::Cancel,
+            |_| Unclonable::Submit,
+        )
+        .on_open_with(|| Unclonable::Open);
+
+        assert!(matches!((picker.on_cancel)(), Unclonable::Cancel));
+        assert!(matches!(
+            picker.on_open.as_deref().map(|on_open| on_open()),
+            Some(Unclonable::Open)
+        ));
+
+        let _element: Element<'_, Unclonable, Theme, Renderer> = picker.into();
+    }
+
+    #[test]
+    fn has_focus_test() {
+        let mut input = text_input::State::<Paragraph>::new();
+        let mut has_focus = HasFocus::default();
+        Operation::<Message>::focusable(&mut has_focus, &mut input, None);
+        Operation::<Message>::text_input(&mut has_focus, &mut input, None);
+        assert!(!has_focus.focused);
+        assert!(!has_focus.text_input);
+
+        input.focus();
+        Operation::<Message>::focusable(&mut has_focus, &mut input, None);
+        assert!(has_focus.focused);
+        assert!(!has_focus.text_input);
+        Operation::<Message>::text_input(&mut has_focus, &mut input, None);
+        assert!(has_focus.text_input);
+    }
+
+    #[test]
+    fn focused_underlay_keys_test() {
+        let stateful = || DatePicker::stateful(input(), Message::Cancel, |_| Message::Submit);
+        let controlled = || {
+            DatePicker::new(
+                false,
+                Date::from_ymd(2024, 6, 5),
+                input(),
+                Message::Cancel,
+                |_| Message::Submit,
+            )
+        };
+
+        // Space is typed into the text input instead of opening the picker.
+        let (_, messages, tree) = press(stateful(), Named::Space, Some(" "));
+        assert!(matches!(messages.as_slice(), [Message::Input(input)] if input == " "));
+        assert!(!state(&tree).is_shown());
+
+        let (status, _, tree) = press(stateful(), Named::Enter, None);
+        assert_eq!(status, event::Status::Captured);
+        assert!(state(&tree).is_shown());
+        assert_eq!(state(&tree).overlay_state.focus, Focus::Day);
+
+        // Without an open message, the key is left to the text input.
+        let (_, messages, _) = press(controlled(), Named::Enter, None);
+        assert!(matches!(messages.as_slice(), [Message::Entered]));
+
+        let (status, messages, _) = press(controlled().on_open(Message::Open), Named::Enter, None);
+        assert_eq!(status, event::Status::Captured);
+        assert!(matches!(messages.as_slice(), [Message::Open]));
+    }
+
+    #[test]
+    fn open_request_test() {
+        let controlled = |show_picker| {
+            DatePicker::new(
+                show_picker,
+                Date::from_ymd(2024, 6, 5),
+                input(),
+                Message::Cancel,
+                |_| Message::Submit,
+            )
+            .on_open(Message::Open)
+        };
+        let moved = || {
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::ORIGIN,
+            })
+        };
+
+        // The application opens the picker on the open message, which is not sent again.
+        let (_, _, mut tree) = press(controlled(false), Named::Enter, None);
+        let mut shown = Element::from(controlled(true));
+        tree.diff(&shown);
+        let (_, messages) = send(&mut shown, &mut tree, moved());
+        assert!(messages.is_empty());
+        assert_eq!(state(&tree).overlay_state.focus, Focus::Day);
+
+        // The request is dropped if the application does not open the picker.
+        let (_, _, mut tree) = press(controlled(false), Named::Enter, None);
+        assert!(state(&tree).open_requested);
+        let mut hidden = Element::from(controlled(false));
+        let _ = send(&mut hidden, &mut tree, moved());
+        assert!(!state(&tree).open_requested);
+
+        let mut shown = Element::from(controlled(true));
+        tree.diff(&shown);
+        let (_, messages) = send(&mut shown, &mut tree, moved());
+        assert!(matches!(messages.as_slice(), [Message::Open]));
+    }
+
+    #[test]
+    fn auto_close_test() {
+        let auto_close = |id| {
+            Box::new(AutoClose {
+                id,
+                duration: Duration::from_millis(150),
+                on_cancel: Message::Cancel,
+            })
+        };
+        let hash = |recipe: &AutoClose<&'static str, Message>| {
+            let mut hasher = Hasher::default();
+            recipe.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        assert_eq!(hash(&auto_close("start")), hash(&auto_close("start")));
+        assert_ne!(hash(&auto_close("start")), hash(&auto_close("end")));
+
+        // Each interaction restarts the timeout.
+        let interactions = stream::unfold(0, |count| async move {
+            Delay::new(Duration::from_millis(50)).await;
+            (count < 4).then(|| {
+                (
+                    (
+                        Event::Mouse(mouse::Event::CursorMoved {
+                            position: Point::ORIGIN,
+                        }),
+                        event::Status::Ignored,
+                    ),
+                    count + 1,
+                )
+            })
+        })
+        .chain(stream::pending());
+
+        let start = Instant::now();
+        let mut messages = auto_close("start").stream(interactions.boxed());
+        assert!(matches!(
+            executor::block_on(messages.next()),
+            Some(Message::Cancel)
+        ));
+        assert!(start.elapsed() >= Duration::from_millis(350));
+
+        let _subscription = DatePicker::<Message, Theme>::auto_close_after(
+            "start",
+            Duration::from_secs(5),
+            Message::Cancel,
+        );
+    }
+}
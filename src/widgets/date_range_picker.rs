@@ -0,0 +1,339 @@
+//! Use a date range picker as an input element for picking a start and end date.
+//!
+//! *This API requires the following crate features to be activated: `date_picker`*
+
+use super::overlay::date_picker::{self, DatePickerOverlay, DatePickerOverlayButtons};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::Renderer as _,
+        widget::{
+            self,
+            tree::{Tag, Tree},
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    widget::button,
+    Element,
+    Event,
+    Length,
+    Pixels,
+    Point,
+    Rectangle,
+    Renderer, // the actual type
+    Size,
+    Vector,
+};
+
+pub use crate::{
+    core::date::{Date, DateRange},
+    style::date_picker::{Appearance, StyleSheet},
+};
+
+//TODO: Remove ignore when Null is updated. Temp fix for Test runs
+/// An input element for picking a range of dates.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{date_range_picker, DateRangePicker};
+/// # use iced::widget::{button, Button, Text};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Open,
+///     Cancel,
+///     Submit(date_range_picker::Date, date_range_picker::Date),
+/// }
+///
+/// let date_range_picker = DateRangePicker::new(
+///     true,
+///     date_range_picker::DateRange::new(
+///         date_range_picker::Date::today(),
+///         date_range_picker::Date::today(),
+///     ),
+///     Button::new(Text::new("Pick dates"))
+///         .on_press(Message::Open),
+///     Message::Cancel,
+///     Message::Submit,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DateRangePicker<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet + button::StyleSheet,
+{
+    /// Show the picker.
+    show_picker: bool,
+    /// The date range to show.
+    range: DateRange,
+    /// The underlying element.
+    underlay: Element<'a, Message, Theme, Renderer>,
+    /// The message that is send if the cancel button of the [`DatePickerOverlay`] is pressed.
+    on_cancel: Message,
+    /// The function that produces a message when the submit button of the [`DatePickerOverlay`] is pressed.
+    on_submit: Box<dyn Fn(Date, Date) -> Message>,
+    /// The style of the [`DatePickerOverlay`].
+    style: <Theme as StyleSheet>::Style,
+    /// The buttons of the overlay.
+    overlay_state: Element<'a, Message, Theme, Renderer>,
+    /// The font and icon size of the [`DatePickerOverlay`] or `None` for the default
+    font_size: Option<Pixels>,
+    /// The earliest date that can be picked or `None` if there is no lower limit.
+    min_date: Option<Date>,
+    /// The latest date that can be picked or `None` if there is no upper limit.
+    max_date: Option<Date>,
+}
+
+impl<'a, Message, Theme> DateRangePicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + iced::widget::text::StyleSheet
+        + iced::widget::container::StyleSheet,
+{
+    /// Creates a new [`DateRangePicker`] wrapping around the given underlay.
+    ///
+    /// It expects:
+    ///     * if the overlay of the date range picker is visible.
+    ///     * the initial date range to show.
+    ///     * the underlay [`Element`] on which this [`DateRangePicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DateRangePicker`]
+    ///         is pressed.
+    ///     * a function that will be called when the submit button of the [`DateRangePicker`]
+    ///         is pressed, which takes the picked start and end [`Date`] values.
+    pub fn new<U, F>(
+        show_picker: bool,
+        range: DateRange,
+        underlay: U,
+        on_cancel: Message,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        F: 'static + Fn(Date, Date) -> Message,
+    {
+        Self {
+            show_picker,
+            range,
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: Box::new(on_submit),
+            style: <Theme as StyleSheet>::Style::default(),
+            overlay_state: DatePickerOverlayButtons::default().into(),
+            font_size: None,
+            min_date: None,
+            max_date: None,
+        }
+    }
+
+    /// Sets the style of the [`DateRangePicker`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the font and icon size of the [`DateRangePicker`].
+    #[must_use]
+    pub fn font_size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.font_size = Some(size.into());
+        self
+    }
+
+    /// Sets the earliest date that can be picked in the [`DateRangePicker`].
+    ///
+    /// Days before this date are shown as disabled.
+    #[must_use]
+    pub fn min_date(mut self, date: impl Into<Date>) -> Self {
+        self.min_date = Some(date.into());
+        self
+    }
+
+    /// Sets the latest date that can be picked in the [`DateRangePicker`].
+    ///
+    /// Days after this date are shown as disabled.
+    #[must_use]
+    pub fn max_date(mut self, date: impl Into<Date>) -> Self {
+        self.max_date = Some(date.into());
+        self
+    }
+}
+
+/// The state of the [`DateRangePicker`] / [`DatePickerOverlay`].
+#[derive(Debug)]
+pub struct State {
+    /// The state of the overlay.
+    pub(crate) overlay_state: date_picker::State,
+}
+
+impl State {
+    /// Creates a new [`State`] with the given date range.
+    #[must_use]
+    pub fn new(range: DateRange) -> Self {
+        Self {
+            overlay_state: date_picker::State::new_range(range),
+        }
+    }
+
+    /// Resets the date range of the state to the current date.
+    pub fn reset(&mut self) {
+        let today = Date::today();
+        self.overlay_state = date_picker::State::new_range(DateRange::new(today, today));
+    }
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for DateRangePicker<'_, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: StyleSheet
+        + button::StyleSheet
+        + iced::widget::text::StyleSheet
+        + iced::widget::container::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::new(self.range))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay), Tree::new(&self.overlay_state)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.underlay, &self.overlay_state]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.underlay
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<iced::overlay::Element<'b, Message, Theme, Renderer>> {
+        let picker_state: &mut State = state.state.downcast_mut();
+
+        if !self.show_picker {
+            return self.underlay.as_widget_mut().overlay(
+                &mut state.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.center_x(), bounds.center_y());
+
+        Some(
+            DatePickerOverlay::new_range(
+                picker_state,
+                || self.on_cancel.clone(),
+                &self.on_submit,
+                position,
+                self.style.clone(),
+                &mut state.children[1],
+                self.font_size.unwrap_or_else(|| renderer.default_size()),
+            )
+            .min_date(self.min_date)
+            .max_date(self.max_date)
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme> From<DateRangePicker<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'static + Clone,
+    Theme: 'a
+        + StyleSheet
+        + button::StyleSheet
+        + iced::widget::text::StyleSheet
+        + iced::widget::container::StyleSheet,
+{
+    fn from(date_range_picker: DateRangePicker<'a, Message, Theme>) -> Self {
+        Element::new(date_range_picker)
+    }
+}
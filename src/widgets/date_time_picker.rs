@@ -0,0 +1,415 @@
+//! Use a date time picker as an input element for picking a date and a time.
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker`*
+
+use super::overlay::{
+    date_picker::{DatePickerOverlay, DatePickerOverlayButtons},
+    date_time_picker::{DateTimePickerOverlay, PhaseMessage},
+    time_picker::{TimePickerOverlay, TimePickerOverlayButtons},
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::Renderer as _,
+        widget::{
+            self,
+            tree::{Tag, Tree},
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    widget::{button, container, text},
+    Element,
+    Event,
+    Length,
+    Pixels,
+    Point,
+    Rectangle,
+    Renderer, // the actual type
+    Size,
+    Vector,
+};
+
+pub use crate::{
+    core::{
+        date::Date,
+        date_time::DateTime,
+        duration::Duration,
+        time::{Period, Time},
+    },
+    widgets::overlay::date_time_picker::DateTimePhase,
+};
+
+use crate::style::{date_picker, time_picker};
+
+//TODO: Remove ignore when Null is updated. Temp fix for Test runs
+/// An input element for picking a date and a time.
+///
+/// The date is picked first, submitting it shows the time picker.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{date_time_picker, DateTimePicker};
+/// # use iced::widget::{button, Button, Text};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Open,
+///     Cancel,
+///     Submit(date_time_picker::Date, date_time_picker::Time),
+/// }
+///
+/// let date_time_picker = DateTimePicker::new(
+///     true,
+///     date_time_picker::DateTime::now(true),
+///     Button::new(Text::new("Pick date and time"))
+///         .on_press(Message::Open),
+///     Message::Cancel,
+///     Message::Submit,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DateTimePicker<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: date_picker::StyleSheet + time_picker::StyleSheet + button::StyleSheet,
+{
+    /// Show the picker.
+    show_picker: bool,
+    /// The date and time to show.
+    date_time: DateTime,
+    /// The underlying element.
+    underlay: Element<'a, Message, Theme, Renderer>,
+    /// The message that is send if the cancel button of one of the overlays is pressed.
+    on_cancel: Message,
+    /// The function that produces a message when the time is submitted.
+    on_submit: Box<dyn Fn(Date, Time) -> Message>,
+    /// The style of the [`DatePickerOverlay`].
+    date_style: <Theme as date_picker::StyleSheet>::Style,
+    /// The style of the [`TimePickerOverlay`].
+    time_style: <Theme as time_picker::StyleSheet>::Style,
+    /// The buttons of the [`DatePickerOverlay`].
+    date_overlay_state: Element<'a, PhaseMessage, Theme, Renderer>,
+    /// The buttons of the [`TimePickerOverlay`].
+    time_overlay_state: Element<'a, PhaseMessage, Theme, Renderer>,
+    /// The font and icon size of the [`DatePickerOverlay`] or `None` for the default
+    font_size: Option<Pixels>,
+    /// Toggle the use of the 24h clock of the [`TimePickerOverlay`].
+    use_24h: bool,
+    /// Toggle the use of the seconds of the [`TimePickerOverlay`].
+    show_seconds: bool,
+}
+
+impl<'a, Message, Theme> DateTimePicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + date_picker::StyleSheet
+        + time_picker::StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet,
+{
+    /// Creates a new [`DateTimePicker`] wrapping around the given underlay.
+    ///
+    /// It expects:
+    ///     * if the overlay of the date time picker is visible.
+    ///     * the initial date and time to show.
+    ///     * the underlay [`Element`] on which this [`DateTimePicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the cancel button of the [`DateTimePicker`]
+    ///         is pressed.
+    ///     * a function that will be called when the time of the [`DateTimePicker`]
+    ///         is submitted, which takes the picked [`Date`] and [`Time`] values.
+    pub fn new<U, F>(
+        show_picker: bool,
+        date_time: impl Into<DateTime>,
+        underlay: U,
+        on_cancel: Message,
+        on_submit: F,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        F: 'static + Fn(Date, Time) -> Message,
+    {
+        Self {
+            show_picker,
+            date_time: date_time.into(),
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: Box::new(on_submit),
+            date_style: <Theme as date_picker::StyleSheet>::Style::default(),
+            time_style: <Theme as time_picker::StyleSheet>::Style::default(),
+            date_overlay_state: DatePickerOverlayButtons::default().into(),
+            time_overlay_state: TimePickerOverlayButtons::default().into(),
+            font_size: None,
+            use_24h: false,
+            show_seconds: false,
+        }
+    }
+
+    /// Sets the style of the date picker of the [`DateTimePicker`].
+    #[must_use]
+    pub fn date_style(mut self, style: <Theme as date_picker::StyleSheet>::Style) -> Self {
+        self.date_style = style;
+        self
+    }
+
+    /// Sets the style of the time picker of the [`DateTimePicker`].
+    #[must_use]
+    pub fn time_style(mut self, style: <Theme as time_picker::StyleSheet>::Style) -> Self {
+        self.time_style = style;
+        self
+    }
+
+    /// Sets the font and icon size of the date picker of the [`DateTimePicker`].
+    #[must_use]
+    pub fn font_size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.font_size = Some(size.into());
+        self
+    }
+
+    /// Use 24 hour format instead of AM/PM.
+    #[must_use]
+    pub fn use_24h(mut self) -> Self {
+        self.use_24h = true;
+        self
+    }
+
+    /// Toggles if the picker can also pick seconds.
+    #[must_use]
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+}
+
+/// The state of the [`DateTimePicker`] / [`DateTimePickerOverlay`].
+#[derive(Debug)]
+pub struct State {
+    /// The current phase of the overlay.
+    pub(crate) phase: DateTimePhase,
+    /// The date submitted in the date phase.
+    pub(crate) date: Date,
+    /// The state of the date overlay.
+    pub(crate) date_picker: crate::widgets::date_picker::State,
+    /// The state of the time overlay.
+    pub(crate) time_picker: crate::widgets::time_picker::State,
+}
+
+impl State {
+    /// Creates a new [`State`] with the given date and time.
+    #[must_use]
+    pub fn new(date_time: DateTime, use_24h: bool, show_seconds: bool) -> Self {
+        Self {
+            phase: DateTimePhase::default(),
+            date: date_time.date,
+            date_picker: crate::widgets::date_picker::State::new(date_time.date),
+            time_picker: crate::widgets::time_picker::State::new(
+                date_time.time,
+                use_24h,
+                show_seconds,
+            ),
+        }
+    }
+
+    /// Gets the current phase of the overlay.
+    #[must_use]
+    pub fn phase(&self) -> DateTimePhase {
+        self.phase
+    }
+
+    /// Resets the state to the current date and time and starts with picking the date.
+    pub fn reset(&mut self) {
+        self.phase = DateTimePhase::default();
+        self.date = Date::today();
+        self.date_picker.reset();
+        self.time_picker.reset();
+    }
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for DateTimePicker<'_, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: date_picker::StyleSheet
+        + time_picker::StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::new(self.date_time, self.use_24h, self.show_seconds))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.underlay),
+            Tree::new(&self.date_overlay_state),
+            Tree::new(&self.time_overlay_state),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        if tree.children.len() != 3 {
+            tree.children = self.children();
+        }
+
+        tree.children[0].diff(&self.underlay);
+        tree.children[1].diff(&self.date_overlay_state);
+        tree.children[2].diff(&self.time_overlay_state);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.underlay
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<iced::overlay::Element<'b, Message, Theme, Renderer>> {
+        if !self.show_picker {
+            return self.underlay.as_widget_mut().overlay(
+                &mut state.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        let State {
+            phase,
+            date,
+            date_picker,
+            time_picker,
+        } = state.state.downcast_mut();
+
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.center_x(), bounds.center_y());
+
+        let content = match phase {
+            DateTimePhase::Date => DatePickerOverlay::new(
+                date_picker,
+                || PhaseMessage::Cancel,
+                &PhaseMessage::Date,
+                position,
+                self.date_style.clone(),
+                &mut state.children[1],
+                self.font_size.unwrap_or_else(|| renderer.default_size()),
+            )
+            .overlay(),
+            DateTimePhase::Time => TimePickerOverlay::new(
+                time_picker,
+                PhaseMessage::Cancel,
+                &PhaseMessage::Time,
+                position,
+                self.time_style.clone(),
+                &mut state.children[2],
+            )
+            .overlay(),
+        };
+
+        Some(
+            DateTimePickerOverlay::new(
+                phase,
+                date,
+                content,
+                self.on_cancel.clone(),
+                &self.on_submit,
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme> From<DateTimePicker<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'static + Clone,
+    Theme: 'a
+        + date_picker::StyleSheet
+        + time_picker::StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet
+        + container::StyleSheet,
+{
+    fn from(date_time_picker: DateTimePicker<'a, Message, Theme>) -> Self {
+        Element::new(date_time_picker)
+    }
+}
@@ -0,0 +1,296 @@
+//! Use a divider for separating content by a horizontal or a vertical line.
+//!
+//! *This API requires the following crate features to be activated: `divider`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::{self, Paragraph as _},
+        widget::Tree,
+        Layout, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    mouse::Cursor,
+    Border, Color, Element, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+pub use crate::style::divider::{Appearance, StyleSheet};
+
+/// The gap between the label and the line of a [`Divider`].
+const LABEL_GAP: f32 = 8.0;
+
+/// The direction of the line of a [`Divider`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Direction {
+    /// A horizontal line filling the width.
+    Horizontal,
+    /// A vertical line filling the height.
+    Vertical,
+}
+
+/// A horizontal or vertical line separating content, optionally with a label
+/// in its center.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::Divider;
+/// #
+/// let divider = Divider::horizontal().label("or");
+/// let separator = Divider::vertical().thickness(2.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Divider<Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// The direction of the line of the [`Divider`].
+    direction: Direction,
+    /// The thickness of the line.
+    thickness: f32,
+    /// The color of the line or `None` for the color of the style.
+    color: Option<Color>,
+    /// The margin on each side of the line.
+    spacing: f32,
+    /// The label shown in the center of the line.
+    label: Option<String>,
+    /// The text size of the label or `None` for the default.
+    text_size: Option<Pixels>,
+    /// The font of the label or `None` for the default.
+    font: Option<Renderer::Font>,
+    /// The style of the [`Divider`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Theme, Renderer> Divider<Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Divider`] in the given direction.
+    fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            thickness: 1.0,
+            color: None,
+            spacing: 8.0,
+            label: None,
+            text_size: None,
+            font: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Creates a new horizontal [`Divider`] filling the available width.
+    #[must_use]
+    pub fn horizontal() -> Self {
+        Self::new(Direction::Horizontal)
+    }
+
+    /// Creates a new vertical [`Divider`] filling the available height.
+    #[must_use]
+    pub fn vertical() -> Self {
+        Self::new(Direction::Vertical)
+    }
+
+    /// Sets the thickness of the line of the [`Divider`].
+    #[must_use]
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets the color of the line of the [`Divider`].
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the margin on each side of the line of the [`Divider`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the label shown in the center of the line of the [`Divider`].
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the text size of the label of the [`Divider`].
+    #[must_use]
+    pub fn text_size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the label of the [`Divider`].
+    #[must_use]
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Divider`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The text of the label.
+    fn text<'b>(&self, content: &'b str, renderer: &Renderer) -> text::Text<'b, Renderer::Font> {
+        text::Text {
+            content,
+            bounds: Size::INFINITY,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        }
+    }
+
+    /// The size of the label, if the [`Divider`] has one.
+    fn label_size(&self, renderer: &Renderer) -> Option<Size> {
+        self.label
+            .as_deref()
+            .map(|label| Renderer::Paragraph::with_text(self.text(label, renderer)).min_bounds())
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Divider<Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    fn size(&self) -> Size<Length> {
+        match self.direction {
+            Direction::Horizontal => Size::new(Length::Fill, Length::Shrink),
+            Direction::Vertical => Size::new(Length::Shrink, Length::Fill),
+        }
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let label = self.label_size(renderer).unwrap_or(Size::ZERO);
+        let size = Widget::<Message, Theme, Renderer>::size(self);
+
+        let intrinsic = match self.direction {
+            Direction::Horizontal => Size::new(
+                label.width,
+                self.thickness.max(label.height) + 2.0 * self.spacing,
+            ),
+            Direction::Vertical => Size::new(
+                self.thickness.max(label.width) + 2.0 * self.spacing,
+                label.height,
+            ),
+        };
+
+        Node::new(limits.resolve(size.width, size.height, intrinsic))
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        if !bounds.intersects(viewport) {
+            return;
+        }
+
+        let appearance = theme.active(&self.style);
+        let color = self.color.unwrap_or(appearance.color);
+        let center = bounds.center();
+
+        // The line is split around the label, leaving a gap on both of its sides.
+        let label = self.label_size(renderer);
+        let lines = match (self.direction, label) {
+            (Direction::Horizontal, None) => vec![Rectangle {
+                x: bounds.x,
+                y: center.y - self.thickness / 2.0,
+                width: bounds.width,
+                height: self.thickness,
+            }],
+            (Direction::Horizontal, Some(label)) => {
+                let gap = label.width / 2.0 + LABEL_GAP;
+                let width = (bounds.width / 2.0 - gap).max(0.0);
+                let y = center.y - self.thickness / 2.0;
+
+                vec![
+                    Rectangle::new(Point::new(bounds.x, y), Size::new(width, self.thickness)),
+                    Rectangle::new(
+                        Point::new(center.x + gap, y),
+                        Size::new(width, self.thickness),
+                    ),
+                ]
+            }
+            (Direction::Vertical, None) => vec![Rectangle {
+                x: center.x - self.thickness / 2.0,
+                y: bounds.y,
+                width: self.thickness,
+                height: bounds.height,
+            }],
+            (Direction::Vertical, Some(label)) => {
+                let gap = label.height / 2.0 + LABEL_GAP;
+                let height = (bounds.height / 2.0 - gap).max(0.0);
+                let x = center.x - self.thickness / 2.0;
+
+                vec![
+                    Rectangle::new(Point::new(x, bounds.y), Size::new(self.thickness, height)),
+                    Rectangle::new(
+                        Point::new(x, center.y + gap),
+                        Size::new(self.thickness, height),
+                    ),
+                ]
+            }
+        };
+
+        for line in lines {
+            if line.width > 0.0 && line.height > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: line,
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                    },
+                    color,
+                );
+            }
+        }
+
+        if let Some(label) = &self.label {
+            renderer.fill_text(
+                text::Text {
+                    bounds: bounds.size(),
+                    ..self.text(label, renderer)
+                },
+                center,
+                appearance.text_color,
+                bounds,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Divider<Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(divider: Divider<Theme, Renderer>) -> Self {
+        Element::new(divider)
+    }
+}
@@ -17,6 +17,12 @@ use iced::{
 
 pub use crate::core::{alignment::Alignment, offset::Offset};
 
+mod list;
+pub mod multi_select;
+pub use multi_select::MultiSelect;
+pub mod select;
+pub use select::Select;
+
 /// Customizable drop down menu widget
 pub struct DropDown<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
 where
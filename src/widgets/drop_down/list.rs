@@ -0,0 +1,404 @@
+//! The list of options shown by the [`Select`](super::Select) and the
+//! [`MultiSelect`](super::MultiSelect).
+//!
+//! *This API requires the following crate features to be activated: `drop_down`*
+
+use iced::{
+    advanced::{layout::Node, overlay, renderer, text, Clipboard, Layout, Shell},
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor},
+    touch, Border, Color, Event, Padding, Pixels, Point, Rectangle, Shadow, Size,
+};
+use std::fmt::Display;
+
+use crate::style::drop_down::StyleSheet;
+
+/// The state of a drop down list.
+#[derive(Debug, Default)]
+pub(super) struct State {
+    /// Whether the list is open.
+    pub(super) is_open: bool,
+    /// The index of the hovered option of the list.
+    pub(super) hovered: Option<usize>,
+    /// The scroll offset of the list.
+    pub(super) scroll_offset: f32,
+    /// Whether the list should scroll to the hovered option when laid out.
+    pub(super) scroll_to_hovered: bool,
+}
+
+impl State {
+    /// Opens or closes the list, hovering the given option once it is open.
+    pub(super) fn toggle(&mut self, hovered: Option<usize>) {
+        self.is_open = !self.is_open;
+        self.hovered = hovered;
+        self.scroll_offset = 0.0;
+        self.scroll_to_hovered = true;
+    }
+}
+
+/// Shrinks the bounds by the padding.
+pub(super) fn shrink(bounds: Rectangle, padding: Padding) -> Rectangle {
+    Rectangle {
+        x: bounds.x + padding.left,
+        y: bounds.y + padding.top,
+        width: (bounds.width - padding.horizontal()).max(0.0),
+        height: (bounds.height - padding.vertical()).max(0.0),
+    }
+}
+
+/// The text of an option or the placeholder.
+pub(super) fn text<Font>(
+    content: &str,
+    bounds: Size,
+    size: Pixels,
+    font: Font,
+) -> iced::advanced::Text<'_, Font> {
+    iced::advanced::Text {
+        content,
+        bounds,
+        size,
+        line_height: text::LineHeight::default(),
+        font,
+        horizontal_alignment: Horizontal::Left,
+        vertical_alignment: Vertical::Center,
+        shaping: text::Shaping::Advanced,
+    }
+}
+
+/// The list of the options shown below or above the field of a drop down.
+pub(super) struct OptionList<'b, T, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// The state of the list.
+    pub(super) state: &'b mut State,
+    /// The options of the list.
+    pub(super) options: &'b [T],
+    /// Whether each option is checked or `None` if the options have no checkboxes.
+    pub(super) checked: Option<Vec<bool>>,
+    /// Whether each option is disabled.
+    pub(super) disabled: Vec<bool>,
+    /// The function that produces a message when the option with the given index is picked.
+    pub(super) on_pick: Box<dyn Fn(usize) -> Message + 'b>,
+    /// Whether the list is closed when an option is picked.
+    pub(super) close_on_pick: bool,
+    /// The bounds of the field the list belongs to.
+    pub(super) field_bounds: Rectangle,
+    /// The maximum height of the list.
+    pub(super) max_height: f32,
+    /// The padding of each option.
+    pub(super) padding: Padding,
+    /// The text size of the options.
+    pub(super) text_size: Pixels,
+    /// The font of the options.
+    pub(super) font: Renderer::Font,
+    /// The style of the list.
+    pub(super) style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<T, Message, Theme, Renderer> OptionList<'_, T, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// The height of the text of an option.
+    fn line_height(&self) -> f32 {
+        text::LineHeight::default().to_absolute(self.text_size).0
+    }
+
+    /// The height of a single option.
+    fn option_height(&self) -> f32 {
+        self.line_height() + self.padding.vertical()
+    }
+
+    /// Whether the option with the given index is disabled.
+    fn is_disabled(&self, index: usize) -> bool {
+        self.disabled.get(index).copied().unwrap_or(false)
+    }
+
+    /// The index of the option under the cursor.
+    fn option_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<usize> {
+        let position = cursor.position_over(bounds)?;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let index =
+            ((position.y - bounds.y + self.state.scroll_offset) / self.option_height()) as usize;
+
+        (index < self.options.len()).then_some(index)
+    }
+
+    /// Picks the option with the given index if it is enabled.
+    fn pick(&mut self, index: usize, shell: &mut Shell<'_, Message>) {
+        if self.is_disabled(index) {
+            return;
+        }
+
+        shell.publish((self.on_pick)(index));
+
+        if self.close_on_pick {
+            self.state.is_open = false;
+        }
+    }
+
+    /// Scrolls the list so that the hovered option is visible.
+    fn scroll_to_hovered(&mut self, visible_height: f32) {
+        if let Some(hovered) = self.state.hovered {
+            let option_height = self.option_height();
+            #[allow(clippy::cast_precision_loss)]
+            let top = hovered as f32 * option_height;
+
+            if top < self.state.scroll_offset {
+                self.state.scroll_offset = top;
+            } else if top + option_height > self.state.scroll_offset + visible_height {
+                self.state.scroll_offset = top + option_height - visible_height;
+            }
+        }
+    }
+}
+
+impl<T, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for OptionList<'_, T, Message, Theme, Renderer>
+where
+    T: Display,
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        #[allow(clippy::cast_precision_loss)]
+        let total_height = self.options.len() as f32 * self.option_height();
+        let height = total_height.min(self.max_height);
+
+        let below = self.field_bounds.y + self.field_bounds.height;
+        let y = if below + height > bounds.height && self.field_bounds.y >= height {
+            self.field_bounds.y - height
+        } else {
+            below
+        };
+
+        if self.state.scroll_to_hovered {
+            self.state.scroll_to_hovered = false;
+            self.scroll_to_hovered(height);
+        }
+
+        Node::new(Size::new(self.field_bounds.width, height))
+            .move_to(Point::new(self.field_bounds.x, y))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let last = self.options.len().saturating_sub(1);
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                match key {
+                    keyboard::Key::Named(Named::ArrowDown) => {
+                        self.state.hovered = Some(
+                            self.state
+                                .hovered
+                                .map_or(0, |hovered| (hovered + 1).min(last)),
+                        );
+                        self.scroll_to_hovered(bounds.height);
+                    }
+                    keyboard::Key::Named(Named::ArrowUp) => {
+                        self.state.hovered = Some(
+                            self.state
+                                .hovered
+                                .map_or(last, |hovered| hovered.saturating_sub(1)),
+                        );
+                        self.scroll_to_hovered(bounds.height);
+                    }
+                    keyboard::Key::Named(Named::Enter) => match self.state.hovered {
+                        Some(index) if index < self.options.len() => self.pick(index, shell),
+                        _ => self.state.is_open = false,
+                    },
+                    keyboard::Key::Named(Named::Escape) => {
+                        self.state.is_open = false;
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(index) = self.option_at(bounds, cursor) {
+                    self.state.hovered = Some(index);
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) = self.option_at(bounds, cursor) {
+                    self.pick(index, shell);
+
+                    event::Status::Captured
+                } else {
+                    // Clicks on the field are left to the field to toggle the list.
+                    if !cursor.is_over(self.field_bounds) {
+                        self.state.is_open = false;
+                    }
+
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * self.option_height(),
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                #[allow(clippy::cast_precision_loss)]
+                let max_offset =
+                    (self.options.len() as f32 * self.option_height() - bounds.height).max(0.0);
+
+                self.state.scroll_offset =
+                    (self.state.scroll_offset - delta).clamp(0.0, max_offset);
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match self.option_at(layout.bounds(), cursor) {
+            Some(index) if self.is_disabled(index) => mouse::Interaction::NotAllowed,
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.hovered(self.style);
+        let option_height = self.option_height();
+        let line_height = self.line_height();
+
+        if bounds.width > 0.0 && bounds.height > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: appearance.border_radius.into(),
+                        width: appearance.border_width,
+                        color: appearance.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.menu_background,
+            );
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            for (index, option) in self.options.iter().enumerate() {
+                #[allow(clippy::cast_precision_loss)]
+                let option_bounds = Rectangle {
+                    x: bounds.x,
+                    y: bounds.y + index as f32 * option_height - self.state.scroll_offset,
+                    width: bounds.width,
+                    height: option_height,
+                };
+
+                if !option_bounds.intersects(&bounds) {
+                    continue;
+                }
+
+                let disabled = self.is_disabled(index);
+                let hovered = !disabled && self.state.hovered == Some(index);
+
+                if hovered {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: option_bounds,
+                            border: Border::default(),
+                            shadow: Shadow::default(),
+                        },
+                        appearance.hovered_option_background,
+                    );
+                }
+
+                let color = if disabled {
+                    appearance.placeholder_color
+                } else if hovered {
+                    appearance.hovered_option_text_color
+                } else {
+                    appearance.text_color
+                };
+
+                let mut text_bounds = shrink(option_bounds, self.padding);
+
+                if let Some(checked) = &self.checked {
+                    let size = line_height * 0.7;
+                    let checkbox_bounds = Rectangle {
+                        x: text_bounds.x,
+                        y: text_bounds.center_y() - size / 2.0,
+                        width: size,
+                        height: size,
+                    };
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: checkbox_bounds,
+                            border: Border {
+                                radius: 2.0.into(),
+                                width: 1.0,
+                                color,
+                            },
+                            shadow: Shadow::default(),
+                        },
+                        Color::TRANSPARENT,
+                    );
+
+                    if checked.get(index).copied().unwrap_or(false) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: shrink(checkbox_bounds, Padding::new(size / 4.0)),
+                                border: Border {
+                                    radius: 1.0.into(),
+                                    width: 0.0,
+                                    color: Color::TRANSPARENT,
+                                },
+                                shadow: Shadow::default(),
+                            },
+                            color,
+                        );
+                    }
+
+                    text_bounds.x += size + self.padding.left;
+                    text_bounds.width = (text_bounds.width - size - self.padding.left).max(0.0);
+                }
+
+                let label = option.to_string();
+
+                renderer.fill_text(
+                    text(&label, text_bounds.size(), self.text_size, self.font),
+                    Point::new(text_bounds.x, text_bounds.center_y()),
+                    color,
+                    bounds,
+                );
+            }
+        });
+    }
+}
@@ -0,0 +1,479 @@
+//! Use a multi select for picking several options of a drop down list.
+//!
+//! *This API requires the following crate features to be activated: `drop_down`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay, renderer,
+        text::{self, Paragraph as _},
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::Horizontal,
+    event,
+    mouse::{self, Cursor},
+    touch, Border, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::fmt::Display;
+
+use super::list::{shrink, text, OptionList, State};
+pub use crate::style::drop_down::{Appearance, StyleSheet};
+
+/// The horizontal padding between the border and the label of a chip.
+const CHIP_PADDING: f32 = 6.0;
+
+/// The spacing between the chips.
+const CHIP_SPACING: f32 = 4.0;
+
+/// The mark of the button removing a chip.
+const DELETE_MARK: &str = "×";
+
+/// A field showing the selected options as removable chips that opens a list of
+/// all options with checkboxes when clicked.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::drop_down::MultiSelect;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Changed(Vec<&'static str>),
+/// }
+///
+/// let multi_select = MultiSelect::new(
+///     vec!["Apple", "Banana", "Cherry"],
+///     vec!["Banana"],
+///     Message::Changed,
+/// )
+/// .placeholder("Pick some fruits")
+/// .max_selected(2);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MultiSelect<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// The options of the [`MultiSelect`].
+    options: Vec<T>,
+    /// The selected options of the [`MultiSelect`].
+    selected: Vec<T>,
+    /// The function that produces a message when the selected options change.
+    on_change: Box<dyn Fn(Vec<T>) -> Message + 'a>,
+    /// The maximum number of options that can be selected.
+    max_selected: Option<usize>,
+    /// The text shown while no option is selected.
+    placeholder: Option<String>,
+    /// The width of the [`MultiSelect`].
+    width: Length,
+    /// The maximum height of the list before it scrolls.
+    max_height: f32,
+    /// The padding of the field and of each option.
+    padding: Padding,
+    /// The text size of the [`MultiSelect`] or `None` for the default.
+    text_size: Option<Pixels>,
+    /// The font of the [`MultiSelect`] or `None` for the default.
+    font: Option<Renderer::Font>,
+    /// The style of the [`MultiSelect`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Theme, Renderer> MultiSelect<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`MultiSelect`].
+    ///
+    /// It expects:
+    ///     * the options to pick from.
+    ///     * the selected options.
+    ///     * a function that will be called with the new selected options when they change.
+    pub fn new<F>(options: Vec<T>, selected: Vec<T>, on_change: F) -> Self
+    where
+        F: 'a + Fn(Vec<T>) -> Message,
+    {
+        MultiSelect {
+            options,
+            selected,
+            on_change: Box::new(on_change),
+            max_selected: None,
+            placeholder: None,
+            width: Length::Shrink,
+            max_height: 200.0,
+            padding: Padding::new(5.0),
+            text_size: None,
+            font: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the maximum number of options that can be selected.
+    ///
+    /// The remaining options of the list are disabled once the limit is reached.
+    #[must_use]
+    pub fn max_selected(mut self, max_selected: usize) -> Self {
+        self.max_selected = Some(max_selected);
+        self
+    }
+
+    /// Sets the text shown while no option of the [`MultiSelect`] is selected.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the [`MultiSelect`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the maximum height of the list of the [`MultiSelect`].
+    ///
+    /// The list can be scrolled if the options exceed it.
+    #[must_use]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the padding of the field and of each option of the [`MultiSelect`].
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MultiSelect`].
+    #[must_use]
+    pub fn text_size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`MultiSelect`].
+    #[must_use]
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`MultiSelect`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Whether the maximum number of selected options is reached.
+    fn is_full(&self) -> bool {
+        self.max_selected
+            .is_some_and(|max_selected| self.selected.len() >= max_selected)
+    }
+}
+
+/// The bounds of the button removing the chip with the given bounds.
+fn delete_bounds(chip: Rectangle) -> Rectangle {
+    Rectangle {
+        x: chip.x + chip.width - chip.height,
+        width: chip.height,
+        ..chip
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MultiSelect<'a, T, Message, Theme, Renderer>
+where
+    T: 'a + Clone + Display + PartialEq,
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let line_height = text::LineHeight::default().to_absolute(text_size).0;
+
+        let measure = |label: &str| {
+            Renderer::Paragraph::with_text(text(label, Size::INFINITY, text_size, font)).min_width()
+        };
+        let chip_width = |label: &str| CHIP_PADDING + measure(label) + line_height;
+
+        let labels_width = self
+            .options
+            .iter()
+            .map(|option| chip_width(&option.to_string()))
+            .chain(self.placeholder.as_deref().map(measure))
+            .fold(0.0, f32::max);
+
+        let width = limits
+            .width(self.width)
+            .resolve(
+                self.width,
+                Length::Shrink,
+                Size::new(labels_width + self.padding.horizontal(), 0.0),
+            )
+            .width;
+        let max_width = (width - self.padding.horizontal()).max(0.0);
+
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let chips = self
+            .selected
+            .iter()
+            .map(|selected| {
+                let chip_width = chip_width(&selected.to_string()).min(max_width);
+
+                if x > 0.0 && x + chip_width > max_width {
+                    x = 0.0;
+                    y += line_height + CHIP_SPACING;
+                }
+
+                let chip = Node::new(Size::new(chip_width, line_height))
+                    .move_to(Point::new(self.padding.left + x, self.padding.top + y));
+                x += chip_width + CHIP_SPACING;
+
+                chip
+            })
+            .collect();
+
+        Node::with_children(
+            Size::new(width, y + line_height + self.padding.vertical()),
+            chips,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                let removed = layout
+                    .children()
+                    .position(|chip| cursor.is_over(delete_bounds(chip.bounds())));
+
+                if let Some(removed) = removed {
+                    let mut selected = self.selected.clone();
+                    let _ = selected.remove(removed);
+                    shell.publish((self.on_change)(selected));
+                } else {
+                    state.state.downcast_mut::<State>().toggle(None);
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = state.state.downcast_ref::<State>();
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let appearance = if state.is_open || cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        if bounds.intersects(viewport) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: appearance.border_radius.into(),
+                        width: appearance.border_width,
+                        color: appearance.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.background,
+            );
+        }
+
+        if self.selected.is_empty() {
+            let text_bounds = shrink(bounds, self.padding);
+
+            renderer.fill_text(
+                text(
+                    self.placeholder.as_deref().unwrap_or_default(),
+                    text_bounds.size(),
+                    text_size,
+                    font,
+                ),
+                Point::new(text_bounds.x, text_bounds.center_y()),
+                appearance.placeholder_color,
+                text_bounds,
+            );
+
+            return;
+        }
+
+        for (selected, chip) in self.selected.iter().zip(layout.children()) {
+            let chip_bounds = chip.bounds();
+            let delete_bounds = delete_bounds(chip_bounds);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: chip_bounds,
+                    border: Border {
+                        radius: (chip_bounds.height / 2.0).into(),
+                        width: 0.0,
+                        color: appearance.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.chip_background,
+            );
+
+            let label_bounds = Rectangle {
+                x: chip_bounds.x + CHIP_PADDING,
+                width: (delete_bounds.x - chip_bounds.x - CHIP_PADDING).max(0.0),
+                ..chip_bounds
+            };
+
+            renderer.fill_text(
+                text(&selected.to_string(), label_bounds.size(), text_size, font),
+                Point::new(label_bounds.x, label_bounds.center_y()),
+                appearance.chip_text_color,
+                label_bounds,
+            );
+
+            renderer.fill_text(
+                iced::advanced::Text {
+                    horizontal_alignment: Horizontal::Center,
+                    ..text(DELETE_MARK, delete_bounds.size(), text_size, font)
+                },
+                delete_bounds.center(),
+                appearance.chip_text_color,
+                delete_bounds,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = state.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        let checked: Vec<bool> = self
+            .options
+            .iter()
+            .map(|option| self.selected.contains(option))
+            .collect();
+        let disabled = if self.is_full() {
+            checked.iter().map(|checked| !checked).collect()
+        } else {
+            Vec::new()
+        };
+
+        let options = &self.options;
+        let selected = &self.selected;
+        let on_change = &self.on_change;
+
+        Some(overlay::Element::new(Box::new(OptionList {
+            state,
+            options,
+            checked: Some(checked),
+            disabled,
+            on_pick: Box::new(move |index| {
+                let option = &options[index];
+                let mut selected = selected.clone();
+
+                if let Some(position) = selected.iter().position(|selected| selected == option) {
+                    let _ = selected.remove(position);
+                } else {
+                    selected.push(option.clone());
+                }
+
+                on_change(selected)
+            }),
+            close_on_pick: false,
+            field_bounds: layout.bounds() + translation,
+            max_height: self.max_height,
+            padding: self.padding,
+            text_size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            style: &self.style,
+        })))
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<MultiSelect<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a + Clone + Display + PartialEq,
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(multi_select: MultiSelect<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(multi_select)
+    }
+}
@@ -0,0 +1,350 @@
+//! Use a select for picking a single option of a drop down list.
+//!
+//! *This API requires the following crate features to be activated: `drop_down`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay, renderer,
+        text::{self, Paragraph as _},
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch, Border, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::fmt::Display;
+
+use super::list::{shrink, text, OptionList, State};
+
+pub use crate::style::drop_down::{Appearance, StyleSheet};
+
+/// A field showing the selected option that opens a list of all options when clicked.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::drop_down::Select;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Selected(&'static str),
+/// }
+///
+/// let select = Select::new(vec!["Apple", "Banana", "Cherry"], None, Message::Selected)
+///     .placeholder("Pick a fruit")
+///     .max_height(100.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Select<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// The options of the [`Select`].
+    options: Vec<T>,
+    /// The selected option of the [`Select`].
+    selected: Option<T>,
+    /// The function that produces a message when an option is selected.
+    on_selected: Box<dyn Fn(T) -> Message + 'a>,
+    /// The text shown while no option is selected.
+    placeholder: Option<String>,
+    /// The width of the [`Select`].
+    width: Length,
+    /// The maximum height of the list before it scrolls.
+    max_height: f32,
+    /// The padding of the field and of each option.
+    padding: Padding,
+    /// The text size of the [`Select`] or `None` for the default.
+    text_size: Option<Pixels>,
+    /// The font of the [`Select`] or `None` for the default.
+    font: Option<Renderer::Font>,
+    /// The style of the [`Select`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Theme, Renderer> Select<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + Display + PartialEq,
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Select`].
+    ///
+    /// It expects:
+    ///     * the options to pick from.
+    ///     * the selected option, if any.
+    ///     * a function that will be called with the option picked from the list.
+    pub fn new<F>(options: Vec<T>, selected: Option<T>, on_selected: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        Select {
+            options,
+            selected,
+            on_selected: Box::new(on_selected),
+            placeholder: None,
+            width: Length::Shrink,
+            max_height: 200.0,
+            padding: Padding::new(5.0),
+            text_size: None,
+            font: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the text shown while no option of the [`Select`] is selected.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the [`Select`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the maximum height of the list of the [`Select`].
+    ///
+    /// The list can be scrolled if the options exceed it.
+    #[must_use]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the padding of the field and of each option of the [`Select`].
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Select`].
+    #[must_use]
+    pub fn text_size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`Select`].
+    #[must_use]
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Select`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The index of the selected option.
+    fn selected_index(&self) -> Option<usize> {
+        self.selected
+            .as_ref()
+            .and_then(|selected| self.options.iter().position(|option| option == selected))
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Select<'a, T, Message, Theme, Renderer>
+where
+    T: 'a + Clone + Display + PartialEq,
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let line_height = text::LineHeight::default().to_absolute(text_size).0;
+
+        let labels_width = self
+            .options
+            .iter()
+            .map(ToString::to_string)
+            .chain(self.placeholder.clone())
+            .map(|label| {
+                Renderer::Paragraph::with_text(text(&label, Size::INFINITY, text_size, font))
+                    .min_width()
+            })
+            .fold(0.0, f32::max);
+
+        let intrinsic = Size::new(
+            labels_width + self.padding.horizontal(),
+            line_height + self.padding.vertical(),
+        );
+
+        Node::new(limits.width(self.width).height(Length::Shrink).resolve(
+            self.width,
+            Length::Shrink,
+            intrinsic,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                let selected = self.selected_index();
+                state.state.downcast_mut::<State>().toggle(selected);
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = state.state.downcast_ref::<State>();
+
+        let appearance = if state.is_open || cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        if bounds.intersects(viewport) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: appearance.border_radius.into(),
+                        width: appearance.border_width,
+                        color: appearance.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.background,
+            );
+        }
+
+        let (label, color) = self.selected.as_ref().map_or_else(
+            || {
+                (
+                    self.placeholder.clone().unwrap_or_default(),
+                    appearance.placeholder_color,
+                )
+            },
+            |selected| (selected.to_string(), appearance.text_color),
+        );
+
+        let text_bounds = shrink(bounds, self.padding);
+
+        renderer.fill_text(
+            text(
+                &label,
+                text_bounds.size(),
+                self.text_size.unwrap_or_else(|| renderer.default_size()),
+                self.font.unwrap_or_else(|| renderer.default_font()),
+            ),
+            Point::new(text_bounds.x, text_bounds.center_y()),
+            color,
+            text_bounds,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = state.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        let options = &self.options;
+        let on_selected = &self.on_selected;
+
+        Some(overlay::Element::new(Box::new(OptionList {
+            state,
+            options,
+            checked: None,
+            disabled: Vec::new(),
+            on_pick: Box::new(move |index| on_selected(options[index].clone())),
+            close_on_pick: true,
+            field_bounds: layout.bounds() + translation,
+            max_height: self.max_height,
+            padding: self.padding,
+            text_size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            style: &self.style,
+        })))
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Select<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a + Clone + Display + PartialEq,
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(select: Select<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(select)
+    }
+}
@@ -0,0 +1,264 @@
+//! Use a duration picker for entering a duration in hours, minutes and seconds.
+//!
+//! *This API requires the following crate features to be activated: `duration_picker`*
+
+use super::number_input::{self, NumberInput};
+
+use std::{marker::PhantomData, rc::Rc};
+
+use iced::{
+    advanced::text::Renderer as TextRenderer,
+    widget::{container, text, text_input, Row, Text},
+    Alignment, Element, Length,
+};
+
+pub use crate::core::duration::Duration;
+
+/// The number of seconds of an hour.
+const SECONDS_PER_HOUR: i64 = 3600;
+
+/// The number of seconds of a minute.
+const SECONDS_PER_MINUTE: i64 = 60;
+
+/// The width of the field of each component.
+const FIELD_WIDTH: f32 = 48.0;
+
+/// A set of fields for entering the hours, minutes and seconds of a duration,
+/// e.g. for seeking in a media player or setting an interval timer.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{duration_picker::Duration, DurationPicker};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     DurationChanged(Duration),
+/// }
+///
+/// let picker = DurationPicker::new(Duration::minutes(90), Message::DurationChanged)
+///     .max_hours(24)
+///     .show_seconds(false);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DurationPicker<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The duration shown in the fields.
+    value: Duration,
+    /// The maximum number of hours that can be entered.
+    max_hours: u32,
+    /// Show the field of the seconds.
+    show_seconds: bool,
+    /// The message that is send when enter is pressed in one of the fields.
+    on_submit: Option<Message>,
+    /// The function that produces a message when a field is changed.
+    on_change: Box<dyn Fn(Duration) -> Message + 'a>,
+    /// The theme and the renderer of the fields.
+    _marker: PhantomData<(Theme, Renderer)>,
+}
+
+/// The message of the field of a component of a [`DurationPicker`], mapped to
+/// the message of the application.
+#[derive(Clone, Copy, Debug)]
+enum FieldMessage {
+    /// The value of the field is changed.
+    Changed(u32),
+    /// Enter is pressed in the field.
+    Submitted,
+}
+
+/// A component of a duration edited by one field of a [`DurationPicker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Component {
+    /// The hours of the duration.
+    Hours,
+    /// The minutes of the duration.
+    Minutes,
+    /// The seconds of the duration.
+    Seconds,
+}
+
+impl Component {
+    /// The value of the component of the duration.
+    fn of(self, duration: Duration) -> u32 {
+        let (hours, minutes, seconds) = split(duration);
+
+        match self {
+            Self::Hours => hours,
+            Self::Minutes => minutes,
+            Self::Seconds => seconds,
+        }
+    }
+
+    /// The duration with the component set to the value.
+    fn set(self, duration: Duration, value: u32) -> Duration {
+        let (hours, minutes, seconds) = split(duration);
+
+        match self {
+            Self::Hours => join(value, minutes, seconds),
+            Self::Minutes => join(hours, value, seconds),
+            Self::Seconds => join(hours, minutes, value),
+        }
+    }
+}
+
+/// Splits the duration into its hours, minutes and seconds, treating negative
+/// durations as zero.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn split(duration: Duration) -> (u32, u32, u32) {
+    let seconds = duration.num_seconds().max(0);
+
+    (
+        (seconds / SECONDS_PER_HOUR).min(i64::from(u32::MAX)) as u32,
+        (seconds % SECONDS_PER_HOUR / SECONDS_PER_MINUTE) as u32,
+        (seconds % SECONDS_PER_MINUTE) as u32,
+    )
+}
+
+/// Joins the hours, minutes and seconds to a duration.
+fn join(hours: u32, minutes: u32, seconds: u32) -> Duration {
+    Duration::hours(hours.into())
+        + Duration::minutes(minutes.into())
+        + Duration::seconds(seconds.into())
+}
+
+impl<'a, Message, Theme, Renderer> DurationPicker<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + TextRenderer<Font = iced::Font>,
+    Theme: 'a
+        + number_input::StyleSheet
+        + text_input::StyleSheet
+        + container::StyleSheet
+        + text::StyleSheet,
+{
+    /// Creates a new [`DurationPicker`].
+    ///
+    /// It expects:
+    ///     * the duration to show.
+    ///     * a function that produces a message when a field is changed, which
+    ///         takes the changed [`Duration`].
+    pub fn new<F>(value: Duration, on_change: F) -> Self
+    where
+        F: 'a + Fn(Duration) -> Message,
+    {
+        Self {
+            value,
+            max_hours: 99,
+            show_seconds: true,
+            on_submit: None,
+            on_change: Box::new(on_change),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets the maximum number of hours that can be entered in the [`DurationPicker`].
+    #[must_use]
+    pub fn max_hours(mut self, max_hours: u32) -> Self {
+        self.max_hours = max_hours;
+        self
+    }
+
+    /// Shows the field of the seconds of the [`DurationPicker`].
+    #[must_use]
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    /// Sets the message that is send when enter is pressed in a field of the
+    /// [`DurationPicker`].
+    #[must_use]
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DurationPicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + TextRenderer<Font = iced::Font>,
+    Theme: 'a
+        + number_input::StyleSheet
+        + text_input::StyleSheet
+        + container::StyleSheet
+        + text::StyleSheet,
+{
+    fn from(picker: DurationPicker<'a, Message, Theme, Renderer>) -> Self {
+        let on_change: Rc<dyn Fn(Duration) -> Message + 'a> = Rc::from(picker.on_change);
+        let mut components = vec![
+            (Component::Hours, picker.max_hours, "h"),
+            (Component::Minutes, 59, "m"),
+        ];
+        if picker.show_seconds {
+            components.push((Component::Seconds, 59, "s"));
+        }
+
+        components
+            .into_iter()
+            .fold(Row::new(), |row, (component, max, unit)| {
+                let mut input =
+                    NumberInput::new(component.of(picker.value), max, FieldMessage::Changed)
+                        .content_width(Length::Fixed(FIELD_WIDTH));
+                if picker.on_submit.is_some() {
+                    input = input.on_submit(FieldMessage::Submitted);
+                }
+
+                let duration = picker.value;
+                let on_change = Rc::clone(&on_change);
+                let on_submit = picker.on_submit.clone();
+                let field = Element::from(input).map(move |message| match message {
+                    FieldMessage::Changed(value) => on_change(component.set(duration, value)),
+                    FieldMessage::Submitted => on_submit
+                        .clone()
+                        .expect("Field should only be submitted with a submit message"),
+                });
+
+                row.push(field).push(Text::new(unit))
+            })
+            .spacing(5)
+            .align_items(Alignment::Center)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split, Component, Duration, DurationPicker};
+
+    #[test]
+    fn split_test() {
+        assert_eq!(split(Duration::seconds(3725)), (1, 2, 5));
+        assert_eq!(split(Duration::seconds(-5)), (0, 0, 0));
+    }
+
+    #[test]
+    fn set_test() {
+        let duration = Duration::seconds(3725);
+
+        assert_eq!(
+            Component::Hours.set(duration, 3),
+            Duration::seconds(3 * 3600 + 125)
+        );
+        assert_eq!(Component::Minutes.set(duration, 0), Duration::seconds(3605));
+        assert_eq!(
+            Component::Seconds.set(duration, 59),
+            Duration::seconds(3779)
+        );
+        assert_eq!(Component::Minutes.of(duration), 2);
+    }
+
+    #[test]
+    fn borrowed_on_change_test() {
+        let label = String::from("timer");
+        let picker: DurationPicker<'_, String> =
+            DurationPicker::new(Duration::seconds(0), |duration| {
+                format!("{label}: {}", duration.num_seconds())
+            });
+
+        assert_eq!(
+            (picker.on_change)(Duration::seconds(42)),
+            String::from("timer: 42")
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! Use a floating button for pinning a button, like a floating action button,
+//! to a corner of some content.
+//!
+//! *This API requires the following crate features to be activated: `floating_button`*
+
+use super::floating_element::FloatingElement;
+
+use iced::{
+    advanced::renderer,
+    widget::{button, Button},
+    Element,
+};
+
+pub use super::floating_element::{Anchor, Offset};
+
+/// A button pinned to a corner of some content, floating over it like a
+/// floating action button without affecting its layout.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::{Button, Column, Text};
+/// # use iced_aw::{floating_button::Anchor, FloatingButton};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Add,
+/// }
+///
+/// let content = Column::new();
+/// let floating_button = FloatingButton::new(
+///     content,
+///     Button::new(Text::new("+")).on_press(Message::Add),
+/// )
+/// .anchor(Anchor::SouthEast);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct FloatingButton<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// The [`FloatingElement`] showing the button over the underlay.
+    floating_element: FloatingElement<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> FloatingButton<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + button::StyleSheet,
+{
+    /// Creates a new [`FloatingButton`] pinning the button to the bottom right
+    /// corner of the underlay.
+    ///
+    /// It expects:
+    ///     * the underlay [`Element`] on which this [`FloatingButton`]
+    ///         will be wrapped around.
+    ///     * the [`Button`] floating over the underlay.
+    pub fn new<U>(underlay: U, button: Button<'a, Message, Theme, Renderer>) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self {
+            floating_element: FloatingElement::new(underlay, button)
+                .anchor(Anchor::SouthEast)
+                .offset(16.0),
+        }
+    }
+
+    /// Sets the corner of the underlay the button of the [`FloatingButton`] is
+    /// pinned to.
+    #[must_use]
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.floating_element = self.floating_element.anchor(anchor);
+        self
+    }
+
+    /// Sets the [`Offset`] of the button of the [`FloatingButton`] from the
+    /// corner, e.g. a [`Vector`](iced::Vector).
+    #[must_use]
+    pub fn offset<O>(mut self, offset: O) -> Self
+    where
+        O: Into<Offset>,
+    {
+        self.floating_element = self.floating_element.offset(offset);
+        self
+    }
+
+    /// Hides or unhides the button of the [`FloatingButton`].
+    #[must_use]
+    pub fn hide(mut self, hide: bool) -> Self {
+        self.floating_element = self.floating_element.hide(hide);
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FloatingButton<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a,
+{
+    fn from(floating_button: FloatingButton<'a, Message, Theme, Renderer>) -> Self {
+        floating_button.floating_element.into()
+    }
+}
@@ -36,4 +36,8 @@ pub enum Anchor {
     /// NorthWest [`Anchor`] for positioning the [`Button`](iced_widget::Button)
     /// on the top left of the underlying element.
     NorthWest,
+
+    /// Center [`Anchor`] for positioning the [`Button`](iced_widget::Button)
+    /// on the center of the underlying element.
+    Center,
 }
@@ -2,7 +2,7 @@
 //!
 //! *This API requires the following crate features to be activated: `floating_element`*
 
-use iced::Point;
+use iced::{Point, Vector};
 
 /// The [`Offset`] for the [`FloatingElement`](super::FloatingElement).
 #[derive(Copy, Clone, Debug)]
@@ -28,6 +28,15 @@ impl From<[f32; 2]> for Offset {
     }
 }
 
+impl From<Vector> for Offset {
+    fn from(vector: Vector) -> Self {
+        Self {
+            x: vector.x,
+            y: vector.y,
+        }
+    }
+}
+
 impl From<Offset> for Point {
     fn from(offset: Offset) -> Self {
         Self::new(offset.x, offset.y)
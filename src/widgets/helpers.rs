@@ -158,6 +158,21 @@ where
     crate::Badge::new(content)
 }
 
+#[cfg(feature = "badge")]
+/// Shortcut helper to create a [`CountBadge`] Widget.
+///
+/// [`CountBadge`]: crate::CountBadge
+pub fn count_badge<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    count: u32,
+) -> crate::CountBadge<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer,
+    Theme: crate::style::badge::StyleSheet,
+{
+    crate::CountBadge::new(content, count)
+}
+
 #[cfg(feature = "card")]
 /// Shortcut helper to create a [`Card`] Widget.
 ///
@@ -173,6 +188,406 @@ where
     crate::Card::new(head, body)
 }
 
+#[cfg(feature = "chip")]
+/// Shortcut helper to create a [`Chip`] Widget.
+///
+/// [`Chip`]: crate::Chip
+pub fn chip<'a, Message, Theme, Renderer>(
+    label: impl Into<String>,
+) -> crate::Chip<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer,
+    Theme: crate::style::chip::StyleSheet,
+{
+    crate::Chip::new(label)
+}
+
+#[cfg(feature = "rating")]
+/// Shortcut helper to create a [`Rating`] Widget.
+///
+/// [`Rating`]: crate::Rating
+pub fn rating<'a, Message, Theme, F>(
+    value: f32,
+    max: u8,
+    on_change: F,
+) -> crate::Rating<'a, Message, Theme>
+where
+    F: 'a + Fn(f32) -> Message,
+    Theme: crate::style::rating::StyleSheet,
+{
+    crate::Rating::new(value, max, on_change)
+}
+
+#[cfg(feature = "tooltip")]
+/// Shortcut helper to create a [`Tooltip`] Widget.
+///
+/// [`Tooltip`]: crate::Tooltip
+pub fn tooltip<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> crate::Tooltip<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: crate::style::tooltip::StyleSheet,
+{
+    crate::Tooltip::new(content, tooltip)
+}
+
+#[cfg(feature = "accordion")]
+/// Shortcut helper to create an [`Accordion`] Widget.
+///
+/// [`Accordion`]: crate::Accordion
+#[must_use]
+pub fn accordion<Message, Theme, Renderer>(
+    items: Vec<crate::widgets::accordion::AccordionItem<Message, Theme, Renderer>>,
+) -> crate::Accordion<Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer,
+    Theme: crate::style::accordion::StyleSheet,
+{
+    crate::Accordion::new(items)
+}
+
+#[cfg(feature = "stepper")]
+/// Shortcut helper to create a [`Stepper`] Widget.
+///
+/// [`Stepper`]: crate::Stepper
+#[must_use]
+pub fn stepper<'a, Message, Theme>(
+    steps: Vec<crate::StepLabel>,
+    current: usize,
+) -> crate::Stepper<'a, Message, Theme>
+where
+    Theme: crate::style::stepper::StyleSheet,
+{
+    crate::Stepper::new(steps, current)
+}
+
+#[cfg(feature = "progress_bar")]
+/// Shortcut helper to create a [`ProgressBar`] Widget.
+///
+/// [`ProgressBar`]: crate::ProgressBar
+#[must_use]
+pub fn progress_bar<Message, Theme>(value: f32) -> crate::ProgressBar<Message, Theme>
+where
+    Theme: crate::style::progress_bar::StyleSheet,
+{
+    crate::ProgressBar::new(value)
+}
+
+#[cfg(feature = "toggle")]
+/// Shortcut helper to create a [`Toggle`] Widget.
+///
+/// [`Toggle`]: crate::Toggle
+pub fn toggle<'a, Message, Theme, F>(is_on: bool, on_toggle: F) -> crate::Toggle<'a, Message, Theme>
+where
+    F: 'a + Fn(bool) -> Message,
+    Theme: crate::style::toggle::StyleSheet,
+{
+    crate::Toggle::new(is_on, on_toggle)
+}
+
+#[cfg(feature = "search_input")]
+/// Shortcut helper to create a [`SearchInput`] Widget.
+///
+/// [`SearchInput`]: crate::SearchInput
+pub fn search_input<'a, Message, Theme, Renderer, F>(
+    value: &str,
+    on_change: F,
+) -> crate::SearchInput<'a, Message, Theme, Renderer>
+where
+    F: 'a + Fn(String) -> Message,
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: iced::widget::text_input::StyleSheet,
+{
+    crate::SearchInput::new(value, on_change)
+}
+
+#[cfg(feature = "password_input")]
+/// Shortcut helper to create a [`PasswordInput`] Widget.
+///
+/// [`PasswordInput`]: crate::PasswordInput
+pub fn password_input<'a, Message, Theme, Renderer, F>(
+    value: &str,
+    on_change: F,
+) -> crate::PasswordInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    F: 'a + Fn(String) -> Message,
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: iced::widget::text_input::StyleSheet,
+{
+    crate::PasswordInput::new(value, on_change)
+}
+
+#[cfg(feature = "range_slider")]
+/// Shortcut helper to create a [`RangeSlider`] Widget.
+///
+/// [`RangeSlider`]: crate::RangeSlider
+pub fn range_slider<'a, Message, Theme, F>(
+    range: std::ops::RangeInclusive<f32>,
+    low: f32,
+    high: f32,
+    on_change: F,
+) -> crate::RangeSlider<'a, Message, Theme>
+where
+    F: 'a + Fn(f32, f32) -> Message,
+    Theme: crate::style::range_slider::StyleSheet,
+{
+    crate::RangeSlider::new(range, low, high, on_change)
+}
+
+#[cfg(feature = "knob")]
+/// Shortcut helper to create a [`Knob`] Widget.
+///
+/// [`Knob`]: crate::Knob
+pub fn knob<'a, Message, Theme, F>(
+    value: f32,
+    range: std::ops::RangeInclusive<f32>,
+    on_change: F,
+) -> crate::Knob<'a, Message, Theme>
+where
+    F: 'a + Fn(f32) -> Message,
+    Theme: crate::style::knob::StyleSheet,
+{
+    crate::Knob::new(value, range, on_change)
+}
+
+#[cfg(feature = "pagination")]
+/// Shortcut helper to create a [`Pagination`] Widget.
+///
+/// [`Pagination`]: crate::Pagination
+pub fn pagination<'a, Message, Theme, F>(
+    total_pages: usize,
+    current_page: usize,
+    on_page_change: F,
+) -> crate::Pagination<'a, Message, Theme>
+where
+    F: 'a + Fn(usize) -> Message,
+    Theme: crate::style::pagination::StyleSheet,
+{
+    crate::Pagination::new(total_pages, current_page, on_page_change)
+}
+
+#[cfg(feature = "breadcrumb")]
+/// Shortcut helper to create a [`Breadcrumb`] Widget.
+///
+/// [`Breadcrumb`]: crate::Breadcrumb
+#[must_use]
+pub fn breadcrumb<'a, Message, Theme, Renderer>(
+    items: Vec<crate::BreadcrumbItem<Message>>,
+) -> crate::Breadcrumb<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced::advanced::text::Renderer,
+    Theme: 'a + crate::style::breadcrumb::StyleSheet + iced::widget::text::StyleSheet,
+{
+    crate::Breadcrumb::new(items)
+}
+
+#[cfg(feature = "tree_view")]
+/// Shortcut helper to create a [`TreeView`] Widget.
+///
+/// [`TreeView`]: crate::TreeView
+pub fn tree_view<'a, T, Message, Theme, Renderer>(
+    root: crate::TreeNode<T>,
+) -> crate::TreeView<'a, T, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced::advanced::Renderer,
+    Theme: 'a + crate::style::spinner::StyleSheet,
+{
+    crate::TreeView::new(root)
+}
+
+#[cfg(feature = "data_table")]
+/// Shortcut helper to create a [`DataTable`] Widget.
+///
+/// [`DataTable`]: crate::DataTable
+#[must_use]
+pub fn data_table<'a, T, Message, Theme, Renderer>(
+    columns: Vec<crate::data_table::Column<'a, T, Message, Theme, Renderer>>,
+    rows: Vec<T>,
+) -> crate::DataTable<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced::advanced::Renderer,
+    Theme: 'a + iced::widget::container::StyleSheet + iced::widget::scrollable::StyleSheet,
+{
+    crate::DataTable::new(columns, rows)
+}
+
+#[cfg(feature = "virtual_list")]
+/// Shortcut helper to create a [`VirtualList`] Widget.
+///
+/// [`VirtualList`]: crate::VirtualList
+pub fn virtual_list<'a, Message, Theme, Renderer, F>(
+    item_count: usize,
+    item_height: f32,
+    render_item: F,
+) -> crate::VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+    F: 'a + Fn(usize) -> iced::Element<'a, Message, Theme, Renderer>,
+{
+    crate::VirtualList::new(item_count, item_height, render_item)
+}
+
+#[cfg(feature = "popover")]
+/// Shortcut helper to create a [`Popover`] Widget.
+///
+/// [`Popover`]: crate::Popover
+#[must_use]
+pub fn popover<'a, Message, Theme>(
+    target: impl Into<Element<'a, Message, Theme, iced::Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, iced::Renderer>>,
+    show: bool,
+) -> crate::Popover<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: crate::style::popover::StyleSheet,
+{
+    crate::Popover::new(target, content, show)
+}
+
+#[cfg(feature = "skeleton")]
+/// Shortcut helper to create a rectangular [`Skeleton`] Widget.
+///
+/// [`Skeleton`]: crate::Skeleton
+#[must_use]
+pub fn skeleton<Theme>(width: iced::Length, height: iced::Length) -> crate::Skeleton<Theme>
+where
+    Theme: crate::style::skeleton::StyleSheet,
+{
+    crate::Skeleton::new(width, height)
+}
+
+#[cfg(feature = "avatar")]
+/// Shortcut helper to create an [`Avatar`] Widget.
+///
+/// [`Avatar`]: crate::Avatar
+#[must_use]
+pub fn avatar<Message, Theme, Renderer>(
+    content: impl Into<crate::avatar::AvatarContent>,
+) -> crate::Avatar<Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer,
+    Theme: crate::style::avatar::StyleSheet,
+{
+    crate::Avatar::new(content)
+}
+
+#[cfg(feature = "status_indicator")]
+/// Shortcut helper to create a [`StatusIndicator`] Widget.
+///
+/// [`StatusIndicator`]: crate::StatusIndicator
+#[must_use]
+pub fn status_indicator<Theme>(
+    status: crate::status_indicator::Status,
+) -> crate::StatusIndicator<Theme>
+where
+    Theme: crate::style::status_indicator::StyleSheet,
+{
+    crate::StatusIndicator::new(status)
+}
+
+#[cfg(feature = "divider")]
+/// Shortcut helper to create a horizontal [`Divider`] Widget.
+///
+/// [`Divider`]: crate::Divider
+#[must_use]
+pub fn horizontal_divider<Theme, Renderer>() -> crate::Divider<Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer,
+    Theme: crate::style::divider::StyleSheet,
+{
+    crate::Divider::horizontal()
+}
+
+#[cfg(feature = "divider")]
+/// Shortcut helper to create a vertical [`Divider`] Widget.
+///
+/// [`Divider`]: crate::Divider
+#[must_use]
+pub fn vertical_divider<Theme, Renderer>() -> crate::Divider<Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer,
+    Theme: crate::style::divider::StyleSheet,
+{
+    crate::Divider::vertical()
+}
+
+#[cfg(feature = "color_swatch")]
+/// Shortcut helper to create a [`ColorSwatch`] Widget.
+///
+/// [`ColorSwatch`]: crate::ColorSwatch
+pub fn color_swatch<'a, Message, Theme, Renderer, F>(
+    colors: Vec<iced::Color>,
+    selected: Option<iced::Color>,
+    on_select: F,
+) -> crate::ColorSwatch<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced::advanced::text::Renderer,
+    Theme: 'a
+        + crate::style::color_swatch::StyleSheet
+        + crate::style::tooltip::StyleSheet
+        + iced::widget::text::StyleSheet,
+    F: 'a + Fn(iced::Color) -> Message,
+{
+    crate::ColorSwatch::new(colors, selected, on_select)
+}
+
+#[cfg(feature = "duration_picker")]
+/// Shortcut helper to create a [`DurationPicker`] Widget.
+///
+/// [`DurationPicker`]: crate::DurationPicker
+pub fn duration_picker<'a, Message, Theme, Renderer, F>(
+    value: crate::core::duration::Duration,
+    on_change: F,
+) -> crate::DurationPicker<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a
+        + crate::style::number_input::StyleSheet
+        + iced::widget::text_input::StyleSheet
+        + iced::widget::container::StyleSheet
+        + iced::widget::text::StyleSheet,
+    F: 'a + Fn(crate::core::duration::Duration) -> Message,
+{
+    crate::DurationPicker::new(value, on_change)
+}
+
+#[cfg(feature = "number_spinner")]
+/// Shortcut helper to create a [`NumberSpinner`] Widget.
+///
+/// [`NumberSpinner`]: crate::NumberSpinner
+#[must_use]
+pub fn number_spinner<Theme, Renderer>(value: i64) -> crate::NumberSpinner<Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer,
+    Theme: crate::style::number_spinner::StyleSheet,
+{
+    crate::NumberSpinner::new(value)
+}
+
+#[cfg(feature = "floating_button")]
+/// Shortcut helper to create a [`FloatingButton`] Widget.
+///
+/// [`FloatingButton`]: crate::FloatingButton
+pub fn floating_button<'a, Message, Theme, Renderer>(
+    underlay: impl Into<iced::Element<'a, Message, Theme, Renderer>>,
+    button: iced::widget::Button<'a, Message, Theme, Renderer>,
+) -> crate::FloatingButton<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced::advanced::renderer::Renderer,
+    Theme: 'a + iced::widget::button::StyleSheet,
+{
+    crate::FloatingButton::new(underlay, button)
+}
+
 #[cfg(feature = "color_picker")]
 /// Shortcut helper to create a [`ColorPicker`] Widget.
 ///
@@ -218,6 +633,141 @@ where
     crate::DatePicker::new(show_picker, date, underlay, on_cancel, on_submit)
 }
 
+#[cfg(feature = "date_picker")]
+/// Shortcut helper to create a [`CalendarView`] Widget.
+///
+/// [`CalendarView`]: crate::CalendarView
+pub fn calendar_view<'a, Message, Theme, F>(
+    selected: Option<crate::core::date::Date>,
+    on_select: F,
+) -> crate::CalendarView<'a, Message, Theme>
+where
+    Theme: crate::style::date_picker::StyleSheet,
+    F: 'a + Fn(crate::core::date::Date) -> Message,
+{
+    crate::CalendarView::new(selected, on_select)
+}
+
+#[cfg(feature = "date_picker")]
+/// Shortcut helper to create a [`DateRangePicker`] Widget.
+///
+/// [`DateRangePicker`]: crate::DateRangePicker
+pub fn date_range_picker<'a, Message, Theme, F>(
+    show_picker: bool,
+    range: crate::core::date::DateRange,
+    underlay: impl Into<Element<'a, Message, Theme, iced::Renderer>>,
+    on_cancel: Message,
+    on_submit: F,
+) -> crate::DateRangePicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::date_picker::StyleSheet
+        + iced::widget::button::StyleSheet
+        + iced::widget::text::StyleSheet
+        + iced::widget::container::StyleSheet,
+    F: 'static + Fn(crate::core::date::Date, crate::core::date::Date) -> Message,
+{
+    crate::DateRangePicker::new(show_picker, range, underlay, on_cancel, on_submit)
+}
+
+#[cfg(feature = "date_picker")]
+/// Shortcut helper to create a [`WeekPicker`] Widget.
+///
+/// [`WeekPicker`]: crate::WeekPicker
+pub fn week_picker<'a, Message, Theme, F>(
+    show_picker: bool,
+    underlay: impl Into<Element<'a, Message, Theme, iced::Renderer>>,
+    on_cancel: Message,
+    on_submit: F,
+) -> crate::WeekPicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::date_picker::StyleSheet
+        + iced::widget::button::StyleSheet
+        + iced::widget::text::StyleSheet
+        + iced::widget::container::StyleSheet,
+    F: 'static + Fn(i32, u8) -> Message,
+{
+    crate::WeekPicker::new(show_picker, underlay, on_cancel, on_submit)
+}
+
+#[cfg(feature = "date_picker")]
+/// Shortcut helper to create a [`MonthPicker`] Widget.
+///
+/// [`MonthPicker`]: crate::MonthPicker
+pub fn month_picker<'a, Message, Theme, F>(
+    show_picker: bool,
+    underlay: impl Into<Element<'a, Message, Theme, iced::Renderer>>,
+    on_cancel: Message,
+    on_submit: F,
+) -> crate::MonthPicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + crate::style::date_picker::StyleSheet,
+    F: 'static + Fn(i32, u8) -> Message,
+{
+    crate::MonthPicker::new(show_picker, underlay, on_cancel, on_submit)
+}
+
+#[cfg(feature = "date_picker")]
+/// Shortcut helper to create a [`YearPicker`] Widget.
+///
+/// [`YearPicker`]: crate::YearPicker
+pub fn year_picker<'a, Message, Theme, F>(
+    show_picker: bool,
+    underlay: impl Into<Element<'a, Message, Theme, iced::Renderer>>,
+    on_cancel: Message,
+    on_submit: F,
+) -> crate::YearPicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + crate::style::date_picker::StyleSheet,
+    F: 'static + Fn(i32) -> Message,
+{
+    crate::YearPicker::new(show_picker, underlay, on_cancel, on_submit)
+}
+
+#[cfg(feature = "date_time_picker")]
+/// Shortcut helper to create a [`DateTimePicker`] Widget.
+///
+/// [`DateTimePicker`]: crate::DateTimePicker
+pub fn date_time_picker<'a, Message, Theme, F>(
+    show_picker: bool,
+    date_time: impl Into<crate::core::date_time::DateTime>,
+    underlay: impl Into<Element<'a, Message, Theme, iced::Renderer>>,
+    on_cancel: Message,
+    on_submit: F,
+) -> crate::DateTimePicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a
+        + crate::style::date_picker::StyleSheet
+        + crate::style::time_picker::StyleSheet
+        + iced::widget::button::StyleSheet
+        + iced::widget::text::StyleSheet
+        + iced::widget::container::StyleSheet,
+    F: 'static + Fn(crate::core::date::Date, crate::core::time::Time) -> Message,
+{
+    crate::DateTimePicker::new(show_picker, date_time, underlay, on_cancel, on_submit)
+}
+
+#[cfg(feature = "time_picker")]
+/// Shortcut helper to create a [`ClockFace`] Widget.
+///
+/// [`ClockFace`]: crate::ClockFace
+pub fn clock_face<'a, Message, Theme, F>(
+    time: impl Into<crate::core::time::Time>,
+    on_change: F,
+) -> crate::ClockFace<'a, Message, Theme>
+where
+    Theme: crate::style::time_picker::StyleSheet,
+    F: 'a + Fn(crate::core::time::Time) -> Message,
+{
+    crate::ClockFace::new(time, on_change)
+}
+
 #[cfg(feature = "time_picker")]
 /// Shortcut helper to create a [`DatePicker`] Widget.
 ///
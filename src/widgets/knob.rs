@@ -0,0 +1,363 @@
+//! Use a knob for selecting a value from a range by turning it.
+//!
+//! *This API requires the following crate features to be activated: `knob`*
+
+use iced::{
+    advanced::{
+        graphics::geometry::Renderer as _,
+        layout::{Limits, Node},
+        renderer,
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Renderer as _, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::canvas::{path::Arc, Frame, LineCap, Path, Stroke},
+    Element, Event, Length, Point, Radians, Rectangle, Renderer, Size, Vector,
+};
+use std::{f32::consts::PI, ops::RangeInclusive};
+
+pub use crate::style::knob::{Appearance, StyleSheet};
+
+/// The angle of the minimum value, clockwise from the positive x-axis.
+const START_ANGLE: f32 = 0.75 * PI;
+
+/// The angle the knob turns from the minimum to the maximum value.
+const SWEEP_ANGLE: f32 = 1.5 * PI;
+
+/// The part of the range around the center that snaps to it.
+const CENTER_SNAP: f32 = 0.03;
+
+/// A circular control for selecting a value by dragging it up and down.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::Knob;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     VolumeChanged(f32),
+/// }
+///
+/// let knob = Knob::new(0.5, 0.0..=1.0, Message::VolumeChanged).step(0.01);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Knob<'a, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The current value.
+    value: f32,
+    /// The range of selectable values.
+    range: RangeInclusive<f32>,
+    /// The function that produces a message when the knob is turned.
+    on_change: Box<dyn Fn(f32) -> Message + 'a>,
+    /// The diameter of the [`Knob`].
+    size: f32,
+    /// The step the value snaps to.
+    step: f32,
+    /// Whether the value snaps to the center of the range.
+    snap_to_center: bool,
+    /// The part of the range the value changes by per dragged pixel.
+    sensitivity: f32,
+    /// The style of the [`Knob`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Knob<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Knob`].
+    ///
+    /// It expects:
+    ///     * the current value.
+    ///     * the range of selectable values.
+    ///     * a function that will be called with the new value when the knob is turned.
+    pub fn new<F>(value: f32, range: RangeInclusive<f32>, on_change: F) -> Self
+    where
+        F: 'a + Fn(f32) -> Message,
+    {
+        Self {
+            value: value.clamp(*range.start(), *range.end()),
+            range,
+            on_change: Box::new(on_change),
+            size: 50.0,
+            step: 0.0,
+            snap_to_center: false,
+            sensitivity: 0.005,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the diameter of the [`Knob`].
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the step the value of the [`Knob`] snaps to.
+    ///
+    /// A step of 0.0 lets the value change continuously.
+    #[must_use]
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets whether the value of the [`Knob`] snaps to the center of the range
+    /// when it is turned close to it.
+    #[must_use]
+    pub fn snap_to_center(mut self, snap_to_center: bool) -> Self {
+        self.snap_to_center = snap_to_center;
+        self
+    }
+
+    /// Sets the part of the range the value of the [`Knob`] changes by for every
+    /// pixel the mouse is dragged.
+    ///
+    /// The default of 0.005 turns the knob over the whole range in 200 pixels.
+    #[must_use]
+    pub fn sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets the style of the [`Knob`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The value dragged the given distance away from the starting value.
+    fn dragged_value(&self, start: f32, distance: f32) -> f32 {
+        let (min, max) = (*self.range.start(), *self.range.end());
+        let span = max - min;
+        let mut value = start + distance * self.sensitivity * span;
+
+        if self.step > 0.0 {
+            value = min + ((value - min) / self.step).round() * self.step;
+        }
+
+        let center = min + span / 2.0;
+        if self.snap_to_center && (value - center).abs() <= span * CENTER_SNAP {
+            value = center;
+        }
+
+        value.clamp(min, max)
+    }
+
+    /// The position of the value between 0.0 and 1.0.
+    fn percent(&self) -> f32 {
+        let (min, max) = (*self.range.start(), *self.range.end());
+
+        if min >= max {
+            0.0
+        } else {
+            (self.value - min) / (max - min)
+        }
+    }
+}
+
+/// The state of a [`Knob`].
+#[derive(Debug, Default)]
+struct State {
+    /// The cursor position and the value when the dragging started.
+    dragging: Option<(Point, f32)>,
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for Knob<'_, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.size), Length::Fixed(self.size))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.width(self.size).height(self.size).resolve(
+            self.size,
+            self.size,
+            Size::ZERO,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    state.dragging = Some((position, self.value));
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.dragging.is_some() =>
+            {
+                state.dragging = None;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let (Some((origin, start)), Some(position)) = (state.dragging, cursor.position())
+                {
+                    // Dragging up or to the right turns the knob up.
+                    let distance = (position.x - origin.x) + (origin.y - position.y);
+                    let value = self.dragged_value(start, distance);
+
+                    if (value - self.value).abs() > f32::EPSILON {
+                        self.value = value;
+                        shell.publish((self.on_change)(value));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance = if state.dragging.is_some() {
+            theme.dragging(&self.style)
+        } else if cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        let center = frame.center();
+        let arc_radius = (bounds.width.min(bounds.height) - appearance.arc_width) / 2.0;
+        let value_angle = START_ANGLE + SWEEP_ANGLE * self.percent();
+
+        let arc = |end_angle: f32| {
+            Path::new(|builder| {
+                builder.move_to(
+                    center + Vector::new(START_ANGLE.cos(), START_ANGLE.sin()) * arc_radius,
+                );
+                builder.arc(Arc {
+                    center,
+                    radius: arc_radius,
+                    start_angle: Radians(START_ANGLE),
+                    end_angle: Radians(end_angle),
+                });
+            })
+        };
+        let arc_stroke = |color| {
+            Stroke::default()
+                .with_color(color)
+                .with_width(appearance.arc_width)
+                .with_line_cap(LineCap::Round)
+        };
+
+        frame.stroke(
+            &arc(START_ANGLE + SWEEP_ANGLE),
+            arc_stroke(appearance.arc_color),
+        );
+        if self.percent() > 0.0 {
+            frame.stroke(&arc(value_angle), arc_stroke(appearance.fill_color));
+        }
+
+        let knob_radius = (arc_radius - 1.5 * appearance.arc_width).max(0.0);
+        let knob = Path::circle(center, knob_radius);
+        frame.fill(&knob, appearance.knob_color);
+        if appearance.knob_border_width > 0.0 {
+            frame.stroke(
+                &knob,
+                Stroke::default()
+                    .with_color(appearance.knob_border)
+                    .with_width(appearance.knob_border_width),
+            );
+        }
+
+        let direction = Vector::new(value_angle.cos(), value_angle.sin());
+        frame.stroke(
+            &Path::line(
+                center + direction * (knob_radius * 0.4),
+                center + direction * (knob_radius * 0.8),
+            ),
+            Stroke::default()
+                .with_color(appearance.indicator_color)
+                .with_width(2.0)
+                .with_line_cap(LineCap::Round),
+        );
+
+        let geometry = frame.into_geometry();
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw(vec![geometry]);
+        });
+    }
+}
+
+impl<'a, Message, Theme> From<Knob<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+{
+    fn from(knob: Knob<'a, Message, Theme>) -> Self {
+        Element::new(knob)
+    }
+}
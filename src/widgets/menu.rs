@@ -139,6 +139,14 @@
 //! ));
 //! ```
 //!
+//! ## Keyboard
+//!
+//! Pressing alt opens the first menu of the [`MenuBar`] with its first item selected.
+//! The arrow keys move the selection through the items and the menus of the bar,
+//! enter or space clicks the selected item or opens its menu,
+//! and escape closes the innermost open menu.
+//! The menus follow the cursor again as soon as it moves over them.
+//!
 //! For a more detailed example please
 //! take a look at the menu example in the iced_aw repo
 //!
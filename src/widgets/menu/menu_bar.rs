@@ -12,7 +12,7 @@ use iced::{
         widget::{tree, Tree},
         Clipboard, Layout, Shell, Widget,
     },
-    alignment, event, Element, Event, Length, Padding, Rectangle, Size,
+    alignment, event, keyboard, Element, Event, Length, Padding, Rectangle, Size,
 };
 
 use super::{common::*, flex, menu_bar_overlay::MenuBarOverlay, menu_tree::*};
@@ -23,6 +23,9 @@ pub(super) struct MenuBarState {
     pub(super) active_root: Index,
     pub(super) open: bool,
     pub(super) is_pressed: bool,
+    /// The menus are navigated with the keyboard and ignore the cursor
+    /// until it moves over them.
+    pub(super) keyboard: bool,
 }
 
 /// menu bar
@@ -243,6 +246,16 @@ where
                     Ignored
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Alt),
+                ..
+            }) if !bar.open && !self.roots.is_empty() => {
+                bar.open = true;
+                bar.keyboard = true;
+                bar.active_root = Some(0);
+                self.roots[0].focus_first(&mut tree.children[0]);
+                Captured
+            }
             _ => Ignored,
         }
         .merge(status)
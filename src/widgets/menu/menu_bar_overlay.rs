@@ -13,7 +13,7 @@ use iced::{
         widget::Tree,
         Clipboard, Layout, Shell,
     },
-    event, Event, Point, Rectangle, Size, Vector,
+    event, keyboard, Event, Point, Rectangle, Size, Vector,
 };
 
 use super::{common::*, menu_bar::MenuBarState, menu_tree::*};
@@ -44,6 +44,126 @@ where
     pub(super) fn overlay_element(self) -> overlay::Element<'b, Message, Theme, Renderer> {
         overlay::Element::new(Box::new(self))
     }
+
+    /// Navigates the menus with the arrow keys, activates the selected item
+    /// with enter or space and closes the menus with escape or alt.
+    ///
+    /// Returns `None` if the key is not used for the navigation.
+    fn on_key(
+        &mut self,
+        key: keyboard::key::Named,
+        active: usize,
+        menu_layouts: Layout<'_>,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> Option<event::Status> {
+        use keyboard::key::Named::*;
+
+        let root = &mut self.roots[active];
+        let root_tree = &mut self.tree.children[active];
+        let path = root.open_path(root_tree);
+
+        match key {
+            Escape | ArrowLeft if !path.is_empty() => {
+                let (parent, parent_tree) = root.descend_mut(root_tree, &path[..path.len() - 1]);
+                parent.close_submenu(parent_tree);
+            }
+            Escape | Alt => {
+                self.close(active);
+                shell.invalidate_layout();
+                return Some(event::Status::Captured);
+            }
+            ArrowDown | ArrowUp => {
+                let (item, item_tree) = root.descend_mut(root_tree, &path);
+                item.move_focus(item_tree, key == ArrowDown);
+            }
+            ArrowLeft => self.switch_root(active, false),
+            ArrowRight => {
+                let (item, item_tree) = root.descend_mut(root_tree, &path);
+                if !item.open_focused(item_tree) {
+                    self.switch_root(active, true);
+                }
+            }
+            Enter | Space => {
+                let (item, item_tree) = root.descend_mut(root_tree, &path);
+                if !item.open_focused(item_tree) {
+                    if let Some(menu) = item.menu.as_mut() {
+                        let menu_tree = &mut item_tree.children[1];
+                        let menu_state = menu_tree.state.downcast_ref::<MenuState>();
+                        let start_index = menu_state.slice.start_index;
+
+                        if let Some(focused) = menu_state.focused {
+                            // menu_node: Node{inf, [ slice_node, prescroll, offset_bounds, check_bounds ]}
+                            let item_layout = menu_layouts
+                                .children()
+                                .nth(path.len())
+                                .and_then(|menu_layout| menu_layout.children().next())
+                                .and_then(|slice_layout| {
+                                    slice_layout
+                                        .children()
+                                        .nth(focused.checked_sub(start_index)?)
+                                });
+
+                            // Click the selected item.
+                            if let Some(item_layout) = item_layout {
+                                let cursor =
+                                    mouse::Cursor::Available(item_layout.bounds().center());
+                                for event in [
+                                    mouse::Event::ButtonPressed(mouse::Button::Left),
+                                    mouse::Event::ButtonReleased(mouse::Button::Left),
+                                ] {
+                                    let _ = menu.items[focused].on_event(
+                                        &mut menu_tree.children[focused],
+                                        Event::Mouse(event),
+                                        item_layout,
+                                        cursor,
+                                        renderer,
+                                        clipboard,
+                                        shell,
+                                        viewport,
+                                    );
+                                }
+                            }
+                        }
+                    }
+
+                    self.close(active);
+                    shell.invalidate_layout();
+                    return Some(event::Status::Captured);
+                }
+            }
+            _ => return None,
+        }
+
+        self.tree.state.downcast_mut::<MenuBarState>().keyboard = true;
+        shell.invalidate_layout();
+        Some(event::Status::Captured)
+    }
+
+    /// Opens the menu of the next or the previous root with its first item
+    /// selected.
+    fn switch_root(&mut self, active: usize, forward: bool) {
+        let count = self.roots.len();
+        let next = if forward {
+            (active + 1) % count
+        } else {
+            (active + count - 1) % count
+        };
+
+        self.roots[active].close_menus(&mut self.tree.children[active]);
+        self.tree.state.downcast_mut::<MenuBarState>().active_root = Some(next);
+        self.roots[next].focus_first(&mut self.tree.children[next]);
+    }
+
+    /// Closes the menu bar and all of its menus.
+    fn close(&mut self, active: usize) {
+        self.roots[active].close_menus(&mut self.tree.children[active]);
+        let bar = self.tree.state.downcast_mut::<MenuBarState>();
+        bar.open = false;
+        bar.keyboard = false;
+    }
 }
 impl<'a, 'b, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
     for MenuBarOverlay<'a, 'b, Message, Theme, Renderer>
@@ -193,16 +313,54 @@ where
         let bar_bounds = lc.next().unwrap().bounds();
         let roots_layout = lc.next().unwrap();
 
-        let bar = self.tree.state.downcast_mut::<MenuBarState>();
+        let bar = self.tree.state.downcast_ref::<MenuBarState>();
 
         let Some(active) = bar.active_root else {
             return Ignored;
         };
+        let keyboard_navigation = bar.keyboard;
 
         let parent_bounds = roots_layout.children().nth(active).unwrap().bounds();
         let Some(menu_layouts_layout) = lc.next() else {
             return Ignored;
         }; // Node{0, [menu_node...]}
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            ..
+        }) = &event
+        {
+            if let Some(status) = self.on_key(
+                *key,
+                active,
+                menu_layouts_layout,
+                &viewport,
+                renderer,
+                clipboard,
+                shell,
+            ) {
+                return status;
+            }
+        }
+
+        // Menus opened with the keyboard stay open until the cursor is used.
+        if keyboard_navigation {
+            let cursor_used = match &event {
+                Event::Mouse(mouse::Event::ButtonPressed(_)) | Event::Touch(_) => true,
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    bar_bounds.contains(*position) || self.is_over(layout, renderer, *position)
+                }
+                _ => false,
+            };
+            if !cursor_used {
+                return Captured;
+            }
+
+            self.tree.state.downcast_mut::<MenuBarState>().keyboard = false;
+            self.roots[active].clear_focus(&mut self.tree.children[active]);
+        }
+
+        let bar = self.tree.state.downcast_mut::<MenuBarState>();
         let mut menu_layouts = menu_layouts_layout.children(); // [menu_node...]
 
         let active_root = &mut self.roots[active];
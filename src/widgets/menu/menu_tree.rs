@@ -60,6 +60,8 @@ Tree{
 pub(super) struct MenuState {
     scroll_offset: f32,
     pub(super) active: Index,
+    /// The item selected with the keyboard.
+    pub(super) focused: Index,
     pub(super) slice: MenuSlice,
     pub(super) pressed: bool,
 }
@@ -68,6 +70,7 @@ impl Default for MenuState {
         Self {
             scroll_offset: 0.0,
             active: None,
+            focused: None,
             slice: MenuSlice {
                 start_index: 0,
                 end_index: usize::MAX,
@@ -444,7 +447,7 @@ where
         }
 
         // draw path
-        if let Some(active) = menu_state.active {
+        if let Some(active) = menu_state.active.or(menu_state.focused) {
             let Some(active_bounds) = slice_layout
                 .children()
                 .nth(active - menu_state.slice.start_index)
@@ -604,6 +607,7 @@ where
             *prev = None;
             menu_state.scroll_offset = 0.0;
             menu_state.active = None;
+            menu_state.focused = None;
             menu_state.pressed = false;
         }
     }
@@ -728,6 +732,148 @@ where
         }
     }
 
+    /// The indices of the items with an open menu, starting at the menu of this item.
+    ///
+    /// tree: Tree{stateless, \[widget_tree, menu_tree]}
+    pub(super) fn open_path(&self, tree: &Tree) -> Vec<usize> {
+        let mut path = Vec::new();
+        let (mut item, mut tree) = (self, tree);
+        while let Some(menu) = item.menu.as_ref() {
+            let menu_tree = &tree.children[1];
+            let Some(active) = menu_tree.state.downcast_ref::<MenuState>().active else {
+                break;
+            };
+            path.push(active);
+            item = &menu.items[active];
+            tree = &menu_tree.children[active];
+        }
+        path
+    }
+
+    /// The item at the end of the given path of indices and its tree.
+    ///
+    /// tree: Tree{stateless, \[widget_tree, menu_tree]}
+    pub(super) fn descend_mut<'s>(
+        &'s mut self,
+        tree: &'s mut Tree,
+        path: &[usize],
+    ) -> (&'s mut Self, &'s mut Tree) {
+        match path.split_first() {
+            Some((&index, rest)) => {
+                let menu = self.menu.as_mut().expect("No menu defined in this item");
+                menu.items[index].descend_mut(&mut tree.children[1].children[index], rest)
+            }
+            None => (self, tree),
+        }
+    }
+
+    /// Closes the menu of this item and all of its open sub menus.
+    ///
+    /// tree: Tree{stateless, \[widget_tree, menu_tree]}
+    pub(super) fn close_menus(&self, tree: &mut Tree) {
+        let Some(menu) = self.menu.as_ref() else {
+            return;
+        };
+        let menu_tree = &mut tree.children[1];
+        let menu_state = menu_tree.state.downcast_mut::<MenuState>();
+        let active = menu_state.active.take();
+        menu_state.focused = None;
+        menu_state.scroll_offset = 0.0;
+        menu_state.pressed = false;
+
+        if let Some(active) = active {
+            menu.items[active].close_menus(&mut menu_tree.children[active]);
+        }
+    }
+
+    /// Removes the keyboard selection from the menu of this item and all of
+    /// its open sub menus.
+    ///
+    /// tree: Tree{stateless, \[widget_tree, menu_tree]}
+    pub(super) fn clear_focus(&self, tree: &mut Tree) {
+        let Some(menu) = self.menu.as_ref() else {
+            return;
+        };
+        let menu_tree = &mut tree.children[1];
+        let menu_state = menu_tree.state.downcast_mut::<MenuState>();
+        menu_state.focused = None;
+
+        if let Some(active) = menu_state.active {
+            menu.items[active].clear_focus(&mut menu_tree.children[active]);
+        }
+    }
+
+    /// Selects the first item of the menu of this item with the keyboard.
+    ///
+    /// tree: Tree{stateless, \[widget_tree, menu_tree]}
+    pub(super) fn focus_first(&self, tree: &mut Tree) {
+        if let Some(menu) = self.menu.as_ref() {
+            if !menu.items.is_empty() {
+                tree.children[1].state.downcast_mut::<MenuState>().focused = Some(0);
+            }
+        }
+    }
+
+    /// Moves the keyboard selection of the menu of this item to the next or
+    /// the previous item.
+    ///
+    /// tree: Tree{stateless, \[widget_tree, menu_tree]}
+    pub(super) fn move_focus(&self, tree: &mut Tree, forward: bool) {
+        let Some(menu) = self.menu.as_ref() else {
+            return;
+        };
+        let count = menu.items.len();
+        if count == 0 {
+            return;
+        }
+
+        let menu_state = tree.children[1].state.downcast_mut::<MenuState>();
+        menu_state.focused = Some(match (menu_state.focused, forward) {
+            (Some(focused), true) => (focused + 1) % count,
+            (Some(focused), false) => (focused + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        });
+    }
+
+    /// Opens the menu of the item selected with the keyboard in the menu of
+    /// this item. Returns `false` if the selected item has no menu.
+    ///
+    /// tree: Tree{stateless, \[widget_tree, menu_tree]}
+    pub(super) fn open_focused(&self, tree: &mut Tree) -> bool {
+        let Some(menu) = self.menu.as_ref() else {
+            return false;
+        };
+        let menu_tree = &mut tree.children[1];
+        let menu_state = menu_tree.state.downcast_mut::<MenuState>();
+        let Some(focused) = menu_state.focused else {
+            return false;
+        };
+        if menu.items[focused].menu.is_none() {
+            return false;
+        }
+
+        menu_state.active = Some(focused);
+        menu.items[focused].focus_first(&mut menu_tree.children[focused]);
+        true
+    }
+
+    /// Closes the open sub menu of the menu of this item, keeping its item
+    /// selected.
+    ///
+    /// tree: Tree{stateless, \[widget_tree, menu_tree]}
+    pub(super) fn close_submenu(&self, tree: &mut Tree) {
+        let Some(menu) = self.menu.as_ref() else {
+            return;
+        };
+        let menu_tree = &mut tree.children[1];
+        let menu_state = menu_tree.state.downcast_mut::<MenuState>();
+        if let Some(active) = menu_state.active.take() {
+            menu_state.focused = Some(active);
+            menu.items[active].close_menus(&mut menu_tree.children[active]);
+        }
+    }
+
     /// tree: Tree{stateless, \[widget_tree, menu_tree]}
     ///
     /* pub(super) fn layout(
@@ -0,0 +1,319 @@
+//! Use a month picker as an input element for picking a year and a month.
+//!
+//! *This API requires the following crate features to be activated: `date_picker`*
+
+use super::overlay::month_picker::MonthPickerOverlay;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::Renderer as _,
+        widget::{
+            self,
+            tree::{Tag, Tree},
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    Element,
+    Event,
+    Length,
+    Pixels,
+    Point,
+    Rectangle,
+    Renderer, // the actual type
+    Size,
+    Vector,
+};
+
+pub use crate::{
+    core::date::Date,
+    style::date_picker::{Appearance, DayAppearance, StyleSheet},
+};
+
+//TODO: Remove ignore when Null is updated. Temp fix for Test runs
+/// An input element for picking a year and a month.
+///
+/// The overlay shows the months of a year, the arrows next to the year switch
+/// to the previous and next year. Clicking on a month submits it.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::MonthPicker;
+/// # use iced::widget::{button, Button, Text};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Open,
+///     Cancel,
+///     Submit(i32, u8),
+/// }
+///
+/// let month_picker = MonthPicker::new(
+///     true,
+///     Button::new(Text::new("Pick month"))
+///         .on_press(Message::Open),
+///     Message::Cancel,
+///     Message::Submit,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MonthPicker<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// Show the picker.
+    show_picker: bool,
+    /// The year and the month that are shown as selected when the picker is opened.
+    month: (i32, u8),
+    /// The underlying element.
+    underlay: Element<'a, Message, Theme, Renderer>,
+    /// The message that is send if the [`MonthPickerOverlay`] is cancelled.
+    on_cancel: Message,
+    /// The function that produces a message when a month of the [`MonthPickerOverlay`] is clicked.
+    on_submit: Box<dyn Fn(i32, u8) -> Message>,
+    /// The style of the [`MonthPickerOverlay`].
+    style: <Theme as StyleSheet>::Style,
+    /// The font size of the [`MonthPickerOverlay`] or `None` for the default
+    font_size: Option<Pixels>,
+}
+
+impl<'a, Message, Theme> MonthPicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet,
+{
+    /// Creates a new [`MonthPicker`] wrapping around the given underlay.
+    ///
+    /// It expects:
+    ///     * if the overlay of the month picker is visible.
+    ///     * the underlay [`Element`] on which this [`MonthPicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the [`MonthPicker`] is cancelled by
+    ///         pressing escape or clicking outside of it.
+    ///     * a function that will be called when a month of the [`MonthPicker`]
+    ///         is clicked, which takes the year and the month (1 to 12).
+    pub fn new<U, F>(show_picker: bool, underlay: U, on_cancel: Message, on_submit: F) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        F: 'static + Fn(i32, u8) -> Message,
+    {
+        let today = Date::today();
+
+        Self {
+            show_picker,
+            #[allow(clippy::cast_possible_truncation)]
+            month: (today.year, today.month as u8),
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: Box::new(on_submit),
+            style: <Theme as StyleSheet>::Style::default(),
+            font_size: None,
+        }
+    }
+
+    /// Sets the year and the month (1 to 12) that are shown as selected when the
+    /// [`MonthPicker`] is opened.
+    #[must_use]
+    pub fn month(mut self, year: i32, month: u8) -> Self {
+        self.month = (year, month);
+        self
+    }
+
+    /// Sets the style of the [`MonthPicker`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the font size of the [`MonthPicker`].
+    #[must_use]
+    pub fn font_size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.font_size = Some(size.into());
+        self
+    }
+}
+
+/// The state of the [`MonthPicker`] / [`MonthPickerOverlay`].
+#[derive(Debug)]
+pub struct State {
+    /// The year whose months are shown.
+    pub(crate) year: i32,
+    /// The selected year and month, if any.
+    pub(crate) selected: Option<(i32, u8)>,
+}
+
+impl State {
+    /// Creates a new [`State`] selecting the given year and month.
+    #[must_use]
+    pub fn new(year: i32, month: u8) -> Self {
+        Self {
+            year,
+            selected: Some((year, month)),
+        }
+    }
+
+    /// Resets the state to show the current year without a selected month.
+    pub fn reset(&mut self) {
+        self.year = Date::today().year;
+        self.selected = None;
+    }
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for MonthPicker<'_, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        let (year, month) = self.month;
+        widget::tree::State::new(State::new(year, month))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.underlay]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.underlay
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<iced::overlay::Element<'b, Message, Theme, Renderer>> {
+        let picker_state: &mut State = state.state.downcast_mut();
+
+        if !self.show_picker {
+            return self.underlay.as_widget_mut().overlay(
+                &mut state.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.center_x(), bounds.center_y());
+
+        Some(
+            MonthPickerOverlay::new(
+                picker_state,
+                self.on_cancel.clone(),
+                &self.on_submit,
+                position,
+                self.style.clone(),
+                self.font_size.unwrap_or_else(|| renderer.default_size()),
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme> From<MonthPicker<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'static + Clone,
+    Theme: 'a + StyleSheet,
+{
+    fn from(month_picker: MonthPicker<'a, Message, Theme>) -> Self {
+        Element::new(month_picker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::State;
+
+    #[test]
+    fn state_test() {
+        let mut state = State::new(2024, 3);
+        assert_eq!(state.year, 2024);
+        assert_eq!(state.selected, Some((2024, 3)));
+
+        state.reset();
+        assert_eq!(state.selected, None);
+    }
+}
@@ -86,7 +86,7 @@ where
     /// The Width to use for the ``NumberBox`` Default is ``Length::Fill``
     width: Length,
     /// Ignore mouse scroll events for the [`NumberInput`] Default is ``false``.
-    ignore_scroll_events: bool,
+    scroll_disabled: bool,
 }
 
 impl<'a, T, Message, Theme, Renderer> NumberInput<'a, T, Message, Theme, Renderer>
@@ -112,9 +112,6 @@ where
         T: 'static,
     {
         let padding = DEFAULT_PADDING;
-        let convert_to_num = move |s: String| {
-            on_changed(T::from_str(&s).unwrap_or(if s.is_empty() { T::zero() } else { value }))
-        };
 
         Self {
             value,
@@ -123,15 +120,35 @@ where
             padding,
             size: None,
             content: TextInput::new("", format!("{value}").as_str())
-                .on_input(convert_to_num)
                 .padding(padding)
                 .width(Length::Fixed(127.0)),
             on_change: Box::new(on_changed),
             style: <Theme as number_input::StyleSheet>::Style::default(),
             font: Renderer::Font::default(),
             width: Length::Shrink,
-            ignore_scroll_events: false,
+            scroll_disabled: false,
         }
+        .on_change(on_changed)
+    }
+
+    /// Sets the function that produces a message when the [`NumberInput`] changes,
+    /// replacing the one given to [`NumberInput::new`].
+    #[must_use]
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message + Copy,
+        T: 'static,
+    {
+        let value = self.value;
+        let convert_to_num = move |s: String| {
+            on_change(
+                T::from_str(&s).unwrap_or_else(|_| if s.is_empty() { T::zero() } else { value }),
+            )
+        };
+
+        self.content = self.content.on_input(convert_to_num);
+        self.on_change = Box::new(on_change);
+        self
     }
 
     /// Sets the minimum & maximum value (bound) of the [`NumberInput`].
@@ -225,14 +242,21 @@ where
         self
     }
 
-    /// Enable or disable mouse scrolling events of the [`NumberInput`], by default this is set to
-    /// ``false``.
+    /// Disables changing the value of the [`NumberInput`] with the mouse wheel, e.g. when it is
+    /// placed inside of a scrollable container. By default this is set to ``false``.
     #[must_use]
-    pub fn ignore_scroll(mut self, ignore: bool) -> Self {
-        self.ignore_scroll_events = ignore;
+    pub fn scroll_disabled(mut self, disabled: bool) -> Self {
+        self.scroll_disabled = disabled;
         self
     }
 
+    /// Disables changing the value of the [`NumberInput`] with the mouse wheel.
+    #[must_use]
+    #[deprecated(note = "use `scroll_disabled` instead")]
+    pub fn ignore_scroll(self, ignore: bool) -> Self {
+        self.scroll_disabled(ignore)
+    }
+
     /// Decrease current value by step of the [`NumberInput`].
     fn decrease_val(&mut self, shell: &mut Shell<Message>) {
         if self.value < self.bounds.0 + self.step {
@@ -418,7 +442,7 @@ where
         let text_input = child
             .state
             .downcast_mut::<text_input::State<Renderer::Paragraph>>();
-        let modifiers = state.state.downcast_mut::<ModifierState>();
+        let modifier_state = state.state.downcast_mut::<ModifierState>();
 
         let mut forward_to_text = |event, shell, child, clipboard| {
             self.content.on_event(
@@ -441,10 +465,12 @@ where
 
                 match key {
                     keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
+                        modifier_state.invalid = false;
                         self.decrease_val(shell);
                         event::Status::Captured
                     }
                     keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+                        modifier_state.invalid = false;
                         self.increase_val(shell);
                         event::Status::Captured
                     }
@@ -495,6 +521,7 @@ where
                                 None => return event::Status::Ignored,
                             }
                         } else if c.parse::<i64>().is_err() && c != "-" {
+                            modifier_state.invalid = true;
                             return event::Status::Ignored;
                         } else {
                             c.to_string()
@@ -519,23 +546,31 @@ where
                                 if (self.bounds.0..=self.bounds.1).contains(&val)
                                     && val != self.value =>
                             {
+                                modifier_state.invalid = false;
                                 self.value = val;
                                 forward_to_text(event, shell, child, clipboard)
                             }
-                            Ok(_) => event::Status::Captured,
-                            _ => event::Status::Ignored,
+                            Ok(val) => {
+                                modifier_state.invalid = val != self.value;
+                                event::Status::Captured
+                            }
+                            _ => {
+                                modifier_state.invalid = true;
+                                event::Status::Ignored
+                            }
                         }
                     }
                 }
             }
             Event::Mouse(mouse::Event::WheelScrolled { delta })
-                if mouse_over_widget && !self.ignore_scroll_events =>
+                if mouse_over_widget && !self.scroll_disabled =>
             {
+                modifier_state.invalid = false;
                 match delta {
                     mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
-                        if y.is_sign_positive() {
+                        if *y > 0.0 {
                             self.increase_val(shell);
-                        } else {
+                        } else if *y < 0.0 {
                             self.decrease_val(shell);
                         }
                     }
@@ -543,11 +578,12 @@ where
                 event::Status::Captured
             }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if mouse_over_button => {
+                modifier_state.invalid = false;
                 if mouse_over_dec {
-                    modifiers.decrease_pressed = true;
+                    modifier_state.decrease_pressed = true;
                     self.decrease_val(shell);
                 } else {
-                    modifiers.increase_pressed = true;
+                    modifier_state.increase_pressed = true;
                     self.increase_val(shell);
                 }
                 event::Status::Captured
@@ -556,9 +592,9 @@ where
                 if mouse_over_button =>
             {
                 if mouse_over_dec {
-                    modifiers.decrease_pressed = false;
+                    modifier_state.decrease_pressed = false;
                 } else {
-                    modifiers.increase_pressed = false;
+                    modifier_state.increase_pressed = false;
                 }
                 event::Status::Captured
             }
@@ -639,6 +675,35 @@ where
             None,
             viewport,
         );
+
+        // Mark the text field while the typed input is rejected
+        let is_focused = state.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+        let content_bounds = content_layout.bounds();
+        if state.state.downcast_ref::<ModifierState>().invalid
+            && is_focused
+            && content_bounds.intersects(viewport)
+        {
+            if let Some(border_color) =
+                style::number_input::StyleSheet::invalid(theme, &self.style).input_border_color
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: content_bounds,
+                        border: Border {
+                            radius: (2.0).into(),
+                            width: 1.0,
+                            color: border_color,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    Background::Color(Color::TRANSPARENT),
+                );
+            }
+        }
+
         let is_decrease_disabled = self.value <= self.bounds.0 || self.bounds.0 == self.bounds.1;
         let is_increase_disabled = self.value >= self.bounds.1 || self.bounds.0 == self.bounds.1;
 
@@ -740,6 +805,8 @@ pub struct ModifierState {
     pub decrease_pressed: bool,
     /// The state of increase button on a [`NumberInput`].
     pub increase_pressed: bool,
+    /// If the last typed input of a [`NumberInput`] was rejected.
+    pub invalid: bool,
 }
 
 impl<'a, T, Message, Theme, Renderer> From<NumberInput<'a, T, Message, Theme, Renderer>>
@@ -0,0 +1,450 @@
+//! Use a number spinner for showing a number whose digits roll to a new value.
+//!
+//! *This API requires the following crate features to be activated: `number_spinner`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::{self, Paragraph as _},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::Cursor,
+    window, Element, Event, Length, Pixels, Point, Rectangle, Size,
+};
+use std::time::Instant;
+
+pub use crate::style::number_spinner::{Appearance, StyleSheet};
+
+/// The text of each digit.
+const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+/// A number whose digits roll vertically to the new value when it changes,
+/// like the reels of a slot machine, e.g. for dashboards, scoreboards and
+/// counters.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::NumberSpinner;
+/// #
+/// let score = NumberSpinner::new(1234)
+///     .font_size(32.0)
+///     .animation_speed(2.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberSpinner<Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// The number shown by the [`NumberSpinner`].
+    value: i64,
+    /// The text size of the digits or `None` for the default.
+    font_size: Option<Pixels>,
+    /// The width of each digit or `None` for the width of a zero.
+    digit_width: Option<f32>,
+    /// The number of rolls to a new digit per second.
+    animation_speed: f32,
+    /// The font of the digits or `None` for the default.
+    font: Option<Renderer::Font>,
+    /// The style of the [`NumberSpinner`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Theme, Renderer> NumberSpinner<Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`NumberSpinner`] showing the given number.
+    #[must_use]
+    pub fn new(value: i64) -> Self {
+        Self {
+            value,
+            font_size: None,
+            digit_width: None,
+            animation_speed: 4.0,
+            font: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the text size of the digits of the [`NumberSpinner`].
+    #[must_use]
+    pub fn font_size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.font_size = Some(size.into());
+        self
+    }
+
+    /// Sets the width of each digit of the [`NumberSpinner`].
+    #[must_use]
+    pub fn digit_width(mut self, width: f32) -> Self {
+        self.digit_width = Some(width);
+        self
+    }
+
+    /// Sets the speed of the animation of the [`NumberSpinner`] in rolls per
+    /// second, each digit taking the same time to roll to its new value.
+    #[must_use]
+    pub fn animation_speed(mut self, speed: f32) -> Self {
+        self.animation_speed = speed;
+        self
+    }
+
+    /// Sets the font of the digits of the [`NumberSpinner`].
+    #[must_use]
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`NumberSpinner`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The text of a digit.
+    fn text<'b>(&self, content: &'b str, renderer: &Renderer) -> text::Text<'b, Renderer::Font> {
+        text::Text {
+            content,
+            bounds: Size::INFINITY,
+            size: self.font_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Basic,
+        }
+    }
+
+    /// The size of the column of a digit.
+    fn digit_size(&self, renderer: &Renderer) -> Size {
+        let text = self.text(DIGITS[0], renderer);
+        let height = text.line_height.to_absolute(text.size).0;
+        let width = self
+            .digit_width
+            .unwrap_or_else(|| Renderer::Paragraph::with_text(text).min_bounds().width);
+
+        Size::new(width, height)
+    }
+}
+
+/// The animation of the column of a digit of a [`NumberSpinner`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Digit {
+    /// The position the column rolls from, e.g. `4.5` halfway between 4 and 5.
+    from: f32,
+    /// The digit the column rolls to.
+    to: u8,
+    /// The progress of the roll from `0.0` to `1.0`.
+    progress: f32,
+}
+
+impl Digit {
+    /// Creates a new [`Digit`] resting on the given digit.
+    fn new(digit: u8) -> Self {
+        Self {
+            from: f32::from(digit),
+            to: digit,
+            progress: 1.0,
+        }
+    }
+
+    /// The current position of the column.
+    fn position(&self) -> f32 {
+        self.from + (f32::from(self.to) - self.from) * self.progress
+    }
+
+    /// Rolls the column from its current position to the given digit.
+    fn roll_to(&mut self, digit: u8) {
+        if digit != self.to {
+            self.from = self.position();
+            self.to = digit;
+            self.progress = 0.0;
+        }
+    }
+
+    /// Whether the column is still rolling.
+    fn is_rolling(&self) -> bool {
+        self.progress < 1.0
+    }
+}
+
+/// The state of a [`NumberSpinner`].
+#[derive(Debug)]
+struct State {
+    /// The columns of the digits, starting with the least significant one.
+    digits: Vec<Digit>,
+    /// Whether a minus sign is shown in front of the digits.
+    is_negative: bool,
+    /// The time of the last animation frame or `None` if the animation
+    /// starts with the next frame.
+    last_update: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`] resting on the given value.
+    fn new(value: i64) -> Self {
+        Self {
+            digits: digits(value).into_iter().map(Digit::new).collect(),
+            is_negative: value < 0,
+            last_update: None,
+        }
+    }
+
+    /// Rolls the columns to the digits of the given value. New columns roll
+    /// up from zero and columns of no longer used digits are removed.
+    fn set(&mut self, value: i64) {
+        let digits = digits(value);
+
+        self.digits.truncate(digits.len());
+        for (index, digit) in digits.into_iter().enumerate() {
+            if let Some(column) = self.digits.get_mut(index) {
+                column.roll_to(digit);
+            } else {
+                let mut column = Digit::new(0);
+                column.roll_to(digit);
+                self.digits.push(column);
+            }
+        }
+        self.is_negative = value < 0;
+    }
+
+    /// Advances the rolls by the given progress.
+    fn advance(&mut self, progress: f32) {
+        for digit in &mut self.digits {
+            digit.progress = (digit.progress + progress).min(1.0);
+        }
+    }
+
+    /// Whether a column is still rolling.
+    fn is_rolling(&self) -> bool {
+        self.digits.iter().any(Digit::is_rolling)
+    }
+
+    /// The number of columns including the minus sign.
+    fn columns(&self) -> usize {
+        self.digits.len() + usize::from(self.is_negative)
+    }
+}
+
+/// The digits of the absolute value, starting with the least significant one.
+#[allow(clippy::cast_possible_truncation)]
+fn digits(value: i64) -> Vec<u8> {
+    let mut value = value.unsigned_abs();
+    let mut digits = Vec::new();
+
+    loop {
+        digits.push((value % 10) as u8);
+        value /= 10;
+        if value == 0 {
+            return digits;
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for NumberSpinner<Theme, Renderer>
+where
+    Renderer: text::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.value))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<State>().set(self.value);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_ref::<State>();
+        let digit = self.digit_size(renderer);
+
+        Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(digit.width * state.columns() as f32, digit.height),
+        ))
+    }
+
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        if !bounds.intersects(viewport) {
+            return;
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.active(&self.style);
+        let digit = self.digit_size(renderer);
+
+        let column = |index: usize| Rectangle {
+            x: bounds.x + digit.width * index as f32,
+            y: bounds.y,
+            width: digit.width,
+            height: digit.height,
+        };
+
+        if state.is_negative {
+            let column = column(0);
+            renderer.fill_text(
+                text::Text {
+                    bounds: column.size(),
+                    ..self.text("-", renderer)
+                },
+                column.center(),
+                appearance.text_color,
+                column,
+            );
+        }
+
+        // Each column is clipped to its bounds, so the digit rolling out and
+        // the one rolling in are cut off at the edges of the column.
+        let offset = usize::from(state.is_negative);
+        for (index, digit) in state.digits.iter().rev().enumerate() {
+            let column = column(index + offset);
+            let position = digit.position();
+            let lower = position.floor();
+            let fraction = position - lower;
+            let lower = lower as usize % DIGITS.len();
+            let upper = (lower + 1) % DIGITS.len();
+
+            renderer.with_layer(column, |renderer| {
+                for (content, shift) in
+                    [(DIGITS[lower], -fraction), (DIGITS[upper], 1.0 - fraction)]
+                {
+                    if shift.abs() >= 1.0 {
+                        continue;
+                    }
+
+                    renderer.fill_text(
+                        text::Text {
+                            bounds: column.size(),
+                            ..self.text(content, renderer)
+                        },
+                        Point::new(column.center_x(), column.center_y() + shift * column.height),
+                        appearance.text_color,
+                        column,
+                    );
+                }
+            });
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            if state.is_rolling() {
+                if let Some(last_update) = state.last_update {
+                    state.advance(
+                        now.saturating_duration_since(last_update).as_secs_f32()
+                            * self.animation_speed,
+                    );
+                }
+
+                if state.is_rolling() {
+                    state.last_update = Some(now);
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                } else {
+                    state.last_update = None;
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<NumberSpinner<Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(spinner: NumberSpinner<Theme, Renderer>) -> Self {
+        Element::new(spinner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{digits, Digit, State};
+
+    #[test]
+    fn digits_test() {
+        assert_eq!(digits(0), vec![0]);
+        assert_eq!(digits(1204), vec![4, 0, 2, 1]);
+        assert_eq!(digits(-37), vec![7, 3]);
+        assert_eq!(digits(i64::MIN).len(), 19);
+    }
+
+    #[test]
+    fn roll_test() {
+        let mut state = State::new(19);
+        assert!(!state.is_rolling());
+
+        state.set(-125);
+        assert!(state.is_rolling());
+        assert!(state.is_negative);
+        assert_eq!(state.columns(), 4);
+        assert!((state.digits[2].position() - 0.0).abs() < f32::EPSILON);
+
+        state.advance(0.5);
+        assert!((state.digits[0].position() - 7.0).abs() < f32::EPSILON);
+        assert!((state.digits[1].position() - 1.5).abs() < f32::EPSILON);
+
+        // Changing the value while rolling continues from the current position.
+        state.set(3);
+        assert_eq!(state.digits.len(), 1);
+        assert_eq!(
+            state.digits[0],
+            Digit {
+                from: 7.0,
+                to: 3,
+                progress: 0.0
+            }
+        );
+
+        state.advance(2.0);
+        assert!(!state.is_rolling());
+        assert!((state.digits[0].position() - 3.0).abs() < f32::EPSILON);
+    }
+}
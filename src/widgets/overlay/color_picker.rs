@@ -6,7 +6,7 @@ use crate::{
     color_picker,
     core::icons::bootstrap::{icon_to_string, Bootstrap},
     core::{
-        color::{HexString, Hsv},
+        color::{color_from_hex, HexString, Hsl, Hsv},
         overlay::Position,
     },
     style::{
@@ -61,8 +61,14 @@ const BUTTON_SPACING: f32 = 5.0;
 const SAT_VALUE_STEP: f32 = 0.005;
 /// The step value of the keyboard change of the hue color value.
 const HUE_STEP: i32 = 1;
-/// The step value of the keyboard change of the RGBA color values.
-const RGBA_STEP: i16 = 1;
+/// The step value of the keyboard change of the RGBA and HSL bar values.
+const BAR_STEP: i16 = 1;
+/// The maximum length of the hex text including the leading `#`.
+const HEX_MAX_LENGTH: usize = 9;
+/// The maximum length of the hex text without the alpha channel including the leading `#`.
+const HEX_RGB_MAX_LENGTH: usize = 7;
+/// The number of checkerboard cells drawn along the height of the color preview.
+const CHECKERBOARD_ROWS: usize = 4;
 
 /// The overlay of the [`ColorPicker`](crate::native::ColorPicker).
 #[allow(missing_debug_implementations)]
@@ -85,6 +91,10 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The reference to the tree holding the state of this overlay.
     tree: &'a mut Tree,
+    /// Toggle the use of the HSL bars instead of the RGB bars.
+    use_hsl: bool,
+    /// Toggle the alpha bar and the alpha channel of the hex text.
+    show_alpha: bool,
 }
 
 impl<'a, Message, Theme> ColorPickerOverlay<'a, Message, Theme>
@@ -126,9 +136,25 @@ where
             position,
             style,
             tree,
+            use_hsl: false,
+            show_alpha: true,
         }
     }
 
+    /// Shows hue, saturation and lightness bars instead of the red, green and blue bars.
+    #[must_use]
+    pub fn use_hsl(mut self, use_hsl: bool) -> Self {
+        self.use_hsl = use_hsl;
+        self
+    }
+
+    /// Toggles the alpha bar and the alpha channel of the hex text.
+    #[must_use]
+    pub fn show_alpha(mut self, show_alpha: bool) -> Self {
+        self.show_alpha = show_alpha;
+        self
+    }
+
     /// Turn this [`ColorPickerOverlay`] into an overlay [`Element`](overlay::Element).
     #[must_use]
     pub fn overlay(self) -> overlay::Element<'a, Message, Theme, Renderer> {
@@ -294,70 +320,46 @@ where
             .expect("Native: Layout should have a blue bar layout")
             .bounds();
 
-        let mut alpha_row_children = rgba_color_children
-            .next()
-            .expect("Native: Layout should have an alpha row layout")
-            .children();
-        let _ = alpha_row_children.next();
-        let alpha_bar_bounds = alpha_row_children
-            .next()
-            .expect("Native: Layout should have an alpha bar layout")
-            .bounds();
+        let mut bars = vec![
+            (red_bar_bounds, ColorBarDragged::Red, Focus::Red),
+            (green_bar_bounds, ColorBarDragged::Green, Focus::Green),
+            (blue_bar_bounds, ColorBarDragged::Blue, Focus::Blue),
+        ];
+
+        // The alpha row is only laid out if the alpha bar is shown.
+        if let Some(alpha_row_layout) = rgba_color_children.next() {
+            let mut alpha_row_children = alpha_row_layout.children();
+            let _ = alpha_row_children.next();
+            let alpha_bar_bounds = alpha_row_children
+                .next()
+                .expect("Native: Layout should have an alpha bar layout")
+                .bounds();
+            bars.push((alpha_bar_bounds, ColorBarDragged::Alpha, Focus::Alpha));
+        }
 
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => match delta {
                 mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
-                    let move_value =
-                        //|value: f32, y: f32| (value * 255.0 + y).clamp(0.0, 255.0) / 255.0;
-                        |value: f32, y: f32| value.mul_add(255.0, y).clamp(0.0, 255.0) / 255.0;
-
-                    if cursor.is_over(red_bar_bounds) {
-                        self.state.color = Color {
-                            r: move_value(self.state.color.r, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if cursor.is_over(green_bar_bounds) {
-                        self.state.color = Color {
-                            g: move_value(self.state.color.g, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if cursor.is_over(blue_bar_bounds) {
-                        self.state.color = Color {
-                            b: move_value(self.state.color.b, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
-                    }
-                    if cursor.is_over(alpha_bar_bounds) {
-                        self.state.color = Color {
-                            a: move_value(self.state.color.a, *y),
-                            ..self.state.color
-                        };
-                        color_changed = true;
+                    for &(bounds, bar, _) in &bars {
+                        if cursor.is_over(bounds) {
+                            let steps = bar_steps(bar, self.use_hsl);
+                            let value = bar_value(self.state.color, bar, self.use_hsl)
+                                .mul_add(steps, *y)
+                                .clamp(0.0, steps)
+                                / steps;
+                            set_bar_value(&mut self.state.color, bar, value, self.use_hsl);
+                            color_changed = true;
+                        }
                     }
                 }
             },
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(red_bar_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::Red;
-                    self.state.focus = Focus::Red;
-                }
-                if cursor.is_over(green_bar_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::Green;
-                    self.state.focus = Focus::Green;
-                }
-                if cursor.is_over(blue_bar_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::Blue;
-                    self.state.focus = Focus::Blue;
-                }
-                if cursor.is_over(alpha_bar_bounds) {
-                    self.state.color_bar_dragged = ColorBarDragged::Alpha;
-                    self.state.focus = Focus::Alpha;
+                for &(bounds, bar, focus) in &bars {
+                    if cursor.is_over(bounds) {
+                        self.state.color_bar_dragged = bar;
+                        self.state.focus = focus;
+                    }
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
@@ -371,48 +373,16 @@ where
             (cursor_position.x.max(0.0) / bounds.width).min(1.0)
         };
 
-        match self.state.color_bar_dragged {
-            ColorBarDragged::Red => {
-                self.state.color = Color {
-                    r: cursor
-                        .position_in(red_bar_bounds)
-                        .map(|position| calc_percentage(red_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            ColorBarDragged::Green => {
-                self.state.color = Color {
-                    g: cursor
-                        .position_in(green_bar_bounds)
-                        .map(|position| calc_percentage(green_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            ColorBarDragged::Blue => {
-                self.state.color = Color {
-                    b: cursor
-                        .position_in(blue_bar_bounds)
-                        .map(|position| calc_percentage(blue_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            ColorBarDragged::Alpha => {
-                self.state.color = Color {
-                    a: cursor
-                        .position_in(alpha_bar_bounds)
-                        .map(|position| calc_percentage(alpha_bar_bounds, position))
-                        .unwrap_or_default(),
-                    ..self.state.color
-                };
-                color_changed = true;
-            }
-            _ => {}
+        if let Some((bounds, bar, _)) = bars
+            .into_iter()
+            .find(|(_, bar, _)| *bar == self.state.color_bar_dragged)
+        {
+            let value = cursor
+                .position_in(bounds)
+                .map(|position| calc_percentage(bounds, position))
+                .unwrap_or_default();
+            set_bar_value(&mut self.state.color, bar, value, self.use_hsl);
+            color_changed = true;
         }
 
         if color_changed {
@@ -436,7 +406,7 @@ where
             return event::Status::Ignored;
         }
 
-        if let Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = event {
+        if let Event::Keyboard(keyboard::Event::KeyPressed { key, text, .. }) = event {
             let mut status = event::Status::Ignored;
 
             if matches!(key, keyboard::Key::Named(keyboard::key::Named::Tab)) {
@@ -445,6 +415,16 @@ where
                 } else {
                     self.state.focus = self.state.focus.next();
                 }
+                if self.state.focus == Focus::Alpha && !self.show_alpha {
+                    self.state.focus = if self.state.keyboard_modifiers.shift() {
+                        Focus::Blue
+                    } else {
+                        Focus::Hex
+                    };
+                }
+                if self.state.focus == Focus::Hex {
+                    self.state.hex = hex_string(self.state.color, self.show_alpha);
+                }
                 // TODO: maybe place this better
                 self.state.sat_value_canvas_cache.clear();
                 self.state.hue_canvas_cache.clear();
@@ -515,37 +495,79 @@ where
                     status
                 };
 
-                let rgba_bar_handle = |key_code: &keyboard::Key, value: &mut f32| {
-                    let mut byte_value = (*value * 255.0) as i16;
+                let use_hsl = self.use_hsl;
+                let bar_handle = |key_code: &keyboard::Key, color: &mut Color, bar| {
+                    let steps = bar_steps(bar, use_hsl);
+                    let mut value = (bar_value(*color, bar, use_hsl) * steps).round();
                     let mut status = event::Status::Captured;
 
                     match key_code {
                         keyboard::Key::Named(
                             keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown,
                         ) => {
-                            byte_value -= RGBA_STEP;
+                            value -= f32::from(BAR_STEP);
                             status = event::Status::Captured;
                         }
                         keyboard::Key::Named(
                             keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp,
                         ) => {
-                            byte_value += RGBA_STEP;
+                            value += f32::from(BAR_STEP);
                             status = event::Status::Captured;
                         }
                         _ => {}
                     }
-                    *value = f32::from(byte_value.clamp(0, 255)) / 255.0;
+                    set_bar_value(color, bar, value.clamp(0.0, steps) / steps, use_hsl);
 
                     status
                 };
 
+                let hex_max_length = if self.show_alpha {
+                    HEX_MAX_LENGTH
+                } else {
+                    HEX_RGB_MAX_LENGTH
+                };
+                let hex_handle =
+                    |key_code: &keyboard::Key, text: Option<&str>, state: &mut State| {
+                        if matches!(
+                            key_code,
+                            keyboard::Key::Named(keyboard::key::Named::Backspace)
+                        ) {
+                            let _ = state.hex.pop();
+                        } else if let Some(text) = text {
+                            for c in text.chars() {
+                                if (c.is_ascii_hexdigit() || (c == '#' && state.hex.is_empty()))
+                                    && state.hex.len() < hex_max_length
+                                {
+                                    state.hex.push(c.to_ascii_uppercase());
+                                }
+                            }
+                        } else {
+                            return event::Status::Ignored;
+                        }
+
+                        if let Some(color) = color_from_hex(&state.hex) {
+                            state.color = color;
+                        }
+
+                        event::Status::Captured
+                    };
+
                 match self.state.focus {
                     Focus::SatValue => status = sat_value_handle(key, &mut self.state.color),
                     Focus::Hue => status = hue_handle(key, &mut self.state.color),
-                    Focus::Red => status = rgba_bar_handle(key, &mut self.state.color.r),
-                    Focus::Green => status = rgba_bar_handle(key, &mut self.state.color.g),
-                    Focus::Blue => status = rgba_bar_handle(key, &mut self.state.color.b),
-                    Focus::Alpha => status = rgba_bar_handle(key, &mut self.state.color.a),
+                    Focus::Red => {
+                        status = bar_handle(key, &mut self.state.color, ColorBarDragged::Red);
+                    }
+                    Focus::Green => {
+                        status = bar_handle(key, &mut self.state.color, ColorBarDragged::Green);
+                    }
+                    Focus::Blue => {
+                        status = bar_handle(key, &mut self.state.color, ColorBarDragged::Blue);
+                    }
+                    Focus::Alpha => {
+                        status = bar_handle(key, &mut self.state.color, ColorBarDragged::Alpha);
+                    }
+                    Focus::Hex => status = hex_handle(key, text.as_deref(), self.state),
                     _ => {}
                 }
             }
@@ -681,9 +703,20 @@ where
         let mut fake_messages: Vec<Message> = Vec::new();
 
         // ----------- Text input ----------------------
-        let _text_input_layout = block2_children
+        let text_input_layout = block2_children
             .next()
             .expect("Native: Layout should have a hex text layout");
+        let text_input_status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(text_input_layout.bounds()) =>
+            {
+                self.state.focus = Focus::Hex;
+                self.state.hex = hex_string(self.state.color, self.show_alpha);
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        };
 
         // ----------- Buttons -------------------------
         let cancel_button_layout = block2_children
@@ -729,6 +762,7 @@ where
         status
             .merge(hsv_color_status)
             .merge(rgba_color_status)
+            .merge(text_input_status)
             .merge(cancel_button_status)
             .merge(submit_button_status)
     }
@@ -802,10 +836,9 @@ where
             .next()
             .expect("Graphics: Layout should have a blue row layout");
         block2_mouse_interaction = block2_mouse_interaction.max(f(blue_row_layout, cursor));
-        let alpha_row_layout = rgba_color_children
-            .next()
-            .expect("Graphics: Layout should have an alpha row layout");
-        block2_mouse_interaction = block2_mouse_interaction.max(f(alpha_row_layout, cursor));
+        if let Some(alpha_row_layout) = rgba_color_children.next() {
+            block2_mouse_interaction = block2_mouse_interaction.max(f(alpha_row_layout, cursor));
+        }
 
         let _hex_text_layout = block2_children.next();
 
@@ -976,7 +1009,8 @@ where
     let mut rgba_colors: Column<'_, Message, Theme, Renderer> =
         Column::<Message, Theme, Renderer>::new();
 
-    for _ in 0..4 {
+    let rows = if color_picker.show_alpha { 4 } else { 3 };
+    for _ in 0..rows {
         rgba_colors = rgba_colors.push(
             Row::new()
                 .align_items(Alignment::Center)
@@ -1129,6 +1163,7 @@ fn block2<Message, Theme>(
         style,
         style_sheet,
         color_picker.state.focus,
+        color_picker.use_hsl,
     );
 
     // ----------- Hex text ----------------------
@@ -1139,10 +1174,12 @@ fn block2<Message, Theme>(
         renderer,
         hex_text_layout,
         &color_picker.state.color,
+        &color_picker.state.hex,
         cursor,
         style,
         style_sheet,
         color_picker.state.focus,
+        color_picker.show_alpha,
     );
 
     // ----------- Buttons -------------------------
@@ -1395,8 +1432,8 @@ fn hsv_color<Message, Theme>(
     });
 }
 
-/// Draws the RGBA color area.
-#[allow(clippy::too_many_lines)]
+/// Draws the RGBA color area, or the HSL and alpha color area if `use_hsl` is set.
+#[allow(clippy::too_many_lines, clippy::too_many_arguments)]
 fn rgba_color(
     renderer: &mut Renderer,
     layout: Layout<'_>,
@@ -1405,16 +1442,19 @@ fn rgba_color(
     style: &renderer::Style,
     style_sheet: &HashMap<StyleState, Appearance>,
     focus: Focus,
+    use_hsl: bool,
 ) {
     let mut rgba_color_children = layout.children();
 
     let f = |renderer: &mut Renderer,
              layout: Layout,
              label: &str,
-             color: Color,
-             value: f32,
+             bar_color: Color,
+             bar: ColorBarDragged,
              cursor: Cursor,
              target: Focus| {
+        let value = bar_value(*color, bar, use_hsl);
+        let steps = bar_steps(bar, use_hsl);
         let mut children = layout.children();
 
         let label_layout = children
@@ -1480,7 +1520,7 @@ fn rgba_color(
                     },
                     shadow: Shadow::default(),
                 },
-                color,
+                bar_color,
             );
         }
 
@@ -1513,7 +1553,7 @@ fn rgba_color(
         // Value
         renderer.fill_text(
             Text {
-                content: &format!("{}", (255.0 * value) as u8),
+                content: &format!("{}", (steps * value) as u16),
                 bounds: Size::new(value_layout.bounds().width, value_layout.bounds().height),
                 size: renderer.default_size(),
                 font: renderer.default_font(),
@@ -1557,7 +1597,9 @@ fn rgba_color(
         }
     };
 
-    // Red
+    let hsl: Hsl = (*color).into();
+
+    // Red or hue
     let red_row_layout = rgba_color_children
         .next()
         .expect("Graphics: Layout should have a red row layout");
@@ -1565,14 +1607,18 @@ fn rgba_color(
     f(
         renderer,
         red_row_layout,
-        "R:",
-        Color::from_rgb(color.r, 0.0, 0.0),
-        color.r,
+        if use_hsl { "H:" } else { "R:" },
+        if use_hsl {
+            Hsl::from_hsl(hsl.hue, 1.0, 0.5).into()
+        } else {
+            Color::from_rgb(color.r, 0.0, 0.0)
+        },
+        ColorBarDragged::Red,
         cursor,
         Focus::Red,
     );
 
-    // Green
+    // Green or saturation
     let green_row_layout = rgba_color_children
         .next()
         .expect("Graphics: Layout should have a green row layout");
@@ -1580,14 +1626,18 @@ fn rgba_color(
     f(
         renderer,
         green_row_layout,
-        "G:",
-        Color::from_rgb(0.0, color.g, 0.0),
-        color.g,
+        if use_hsl { "S:" } else { "G:" },
+        if use_hsl {
+            Hsl::from_hsl(hsl.hue, hsl.saturation, 0.5).into()
+        } else {
+            Color::from_rgb(0.0, color.g, 0.0)
+        },
+        ColorBarDragged::Green,
         cursor,
         Focus::Green,
     );
 
-    // Blue
+    // Blue or lightness
     let blue_row_layout = rgba_color_children
         .next()
         .expect("Graphics: Layout should have a blue row layout");
@@ -1595,48 +1645,61 @@ fn rgba_color(
     f(
         renderer,
         blue_row_layout,
-        "B:",
-        Color::from_rgb(0.0, 0.0, color.b),
-        color.b,
+        if use_hsl { "L:" } else { "B:" },
+        if use_hsl {
+            Color::from_rgb(hsl.lightness, hsl.lightness, hsl.lightness)
+        } else {
+            Color::from_rgb(0.0, 0.0, color.b)
+        },
+        ColorBarDragged::Blue,
         cursor,
         Focus::Blue,
     );
 
     // Alpha
-    let alpha_row_layout = rgba_color_children
-        .next()
-        .expect("Graphics: Layout should have an alpha row layout");
-
-    f(
-        renderer,
-        alpha_row_layout,
-        "A:",
-        Color::from_rgba(0.0, 0.0, 0.0, color.a),
-        color.a,
-        cursor,
-        Focus::Alpha,
-    );
+    if let Some(alpha_row_layout) = rgba_color_children.next() {
+        f(
+            renderer,
+            alpha_row_layout,
+            "A:",
+            Color::from_rgba(0.0, 0.0, 0.0, color.a),
+            ColorBarDragged::Alpha,
+            cursor,
+            Focus::Alpha,
+        );
+    }
 }
 
 /// Draws the hex text representation of the color.
+///
+/// While the hex text field is focused the typed hex text is shown instead.
+/// A checkerboard is drawn behind transparent colors.
+#[allow(clippy::too_many_arguments)]
 fn hex_text(
     renderer: &mut Renderer,
     layout: Layout<'_>,
     color: &Color,
+    hex: &str,
     cursor: Cursor,
     _style: &renderer::Style,
     style_sheet: &HashMap<StyleState, Appearance>,
-    _focus: Focus,
+    focus: Focus,
+    show_alpha: bool,
 ) {
     let hsv: Hsv = (*color).into();
 
-    let hex_text_style_state = if cursor.is_over(layout.bounds()) {
+    let hex_text_style_state = if focus == Focus::Hex {
+        StyleState::Focused
+    } else if cursor.is_over(layout.bounds()) {
         StyleState::Hovered
     } else {
         StyleState::Active
     };
 
     let bounds = layout.bounds();
+    if color.a < 1.0 {
+        checkerboard(renderer, bounds);
+    }
     if (bounds.width > 0.) && (bounds.height > 0.) {
         renderer.fill_quad(
             renderer::Quad {
@@ -1654,7 +1717,11 @@ fn hex_text(
 
     renderer.fill_text(
         Text {
-            content: &color.as_hex_string(),
+            content: &if focus == Focus::Hex {
+                hex.to_owned()
+            } else {
+                hex_string(*color, show_alpha)
+            },
             bounds: Size::new(bounds.width, bounds.height),
             size: renderer.default_size(),
             font: renderer.default_font(),
@@ -1677,6 +1744,100 @@ fn hex_text(
     );
 }
 
+/// Draws a checkerboard of light and dark gray cells filling the bounds.
+fn checkerboard(renderer: &mut Renderer, bounds: Rectangle) {
+    let cell_size = bounds.height / CHECKERBOARD_ROWS as f32;
+    if cell_size <= 0.0 {
+        return;
+    }
+    let columns = (bounds.width / cell_size).ceil() as usize;
+
+    for row in 0..CHECKERBOARD_ROWS {
+        for column in 0..columns {
+            let x = (column as f32).mul_add(cell_size, bounds.x);
+            let y = (row as f32).mul_add(cell_size, bounds.y);
+            let cell = Rectangle {
+                x,
+                y,
+                width: cell_size.min(bounds.x + bounds.width - x),
+                height: cell_size,
+            };
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: cell,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                if (row + column).is_multiple_of(2) {
+                    Color::from_rgb(0.8, 0.8, 0.8)
+                } else {
+                    Color::from_rgb(0.6, 0.6, 0.6)
+                },
+            );
+        }
+    }
+}
+
+/// Creates the hex text of the color, without the alpha channel if `show_alpha` is not set.
+fn hex_string(color: Color, show_alpha: bool) -> String {
+    let mut hex = color.as_hex_string();
+    if !show_alpha {
+        hex.truncate(HEX_RGB_MAX_LENGTH);
+    }
+    hex
+}
+
+/// Gets the number of steps the value of the given color bar is shown and changed in.
+fn bar_steps(bar: ColorBarDragged, use_hsl: bool) -> f32 {
+    match bar {
+        ColorBarDragged::Red if use_hsl => 360.0,
+        ColorBarDragged::Green | ColorBarDragged::Blue | ColorBarDragged::Alpha if use_hsl => 100.0,
+        _ => 255.0,
+    }
+}
+
+/// Gets the value of the given color bar of the color in the range of `0.0..=1.0`.
+fn bar_value(color: Color, bar: ColorBarDragged, use_hsl: bool) -> f32 {
+    let hsl: Hsl = color.into();
+
+    match bar {
+        ColorBarDragged::Red if use_hsl => f32::from(hsl.hue) / 360.0,
+        ColorBarDragged::Green if use_hsl => hsl.saturation,
+        ColorBarDragged::Blue if use_hsl => hsl.lightness,
+        ColorBarDragged::Red => color.r,
+        ColorBarDragged::Green => color.g,
+        ColorBarDragged::Blue => color.b,
+        ColorBarDragged::Alpha => color.a,
+        ColorBarDragged::None | ColorBarDragged::SatValue | ColorBarDragged::Hue => 0.0,
+    }
+}
+
+/// Sets the value of the given color bar of the color from the range of `0.0..=1.0`.
+fn set_bar_value(color: &mut Color, bar: ColorBarDragged, value: f32, use_hsl: bool) {
+    let value = value.clamp(0.0, 1.0);
+
+    match bar {
+        ColorBarDragged::Red | ColorBarDragged::Green | ColorBarDragged::Blue if use_hsl => {
+            let mut hsl: Hsl = (*color).into();
+            match bar {
+                // A hue of 360 degrees would wrap around to 0.
+                ColorBarDragged::Red => hsl.hue = ((value * 360.0).round() as u16).min(359),
+                ColorBarDragged::Green => hsl.saturation = value,
+                _ => hsl.lightness = value,
+            }
+            *color = Color {
+                a: color.a,
+                ..hsl.into()
+            };
+        }
+        ColorBarDragged::Red => color.r = value,
+        ColorBarDragged::Green => color.g = value,
+        ColorBarDragged::Blue => color.b = value,
+        ColorBarDragged::Alpha => color.a = value,
+        ColorBarDragged::None | ColorBarDragged::SatValue | ColorBarDragged::Hue => {}
+    }
+}
+
 /// The state of the [`ColorPickerOverlay`].
 #[derive(Debug)]
 pub struct State {
@@ -1692,6 +1853,8 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The hex text typed into the hex text field.
+    pub(crate) hex: String,
 }
 
 impl State {
@@ -1714,6 +1877,7 @@ impl Default for State {
             color_bar_dragged: ColorBarDragged::None,
             focus: Focus::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            hex: String::new(),
         }
     }
 }
@@ -1802,7 +1966,7 @@ where
 }
 
 /// The state of the currently dragged area.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ColorBarDragged {
     /// No area is focussed.
     None,
@@ -1813,13 +1977,13 @@ pub enum ColorBarDragged {
     /// The hue area is focussed.
     Hue,
 
-    /// The red area is focussed.
+    /// The red area, or the hue area of the HSL bars, is focussed.
     Red,
 
-    /// The green area is focussed.
+    /// The green area, or the saturation area of the HSL bars, is focussed.
     Green,
 
-    /// The blue area is focussed.
+    /// The blue area, or the lightness area of the HSL bars, is focussed.
     Blue,
 
     /// The alpha area is focussed.
@@ -1847,18 +2011,21 @@ pub enum Focus {
     /// The hue bar is in focus.
     Hue,
 
-    /// The red bar is in focus.
+    /// The red bar, or the hue bar of the HSL bars, is in focus.
     Red,
 
-    /// The green bar is in focus.
+    /// The green bar, or the saturation bar of the HSL bars, is in focus.
     Green,
 
-    /// The blue bar is in focus.
+    /// The blue bar, or the lightness bar of the HSL bars, is in focus.
     Blue,
 
     /// The alpha bar is in focus.
     Alpha,
 
+    /// The hex text field is in focus.
+    Hex,
+
     /// The cancel button is in focus.
     Cancel,
 
@@ -1877,7 +2044,8 @@ impl Focus {
             Self::Red => Self::Green,
             Self::Green => Self::Blue,
             Self::Blue => Self::Alpha,
-            Self::Alpha => Self::Cancel,
+            Self::Alpha => Self::Hex,
+            Self::Hex => Self::Cancel,
             Self::Cancel => Self::Submit,
             Self::Submit | Self::None => Self::Overlay,
         }
@@ -1895,7 +2063,8 @@ impl Focus {
             Self::Green => Self::Red,
             Self::Blue => Self::Green,
             Self::Alpha => Self::Blue,
-            Self::Cancel => Self::Alpha,
+            Self::Hex => Self::Alpha,
+            Self::Cancel => Self::Hex,
             Self::Submit => Self::Cancel,
         }
     }
@@ -33,6 +33,8 @@ where
     content: Element<'a, Message, Theme, Renderer>,
     /// The style of the [`ContextMenuOverlay`].
     style: <Theme as StyleSheet>::Style,
+    /// The optional message that will be send when the [`ContextMenuOverlay`] is dismissed.
+    on_dismiss: Option<Message>,
     /// The state shared between [`ContextMenu`](crate::native::ContextMenu) and [`ContextMenuOverlay`].
     state: &'a mut context_menu::State,
 }
@@ -49,6 +51,7 @@ where
         tree: &'a mut Tree,
         content: C,
         style: <Theme as StyleSheet>::Style,
+        on_dismiss: Option<Message>,
         state: &'a mut context_menu::State,
     ) -> Self
     where
@@ -59,6 +62,7 @@ where
             tree,
             content: content.into(),
             style,
+            on_dismiss,
             state,
         }
     }
@@ -165,6 +169,9 @@ where
                 if *key == keyboard::Key::Named(keyboard::key::Named::Escape) {
                     self.state.show = false;
                     forward_event_to_children = false;
+                    if let Some(on_dismiss) = &self.on_dismiss {
+                        shell.publish(on_dismiss.clone());
+                    }
                     Status::Captured
                 } else {
                     Status::Ignored
@@ -178,6 +185,9 @@ where
                 if !cursor.is_over(layout_children.bounds()) {
                     self.state.show = false;
                     forward_event_to_children = false;
+                    if let Some(on_dismiss) = &self.on_dismiss {
+                        shell.publish(on_dismiss.clone());
+                    }
                 }
                 Status::Captured
             }
@@ -5,22 +5,25 @@
 use crate::{
     core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
     core::{
-        date::{Date, IsInMonth},
+        date::{Date, DateRange, IsInMonth, Weekday},
         overlay::Position,
     },
-    date_picker,
+    date_picker::{self, HighlightedDate},
+    date_range_picker,
     style::{
-        date_picker::{Appearance, StyleSheet},
+        date_picker::{Appearance, DayAppearance, StyleSheet},
         style_state::StyleState,
     },
+    week_picker,
 };
 
 use chrono::{Datelike, Local, NaiveDate};
+use iced::window;
 use iced::{
     advanced::{
         layout::{Limits, Node},
         overlay, renderer,
-        text::Renderer as _,
+        text::{Paragraph as _, Renderer as _},
         widget::tree::Tree,
         Clipboard, Layout, Overlay, Renderer as _, Shell, Widget,
     },
@@ -44,51 +47,84 @@ use iced::{
     Shadow,
     Size,
 };
-use std::collections::HashMap;
+use std::{collections::HashMap, marker::PhantomData};
 
-/// The padding around the elements.
-const PADDING: f32 = 10.0;
+/// The default padding around the elements.
+pub(crate) const DEFAULT_PADDING: f32 = 10.0;
 /// The spacing between the elements.
 const SPACING: f32 = 15.0;
 /// The padding of the day cells.
 const DAY_CELL_PADDING: f32 = 7.0;
 /// The spacing between the buttons.
 const BUTTON_SPACING: f32 = 5.0;
-
-/// The overlay of the [`DatePicker`](crate::native::DatePicker).
+/// The number of columns of the months in the year/month view.
+const MONTH_COLUMNS: u32 = 3;
+/// The number of rows of the months in the year/month view.
+const MONTH_ROWS: u32 = 4;
+/// The size of the dot marking a highlighted day.
+const HIGHLIGHT_DOT_SIZE: f32 = 4.0;
+/// The default minimal distance between the overlay and the edges of the window.
+pub(crate) const DEFAULT_MARGIN: f32 = 8.0;
+/// The default horizontal distance of a swipe changing the month.
+pub(crate) const DEFAULT_SWIPE_THRESHOLD: f32 = 40.0;
+
+/// The overlay of the [`DatePicker`](crate::widgets::DatePicker).
 #[allow(missing_debug_implementations)]
 pub struct DatePickerOverlay<'a, Message, Theme>
 where
-    Message: Clone,
     Theme: StyleSheet + button::StyleSheet,
 {
     /// The state of the [`DatePickerOverlay`].
     state: &'a mut State,
     /// The cancel button of the [`DatePickerOverlay`].
-    cancel_button: Button<'a, Message, Theme, Renderer>,
+    cancel_button: Button<'a, (), Theme, Renderer>,
     /// The submit button of the [`DatePickerOverlay`].
-    submit_button: Button<'a, Message, Theme, Renderer>,
+    submit_button: Button<'a, (), Theme, Renderer>,
+    /// The button jumping to the current date or `None` if it is not shown.
+    today_button: Option<Button<'a, (), Theme, Renderer>>,
+    /// The function that produces a message when the cancel button of the [`DatePickerOverlay`] is pressed.
+    on_cancel: Box<dyn Fn() -> Message + 'a>,
     /// The function that produces a message when the submit button of the [`DatePickerOverlay`] is pressed.
-    on_submit: &'a dyn Fn(Date) -> Message,
+    on_submit: OnSubmit<'a, Message>,
+    /// The function that produces a message when the [`DatePickerOverlay`] is cancelled or submitted.
+    on_close: Option<&'a dyn Fn() -> Message>,
     /// The position of the [`DatePickerOverlay`].
     position: Point,
+    /// The minimal distance between the [`DatePickerOverlay`] and the edges of the window.
+    margin: f32,
+    /// The dates that are marked in the calendar.
+    highlights: &'a [HighlightedDate],
+    /// The padding between the border and the elements of the [`DatePickerOverlay`].
+    padding: Padding,
+    /// The border radius of the [`DatePickerOverlay`] or `None` for the radius of the style.
+    border_radius: Option<f32>,
+    /// The border width of the [`DatePickerOverlay`] or `None` for the width of the style.
+    border_width: Option<f32>,
     /// The style of the [`DatePickerOverlay`].
     style: <Theme as StyleSheet>::Style,
     /// The reference to the tree holding the state of this overlay.
     tree: &'a mut Tree,
     /// The font size of text and icons in the [`DatePickerOverlay`]
     font_size: Pixels,
+    /// The options of the calendar table.
+    options: CalendarOptions,
+    /// The horizontal distance of a swipe over the days changing the month.
+    swipe_threshold: f32,
+    /// The width of the [`DatePickerOverlay`].
+    width: Length,
+    /// The maximum width of the [`DatePickerOverlay`].
+    max_width: f32,
 }
 
 impl<'a, Message, Theme> DatePickerOverlay<'a, Message, Theme>
 where
-    Message: 'static + Clone,
+    Message: 'static,
     Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
 {
     /// Creates a new [`DatePickerOverlay`] on the given position.
     pub fn new(
         state: &'a mut date_picker::State,
-        on_cancel: Message,
+        on_cancel: impl Fn() -> Message + 'a,
         on_submit: &'a dyn Fn(Date) -> Message,
         position: Point,
         style: <Theme as StyleSheet>::Style,
@@ -96,10 +132,79 @@ where
         //button_style: impl Clone +  Into<<Renderer as button::Renderer>::Style>, // clone not satisfied
         font_size: Pixels,
     ) -> Self {
-        let date_picker::State { overlay_state } = state;
+        let date_picker::State { overlay_state, .. } = state;
+
+        Self::with_state(
+            overlay_state,
+            Box::new(on_cancel),
+            OnSubmit::Date(on_submit),
+            position,
+            style,
+            tree,
+            font_size,
+        )
+    }
+
+    /// Creates a new [`DatePickerOverlay`] for picking a date range on the given position.
+    pub fn new_range(
+        state: &'a mut date_range_picker::State,
+        on_cancel: impl Fn() -> Message + 'a,
+        on_submit: &'a dyn Fn(Date, Date) -> Message,
+        position: Point,
+        style: <Theme as StyleSheet>::Style,
+        tree: &'a mut Tree,
+        font_size: Pixels,
+    ) -> Self {
+        let date_range_picker::State { overlay_state } = state;
+
+        Self::with_state(
+            overlay_state,
+            Box::new(on_cancel),
+            OnSubmit::Range(on_submit),
+            position,
+            style,
+            tree,
+            font_size,
+        )
+    }
+
+    /// Creates a new [`DatePickerOverlay`] for picking a week on the given position.
+    pub fn new_week(
+        state: &'a mut week_picker::State,
+        on_cancel: impl Fn() -> Message + 'a,
+        on_submit: &'a dyn Fn(i32, u8) -> Message,
+        position: Point,
+        style: <Theme as StyleSheet>::Style,
+        tree: &'a mut Tree,
+        font_size: Pixels,
+    ) -> Self {
+        let week_picker::State { overlay_state } = state;
+
+        let mut overlay = Self::with_state(
+            overlay_state,
+            Box::new(on_cancel),
+            OnSubmit::Week(on_submit),
+            position,
+            style,
+            tree,
+            font_size,
+        );
+        overlay.options.select_weeks = true;
+        overlay
+    }
 
+    /// Creates a new [`DatePickerOverlay`] from the state of the overlay.
+    fn with_state(
+        state: &'a mut State,
+        on_cancel: Box<dyn Fn() -> Message + 'a>,
+        on_submit: OnSubmit<'a, Message>,
+        position: Point,
+        style: <Theme as StyleSheet>::Style,
+        tree: &'a mut Tree,
+        font_size: Pixels,
+    ) -> Self {
         DatePickerOverlay {
-            state: overlay_state,
+            state,
             cancel_button: Button::new(
                 text::Text::new(icon_to_string(Bootstrap::X))
                     .font(crate::BOOTSTRAP_FONT)
@@ -108,7 +213,7 @@ where
                     .width(Length::Fill),
             )
             .width(Length::Fill)
-            .on_press(on_cancel.clone()),
+            .on_press(()),
             submit_button: Button::new(
                 text::Text::new(icon_to_string(Bootstrap::Check))
                     .font(crate::BOOTSTRAP_FONT)
@@ -117,13 +222,165 @@ where
                     .width(Length::Fill),
             )
             .width(Length::Fill)
-            .on_press(on_cancel), // Sending a fake message
+            .on_press(()), // Sending a fake message
+            today_button: None,
+            on_cancel,
             on_submit,
+            on_close: None,
             position,
+            margin: DEFAULT_MARGIN,
+            highlights: &[],
+            padding: Padding::from(DEFAULT_PADDING),
+            border_radius: None,
+            border_width: None,
             style,
             tree,
             font_size,
+            options: CalendarOptions::default(),
+            swipe_threshold: DEFAULT_SWIPE_THRESHOLD,
+            width: Length::Shrink,
+            max_width: f32::INFINITY,
+        }
+    }
+
+    /// Sets the earliest date that can be picked in the [`DatePickerOverlay`].
+    #[must_use]
+    pub fn min_date(mut self, date: Option<Date>) -> Self {
+        self.options.date_bounds.min = date.map(Into::into);
+        self
+    }
+
+    /// Sets the latest date that can be picked in the [`DatePickerOverlay`].
+    #[must_use]
+    pub fn max_date(mut self, date: Option<Date>) -> Self {
+        self.options.date_bounds.max = date.map(Into::into);
+        self
+    }
+
+    /// Shows a column with the ISO 8601 week numbers in front of the days.
+    #[must_use]
+    pub fn show_week_numbers(mut self, show_week_numbers: bool) -> Self {
+        self.options.show_week_numbers = show_week_numbers;
+        self
+    }
+
+    /// Sets the function that produces a message when the [`DatePickerOverlay`] is
+    /// cancelled or submitted.
+    #[must_use]
+    pub fn on_close(mut self, on_close: Option<&'a dyn Fn() -> Message>) -> Self {
+        self.on_close = on_close;
+        self
+    }
+
+    /// Sets the weekday of the first column of the calendar.
+    #[must_use]
+    pub fn first_day_of_week(mut self, first_day_of_week: Weekday) -> Self {
+        self.options.first_day_of_week = first_day_of_week;
+        self
+    }
+
+    /// Shows the days of the previous and next month in the empty day cells.
+    #[must_use]
+    pub fn show_outside_days(mut self, show_outside_days: bool) -> Self {
+        self.options.hide_outside_days = !show_outside_days;
+        self
+    }
+
+    /// Sets the horizontal distance of a swipe over the days that changes the month.
+    #[must_use]
+    pub fn swipe_threshold(mut self, swipe_threshold: f32) -> Self {
+        self.swipe_threshold = swipe_threshold;
+        self
+    }
+
+    /// Sets the minimal distance between the [`DatePickerOverlay`] and the edges of the window.
+    #[must_use]
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the padding between the border and the elements of the [`DatePickerOverlay`].
+    #[must_use]
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the width of the [`DatePickerOverlay`] including its padding.
+    ///
+    /// The calendar is stretched to the width unless it is [`Length::Shrink`].
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the maximum width of the [`DatePickerOverlay`] including its padding.
+    #[must_use]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the border radius of the [`DatePickerOverlay`], overriding the radius of the style.
+    #[must_use]
+    pub fn border_radius(mut self, border_radius: Option<f32>) -> Self {
+        self.border_radius = border_radius;
+        self
+    }
+
+    /// Sets the border width of the [`DatePickerOverlay`], overriding the width of the style.
+    #[must_use]
+    pub fn border_width(mut self, border_width: Option<f32>) -> Self {
+        self.border_width = border_width;
+        self
+    }
+
+    /// Sets the dates that are marked with a colored dot in the calendar.
+    #[must_use]
+    pub fn highlight_dates(mut self, highlights: &'a [HighlightedDate]) -> Self {
+        self.highlights = highlights;
+        self
+    }
+
+    /// Replaces the icon of the cancel button with the given label, if any.
+    #[must_use]
+    pub fn cancel_label(mut self, label: Option<String>) -> Self {
+        if let Some(label) = label {
+            self.cancel_button = self.label_button(label);
+        }
+        self
+    }
+
+    /// Replaces the icon of the submit button with the given label, if any.
+    #[must_use]
+    pub fn submit_label(mut self, label: Option<String>) -> Self {
+        if let Some(label) = label {
+            self.submit_button = self.label_button(label);
         }
+        self
+    }
+
+    /// Shows a button with the given label below the days that jumps to the current date
+    /// without submitting it, or no such button if `None`.
+    #[must_use]
+    pub fn today_button(mut self, label: Option<String>) -> Self {
+        self.today_button = label.map(|label| self.label_button(label));
+        self
+    }
+
+    /// Creates a button of the overlay showing the given label.
+    fn label_button(&self, label: String) -> Button<'a, (), Theme, Renderer> {
+        Button::new(
+            text::Text::new(label)
+                .size(self.font_size)
+                .horizontal_alignment(Horizontal::Center)
+                .width(Length::Fill),
+        )
+        .width(Length::Fill)
+        // Sending a fake message
+        .on_press(())
     }
 
     /// Turn this [`DatePickerOverlay`] into an overlay [`Element`](overlay::Element).
@@ -137,8 +394,88 @@ where
         crate::core::date::date_as_string(self.state.date)
     }
 
-    /// The event handling for the month / year bar.
-    fn on_event_month_year(
+    /// The range of the days that are shown as selected if a date range or a week is picked.
+    fn selected_range(&self) -> Option<DateRange> {
+        match self.on_submit {
+            OnSubmit::Date(_) => None,
+            OnSubmit::Week(_) => self
+                .state
+                .is_selected
+                .then(|| crate::core::date::week_range(self.state.date)),
+            OnSubmit::Range(_) => match self.state.selection_stage {
+                SelectionStage::PickingStart => self.state.range,
+                SelectionStage::PickingEnd(start) => Some(DateRange::new(start, start)),
+            },
+        }
+    }
+
+    /// Picks the given day as the selected date or as the next end of the date range.
+    fn pick_day(&mut self, date: NaiveDate) {
+        self.state.date = date;
+        self.state.focused_day = None;
+        self.state.is_selected = true;
+
+        if let OnSubmit::Range(_) = self.on_submit {
+            self.state.selection_stage = match self.state.selection_stage {
+                SelectionStage::PickingStart => SelectionStage::PickingEnd(date.into()),
+                SelectionStage::PickingEnd(start) => {
+                    self.state.range = Some(DateRange::new(start, date));
+                    SelectionStage::PickingStart
+                }
+            };
+        }
+    }
+
+    /// Publishes the cancel message and the close message.
+    fn cancel(&mut self, shell: &mut Shell<Message>) {
+        shell.publish((self.on_cancel)());
+        self.close(shell);
+    }
+
+    /// Publishes the close message, if any.
+    fn close(&mut self, shell: &mut Shell<Message>) {
+        self.state.is_shown = false;
+        // The days have the focus again when the overlay is opened the next time.
+        self.state.focus = Focus::Day;
+        if let Some(on_close) = &self.on_close {
+            shell.publish(on_close());
+        }
+        // The picker hands the focus back to its underlay on the next frame.
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+
+    /// Publishes the message of the picked date or the picked date range
+    /// and the close message.
+    fn submit(&mut self, shell: &mut Shell<Message>) {
+        match self.on_submit {
+            OnSubmit::Date(on_submit) => {
+                shell.publish(on_submit(
+                    self.options.date_bounds.clamp(self.state.date).into(),
+                ));
+            }
+            OnSubmit::Range(on_submit) => {
+                let range = self
+                    .selected_range()
+                    .unwrap_or_else(|| DateRange::new(self.state.date, self.state.date));
+
+                shell.publish(on_submit(
+                    self.options.date_bounds.clamp(range.start.into()).into(),
+                    self.options.date_bounds.clamp(range.end.into()).into(),
+                ));
+            }
+            OnSubmit::Week(on_submit) => {
+                let week = self.options.date_bounds.clamp(self.state.date).iso_week();
+
+                #[allow(clippy::cast_possible_truncation)]
+                shell.publish(on_submit(week.year(), week.week() as u8));
+            }
+        }
+
+        self.close(shell);
+    }
+
+    /// The event handling for the calendar days.
+    fn on_event_days(
         &mut self,
         event: &Event,
         layout: Layout<'_>,
@@ -147,149 +484,119 @@ where
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
     ) -> event::Status {
-        let mut children = layout.children();
-
         let mut status = event::Status::Ignored;
 
-        // ----------- Month ----------------------
-        let month_layout = children
-            .next()
-            .expect("Native: Layout should have a month layout");
-        let mut month_children = month_layout.children();
-
-        let left_bounds = month_children
-            .next()
-            .expect("Native: Layout should have a left month arrow layout")
-            .bounds();
-        let _center_bounds = month_children
-            .next()
-            .expect("Native: Layout should have a center month layout")
-            .bounds();
-        let right_bounds = month_children
-            .next()
-            .expect("Native: Layout should have a right month arrow layout")
-            .bounds();
-
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(month_layout.bounds()) {
-                    self.state.focus = Focus::Month;
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if cursor.is_over(layout.bounds()) {
+                    self.state.focus = Focus::Day;
                 }
 
-                if cursor.is_over(left_bounds) {
-                    self.state.date = crate::core::date::pred_month(self.state.date);
+                if let Some(date) = hovered_day(self.state.date, &self.options, layout, cursor) {
+                    self.pick_day(date);
                     status = event::Status::Captured;
-                } else if cursor.is_over(right_bounds) {
-                    self.state.date = crate::core::date::succ_month(self.state.date);
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { position, .. })
+                if layout.bounds().contains(*position) =>
+            {
+                self.state.focus = Focus::Day;
+                self.state.drag_start = Some(*position);
+                status = event::Status::Captured;
+            }
+            Event::Touch(touch::Event::FingerLifted { position, .. }) => {
+                if let Some(start) = self.state.drag_start.take() {
+                    let distance = *position - start;
+
+                    // A horizontal swipe changes the month, a tap picks the day.
+                    if distance.x.abs() > self.swipe_threshold
+                        && distance.x.abs() > distance.y.abs()
+                    {
+                        let date = if distance.x < 0.0 {
+                            crate::core::date::succ_month(self.state.date)
+                        } else {
+                            crate::core::date::pred_month(self.state.date)
+                        };
+                        self.state.date = self.options.date_bounds.clamp(date);
+                    } else if let Some(date) = hovered_day(
+                        self.state.date,
+                        &self.options,
+                        layout,
+                        Cursor::Available(*position),
+                    ) {
+                        self.pick_day(date);
+                    }
                     status = event::Status::Captured;
                 }
             }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.drag_start = None;
+            }
             _ => {}
         }
 
-        // ----------- Year -----------------------
-        let year_layout = children
-            .next()
-            .expect("Native: Layout should have a year layout");
-        let mut year_children = year_layout.children();
-
-        let left_bounds = year_children
-            .next()
-            .expect("Native: Layout should have a left year arrow layout")
-            .bounds();
-        let _center_bounds = year_children
-            .next()
-            .expect("Native: Layout should have a center year layout")
-            .bounds();
-        let right_bounds = year_children
-            .next()
-            .expect("Native: Layout should have a right year arrow layout")
-            .bounds();
+        status
+    }
 
+    /// The event handling for the month and year labels of the header, which
+    /// switch between the days of the month and the months of the year.
+    fn on_event_header(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) -> event::Status {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(year_layout.bounds()) {
-                    self.state.focus = Focus::Year;
-                }
-
-                if cursor.is_over(left_bounds) {
-                    self.state.date = crate::core::date::pred_year(self.state.date);
-                    status = event::Status::Captured;
-                } else if cursor.is_over(right_bounds) {
-                    self.state.date = crate::core::date::succ_year(self.state.date);
-                    status = event::Status::Captured;
-                }
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if header_label_hovered(layout, cursor) =>
+            {
+                self.state.header_mode = self.state.header_mode.toggle();
+                event::Status::Captured
             }
-            _ => {}
+            _ => event::Status::Ignored,
         }
-
-        status
     }
 
-    /// The event handling for the calendar days.
-    fn on_event_days(
+    /// The event handling for the months of the year/month view.
+    fn on_event_months(
         &mut self,
         event: &Event,
         layout: Layout<'_>,
         cursor: Cursor,
-        _messages: &mut Shell<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
     ) -> event::Status {
-        let mut children = layout.children();
-
-        let _day_labels_layout = children
-            .next()
-            .expect("Native: Layout should have a day label layout");
-
-        let mut status = event::Status::Ignored;
-
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(layout.bounds()) {
-                    self.state.focus = Focus::Day;
-                }
-
-                'outer: for (y, row) in children.enumerate() {
-                    for (x, label) in row.children().enumerate() {
-                        let bounds = label.bounds();
-                        if cursor.is_over(bounds) {
-                            let (day, is_in_month) = crate::core::date::position_to_day(
-                                x,
-                                y,
-                                self.state.date.year(),
-                                self.state.date.month(),
-                            );
-
-                            self.state.date = match is_in_month {
-                                IsInMonth::Previous => {
-                                    crate::core::date::pred_month(self.state.date)
-                                        .with_day(day as u32)
-                                        .expect("Previous month with day should be valid")
-                                }
-                                IsInMonth::Same => self
-                                    .state
-                                    .date
-                                    .with_day(day as u32)
-                                    .expect("Same month with day should be valid"),
-                                IsInMonth::Next => crate::core::date::succ_month(self.state.date)
-                                    .with_day(day as u32)
-                                    .expect("Succeeding month with day should be valid"),
-                            };
-
-                            status = event::Status::Captured;
-                            break 'outer;
-                        }
+                match hovered_month(self.state.date, &self.options, layout.bounds(), cursor) {
+                    Some(date) => {
+                        self.state.date = date;
+                        self.state.focused_day = None;
+                        self.state.header_mode = HeaderMode::MonthView;
+                        event::Status::Captured
                     }
+                    None => event::Status::Ignored,
                 }
             }
-            _ => {}
-        }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
+                        *y
+                    }
+                };
 
-        status
+                let date = if y > 0.0 {
+                    crate::core::date::pred_year(self.state.date)
+                } else {
+                    crate::core::date::succ_year(self.state.date)
+                };
+                self.state.date = self.options.date_bounds.clamp(date);
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
     }
 
     /// The event handling for the keyboard input.
@@ -298,14 +605,10 @@ where
         event: &Event,
         _layout: Layout<'_>,
         _cursor: Cursor,
-        _messages: &mut Shell<Message>,
+        messages: &mut Shell<Message>,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
     ) -> event::Status {
-        if self.state.focus == Focus::None {
-            return event::Status::Ignored;
-        }
-
         if let Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = event {
             let mut status = event::Status::Ignored;
 
@@ -316,51 +619,81 @@ where
                     } else {
                         self.state.focus = self.state.focus.next();
                     }
+                    status = event::Status::Captured;
                 }
-                keyboard::Key::Named(k) => match self.state.focus {
-                    Focus::Month => match k {
-                        keyboard::key::Named::ArrowLeft => {
-                            self.state.date = crate::core::date::pred_month(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::key::Named::ArrowRight => {
-                            self.state.date = crate::core::date::succ_month(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    },
-                    Focus::Year => match k {
-                        keyboard::key::Named::ArrowLeft => {
-                            self.state.date = crate::core::date::pred_year(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::key::Named::ArrowRight => {
-                            self.state.date = crate::core::date::succ_year(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        _ => {}
-                    },
-                    Focus::Day => match k {
-                        keyboard::key::Named::ArrowLeft => {
-                            self.state.date = crate::core::date::pred_day(self.state.date);
-                            status = event::Status::Captured;
-                        }
-                        keyboard::key::Named::ArrowRight => {
-                            self.state.date = crate::core::date::succ_day(self.state.date);
-                            status = event::Status::Captured;
+                keyboard::Key::Named(keyboard::key::Named::Escape)
+                    if self.state.focus != Focus::None =>
+                {
+                    if self.state.header_mode == HeaderMode::YearMonthView {
+                        self.state.header_mode = HeaderMode::MonthView;
+                    } else {
+                        self.cancel(messages);
+                    }
+                    status = event::Status::Captured;
+                }
+                keyboard::Key::Named(keyboard::key::Named::Enter)
+                    if self.state.focus != Focus::None =>
+                {
+                    if self.state.focus == Focus::Cancel {
+                        self.cancel(messages);
+                    } else if matches!(self.state.focus, Focus::Month | Focus::Year) {
+                        // Like clicking the header, switching between the days and the months.
+                        self.state.header_mode = self.state.header_mode.toggle();
+                    } else {
+                        if self.state.focus == Focus::Day {
+                            self.pick_day(self.state.focused_date());
                         }
-                        keyboard::key::Named::ArrowUp => {
-                            self.state.date = crate::core::date::pred_week(self.state.date);
-                            status = event::Status::Captured;
+                        // A date range is only submitted once its end is picked.
+                        let picking_end = self.state.focus == Focus::Day
+                            && matches!(self.state.selection_stage, SelectionStage::PickingEnd(_));
+                        if !picking_end {
+                            self.submit(messages);
                         }
-                        keyboard::key::Named::ArrowDown => {
-                            self.state.date = crate::core::date::succ_week(self.state.date);
-                            status = event::Status::Captured;
+                    }
+                    status = event::Status::Captured;
+                }
+                keyboard::Key::Named(k) if self.state.focus != Focus::None => {
+                    match self.state.focus {
+                        Focus::Month => match k {
+                            keyboard::key::Named::ArrowLeft => {
+                                self.state.date = self
+                                    .options
+                                    .date_bounds
+                                    .clamp(crate::core::date::pred_month(self.state.date));
+                                status = event::Status::Captured;
+                            }
+                            keyboard::key::Named::ArrowRight => {
+                                self.state.date = self
+                                    .options
+                                    .date_bounds
+                                    .clamp(crate::core::date::succ_month(self.state.date));
+                                status = event::Status::Captured;
+                            }
+                            _ => {}
+                        },
+                        Focus::Year => match k {
+                            keyboard::key::Named::ArrowLeft => {
+                                self.state.date = self
+                                    .options
+                                    .date_bounds
+                                    .clamp(crate::core::date::pred_year(self.state.date));
+                                status = event::Status::Captured;
+                            }
+                            keyboard::key::Named::ArrowRight => {
+                                self.state.date = self
+                                    .options
+                                    .date_bounds
+                                    .clamp(crate::core::date::succ_year(self.state.date));
+                                status = event::Status::Captured;
+                            }
+                            _ => {}
+                        },
+                        Focus::Day => {
+                            status = self.on_event_keyboard_days(k);
                         }
                         _ => {}
-                    },
-                    _ => {}
-                },
+                    }
+                }
                 _ => {}
             }
 
@@ -372,19 +705,56 @@ where
             event::Status::Ignored
         }
     }
+
+    /// The keyboard handling for moving the focused day cell.
+    fn on_event_keyboard_days(&mut self, key: keyboard::key::Named) -> event::Status {
+        let focused = self.state.focused_date();
+
+        let target = match key {
+            keyboard::key::Named::ArrowLeft => crate::core::date::pred_day(focused),
+            keyboard::key::Named::ArrowRight => crate::core::date::succ_day(focused),
+            keyboard::key::Named::ArrowUp => crate::core::date::pred_week(focused),
+            keyboard::key::Named::ArrowDown => crate::core::date::succ_week(focused),
+            keyboard::key::Named::PageUp => crate::core::date::pred_month(focused),
+            keyboard::key::Named::PageDown => crate::core::date::succ_month(focused),
+            keyboard::key::Named::Home => focused
+                .with_day(1)
+                .expect("First day of the month should be valid"),
+            keyboard::key::Named::End => focused
+                .with_day(crate::core::date::num_days_of_month(
+                    focused.year(),
+                    focused.month(),
+                ))
+                .expect("Last day of the month should be valid"),
+            _ => return event::Status::Ignored,
+        };
+
+        let target = self.options.date_bounds.clamp(target);
+
+        // Moving the focus into another month also changes the displayed month.
+        if target.year() != self.state.date.year() || target.month() != self.state.date.month() {
+            self.state.date = target;
+        }
+        self.state.focused_day = Some(target.day() as u8);
+
+        event::Status::Captured
+    }
 }
 
 impl<'a, Message, Theme> Overlay<Message, Theme, Renderer> for DatePickerOverlay<'a, Message, Theme>
 where
-    Message: 'static + Clone,
+    Message: 'static,
     Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
 {
     #[allow(clippy::too_many_lines)]
     fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
-        let limits = Limits::new(Size::ZERO, bounds)
-            .shrink(Padding::from(PADDING))
-            .width(Length::Shrink)
-            .height(Length::Shrink);
+        let mut limits = Limits::new(Size::ZERO, bounds)
+            .max_width(self.max_width)
+            .width(self.width);
+        if self.width == Length::Fill {
+            limits = limits.min_width(limits.max().width);
+        }
+        let limits = limits.shrink(self.padding).height(Length::Shrink);
 
         // Pre-Buttons TODO: get rid of it
         let cancel_limits = limits;
@@ -392,119 +762,50 @@ where
             self.cancel_button
                 .layout(&mut self.tree.children[0], renderer, &cancel_limits);
 
-        let limits = limits.shrink(Size::new(0.0, cancel_button.bounds().height + SPACING));
-
-        // Month/Year
-        let font_size = self.font_size;
-
-        let month_year = Row::<Message, Theme, Renderer>::new()
-            .width(Length::Shrink)
-            .spacing(SPACING)
-            .push(
-                Row::new()
-                    .width(Length::Shrink)
-                    .spacing(SPACING)
-                    .align_items(Alignment::Center)
-                    .push(
-                        // Left Month arrow
-                        Container::new(
-                            Text::new(icon_to_string(Bootstrap::CaretLeftFill))
-                                .size(font_size.0 + 1.0)
-                                .font(crate::BOOTSTRAP_FONT),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    )
-                    .push(
-                        // Month
-                        Text::new("September").size(font_size).width(Length::Shrink),
-                    )
-                    .push(
-                        // Right Month arrow
-                        Container::new(
-                            Text::new(icon_to_string(Bootstrap::CaretRightFill))
-                                .size(font_size.0 + 1.0)
-                                .font(crate::BOOTSTRAP_FONT),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    ),
-            )
-            .push(
-                Row::new()
-                    .width(Length::Shrink)
-                    .spacing(SPACING)
-                    .align_items(Alignment::Center)
-                    .push(
-                        // Left Year arrow
-                        Container::new(
-                            Text::new(icon_to_string(Bootstrap::CaretLeftFill))
-                                .size(font_size.0 + 1.0)
-                                .font(BOOTSTRAP_FONT),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    )
-                    .push(
-                        // Year
-                        Text::new("9999").size(font_size).width(Length::Shrink),
-                    )
-                    .push(
-                        // Right Year arrow
-                        Container::new(
-                            Text::new(icon_to_string(Bootstrap::CaretRightFill))
-                                .size(font_size.0 + 1.0)
-                                .font(BOOTSTRAP_FONT),
-                        )
-                        .height(Length::Shrink)
-                        .width(Length::Shrink),
-                    ),
-            );
+        let mut limits = limits.shrink(Size::new(0.0, cancel_button.bounds().height + SPACING));
 
-        let days = Container::<Message, Theme, Renderer>::new((0..7).fold(
-            Column::new().width(Length::Shrink).height(Length::Shrink),
-            |column, _y| {
-                column.push(
-                    (0..7).fold(
-                        Row::new()
-                            .height(Length::Shrink)
-                            .width(Length::Shrink)
-                            .spacing(SPACING),
-                        |row, _x| {
-                            row.push(
-                                Container::new(Row::new().push(Text::new("31").size(font_size)))
-                                    .width(Length::Shrink)
-                                    .height(Length::Shrink)
-                                    .padding(DAY_CELL_PADDING),
-                            )
-                        },
-                    ),
-                )
-            },
-        ))
-        .width(Length::Shrink)
-        .height(Length::Shrink)
-        .center_y();
+        if let Some(today_button) = &self.today_button {
+            let today_button =
+                today_button.layout(&mut self.tree.children[2], renderer, &cancel_limits);
+            limits = limits.shrink(Size::new(0.0, today_button.bounds().height + SPACING));
+        }
 
-        let col = Column::<Message, Theme, Renderer>::new()
-            .spacing(SPACING)
-            .align_items(Alignment::Center)
-            .push(month_year)
-            .push(days);
+        let col =
+            calendar::<Message, Theme>(self.font_size, self.options.show_week_numbers, self.width);
 
         let element: Element<Message, Theme, Renderer> = Element::new(col);
-        let col_tree = if let Some(child_tree) = self.tree.children.get_mut(2) {
+        let col_tree = if let Some(child_tree) = self.tree.children.get_mut(3) {
             child_tree.diff(element.as_widget());
             child_tree
         } else {
             let child_tree = Tree::new(element.as_widget());
-            self.tree.children.insert(2, child_tree);
-            &mut self.tree.children[2]
+            self.tree.children.insert(3, child_tree);
+            &mut self.tree.children[3]
         };
 
         let mut col = element.as_widget().layout(col_tree, renderer, &limits);
         let col_bounds = col.bounds();
-        col = col.move_to(Point::new(col_bounds.x + PADDING, col_bounds.y + PADDING));
+        col = col.move_to(Point::new(
+            col_bounds.x + self.padding.left,
+            col_bounds.y + self.padding.top,
+        ));
+
+        // Today button
+        let today_button = self.today_button.as_ref().map(|today_button| {
+            today_button
+                .layout(
+                    &mut self.tree.children[2],
+                    renderer,
+                    &limits.max_width(col.bounds().width),
+                )
+                .move_to(Point::new(
+                    self.padding.left,
+                    col.bounds().height + self.padding.top + SPACING,
+                ))
+        });
+        let today_height = today_button
+            .as_ref()
+            .map_or(0.0, |today_button| today_button.bounds().height + SPACING);
 
         // Buttons
         let cancel_limits =
@@ -523,24 +824,31 @@ where
 
         let cancel_bounds = cancel_button.bounds();
         cancel_button = cancel_button.move_to(Point {
-            x: cancel_bounds.x + PADDING,
-            y: cancel_bounds.y + col.bounds().height + PADDING + SPACING,
+            x: cancel_bounds.x + self.padding.left,
+            y: cancel_bounds.y + col.bounds().height + today_height + self.padding.top + SPACING,
         });
 
         let submit_bounds = submit_button.bounds();
         submit_button = submit_button.move_to(Point {
-            x: submit_bounds.x + col.bounds().width - submit_bounds.width + PADDING,
-            y: submit_bounds.y + col.bounds().height + PADDING + SPACING,
+            x: submit_bounds.x + col.bounds().width - submit_bounds.width + self.padding.left,
+            y: submit_bounds.y + col.bounds().height + today_height + self.padding.top + SPACING,
         });
 
         let mut node = Node::with_children(
             Size::new(
-                col.bounds().width + (2.0 * PADDING),
-                col.bounds().height + cancel_button.bounds().height + (2.0 * PADDING) + SPACING,
+                col.bounds().width + self.padding.horizontal(),
+                col.bounds().height
+                    + today_height
+                    + cancel_button.bounds().height
+                    + self.padding.vertical()
+                    + SPACING,
             ),
-            vec![col, cancel_button, submit_button],
+            [col, cancel_button, submit_button]
+                .into_iter()
+                .chain(today_button)
+                .collect(),
         );
-        node.center_and_bounce(self.position, bounds);
+        node.center_and_bounce_with_margin(self.position, bounds, self.margin);
         node
     }
 
@@ -570,14 +878,9 @@ where
         let month_year_layout = date_children
             .next()
             .expect("Native: Layout should have a month/year layout");
-        let month_year_status = self.on_event_month_year(
-            &event,
-            month_year_layout,
-            cursor,
-            shell,
-            renderer,
-            clipboard,
-        );
+        let month_year_status =
+            on_event_month_year(self.state, &self.options, &event, month_year_layout, cursor)
+                .merge(self.on_event_header(&event, month_year_layout, cursor));
 
         // ----------- Days ----------------------
         let days_layout = date_children
@@ -586,14 +889,20 @@ where
             .children()
             .next()
             .expect("Native: Layout should have a days table layout");
-        let days_status =
-            self.on_event_days(&event, days_layout, cursor, shell, renderer, clipboard);
+        let days_status = match self.state.header_mode {
+            HeaderMode::MonthView => {
+                self.on_event_days(&event, days_layout, cursor, shell, renderer, clipboard)
+            }
+            HeaderMode::YearMonthView => self.on_event_months(&event, days_layout, cursor),
+        };
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
             .next()
             .expect("Native: Layout should have a cancel button layout for a DatePicker");
 
+        let mut fake_messages: Vec<()> = Vec::new();
+
         let cancel_status = self.cancel_button.on_event(
             &mut self.tree.children[0],
             event.clone(),
@@ -601,19 +910,23 @@ where
             cursor,
             renderer,
             clipboard,
-            shell,
+            &mut Shell::new(&mut fake_messages),
             &layout.bounds(),
         );
 
+        if !fake_messages.is_empty() {
+            self.cancel(shell);
+        }
+
         let submit_button_layout = children
             .next()
             .expect("Native: Layout should have a submit button layout for a DatePicker");
 
-        let mut fake_messages: Vec<Message> = Vec::new();
+        fake_messages.clear();
 
         let submit_status = self.submit_button.on_event(
             &mut self.tree.children[1],
-            event,
+            event.clone(),
             submit_button_layout,
             cursor,
             renderer,
@@ -623,14 +936,42 @@ where
         );
 
         if !fake_messages.is_empty() {
-            shell.publish((self.on_submit)(self.state.date.into()));
+            self.submit(shell);
         }
 
-        month_year_status
-            .merge(days_status)
-            .merge(cancel_status)
-            .merge(submit_status)
-    }
+        let mut today_status = event::Status::Ignored;
+
+        if let (Some(today_button), Some(today_button_layout)) =
+            (&mut self.today_button, children.next())
+        {
+            fake_messages.clear();
+
+            today_status = today_button.on_event(
+                &mut self.tree.children[2],
+                event,
+                today_button_layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut Shell::new(&mut fake_messages),
+                &layout.bounds(),
+            );
+
+            if !fake_messages.is_empty() {
+                self.state.date = self
+                    .options
+                    .date_bounds
+                    .clamp(Local::now().naive_local().date());
+                self.state.focused_day = None;
+            }
+        }
+
+        month_year_status
+            .merge(days_status)
+            .merge(cancel_status)
+            .merge(submit_status)
+            .merge(today_status)
+    }
 
     fn mouse_interaction(
         &self,
@@ -651,42 +992,12 @@ where
         let month_year_layout = date_children
             .next()
             .expect("Graphics: Layout should have a month/year layout");
-        let mut month_year_children = month_year_layout.children();
-        let month_layout = month_year_children
-            .next()
-            .expect("Graphics: Layout should have a month layout");
-        let year_layout = month_year_children
-            .next()
-            .expect("Graphics: Layout should have a year layout");
-
-        let f = |layout: Layout<'_>| {
-            let mut children = layout.children();
-
-            let left_bounds = children
-                .next()
-                .expect("Graphics: Layout should have a left arrow layout")
-                .bounds();
-            let _center = children.next();
-            let right_bounds = children
-                .next()
-                .expect("Graphics: Layout should have a right arrow layout")
-                .bounds();
-
-            let mut mouse_interaction = mouse::Interaction::default();
-
-            let left_arrow_hovered = cursor.is_over(left_bounds);
-            let right_arrow_hovered = cursor.is_over(right_bounds);
-
-            if left_arrow_hovered || right_arrow_hovered {
-                mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
-            }
-
-            mouse_interaction
+        let month_year_mouse_interaction = if header_label_hovered(month_year_layout, cursor) {
+            mouse::Interaction::Pointer
+        } else {
+            month_year_interaction(month_year_layout, cursor)
         };
 
-        let month_mouse_interaction = f(month_layout);
-        let year_mouse_interaction = f(year_layout);
-
         // Days
         let days_layout = date_children
             .next()
@@ -694,22 +1005,20 @@ where
             .children()
             .next()
             .expect("Graphics: Layout should have a days layout");
-        let mut days_children = days_layout.children();
-        let _day_labels_layout = days_children.next();
-
-        let mut table_mouse_interaction = mouse::Interaction::default();
-
-        for row in days_children {
-            for label in row.children() {
-                let bounds = label.bounds();
-
-                let mouse_over = cursor.is_over(bounds);
-                if mouse_over {
-                    table_mouse_interaction =
-                        table_mouse_interaction.max(mouse::Interaction::Pointer);
-                }
+        let table_hovered = match self.state.header_mode {
+            HeaderMode::MonthView => {
+                hovered_day(self.state.date, &self.options, days_layout, cursor).is_some()
             }
-        }
+            HeaderMode::YearMonthView => {
+                hovered_month(self.state.date, &self.options, days_layout.bounds(), cursor)
+                    .is_some()
+            }
+        };
+        let table_mouse_interaction = if table_hovered {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
 
         // Buttons
         let cancel_button_layout = children
@@ -736,12 +1045,27 @@ where
             renderer,
         );
 
+        let today_button_mouse_interaction = self
+            .today_button
+            .as_ref()
+            .zip(children.next())
+            .map(|(today_button, today_button_layout)| {
+                today_button.mouse_interaction(
+                    &self.tree.children[2],
+                    today_button_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .unwrap_or_default();
+
         mouse_interaction
-            .max(month_mouse_interaction)
-            .max(year_mouse_interaction)
+            .max(month_year_mouse_interaction)
             .max(table_mouse_interaction)
             .max(cancel_button_mouse_interaction)
             .max(submit_button_mouse_interaction)
+            .max(today_button_mouse_interaction)
     }
 
     fn draw(
@@ -759,14 +1083,9 @@ where
             .expect("Graphics: Layout should have a date layout")
             .children();
 
-        let mut style_sheet: HashMap<StyleState, Appearance> = HashMap::new();
-        let _ = style_sheet.insert(StyleState::Active, StyleSheet::active(theme, &self.style));
-        let _ = style_sheet.insert(
-            StyleState::Selected,
-            StyleSheet::selected(theme, &self.style),
-        );
-        let _ = style_sheet.insert(StyleState::Hovered, StyleSheet::hovered(theme, &self.style));
-        let _ = style_sheet.insert(StyleState::Focused, StyleSheet::focused(theme, &self.style));
+        let style_sheet = appearances(theme, &self.style);
+
+        let day_styles = DayStyles::new(theme, &self.style);
 
         let mut style_state = StyleState::Active;
         if self.state.focus == Focus::Overlay {
@@ -782,8 +1101,13 @@ where
                 renderer::Quad {
                     bounds,
                     border: Border {
-                        radius: style_sheet[&style_state].border_radius.into(),
-                        width: style_sheet[&style_state].border_width,
+                        radius: self
+                            .border_radius
+                            .unwrap_or_else(|| style_sheet[&style_state].border_radius)
+                            .into(),
+                        width: self
+                            .border_width
+                            .unwrap_or_else(|| style_sheet[&style_state].border_width),
                         color: style_sheet[&style_state].border_color,
                     },
                     shadow: Shadow::default(),
@@ -815,15 +1139,38 @@ where
             .next()
             .expect("Graphics: Layout should have a days layout");
 
-        days(
-            renderer,
-            days_layout,
-            self.state.date,
-            cursor.position().unwrap_or_default(),
-            &style_sheet,
-            self.state.focus,
-            self.font_size,
-        );
+        let hovered_tooltip = match self.state.header_mode {
+            HeaderMode::MonthView => days(
+                renderer,
+                days_layout,
+                self.state.date,
+                self.state.focused_date(),
+                self.selected_range().or_else(|| {
+                    self.state
+                        .is_selected
+                        .then(|| DateRange::new(self.state.date, self.state.date))
+                }),
+                &self.options,
+                self.highlights,
+                cursor.position().unwrap_or_default(),
+                &style_sheet,
+                &day_styles,
+                self.state.focus,
+                self.font_size,
+            ),
+            HeaderMode::YearMonthView => {
+                months(
+                    renderer,
+                    days_layout.bounds(),
+                    self.state.date,
+                    &self.options,
+                    cursor,
+                    &day_styles,
+                    self.font_size,
+                );
+                None
+            }
+        };
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
@@ -854,6 +1201,20 @@ where
             &bounds,
         );
 
+        if let (Some(today_button), Some(today_button_layout)) =
+            (&self.today_button, children.next())
+        {
+            today_button.draw(
+                &self.tree.children[2],
+                renderer,
+                theme,
+                style,
+                today_button_layout,
+                cursor,
+                &bounds,
+            );
+        }
+
         // Buttons are not focusable right now...
         let cancel_button_bounds = cancel_button_layout.bounds();
         if (self.state.focus == Focus::Cancel)
@@ -892,6 +1253,17 @@ where
                 Color::TRANSPARENT,
             );
         }
+
+        if let Some((cell_bounds, tooltip)) = hovered_tooltip {
+            highlight_tooltip(
+                renderer,
+                cell_bounds,
+                tooltip,
+                bounds,
+                &style_sheet[&StyleState::Active],
+                self.font_size,
+            );
+        }
     }
 }
 
@@ -902,8 +1274,23 @@ pub struct State {
     pub(crate) date: NaiveDate,
     /// The focus of the [`DatePickerOverlay`].
     pub(crate) focus: Focus,
+    /// The day of the shown month that has the keyboard focus or `None` if
+    /// the focus is on the selected day.
+    pub(crate) focused_day: Option<u8>,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// The picked date range if the [`DatePickerOverlay`] is used for picking date ranges.
+    pub(crate) range: Option<DateRange>,
+    /// The stage of picking a date range.
+    pub(crate) selection_stage: SelectionStage,
+    /// Whether the date is shown as selected or only its month is shown.
+    pub(crate) is_selected: bool,
+    /// The position where a touch on the days started, if any.
+    pub(crate) drag_start: Option<Point>,
+    /// Whether the [`DatePickerOverlay`] is shown, if the picker keeps track of it itself.
+    pub(crate) is_shown: bool,
+    /// Whether the days of the month or the months of the year are shown.
+    pub(crate) header_mode: HeaderMode,
 }
 
 impl State {
@@ -915,34 +1302,117 @@ impl State {
             ..Self::default()
         }
     }
+
+    /// Creates a new State with the given date range.
+    #[must_use]
+    pub fn new_range(range: DateRange) -> Self {
+        Self {
+            date: range.end.into(),
+            range: Some(range),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new State showing the month of the given date without
+    /// selecting it.
+    #[must_use]
+    pub fn viewing(date: NaiveDate) -> Self {
+        Self {
+            date,
+            is_selected: false,
+            ..Self::default()
+        }
+    }
+
+    /// Gets the date of the day cell that has the keyboard focus.
+    pub(crate) fn focused_date(&self) -> NaiveDate {
+        self.focused_day
+            .and_then(|day| self.date.with_day(u32::from(day)))
+            .unwrap_or(self.date)
+    }
 }
 
 impl Default for State {
     fn default() -> Self {
         Self {
             date: Local::now().naive_local().date(),
-            focus: Focus::default(),
+            // The keys of the overlay work as soon as it is opened.
+            focus: Focus::Day,
+            focused_day: None,
             keyboard_modifiers: keyboard::Modifiers::default(),
+            range: None,
+            selection_stage: SelectionStage::default(),
+            is_selected: true,
+            drag_start: None,
+            is_shown: false,
+            header_mode: HeaderMode::default(),
         }
     }
 }
 
+/// The stage of picking a date range in the [`DatePickerOverlay`].
+#[derive(Clone, Copy, Debug, Default)]
+pub enum SelectionStage {
+    /// The next picked day is the start of the range.
+    #[default]
+    PickingStart,
+
+    /// The next picked day is the end of the range starting at the given date.
+    PickingEnd(Date),
+}
+
+/// The view below the month/year header of the [`DatePickerOverlay`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeaderMode {
+    /// The days of the shown month are shown.
+    #[default]
+    MonthView,
+
+    /// The months of the shown year are shown for jumping to another month.
+    YearMonthView,
+}
+
+impl HeaderMode {
+    /// Switches between the two views.
+    #[must_use]
+    pub const fn toggle(self) -> Self {
+        match self {
+            Self::MonthView => Self::YearMonthView,
+            Self::YearMonthView => Self::MonthView,
+        }
+    }
+}
+
+/// The function producing the message when the submit button is pressed.
+enum OnSubmit<'a, Message> {
+    /// Submits a single date.
+    Date(&'a dyn Fn(Date) -> Message),
+
+    /// Submits the start and end date of a date range.
+    Range(&'a dyn Fn(Date, Date) -> Message),
+
+    /// Submits the ISO year and the ISO week number of a week.
+    Week(&'a dyn Fn(i32, u8) -> Message),
+}
+
 /// Just a workaround to pass the button states from the tree to the overlay
-#[allow(missing_debug_implementations)]
+#[allow(missing_debug_implementations, clippy::struct_field_names)]
 pub struct DatePickerOverlayButtons<'a, Message, Theme>
 where
-    Message: Clone,
     Theme: StyleSheet + button::StyleSheet,
 {
     /// The cancel button of the [`DatePickerOverlay`].
-    cancel_button: Element<'a, Message, Theme, Renderer>,
+    cancel_button: Element<'a, (), Theme, Renderer>,
     /// The submit button of the [`DatePickerOverlay`].
-    submit_button: Element<'a, Message, Theme, Renderer>,
+    submit_button: Element<'a, (), Theme, Renderer>,
+    /// The today button of the [`DatePickerOverlay`].
+    today_button: Element<'a, (), Theme, Renderer>,
+    /// The type of the messages of the [`DatePickerOverlay`].
+    message: PhantomData<Message>,
 }
 
 impl<'a, Message, Theme> Default for DatePickerOverlayButtons<'a, Message, Theme>
 where
-    Message: 'a + Clone,
     Theme: 'a + StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
 {
     fn default() -> Self {
@@ -961,6 +1431,13 @@ where
                     .width(Length::Fill),
             )
             .into(),
+            today_button: Button::new(
+                text::Text::new("")
+                    .horizontal_alignment(Horizontal::Center)
+                    .width(Length::Fill),
+            )
+            .into(),
+            message: PhantomData,
         }
     }
 }
@@ -969,18 +1446,18 @@ where
 impl<'a, Message, Theme> Widget<Message, Theme, Renderer>
     for DatePickerOverlayButtons<'a, Message, Theme>
 where
-    Message: Clone,
     Theme: StyleSheet + button::StyleSheet + container::StyleSheet,
 {
     fn children(&self) -> Vec<Tree> {
         vec![
             Tree::new(&self.cancel_button),
             Tree::new(&self.submit_button),
+            Tree::new(&self.today_button),
         ]
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.cancel_button, &self.submit_button]);
+        tree.diff_children(&[&self.cancel_button, &self.submit_button, &self.today_button]);
     }
 
     fn size(&self) -> Size<Length> {
@@ -1008,7 +1485,7 @@ where
 impl<'a, Message, Theme> From<DatePickerOverlayButtons<'a, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Message: 'a + Clone,
+    Message: 'a,
     Theme: 'a + StyleSheet + button::StyleSheet + container::StyleSheet,
 {
     fn from(overlay: DatePickerOverlayButtons<'a, Message, Theme>) -> Self {
@@ -1076,8 +1553,475 @@ impl Default for Focus {
     }
 }
 
+/// The range of the dates that can be picked in the [`DatePickerOverlay`].
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct DateBounds {
+    /// The earliest date that can be picked.
+    pub(crate) min: Option<NaiveDate>,
+    /// The latest date that can be picked.
+    pub(crate) max: Option<NaiveDate>,
+}
+
+impl DateBounds {
+    /// Checks if the given date can be picked.
+    pub(crate) fn contains(self, date: NaiveDate) -> bool {
+        (self.min.unwrap_or(NaiveDate::MIN)..=self.max.unwrap_or(NaiveDate::MAX)).contains(&date)
+    }
+
+    /// Moves the given date into the range of the dates that can be picked.
+    pub(crate) fn clamp(self, date: NaiveDate) -> NaiveDate {
+        let date = self.min.map_or(date, |min| date.max(min));
+        self.max.map_or(date, |max| date.min(max))
+    }
+}
+
+/// The options of the calendar table of the [`DatePickerOverlay`].
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct CalendarOptions {
+    /// The range of the dates that can be picked.
+    pub(crate) date_bounds: DateBounds,
+    /// Show a column with the week numbers in front of the days.
+    pub(crate) show_week_numbers: bool,
+    /// Leave the day cells of the previous and next month empty.
+    pub(crate) hide_outside_days: bool,
+    /// The weekday of the first column.
+    pub(crate) first_day_of_week: Weekday,
+    /// Select whole weeks, shown by the background of their row.
+    pub(crate) select_weeks: bool,
+}
+
+impl CalendarOptions {
+    /// Calculates the day number at the given position in the calendar table
+    /// showing the month of the given date.
+    pub(crate) fn position_to_day(
+        &self,
+        x: usize,
+        y: usize,
+        date: NaiveDate,
+    ) -> (usize, IsInMonth) {
+        crate::core::date::position_to_day_from(
+            x,
+            y,
+            date.year(),
+            date.month(),
+            self.first_day_of_week,
+        )
+    }
+
+    /// The column of the Mondays in the calendar table.
+    fn monday_column(&self) -> usize {
+        (7 - chrono::Weekday::from(self.first_day_of_week).num_days_from_monday() as usize) % 7
+    }
+
+    /// Checks if the day cell showing the given date is shown and can be picked.
+    pub(crate) fn is_selectable(&self, date: NaiveDate, is_in_month: &IsInMonth) -> bool {
+        self.date_bounds.contains(date)
+            && (!self.hide_outside_days || *is_in_month == IsInMonth::Same)
+    }
+}
+
+/// The appearances of the day cells of the [`DatePickerOverlay`].
+pub(crate) struct DayStyles {
+    /// The appearance of the days of the shown month.
+    active: DayAppearance,
+    /// The appearance of a hovered day.
+    hovered: DayAppearance,
+    /// The appearance of the selected days.
+    selected: DayAppearance,
+    /// The appearance of the current day.
+    today: DayAppearance,
+    /// The appearance of the days of the previous and next month.
+    outside_month: DayAppearance,
+    /// The appearance of the days that can not be selected.
+    disabled: DayAppearance,
+    /// The appearance of the shown month in the year/month view.
+    month_selected: DayAppearance,
+    /// The appearance of a hovered month in the year/month view.
+    month_hovered: DayAppearance,
+}
+
+impl DayStyles {
+    /// Gets the appearances of the day cells from the given theme.
+    pub(crate) fn new<Theme: StyleSheet>(
+        theme: &Theme,
+        style: &<Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            active: theme.day_active(style),
+            hovered: theme.day_hovered(style),
+            selected: theme.day_selected(style),
+            today: theme.day_today(style),
+            outside_month: theme.day_outside_month(style),
+            disabled: theme.day_disabled(style),
+            month_selected: theme.month_selected(style),
+            month_hovered: theme.month_hovered(style),
+        }
+    }
+}
+
+/// Creates the element used for the layout of the month/year row and the
+/// day table.
+/// The days are stretched to the available width unless `width` is [`Length::Shrink`].
+pub(crate) fn calendar<'e, Message, Theme>(
+    font_size: Pixels,
+    show_week_numbers: bool,
+    width: Length,
+) -> Column<'e, Message, Theme, Renderer>
+where
+    Message: 'e,
+    Theme: 'e + text::StyleSheet + container::StyleSheet,
+{
+    let fill = if width == Length::Shrink {
+        Length::Shrink
+    } else {
+        Length::Fill
+    };
+
+    let month_year = Row::<Message, Theme, Renderer>::new()
+        .width(Length::Shrink)
+        .spacing(SPACING)
+        .push(
+            Row::new()
+                .width(Length::Shrink)
+                .spacing(SPACING)
+                .align_items(Alignment::Center)
+                .push(
+                    // Left Month arrow
+                    Container::new(
+                        Text::new(icon_to_string(Bootstrap::CaretLeftFill))
+                            .size(font_size.0 + 1.0)
+                            .font(crate::BOOTSTRAP_FONT),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                )
+                .push(
+                    // Month
+                    Text::new("September").size(font_size).width(Length::Shrink),
+                )
+                .push(
+                    // Right Month arrow
+                    Container::new(
+                        Text::new(icon_to_string(Bootstrap::CaretRightFill))
+                            .size(font_size.0 + 1.0)
+                            .font(crate::BOOTSTRAP_FONT),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                ),
+        )
+        .push(
+            Row::new()
+                .width(Length::Shrink)
+                .spacing(SPACING)
+                .align_items(Alignment::Center)
+                .push(
+                    // Left Year arrow
+                    Container::new(
+                        Text::new(icon_to_string(Bootstrap::CaretLeftFill))
+                            .size(font_size.0 + 1.0)
+                            .font(BOOTSTRAP_FONT),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                )
+                .push(
+                    // Year
+                    Text::new("9999").size(font_size).width(Length::Shrink),
+                )
+                .push(
+                    // Right Year arrow
+                    Container::new(
+                        Text::new(icon_to_string(Bootstrap::CaretRightFill))
+                            .size(font_size.0 + 1.0)
+                            .font(BOOTSTRAP_FONT),
+                    )
+                    .height(Length::Shrink)
+                    .width(Length::Shrink),
+                ),
+        );
+
+    let days = Container::<Message, Theme, Renderer>::new((0..7).fold(
+        Column::new().width(fill).height(Length::Shrink),
+        |column, _y| {
+            let cells = 7 + usize::from(show_week_numbers);
+
+            column.push(
+                (0..cells).fold(
+                    Row::new()
+                        .height(Length::Shrink)
+                        .width(fill)
+                        .spacing(SPACING),
+                    |row, _x| {
+                        row.push(
+                            Container::new(Row::new().push(Text::new("31").size(font_size)))
+                                .width(fill)
+                                .height(Length::Shrink)
+                                .padding(DAY_CELL_PADDING),
+                        )
+                    },
+                ),
+            )
+        },
+    ))
+    .width(fill)
+    .height(Length::Shrink)
+    .center_y();
+
+    Column::new()
+        .width(fill)
+        .spacing(SPACING)
+        .align_items(Alignment::Center)
+        .push(month_year)
+        .push(days)
+}
+
+/// Gets the appearances of the [`DatePickerOverlay`] for each style state.
+pub(crate) fn appearances<Theme: StyleSheet>(
+    theme: &Theme,
+    style: &<Theme as StyleSheet>::Style,
+) -> HashMap<StyleState, Appearance> {
+    let mut style_sheet: HashMap<StyleState, Appearance> = HashMap::new();
+    let _ = style_sheet.insert(StyleState::Active, StyleSheet::active(theme, style));
+    let _ = style_sheet.insert(StyleState::Selected, StyleSheet::selected(theme, style));
+    let _ = style_sheet.insert(StyleState::Hovered, StyleSheet::hovered(theme, style));
+    let _ = style_sheet.insert(StyleState::Focused, StyleSheet::focused(theme, style));
+    let _ = style_sheet.insert(StyleState::Disabled, StyleSheet::disabled(theme, style));
+    style_sheet
+}
+
+/// The event handling for the month / year bar.
+pub(crate) fn on_event_month_year(
+    state: &mut State,
+    options: &CalendarOptions,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> event::Status {
+    let mut children = layout.children();
+
+    let mut status = event::Status::Ignored;
+
+    // ----------- Month ----------------------
+    let month_layout = children
+        .next()
+        .expect("Native: Layout should have a month layout");
+    let mut month_children = month_layout.children();
+
+    let left_bounds = month_children
+        .next()
+        .expect("Native: Layout should have a left month arrow layout")
+        .bounds();
+    let _center_bounds = month_children
+        .next()
+        .expect("Native: Layout should have a center month layout")
+        .bounds();
+    let right_bounds = month_children
+        .next()
+        .expect("Native: Layout should have a right month arrow layout")
+        .bounds();
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if cursor.is_over(month_layout.bounds()) {
+                state.focus = Focus::Month;
+            }
+
+            if cursor.is_over(left_bounds) {
+                state.date = options
+                    .date_bounds
+                    .clamp(crate::core::date::pred_month(state.date));
+                status = event::Status::Captured;
+            } else if cursor.is_over(right_bounds) {
+                state.date = options
+                    .date_bounds
+                    .clamp(crate::core::date::succ_month(state.date));
+                status = event::Status::Captured;
+            }
+        }
+        _ => {}
+    }
+
+    // ----------- Year -----------------------
+    let year_layout = children
+        .next()
+        .expect("Native: Layout should have a year layout");
+    let mut year_children = year_layout.children();
+
+    let left_bounds = year_children
+        .next()
+        .expect("Native: Layout should have a left year arrow layout")
+        .bounds();
+    let _center_bounds = year_children
+        .next()
+        .expect("Native: Layout should have a center year layout")
+        .bounds();
+    let right_bounds = year_children
+        .next()
+        .expect("Native: Layout should have a right year arrow layout")
+        .bounds();
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if cursor.is_over(year_layout.bounds()) {
+                state.focus = Focus::Year;
+            }
+
+            if cursor.is_over(left_bounds) {
+                state.date = options
+                    .date_bounds
+                    .clamp(crate::core::date::pred_year(state.date));
+                status = event::Status::Captured;
+            } else if cursor.is_over(right_bounds) {
+                state.date = options
+                    .date_bounds
+                    .clamp(crate::core::date::succ_year(state.date));
+                status = event::Status::Captured;
+            }
+        }
+        _ => {}
+    }
+
+    status
+}
+
+/// Gets the date of the selectable day cell below the cursor in the day table
+/// showing the month of the given date.
+#[allow(clippy::unwrap_in_result)]
+pub(crate) fn hovered_day(
+    date: NaiveDate,
+    options: &CalendarOptions,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> Option<NaiveDate> {
+    let mut children = layout.children();
+
+    let _day_labels_layout = children
+        .next()
+        .expect("Native: Layout should have a day label layout");
+
+    for (y, row) in children.enumerate() {
+        let cells = row.children().skip(usize::from(options.show_week_numbers));
+
+        for (x, label) in cells.enumerate() {
+            if cursor.is_over(label.bounds()) {
+                let (day, is_in_month) = options.position_to_day(x, y, date);
+                let cell = cell_date(date, day, &is_in_month);
+
+                return options.is_selectable(cell, &is_in_month).then_some(cell);
+            }
+        }
+    }
+
+    None
+}
+
+/// Checks if the cursor is over the month or the year label of the month/year row.
+fn header_label_hovered(layout: Layout<'_>, cursor: Cursor) -> bool {
+    layout.children().any(|child| {
+        child
+            .children()
+            .nth(1)
+            .is_some_and(|center| cursor.is_over(center.bounds()))
+    })
+}
+
+/// Gets the bounds of the month cells of the year/month view filling the given bounds.
+fn month_cells(bounds: Rectangle) -> impl Iterator<Item = (u32, Rectangle)> {
+    let width = bounds.width / MONTH_COLUMNS as f32;
+    let height = bounds.height / MONTH_ROWS as f32;
+
+    (1..=12).map(move |month| {
+        let index = month - 1;
+        #[allow(clippy::cast_precision_loss)]
+        let cell = Rectangle {
+            x: bounds.x + (index % MONTH_COLUMNS) as f32 * width,
+            y: bounds.y + (index / MONTH_COLUMNS) as f32 * height,
+            width,
+            height,
+        };
+        (month, cell)
+    })
+}
+
+/// Calculates the date in the given month of the year of the given date,
+/// keeping the day if the month is long enough.
+fn date_in_month(date: NaiveDate, month: u32) -> NaiveDate {
+    let day = date
+        .day()
+        .min(crate::core::date::num_days_of_month(date.year(), month));
+
+    NaiveDate::from_ymd_opt(date.year(), month, day).expect("Year, Month or Day doesnt Exist")
+}
+
+/// Checks if the given month of the year of the given date contains days that can be picked.
+fn is_month_selectable(date: NaiveDate, month: u32, options: &CalendarOptions) -> bool {
+    options
+        .date_bounds
+        .clamp(date_in_month(date, month))
+        .month()
+        == month
+}
+
+/// Gets the date in the month cell below the cursor in the year/month view
+/// if the month contains days that can be picked.
+fn hovered_month(
+    date: NaiveDate,
+    options: &CalendarOptions,
+    bounds: Rectangle,
+    cursor: Cursor,
+) -> Option<NaiveDate> {
+    month_cells(bounds)
+        .find(|(_, cell)| cursor.is_over(*cell))
+        .filter(|(month, _)| is_month_selectable(date, *month, options))
+        .map(|(month, _)| options.date_bounds.clamp(date_in_month(date, month)))
+}
+
+/// Gets the mouse interaction of the arrows of the month/year row.
+pub(crate) fn month_year_interaction(layout: Layout<'_>, cursor: Cursor) -> mouse::Interaction {
+    let f = |layout: Layout<'_>| {
+        let mut children = layout.children();
+
+        let left_bounds = children
+            .next()
+            .expect("Graphics: Layout should have a left arrow layout")
+            .bounds();
+        let _center = children.next();
+        let right_bounds = children
+            .next()
+            .expect("Graphics: Layout should have a right arrow layout")
+            .bounds();
+
+        if cursor.is_over(left_bounds) || cursor.is_over(right_bounds) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    };
+
+    layout.children().map(f).max().unwrap_or_default()
+}
+
+/// Calculates the date of a day cell in the calendar table showing the month
+/// of the given date.
+fn cell_date(date: NaiveDate, day: usize, is_in_month: &IsInMonth) -> NaiveDate {
+    match is_in_month {
+        IsInMonth::Previous => crate::core::date::pred_month(date)
+            .with_day(day as u32)
+            .expect("Previous month with day should be valid"),
+        IsInMonth::Same => date
+            .with_day(day as u32)
+            .expect("Same month with day should be valid"),
+        IsInMonth::Next => crate::core::date::succ_month(date)
+            .with_day(day as u32)
+            .expect("Succeeding month with day should be valid"),
+    }
+}
+
 /// Draws the month/year row
-fn month_year(
+pub(crate) fn month_year(
     renderer: &mut Renderer,
     layout: Layout<'_>,
     date: &str,
@@ -1087,6 +2031,21 @@ fn month_year(
     focus: Focus,
     font_size: Pixels,
 ) {
+    let bounds = layout.bounds();
+    if (bounds.width > 0.) && (bounds.height > 0.) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            style
+                .get(&StyleState::Active)
+                .expect("Style Sheet not found.")
+                .header_background,
+        );
+    }
+
     let mut children = layout.children();
 
     let month_layout = children
@@ -1102,6 +2061,7 @@ fn month_year(
         } else {
             StyleState::Active
         };
+        let appearance = style.get(&style_state).expect("Style Sheet not found.");
 
         let mut children = layout.children();
 
@@ -1127,29 +2087,24 @@ fn month_year(
                 renderer::Quad {
                     bounds,
                     border: Border {
-                        radius: style
-                            .get(&style_state)
-                            .expect("Style Sheet not found.")
-                            .border_radius
-                            .into(),
-                        width: style
-                            .get(&style_state)
-                            .expect("Style Sheet not found.")
-                            .border_width,
-                        color: style
-                            .get(&style_state)
-                            .expect("Style Sheet not found.")
-                            .border_color,
+                        radius: appearance.border_radius.into(),
+                        width: appearance.border_width,
+                        color: appearance.border_color,
                     },
                     shadow: Shadow::default(),
                 },
-                style
-                    .get(&style_state)
-                    .expect("Style Sheet not found.")
-                    .background,
+                appearance.header_background,
             );
         }
 
+        let icon_color = |hovered: bool| {
+            if hovered {
+                appearance.header_icon_hovered_color
+            } else {
+                appearance.header_icon_color
+            }
+        };
+
         // Left caret
         renderer.fill_text(
             iced::advanced::Text {
@@ -1163,10 +2118,7 @@ fn month_year(
                 shaping: text::Shaping::Advanced,
             },
             Point::new(left_bounds.center_x(), left_bounds.center_y()),
-            style
-                .get(&style_state)
-                .expect("Style Sheet not found.")
-                .text_color,
+            icon_color(left_arrow_hovered),
             left_bounds,
         );
 
@@ -1183,10 +2135,7 @@ fn month_year(
                 shaping: text::Shaping::Basic,
             },
             Point::new(center_bounds.center_x(), center_bounds.center_y()),
-            style
-                .get(&style_state)
-                .expect("Style Sheet not found.")
-                .text_color,
+            appearance.header_text_color,
             center_bounds,
         );
 
@@ -1203,10 +2152,7 @@ fn month_year(
                 shaping: text::Shaping::Advanced,
             },
             Point::new(right_bounds.center_x(), right_bounds.center_y()),
-            style
-                .get(&style_state)
-                .expect("Style Sheet not found.")
-                .text_color,
+            icon_color(right_arrow_hovered),
             right_bounds,
         );
     };
@@ -1220,49 +2166,135 @@ fn month_year(
     f(year_layout, year, Focus::Year, font_size);
 }
 
-/// Draws the days
-fn days(
+/// Draws the days and returns the bounds and the tooltip of the hovered
+/// highlighted day, if any.
+#[allow(clippy::too_many_arguments, clippy::unwrap_in_result)]
+pub(crate) fn days<'h>(
     renderer: &mut Renderer,
     layout: Layout<'_>,
     date: chrono::NaiveDate,
+    focused_date: chrono::NaiveDate,
+    selected_range: Option<DateRange>,
+    options: &CalendarOptions,
+    highlights: &'h [HighlightedDate],
     cursor: Point,
     //style: &Style,
     style: &HashMap<StyleState, Appearance>,
+    day_styles: &DayStyles,
     focus: Focus,
     font_size: Pixels,
-) {
+) -> Option<(Rectangle, &'h str)> {
     let mut children = layout.children();
 
     let day_labels_layout = children
         .next()
         .expect("Graphics: Layout should have a day labels layout");
-    day_labels(renderer, day_labels_layout, style, focus, font_size);
+    day_labels(
+        renderer,
+        day_labels_layout,
+        options,
+        style,
+        focus,
+        font_size,
+    );
 
     day_table(
         renderer,
         &mut children,
         date,
+        focused_date,
+        selected_range,
+        options,
+        highlights,
         cursor,
         style,
+        day_styles,
         focus,
         font_size,
-    );
+    )
+}
+
+/// Draws the months of the year/month view.
+fn months(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    date: NaiveDate,
+    options: &CalendarOptions,
+    cursor: Cursor,
+    day_styles: &DayStyles,
+    font_size: Pixels,
+) {
+    for (month, cell) in month_cells(bounds) {
+        let month_style = if !is_month_selectable(date, month, options) {
+            day_styles.disabled
+        } else if cursor.is_over(cell) {
+            day_styles.month_hovered
+        } else if month == date.month() {
+            day_styles.month_selected
+        } else {
+            day_styles.active
+        };
+
+        // Leave some space between the cells.
+        let bounds = Rectangle {
+            x: cell.x + DAY_CELL_PADDING / 2.0,
+            y: cell.y + DAY_CELL_PADDING / 2.0,
+            width: (cell.width - DAY_CELL_PADDING).max(0.0),
+            height: (cell.height - DAY_CELL_PADDING).max(0.0),
+        };
+
+        if (bounds.width > 0.) && (bounds.height > 0.) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: month_style.border_radius.min(bounds.height / 2.0).into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    shadow: Shadow::default(),
+                },
+                month_style.background,
+            );
+        }
+
+        renderer.fill_text(
+            iced::advanced::Text {
+                content: crate::core::date::month_label(month),
+                bounds: bounds.size(),
+                size: font_size,
+                font: renderer.default_font(),
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                line_height: text::LineHeight::Relative(1.3),
+                shaping: text::Shaping::Basic,
+            },
+            Point::new(bounds.center_x(), bounds.center_y()),
+            month_style.text_color,
+            bounds,
+        );
+    }
 }
 
 /// Draws the day labels
 fn day_labels(
     renderer: &mut Renderer,
     layout: Layout<'_>,
+    options: &CalendarOptions,
     style: &HashMap<StyleState, Appearance>,
     _focus: Focus,
     font_size: Pixels,
 ) {
-    for (i, label) in layout.children().enumerate() {
+    let week_number_cells = usize::from(options.show_week_numbers);
+    let first_label =
+        chrono::Weekday::from(options.first_day_of_week).num_days_from_monday() as usize;
+
+    for (i, label) in layout.children().skip(week_number_cells).enumerate() {
         let bounds = label.bounds();
 
         renderer.fill_text(
             iced::advanced::Text {
-                content: &crate::core::date::WEEKDAY_LABELS[i],
+                content: &crate::core::date::WEEKDAY_LABELS[(first_label + i) % 7],
                 bounds: Size::new(bounds.width, bounds.height),
                 size: font_size,
                 font: renderer.default_font(),
@@ -1281,52 +2313,138 @@ fn day_labels(
     }
 }
 
-/// Draws the day table
-fn day_table(
+/// Draws the day table and returns the bounds and the tooltip of the hovered
+/// highlighted day, if any.
+#[allow(clippy::too_many_arguments, clippy::unwrap_in_result)]
+fn day_table<'h>(
     renderer: &mut Renderer,
     children: &mut dyn Iterator<Item = Layout<'_>>,
     date: chrono::NaiveDate,
+    focused_date: chrono::NaiveDate,
+    selected_range: Option<DateRange>,
+    options: &CalendarOptions,
+    highlights: &'h [HighlightedDate],
     cursor: Point,
     style: &HashMap<StyleState, Appearance>,
+    day_styles: &DayStyles,
     focus: Focus,
     font_size: Pixels,
-) {
+) -> Option<(Rectangle, &'h str)> {
+    let mut hovered_tooltip = None;
+    let today = Local::now().naive_local().date();
+
     for (y, row) in children.enumerate() {
-        for (x, label) in row.children().enumerate() {
+        let mut cells = row.children();
+
+        if options.select_weeks {
+            let (number, is_in_month) = options.position_to_day(0, y, date);
+            let first_cell = cell_date(date, number, &is_in_month);
+            let bounds = row.bounds();
+
+            if selected_range.is_some_and(|range| range.contains(first_cell))
+                && (bounds.width > 0.)
+                && (bounds.height > 0.)
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            radius: day_styles
+                                .selected
+                                .border_radius
+                                .min(bounds.height / 2.0)
+                                .into(),
+                            width: 0.0,
+                            color: Color::TRANSPARENT,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    style
+                        .get(&StyleState::Active)
+                        .expect("Style Sheet not found.")
+                        .week_selected_background,
+                );
+            }
+        }
+
+        if options.show_week_numbers {
+            let bounds = cells
+                .next()
+                .expect("Graphics: Layout should have a week number layout")
+                .bounds();
+            let (number, is_in_month) = options.position_to_day(options.monday_column(), y, date);
+            let week = Date::from(cell_date(date, number, &is_in_month)).week_number();
+
+            renderer.fill_text(
+                iced::advanced::Text {
+                    content: &format!("{week:02}"),
+                    bounds: Size::new(bounds.width, bounds.height),
+                    size: font_size,
+                    font: renderer.default_font(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    line_height: text::LineHeight::Relative(1.3),
+                    shaping: text::Shaping::Basic,
+                },
+                Point::new(bounds.center_x(), bounds.center_y()),
+                style
+                    .get(&StyleState::Active)
+                    .expect("Style Sheet not found.")
+                    .week_number_color,
+                bounds,
+            );
+        }
+
+        for (x, label) in cells.enumerate() {
             let bounds = label.bounds();
-            let (number, is_in_month) =
-                crate::core::date::position_to_day(x, y, date.year(), date.month());
+            let (number, is_in_month) = options.position_to_day(x, y, date);
+
+            if options.hide_outside_days && is_in_month != IsInMonth::Same {
+                continue;
+            }
 
             let mouse_over = bounds.contains(cursor);
 
-            let selected = date.day() == number as u32 && is_in_month == IsInMonth::Same;
+            let cell = cell_date(date, number, &is_in_month);
 
-            let mut style_state = StyleState::Active;
-            if selected {
-                style_state = style_state.max(StyleState::Selected);
-            }
-            if mouse_over {
-                style_state = style_state.max(StyleState::Hovered);
-            }
+            let selected = selected_range.is_some_and(|range| range.contains(cell));
+            let focused = focused_date.day() == number as u32 && is_in_month == IsInMonth::Same;
+
+            let day_style = if !options.date_bounds.contains(cell) {
+                day_styles.disabled
+            } else if mouse_over {
+                day_styles.hovered
+            } else if selected && options.select_weeks {
+                // The background of the row shows the selected week.
+                DayAppearance {
+                    background: Color::TRANSPARENT.into(),
+                    ..day_styles.selected
+                }
+            } else if selected {
+                day_styles.selected
+            } else if cell == today {
+                day_styles.today
+            } else if is_in_month != IsInMonth::Same {
+                day_styles.outside_month
+            } else {
+                day_styles.active
+            };
 
             if (bounds.width > 0.) && (bounds.height > 0.) {
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds,
                         border: Border {
-                            radius: (bounds.height / 2.0).into(),
+                            radius: day_style.border_radius.min(bounds.height / 2.0).into(),
                             width: 0.0,
                             color: Color::TRANSPARENT,
                         },
                         shadow: Shadow::default(),
                     },
-                    style
-                        .get(&style_state)
-                        .expect("Style Sheet not found.")
-                        .day_background,
+                    day_style.background,
                 );
 
-                if focus == Focus::Day && selected {
+                if focus == Focus::Day && focused {
                     renderer.fill_quad(
                         renderer::Quad {
                             bounds,
@@ -1364,19 +2482,94 @@ fn day_table(
                     shaping: text::Shaping::Basic,
                 },
                 Point::new(bounds.center_x(), bounds.center_y()),
-                if is_in_month == IsInMonth::Same {
-                    style
-                        .get(&style_state)
-                        .expect("Style Sheet not found.")
-                        .text_color
-                } else {
-                    style
-                        .get(&style_state)
-                        .expect("Style Sheet not found.")
-                        .text_attenuated_color
-                },
+                day_style.text_color,
                 bounds,
             );
+
+            if let Some(highlight) = highlights.iter().find(|h| h.date == Date::from(cell)) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.center_x() - HIGHLIGHT_DOT_SIZE / 2.0,
+                            y: bounds.y + bounds.height - HIGHLIGHT_DOT_SIZE * 1.5,
+                            width: HIGHLIGHT_DOT_SIZE,
+                            height: HIGHLIGHT_DOT_SIZE,
+                        },
+                        border: Border {
+                            radius: (HIGHLIGHT_DOT_SIZE / 2.0).into(),
+                            width: 0.0,
+                            color: Color::TRANSPARENT,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    highlight.color,
+                );
+
+                if mouse_over {
+                    hovered_tooltip = highlight
+                        .tooltip
+                        .as_deref()
+                        .map(|tooltip| (bounds, tooltip));
+                }
+            }
         }
     }
+
+    hovered_tooltip
+}
+
+/// Draws the tooltip of a highlighted day above the day cell, kept inside of
+/// the given bounds.
+pub(crate) fn highlight_tooltip(
+    renderer: &mut Renderer,
+    cell_bounds: Rectangle,
+    tooltip: &str,
+    overlay_bounds: Rectangle,
+    style: &Appearance,
+    font_size: Pixels,
+) {
+    let text = iced::advanced::Text {
+        content: tooltip,
+        bounds: Size::INFINITY,
+        size: font_size,
+        font: renderer.default_font(),
+        horizontal_alignment: Horizontal::Center,
+        vertical_alignment: Vertical::Center,
+        line_height: text::LineHeight::Relative(1.3),
+        shaping: text::Shaping::Advanced,
+    };
+    let text_size = <Renderer as iced::advanced::text::Renderer>::Paragraph::with_text(text)
+        .min_bounds()
+        .expand(Padding::from(DAY_CELL_PADDING));
+
+    let x = (cell_bounds.center_x() - text_size.width / 2.0)
+        .min(overlay_bounds.x + overlay_bounds.width - text_size.width)
+        .max(overlay_bounds.x);
+    let tooltip_bounds = Rectangle::new(Point::new(x, cell_bounds.y - text_size.height), text_size);
+
+    // Draw on an own layer to cover the day labels below the tooltip.
+    renderer.with_layer(tooltip_bounds, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: tooltip_bounds,
+                border: Border {
+                    radius: style.border_radius.into(),
+                    width: style.border_width,
+                    color: style.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            style.background,
+        );
+
+        renderer.fill_text(
+            iced::advanced::Text {
+                bounds: tooltip_bounds.size(),
+                ..text
+            },
+            tooltip_bounds.center(),
+            style.text_color,
+            tooltip_bounds,
+        );
+    });
 }
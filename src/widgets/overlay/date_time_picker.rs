@@ -0,0 +1,174 @@
+//! Use a date time picker as an input element for picking a date and a time.
+//!
+//! *This API requires the following crate features to be activated: `date_time_picker`*
+
+use iced::{
+    advanced::{layout::Node, overlay, renderer, Clipboard, Layout, Overlay, Shell},
+    event,
+    mouse::{self, Cursor},
+    Event,
+    Point,
+    Rectangle,
+    Renderer, // the actual type
+    Size,
+};
+
+use crate::core::{date::Date, time::Time};
+
+/// The phase of the [`DateTimePickerOverlay`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DateTimePhase {
+    /// The date is picked.
+    #[default]
+    Date,
+
+    /// The time is picked after the date was submitted.
+    Time,
+}
+
+/// The messages of the date and time overlays shown by the [`DateTimePickerOverlay`].
+#[derive(Clone, Debug)]
+pub(crate) enum PhaseMessage {
+    /// The overlay of the current phase was cancelled.
+    Cancel,
+    /// The date overlay was submitted.
+    Date(Date),
+    /// The time overlay was submitted.
+    Time(Time),
+}
+
+/// The overlay of the [`DateTimePicker`](crate::widgets::DateTimePicker).
+///
+/// It shows the overlay of the current [`DateTimePhase`] and advances to the
+/// next phase once the shown overlay is submitted.
+#[allow(missing_debug_implementations)]
+pub struct DateTimePickerOverlay<'a, Message, Theme> {
+    /// The current phase of the [`DateTimePickerOverlay`].
+    phase: &'a mut DateTimePhase,
+    /// The date submitted in the date phase.
+    date: &'a mut Date,
+    /// The overlay of the current phase.
+    content: overlay::Element<'a, PhaseMessage, Theme, Renderer>,
+    /// The message that is send if the [`DateTimePickerOverlay`] is cancelled.
+    on_cancel: Message,
+    /// The function that produces a message when the time of the [`DateTimePickerOverlay`] is submitted.
+    on_submit: &'a dyn Fn(Date, Time) -> Message,
+}
+
+impl<'a, Message, Theme> DateTimePickerOverlay<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a,
+{
+    /// Creates a new [`DateTimePickerOverlay`] showing the overlay of the current phase.
+    pub(crate) fn new(
+        phase: &'a mut DateTimePhase,
+        date: &'a mut Date,
+        content: overlay::Element<'a, PhaseMessage, Theme, Renderer>,
+        on_cancel: Message,
+        on_submit: &'a dyn Fn(Date, Time) -> Message,
+    ) -> Self {
+        Self {
+            phase,
+            date,
+            content,
+            on_cancel,
+            on_submit,
+        }
+    }
+
+    /// Turn this [`DateTimePickerOverlay`] into an overlay [`Element`](overlay::Element).
+    #[must_use]
+    pub fn overlay(self) -> overlay::Element<'a, Message, Theme, Renderer> {
+        overlay::Element::new(Box::new(self))
+    }
+}
+
+impl<'a, Message, Theme> Overlay<Message, Theme, Renderer>
+    for DateTimePickerOverlay<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        self.content.layout(renderer, bounds)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let mut messages = Vec::new();
+        let mut content_shell = Shell::new(&mut messages);
+
+        let status = self.content.on_event(
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut content_shell,
+        );
+
+        if content_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if content_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+        if let Some(redraw_request) = content_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        for message in messages {
+            match message {
+                PhaseMessage::Cancel => {
+                    *self.phase = DateTimePhase::Date;
+                    shell.publish(self.on_cancel.clone());
+                }
+                PhaseMessage::Date(date) => {
+                    *self.date = date;
+                    *self.phase = DateTimePhase::Time;
+                    shell.invalidate_layout();
+                }
+                PhaseMessage::Time(time) => {
+                    *self.phase = DateTimePhase::Date;
+                    shell.publish((self.on_submit)(*self.date, time));
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .mouse_interaction(layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        self.content.draw(renderer, theme, style, layout, cursor);
+    }
+
+    fn is_over(&self, layout: Layout<'_>, renderer: &Renderer, cursor_position: Point) -> bool {
+        self.content.is_over(layout, renderer, cursor_position)
+    }
+}
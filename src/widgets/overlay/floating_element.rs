@@ -117,6 +117,12 @@ where
                 self.position.y + self.underlay_bounds.height / 2.0 - node.bounds().height / 2.0
                     + self.offset.y,
             ),
+            Anchor::Center => Point::new(
+                self.position.x + self.underlay_bounds.width / 2.0 - node.bounds().width / 2.0
+                    + self.offset.x,
+                self.position.y + self.underlay_bounds.height / 2.0 - node.bounds().height / 2.0
+                    + self.offset.y,
+            ),
         };
 
         node.move_to(position)
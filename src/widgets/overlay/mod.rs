@@ -10,6 +10,21 @@ pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePickerOverlay;
 
+#[cfg(feature = "date_picker")]
+pub mod month_picker;
+#[cfg(feature = "date_picker")]
+pub use month_picker::MonthPickerOverlay;
+
+#[cfg(feature = "date_picker")]
+pub mod year_picker;
+#[cfg(feature = "date_picker")]
+pub use year_picker::YearPickerOverlay;
+
+#[cfg(feature = "date_time_picker")]
+pub mod date_time_picker;
+#[cfg(feature = "date_time_picker")]
+pub use date_time_picker::DateTimePickerOverlay;
+
 #[cfg(feature = "floating_element")]
 pub mod floating_element;
 #[cfg(feature = "floating_element")]
@@ -20,6 +35,16 @@ pub mod modal;
 #[cfg(feature = "modal")]
 pub use modal::ModalOverlay;
 
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toast")]
+pub use toast::ToastOverlay;
+
+#[cfg(feature = "tooltip")]
+pub mod tooltip;
+#[cfg(feature = "tooltip")]
+pub use tooltip::TooltipOverlay;
+
 #[cfg(feature = "time_picker")]
 pub mod time_picker;
 #[cfg(feature = "time_picker")]
@@ -29,3 +54,8 @@ pub use time_picker::{State, TimePickerOverlay};
 pub mod context_menu;
 #[cfg(feature = "context_menu")]
 pub use context_menu::ContextMenuOverlay;
+
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "popover")]
+pub use popover::PopoverOverlay;
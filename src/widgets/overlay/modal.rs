@@ -6,7 +6,10 @@ use iced::{
     advanced::{
         layout::{Limits, Node},
         overlay, renderer,
-        widget::{Operation, Tree},
+        widget::{
+            operation::{focusable, Outcome},
+            Operation, Tree,
+        },
         Clipboard, Layout, Overlay, Shell,
     },
     alignment, event, keyboard,
@@ -133,6 +136,42 @@ where
             },
         );
 
+        // Keep the focus inside of the content while the modal is open.
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::Tab),
+            modifiers,
+            ..
+        }) = &event
+        {
+            let content_layout = layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout.");
+
+            let mut operation: Box<dyn Operation<Message>> = if modifiers.shift() {
+                Box::new(focusable::focus_previous())
+            } else {
+                Box::new(focusable::focus_next())
+            };
+
+            loop {
+                self.content.as_widget().operate(
+                    self.state,
+                    content_layout,
+                    renderer,
+                    operation.as_mut(),
+                );
+
+                match operation.finish() {
+                    Outcome::Chain(next) => operation = next,
+                    Outcome::None | Outcome::Some(_) => break,
+                }
+            }
+
+            shell.invalidate_widgets();
+            return event::Status::Captured;
+        }
+
         match esc_status.merge(backdrop_status) {
             event::Status::Ignored => self.content.as_widget_mut().on_event(
                 self.state,
@@ -0,0 +1,359 @@
+//! Use a month picker as an input element for picking a year and a month.
+//!
+//! *This API requires the following crate features to be activated: `date_picker`*
+
+use crate::{
+    core::{
+        date::month_label,
+        icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+        overlay::Position,
+    },
+    month_picker,
+    style::date_picker::{DayAppearance, StyleSheet},
+};
+
+use iced::{
+    advanced::{
+        layout::Node,
+        overlay, renderer,
+        text::{self, Renderer as _},
+        Clipboard, Layout, Overlay, Renderer as _, Shell,
+    },
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch, Border, Color, Event, Pixels, Point, Rectangle, Renderer, Shadow, Size,
+};
+
+/// The padding around the elements.
+const PADDING: f32 = 10.0;
+/// The spacing between the year row and the months.
+const SPACING: f32 = 10.0;
+/// The number of columns of the months.
+const COLUMNS: usize = 3;
+/// The number of rows of the months.
+const ROWS: usize = 4;
+/// The default minimal distance between the overlay and the edges of the window.
+const DEFAULT_MARGIN: f32 = 8.0;
+
+/// The overlay of the [`MonthPicker`](crate::widgets::MonthPicker).
+#[allow(missing_debug_implementations)]
+pub struct MonthPickerOverlay<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// The state of the [`MonthPickerOverlay`].
+    state: &'a mut month_picker::State,
+    /// The message that is send if the [`MonthPickerOverlay`] is cancelled.
+    on_cancel: Message,
+    /// The function that produces a message when a month of the [`MonthPickerOverlay`] is clicked.
+    on_submit: &'a dyn Fn(i32, u8) -> Message,
+    /// The position of the [`MonthPickerOverlay`].
+    position: Point,
+    /// The style of the [`MonthPickerOverlay`].
+    style: <Theme as StyleSheet>::Style,
+    /// The font size of text and icons in the [`MonthPickerOverlay`].
+    font_size: Pixels,
+}
+
+impl<'a, Message, Theme> MonthPickerOverlay<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a + StyleSheet,
+{
+    /// Creates a new [`MonthPickerOverlay`] on the given position.
+    pub fn new(
+        state: &'a mut month_picker::State,
+        on_cancel: Message,
+        on_submit: &'a dyn Fn(i32, u8) -> Message,
+        position: Point,
+        style: <Theme as StyleSheet>::Style,
+        font_size: Pixels,
+    ) -> Self {
+        Self {
+            state,
+            on_cancel,
+            on_submit,
+            position,
+            style,
+            font_size,
+        }
+    }
+
+    /// Turn this [`MonthPickerOverlay`] into an overlay [`Element`](overlay::Element).
+    #[must_use]
+    pub fn overlay(self) -> overlay::Element<'a, Message, Theme, Renderer> {
+        overlay::Element::new(Box::new(self))
+    }
+
+    /// The size of a month cell.
+    fn cell_size(&self) -> Size {
+        Size::new(self.font_size.0 * 4.0, self.font_size.0 * 2.5)
+    }
+
+    /// Picks the given month of the shown year and publishes the submit message.
+    fn pick(&mut self, month: u8, shell: &mut Shell<Message>) {
+        self.state.selected = Some((self.state.year, month));
+        shell.publish((self.on_submit)(self.state.year, month));
+    }
+}
+
+impl<'a, Message, Theme> Overlay<Message, Theme, Renderer>
+    for MonthPickerOverlay<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a + StyleSheet,
+{
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let cell = self.cell_size();
+        let width = cell.width * COLUMNS as f32;
+
+        let arrow = Size::new(cell.height, cell.height);
+        let year_row = Node::with_children(
+            Size::new(width, cell.height),
+            vec![
+                Node::new(arrow),
+                Node::new(Size::new(width - 2.0 * arrow.width, cell.height))
+                    .move_to(Point::new(arrow.width, 0.0)),
+                Node::new(arrow).move_to(Point::new(width - arrow.width, 0.0)),
+            ],
+        )
+        .move_to(Point::new(PADDING, PADDING));
+
+        let months = (0..COLUMNS * ROWS).map(|index| {
+            Node::new(cell).move_to(Point::new(
+                PADDING + (index % COLUMNS) as f32 * cell.width,
+                PADDING + cell.height + SPACING + (index / COLUMNS) as f32 * cell.height,
+            ))
+        });
+
+        let mut node = Node::with_children(
+            Size::new(
+                width + 2.0 * PADDING,
+                cell.height * (ROWS + 1) as f32 + SPACING + 2.0 * PADDING,
+            ),
+            std::iter::once(year_row).chain(months).collect(),
+        );
+        node.center_and_bounce_with_margin(self.position, bounds, DEFAULT_MARGIN);
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                if !layout.bounds().contains(position) {
+                    shell.publish(self.on_cancel.clone());
+                    return event::Status::Captured;
+                }
+
+                let mut children = layout.children();
+                let mut year_row = children
+                    .next()
+                    .expect("Graphics: Layout should have a year layout")
+                    .children();
+
+                let left = year_row
+                    .next()
+                    .expect("Graphics: Layout should have a left arrow layout");
+                let right = year_row
+                    .nth(1)
+                    .expect("Graphics: Layout should have a right arrow layout");
+
+                if left.bounds().contains(position) {
+                    self.state.year -= 1;
+                } else if right.bounds().contains(position) {
+                    self.state.year += 1;
+                } else if let Some(index) =
+                    children.position(|month| month.bounds().contains(position))
+                {
+                    #[allow(clippy::cast_possible_truncation)]
+                    self.pick(index as u8 + 1, shell);
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => match key {
+                keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                    shell.publish(self.on_cancel.clone());
+                    event::Status::Captured
+                }
+                keyboard::Key::Named(keyboard::key::Named::PageUp) => {
+                    self.state.year -= 1;
+                    event::Status::Captured
+                }
+                keyboard::Key::Named(keyboard::key::Named::PageDown) => {
+                    self.state.year += 1;
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            },
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let year_row = children
+            .next()
+            .expect("Graphics: Layout should have a year layout");
+
+        let over_arrow = year_row
+            .children()
+            .step_by(2)
+            .any(|arrow| cursor.is_over(arrow.bounds()));
+
+        if over_arrow || children.any(|month| cursor.is_over(month.bounds())) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        if (bounds.width > 0.) && (bounds.height > 0.) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: appearance.border_radius.into(),
+                        width: appearance.border_width,
+                        color: appearance.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.background,
+            );
+        }
+
+        let mut children = layout.children();
+        let year_row = children
+            .next()
+            .expect("Graphics: Layout should have a year layout");
+
+        // ----------- Year ---------------------------
+        let year_bounds = year_row.bounds();
+        if (year_bounds.width > 0.) && (year_bounds.height > 0.) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: year_bounds,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                appearance.header_background,
+            );
+        }
+
+        let labels = [
+            (icon_to_string(Bootstrap::CaretLeftFill), BOOTSTRAP_FONT),
+            (self.state.year.to_string(), renderer.default_font()),
+            (icon_to_string(Bootstrap::CaretRightFill), BOOTSTRAP_FONT),
+        ];
+
+        for (child, (content, font)) in year_row.children().zip(labels) {
+            let bounds = child.bounds();
+            let is_icon = font == BOOTSTRAP_FONT;
+            let hovered = is_icon && cursor.is_over(bounds);
+
+            let color = if hovered {
+                appearance.header_icon_hovered_color
+            } else if is_icon {
+                appearance.header_icon_color
+            } else {
+                appearance.header_text_color
+            };
+
+            renderer.fill_text(
+                iced::advanced::Text {
+                    content: &content,
+                    bounds: bounds.size(),
+                    size: Pixels(self.font_size.0 + if hovered { 1.0 } else { 0.0 }),
+                    font,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    line_height: text::LineHeight::Relative(1.3),
+                    shaping: text::Shaping::Basic,
+                },
+                Point::new(bounds.center_x(), bounds.center_y()),
+                color,
+                bounds,
+            );
+        }
+
+        // ----------- Months -------------------------
+        for (index, month) in children.enumerate() {
+            let bounds = month.bounds();
+            #[allow(clippy::cast_possible_truncation)]
+            let number = index as u8 + 1;
+
+            let month_style: DayAppearance = if cursor.is_over(bounds) {
+                theme.month_hovered(&self.style)
+            } else if self.state.selected == Some((self.state.year, number)) {
+                theme.month_selected(&self.style)
+            } else {
+                theme.day_active(&self.style)
+            };
+
+            if (bounds.width > 0.) && (bounds.height > 0.) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            radius: month_style.border_radius.min(bounds.height / 2.0).into(),
+                            width: 0.0,
+                            color: Color::TRANSPARENT,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    month_style.background,
+                );
+            }
+
+            renderer.fill_text(
+                iced::advanced::Text {
+                    content: month_label(u32::from(number)),
+                    bounds: bounds.size(),
+                    size: self.font_size,
+                    font: renderer.default_font(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    line_height: text::LineHeight::Relative(1.3),
+                    shaping: text::Shaping::Basic,
+                },
+                Point::new(bounds.center_x(), bounds.center_y()),
+                month_style.text_color,
+                bounds,
+            );
+        }
+    }
+}
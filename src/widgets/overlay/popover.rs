@@ -0,0 +1,386 @@
+//! Use a popover for showing floating content anchored to an element.
+//!
+//! *This API requires the following crate features to be activated: `popover`*
+use crate::{style::popover::StyleSheet, widgets::popover::PopoverPosition};
+use iced::{
+    advanced::{
+        graphics::geometry::Renderer as _,
+        layout::{Limits, Node},
+        renderer,
+        widget::Tree,
+        Clipboard, Layout, Overlay, Renderer as _, Shell,
+    },
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch,
+    widget::canvas::{Frame, Path, Stroke},
+    Border, Element, Event, Padding, Point, Rectangle, Renderer, Shadow, Size, Vector,
+};
+
+/// The distance between the tip and the base of the arrow, which is half of
+/// the width of its base.
+const ARROW_SIZE: f32 = 8.0;
+
+/// The overlay of the [`Popover`](crate::widgets::popover::Popover).
+#[allow(missing_debug_implementations)]
+pub struct PopoverOverlay<'a, 'b, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// The state of the content element.
+    state: &'b mut Tree,
+    /// The content of the popover.
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    /// The side of the target the popover is shown at.
+    position: PopoverPosition,
+    /// The bounds of the target.
+    target_bounds: Rectangle,
+    /// The gap between the target and the popover.
+    offset: f32,
+    /// Show an arrow pointing to the target.
+    show_arrow: bool,
+    /// The message that is send when the user clicks outside of the popover.
+    on_dismiss: Option<&'b Message>,
+    /// The padding between the border and the content of the popover.
+    padding: Padding,
+    /// The style of the popover.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Theme> PopoverOverlay<'a, 'b, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`PopoverOverlay`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        state: &'b mut Tree,
+        content: &'b mut Element<'a, Message, Theme, Renderer>,
+        position: PopoverPosition,
+        target_bounds: Rectangle,
+        offset: f32,
+        show_arrow: bool,
+        on_dismiss: Option<&'b Message>,
+        padding: Padding,
+        style: &'b <Theme as StyleSheet>::Style,
+    ) -> Self {
+        PopoverOverlay {
+            state,
+            content,
+            position,
+            target_bounds,
+            offset,
+            show_arrow,
+            on_dismiss,
+            padding,
+            style,
+        }
+    }
+
+    /// The gap between the target and the popover including the arrow.
+    fn gap(&self) -> f32 {
+        if self.show_arrow {
+            self.offset + ARROW_SIZE
+        } else {
+            self.offset
+        }
+    }
+
+    /// The side of the target a popover of the given size is shown at in a
+    /// window of the given size.
+    fn resolve_position(&self, size: Size, bounds: Size) -> PopoverPosition {
+        if self.position != PopoverPosition::Auto {
+            return self.position;
+        }
+
+        let target = self.target_bounds;
+        let gap = self.gap();
+        let below = bounds.height - (target.y + target.height) - gap - size.height;
+        if below >= 0.0 {
+            return PopoverPosition::Bottom;
+        }
+
+        [
+            (PopoverPosition::Top, target.y - gap - size.height),
+            (
+                PopoverPosition::Right,
+                bounds.width - (target.x + target.width) - gap - size.width,
+            ),
+            (PopoverPosition::Left, target.x - gap - size.width),
+        ]
+        .into_iter()
+        .fold((PopoverPosition::Bottom, below), |best, side| {
+            if side.1 > best.1 {
+                side
+            } else {
+                best
+            }
+        })
+        .0
+    }
+}
+
+/// The side of the target the popover with the given bounds is shown at.
+fn side_of(bounds: Rectangle, target: Rectangle) -> PopoverPosition {
+    if bounds.y >= target.y + target.height {
+        PopoverPosition::Bottom
+    } else if bounds.y + bounds.height <= target.y {
+        PopoverPosition::Top
+    } else if bounds.x >= target.x + target.width {
+        PopoverPosition::Right
+    } else {
+        PopoverPosition::Left
+    }
+}
+
+/// The tip and the two corners of the base of the arrow of a popover with the
+/// given bounds, and the direction pointing from the base into the popover.
+fn arrow(bounds: Rectangle, target: Rectangle, inset: f32) -> ([Point; 3], Vector) {
+    // The arrow stays away from the rounded corners.
+    let center_x = target.center_x().clamp(
+        bounds.x + 2.0 * ARROW_SIZE,
+        (bounds.x + bounds.width - 2.0 * ARROW_SIZE).max(bounds.x + 2.0 * ARROW_SIZE),
+    );
+    let center_y = target.center_y().clamp(
+        bounds.y + 2.0 * ARROW_SIZE,
+        (bounds.y + bounds.height - 2.0 * ARROW_SIZE).max(bounds.y + 2.0 * ARROW_SIZE),
+    );
+
+    match side_of(bounds, target) {
+        PopoverPosition::Bottom | PopoverPosition::Auto => (
+            [
+                Point::new(center_x - ARROW_SIZE, bounds.y),
+                Point::new(center_x, bounds.y - ARROW_SIZE),
+                Point::new(center_x + ARROW_SIZE, bounds.y),
+            ],
+            Vector::new(0.0, inset),
+        ),
+        PopoverPosition::Top => {
+            let bottom = bounds.y + bounds.height;
+            (
+                [
+                    Point::new(center_x - ARROW_SIZE, bottom),
+                    Point::new(center_x, bottom + ARROW_SIZE),
+                    Point::new(center_x + ARROW_SIZE, bottom),
+                ],
+                Vector::new(0.0, -inset),
+            )
+        }
+        PopoverPosition::Right => (
+            [
+                Point::new(bounds.x, center_y - ARROW_SIZE),
+                Point::new(bounds.x - ARROW_SIZE, center_y),
+                Point::new(bounds.x, center_y + ARROW_SIZE),
+            ],
+            Vector::new(inset, 0.0),
+        ),
+        PopoverPosition::Left => {
+            let right = bounds.x + bounds.width;
+            (
+                [
+                    Point::new(right, center_y - ARROW_SIZE),
+                    Point::new(right + ARROW_SIZE, center_y),
+                    Point::new(right, center_y + ARROW_SIZE),
+                ],
+                Vector::new(-inset, 0.0),
+            )
+        }
+    }
+}
+
+impl<Message, Theme> Overlay<Message, Theme, Renderer> for PopoverOverlay<'_, '_, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let limits = Limits::new(Size::ZERO, bounds).shrink(self.padding);
+        let content = self
+            .content
+            .as_widget()
+            .layout(self.state, renderer, &limits)
+            .move_to(Point::new(self.padding.left, self.padding.top));
+        let size = content.size().expand(self.padding);
+
+        let target = self.target_bounds;
+        let gap = self.gap();
+        let (x, y) = match self.resolve_position(size, bounds) {
+            PopoverPosition::Top => (
+                target.center_x() - size.width / 2.0,
+                target.y - gap - size.height,
+            ),
+            PopoverPosition::Bottom | PopoverPosition::Auto => (
+                target.center_x() - size.width / 2.0,
+                target.y + target.height + gap,
+            ),
+            PopoverPosition::Left => (
+                target.x - gap - size.width,
+                target.center_y() - size.height / 2.0,
+            ),
+            PopoverPosition::Right => (
+                target.x + target.width + gap,
+                target.center_y() - size.height / 2.0,
+            ),
+        };
+
+        // Keep the popover inside of the window.
+        let position = Point::new(
+            x.clamp(0.0, (bounds.width - size.width).max(0.0)),
+            y.clamp(0.0, (bounds.height - size.height).max(0.0)),
+        );
+
+        Node::with_children(size, vec![content]).move_to(position)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        if let Some(on_dismiss) = self.on_dismiss {
+            match &event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                    ..
+                }) => {
+                    shell.publish(on_dismiss.clone());
+                }
+                Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                    if !cursor.is_over(layout.bounds()) && !cursor.is_over(self.target_bounds) =>
+                {
+                    shell.publish(on_dismiss.clone());
+                }
+                _ => {}
+            }
+        }
+
+        let bounds = layout.bounds();
+        self.content.as_widget_mut().on_event(
+            self.state,
+            event,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &bounds,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.state,
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style_sheet = theme.active(self.style);
+
+        // Background
+        if (bounds.width > 0.) && (bounds.height > 0.) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: style_sheet.border_radius.into(),
+                        width: style_sheet.border_width,
+                        color: style_sheet.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                style_sheet.background,
+            );
+        }
+
+        // Arrow
+        if self.show_arrow {
+            let area = Rectangle {
+                x: bounds.x - ARROW_SIZE,
+                y: bounds.y - ARROW_SIZE,
+                width: bounds.width + 2.0 * ARROW_SIZE,
+                height: bounds.height + 2.0 * ARROW_SIZE,
+            };
+            let origin = Vector::new(area.x, area.y);
+            let ([start, tip, end], inset) =
+                arrow(bounds, self.target_bounds, style_sheet.border_width);
+            let (start, tip, end) = (start - origin, tip - origin, end - origin);
+
+            let mut frame = Frame::new(renderer, area.size());
+            // The filled base covers the border of the popover below the arrow.
+            let fill = Path::new(|builder| {
+                builder.move_to(start + inset);
+                builder.line_to(start);
+                builder.line_to(tip);
+                builder.line_to(end);
+                builder.line_to(end + inset);
+                builder.close();
+            });
+            frame.fill(&fill, style_sheet.background);
+
+            if style_sheet.border_width > 0.0 {
+                let outline = Path::new(|builder| {
+                    builder.move_to(start);
+                    builder.line_to(tip);
+                    builder.line_to(end);
+                });
+                frame.stroke(
+                    &outline,
+                    Stroke::default()
+                        .with_color(style_sheet.border_color)
+                        .with_width(style_sheet.border_width),
+                );
+            }
+
+            let geometry = frame.into_geometry();
+            renderer.with_translation(origin, |renderer| {
+                renderer.draw(vec![geometry]);
+            });
+        }
+
+        self.content.as_widget().draw(
+            self.state,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: style_sheet.text_color,
+            },
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor,
+            &bounds,
+        );
+    }
+}
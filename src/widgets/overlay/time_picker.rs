@@ -18,6 +18,7 @@ use crate::{
 };
 
 use chrono::{Duration, Local, NaiveTime, Timelike};
+use iced::window;
 use iced::{
     advanced::{
         graphics::geometry::Renderer as _,
@@ -66,8 +67,10 @@ const BUTTON_SPACING: f32 = 5.0;
 const NUMBER_SIZE_PERCENTAGE: f32 = 0.15;
 /// The percentage size of the period.
 const PERIOD_SIZE_PERCENTAGE: f32 = 0.2;
+/// The size of the button switching between the clock and the digital mode.
+const MODE_BUTTON_SIZE: f32 = 20.0;
 
-/// The overlay of the [`TimePicker`](crate::native::TimePicker).
+/// The overlay of the [`TimePicker`](crate::widgets::TimePicker).
 #[allow(missing_debug_implementations)]
 pub struct TimePickerOverlay<'a, Message, Theme>
 where
@@ -80,6 +83,8 @@ where
     cancel_button: Button<'a, Message, Theme, Renderer>,
     /// The submit button of the [`TimePickerOverlay`].
     submit_button: Button<'a, Message, Theme, Renderer>,
+    /// The message that is send if the [`TimePickerOverlay`] is cancelled.
+    on_cancel: Message,
     /// The function that produces a message when the submit button of the [`TimePickerOverlay`] is pressed.
     on_submit: &'a dyn Fn(Time) -> Message,
     /// The position of the [`TimePickerOverlay`].
@@ -88,6 +93,8 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The reference to the tree holding the state of this overlay.
     tree: &'a mut Tree,
+    /// The interval the minutes and seconds of the [`TimePickerOverlay`] snap to.
+    step: u32,
 }
 
 impl<'a, Message, Theme> TimePickerOverlay<'a, Message, Theme>
@@ -104,7 +111,7 @@ where
         style: <Theme as StyleSheet>::Style,
         tree: &'a mut Tree,
     ) -> Self {
-        let time_picker::State { overlay_state } = state;
+        let time_picker::State { overlay_state, .. } = state;
 
         TimePickerOverlay {
             state: overlay_state,
@@ -123,191 +130,40 @@ where
                     .width(Length::Fill),
             )
             .width(Length::Fill)
-            .on_press(on_cancel), // Sending a fake message
+            .on_press(on_cancel.clone()), // Sending a fake message
+            on_cancel,
             on_submit,
             position,
             style,
             tree,
+            step: 1,
         }
     }
 
+    /// Sets the interval the minutes and seconds of the [`TimePickerOverlay`] snap to.
+    #[must_use]
+    pub fn step(mut self, step: u8) -> Self {
+        self.step = u32::from(step.clamp(1, 60));
+        self
+    }
+
     /// Turn this [`TimePickerOverlay`] into an overlay [`Element`](overlay::Element).
     #[must_use]
     pub fn overlay(self) -> overlay::Element<'a, Message, Theme, Renderer> {
         overlay::Element::new(Box::new(self))
     }
 
-    /// The event handling for the clock.
-    #[allow(clippy::too_many_lines)]
-    fn on_event_clock(
-        &mut self,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor: Cursor,
-        _shell: &mut Shell<Message>,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-    ) -> event::Status {
-        if cursor.is_over(layout.bounds()) {
-            self.state.clock_cache_needs_clearance = true;
-            self.state.clock_cache.clear();
-        } else if self.state.clock_cache_needs_clearance {
-            self.state.clock_cache.clear();
-            self.state.clock_cache_needs_clearance = false;
-        }
-
-        let clock_bounds = layout.bounds();
-        if cursor.is_over(clock_bounds) {
-            let center = clock_bounds.center();
-            let radius = clock_bounds.width.min(clock_bounds.height) * 0.5;
-
-            let period_radius = radius * PERIOD_PERCENTAGE;
-
-            let (hour_radius, minute_radius, second_radius) = if self.state.show_seconds {
-                (
-                    radius * HOUR_RADIUS_PERCENTAGE,
-                    radius * MINUTE_RADIUS_PERCENTAGE,
-                    radius * SECOND_RADIUS_PERCENTAGE,
-                )
-            } else {
-                (
-                    radius * HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
-                    radius * MINUTE_RADIUS_PERCENTAGE_NO_SECONDS,
-                    f32::MAX,
-                )
-            };
-
-            let nearest_radius = crate::core::clock::nearest_radius(
-                &if self.state.show_seconds {
-                    vec![
-                        (period_radius, NearestRadius::Period),
-                        (hour_radius, NearestRadius::Hour),
-                        (minute_radius, NearestRadius::Minute),
-                        (second_radius, NearestRadius::Second),
-                    ]
-                } else {
-                    vec![
-                        (period_radius, NearestRadius::Period),
-                        (hour_radius, NearestRadius::Hour),
-                        (minute_radius, NearestRadius::Minute),
-                    ]
-                },
-                cursor.position().unwrap_or_default(),
-                center,
-            );
-
-            let clock_clicked_status = match event {
-                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerPressed { .. }) => match nearest_radius {
-                    NearestRadius::Period => {
-                        let (pm, hour) = self.state.time.hour12();
-                        let hour = if hour == 12 {
-                            if pm {
-                                12
-                            } else {
-                                0
-                            }
-                        } else {
-                            hour
-                        };
-
-                        self.state.time = self
-                            .state
-                            .time
-                            .with_hour(if pm && hour != 12 { hour } else { hour + 12 } % 24)
-                            .expect("New time with hour should be valid");
-                        event::Status::Captured
-                    }
-                    NearestRadius::Hour => {
-                        self.state.focus = Focus::DigitalHour;
-                        self.state.clock_dragged = ClockDragged::Hour;
-                        event::Status::Captured
-                    }
-                    NearestRadius::Minute => {
-                        self.state.focus = Focus::DigitalMinute;
-                        self.state.clock_dragged = ClockDragged::Minute;
-                        event::Status::Captured
-                    }
-                    NearestRadius::Second => {
-                        self.state.focus = Focus::DigitalSecond;
-                        self.state.clock_dragged = ClockDragged::Second;
-                        event::Status::Captured
-                    }
-                    NearestRadius::None => event::Status::Ignored,
-                },
-                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                | Event::Touch(
-                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
-                ) => {
-                    self.state.clock_dragged = ClockDragged::None;
-                    event::Status::Captured
-                }
-                _ => event::Status::Ignored,
-            };
-
-            let clock_dragged_status = match self.state.clock_dragged {
-                ClockDragged::Hour => {
-                    let hour_points = crate::core::clock::circle_points(hour_radius, center, 12);
-                    let nearest_point = crate::core::clock::nearest_point(
-                        &hour_points,
-                        cursor.position().unwrap_or_default(),
-                    );
-
-                    let (pm, _) = self.state.time.hour12();
-
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_hour((nearest_point as u32 + if pm { 12 } else { 0 }) % 24)
-                        .expect("New time with hour should be valid");
-                    event::Status::Captured
-                }
-                ClockDragged::Minute => {
-                    let minute_points =
-                        crate::core::clock::circle_points(minute_radius, center, 60);
-                    let nearest_point = crate::core::clock::nearest_point(
-                        &minute_points,
-                        cursor.position().unwrap_or_default(),
-                    );
-
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_minute(nearest_point as u32)
-                        .expect("New time with minute should be valid");
-                    event::Status::Captured
-                }
-                ClockDragged::Second => {
-                    let second_points =
-                        crate::core::clock::circle_points(second_radius, center, 60);
-                    let nearest_point = crate::core::clock::nearest_point(
-                        &second_points,
-                        cursor.position().unwrap_or_default(),
-                    );
-
-                    self.state.time = self
-                        .state
-                        .time
-                        .with_second(nearest_point as u32)
-                        .expect("New time with second should be valid");
-                    event::Status::Captured
-                }
-                ClockDragged::None => event::Status::Ignored,
-            };
+    /// Publishes the submit message with the selected time.
+    fn submit(&self, shell: &mut Shell<Message>) {
+        shell.publish((self.on_submit)(self.state.selected_time()));
+        // The picker hands the focus back to its underlay on the next frame.
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
 
-            clock_clicked_status.merge(clock_dragged_status)
-        } else {
-            match event {
-                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                | Event::Touch(
-                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
-                ) => {
-                    self.state.clock_dragged = ClockDragged::None;
-                    event::Status::Captured
-                }
-                _ => event::Status::Ignored,
-            }
-        }
+    /// Publishes the cancel message.
+    fn cancel(&self, shell: &mut Shell<Message>) {
+        shell.publish(self.on_cancel.clone());
+        shell.request_redraw(window::RedrawRequest::NextFrame);
     }
 
     /// The event handling for the digital clock.
@@ -356,20 +212,19 @@ where
             .next()
             .expect("Native: Layout should have a down arrow for minutes");
 
-        let calculate_time = |time: &mut NaiveTime,
-                              up_arrow: Layout<'_>,
-                              down_arrow: Layout<'_>,
-                              duration: Duration| {
-            if cursor.is_over(up_arrow.bounds()) {
-                *time += duration;
-                event::Status::Captured
-            } else if cursor.is_over(down_arrow.bounds()) {
-                *time -= duration;
-                event::Status::Captured
-            } else {
-                event::Status::Ignored
-            }
-        };
+        let step = self.step;
+        let calculate_time =
+            |time: &mut NaiveTime, up_arrow: Layout<'_>, down_arrow: Layout<'_>, focus: Focus| {
+                if cursor.is_over(up_arrow.bounds()) {
+                    step_time(time, focus, step, true);
+                    event::Status::Captured
+                } else if cursor.is_over(down_arrow.bounds()) {
+                    step_time(time, focus, step, false);
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            };
 
         let digital_clock_status = match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
@@ -381,7 +236,7 @@ where
                         &mut self.state.time,
                         hour_up_arrow,
                         hour_down_arrow,
-                        Duration::hours(1),
+                        Focus::DigitalHour,
                     )
                 } else if cursor.is_over(minute_layout.bounds()) {
                     self.state.focus = Focus::DigitalMinute;
@@ -390,12 +245,29 @@ where
                         &mut self.state.time,
                         minute_up_arrow,
                         minute_down_arrow,
-                        Duration::minutes(1),
+                        Focus::DigitalMinute,
                     )
                 } else {
                     event::Status::Ignored
                 }
             }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let focus = if cursor.is_over(hour_layout.bounds()) {
+                    Some(Focus::DigitalHour)
+                } else if cursor.is_over(minute_layout.bounds()) {
+                    Some(Focus::DigitalMinute)
+                } else {
+                    None
+                };
+
+                match (focus, scroll_direction(*delta)) {
+                    (Some(focus), Some(forward)) => {
+                        step_time(&mut self.state.time, focus, step, forward);
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
             _ => event::Status::Ignored,
         };
 
@@ -425,19 +297,61 @@ where
                             &mut self.state.time,
                             second_up_arrow,
                             second_down_arrow,
-                            Duration::seconds(1),
+                            Focus::DigitalSecond,
                         )
                     } else {
                         event::Status::Ignored
                     }
                 }
+                Event::Mouse(mouse::Event::WheelScrolled { delta })
+                    if cursor.is_over(second_layout.bounds()) =>
+                {
+                    scroll_direction(*delta).map_or(event::Status::Ignored, |forward| {
+                        step_time(&mut self.state.time, Focus::DigitalSecond, step, forward);
+                        event::Status::Captured
+                    })
+                }
                 _ => event::Status::Ignored,
             }
         } else {
             event::Status::Ignored
         };
 
-        let digital_clock_status = digital_clock_status.merge(second_status);
+        let period_status = if self.state.use_24h {
+            event::Status::Ignored
+        } else {
+            let mut period_children = digital_clock_children
+                .next()
+                .expect("Native: Layout should have a period layout")
+                .children();
+
+            let am_layout = period_children
+                .next()
+                .expect("Native: Layout should have an AM button layout");
+            let pm_layout = period_children
+                .next()
+                .expect("Native: Layout should have a PM button layout");
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    if cursor.is_over(am_layout.bounds()) {
+                        self.state.set_pm(false);
+                        event::Status::Captured
+                    } else if cursor.is_over(pm_layout.bounds()) {
+                        self.state.set_pm(true);
+                        event::Status::Captured
+                    } else {
+                        event::Status::Ignored
+                    }
+                }
+                _ => event::Status::Ignored,
+            }
+        };
+
+        let digital_clock_status = digital_clock_status
+            .merge(second_status)
+            .merge(period_status);
 
         if digital_clock_status == event::Status::Captured {
             self.state.clock_cache.clear();
@@ -452,55 +366,75 @@ where
         event: &Event,
         _layout: Layout<'_>,
         _cursor: Cursor,
-        _shell: &mut Shell<Message>,
+        shell: &mut Shell<Message>,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
     ) -> event::Status {
-        if self.state.focus == Focus::None {
-            return event::Status::Ignored;
-        }
-
         if let Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = event {
             let mut status = event::Status::Ignored;
 
-            if matches!(key, keyboard::Key::Named(keyboard::key::Named::Tab)) {
-                if self.state.keyboard_modifiers.shift() {
-                    self.state.focus = self.state.focus.previous(self.state.show_seconds);
-                } else {
-                    self.state.focus = self.state.focus.next(self.state.show_seconds);
-                }
-            } else {
-                let mut keyboard_handle =
-                    |key_code: &keyboard::Key, time: &mut NaiveTime, duration: Duration| {
-                        match key_code {
-                            keyboard::Key::Named(
-                                keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown,
-                            ) => {
-                                *time -= duration;
-                                status = event::Status::Captured;
-                            }
-                            keyboard::Key::Named(
-                                keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp,
-                            ) => {
-                                *time += duration;
-                                status = event::Status::Captured;
-                            }
-                            _ => {}
-                        }
-                    };
+            let digit = match key.as_ref() {
+                keyboard::Key::Character(c) => c.parse::<u32>().ok().filter(|digit| *digit < 10),
+                _ => None,
+            };
+            if digit.is_none() {
+                self.state.typed_digit = None;
+            }
 
-                match self.state.focus {
-                    Focus::DigitalHour => {
-                        keyboard_handle(key, &mut self.state.time, Duration::hours(1));
+            match key.as_ref() {
+                keyboard::Key::Character(_)
+                    if digit.is_some()
+                        && matches!(
+                            self.state.focus,
+                            Focus::DigitalHour | Focus::DigitalMinute | Focus::DigitalSecond
+                        ) =>
+                {
+                    self.state.enter_digit(digit.unwrap_or_default());
+                    status = event::Status::Captured;
+                }
+                keyboard::Key::Named(keyboard::key::Named::Tab) => {
+                    if self.state.keyboard_modifiers.shift() {
+                        self.state.focus = self.state.focus.previous(self.state.show_seconds);
+                    } else {
+                        self.state.focus = self.state.focus.next(self.state.show_seconds);
                     }
-                    Focus::DigitalMinute => {
-                        keyboard_handle(key, &mut self.state.time, Duration::minutes(1));
+                    status = event::Status::Captured;
+                }
+                keyboard::Key::Named(keyboard::key::Named::Escape)
+                    if self.state.focus != Focus::None =>
+                {
+                    self.cancel(shell);
+                    status = event::Status::Captured;
+                }
+                keyboard::Key::Named(keyboard::key::Named::Enter)
+                    if self.state.focus != Focus::None =>
+                {
+                    if self.state.focus == Focus::Cancel {
+                        self.cancel(shell);
+                    } else {
+                        self.submit(shell);
                     }
-                    Focus::DigitalSecond => {
-                        keyboard_handle(key, &mut self.state.time, Duration::seconds(1));
+                    status = event::Status::Captured;
+                }
+                keyboard::Key::Named(k)
+                    if matches!(
+                        self.state.focus,
+                        Focus::DigitalHour | Focus::DigitalMinute | Focus::DigitalSecond
+                    ) =>
+                {
+                    match k {
+                        keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown => {
+                            step_time(&mut self.state.time, self.state.focus, self.step, false);
+                            status = event::Status::Captured;
+                        }
+                        keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp => {
+                            step_time(&mut self.state.time, self.state.focus, self.step, true);
+                            status = event::Status::Captured;
+                        }
+                        _ => {}
                     }
-                    _ => {}
                 }
+                _ => {}
             }
 
             if status == event::Status::Captured {
@@ -545,10 +479,14 @@ where
             digital_clock.bounds().height + cancel_button.bounds().height + 2.0 * SPACING,
         ));
 
-        // Clock-Canvas
+        // Clock-Canvas, only leaving space for the mode button in the digital mode
         let mut clock = Row::<(), Renderer>::new()
             .width(Length::Fill)
-            .height(Length::Fill)
+            .height(if self.state.digital_mode {
+                Length::Fixed(MODE_BUTTON_SIZE)
+            } else {
+                Length::Fill
+            })
             .layout(self.tree, renderer, &limits);
 
         let clock_bounds = clock.bounds();
@@ -598,6 +536,11 @@ where
                 + 2.0 * SPACING,
         });
 
+        // Mode button in the top right corner of the clock
+        let mode_button = Node::new(Size::new(MODE_BUTTON_SIZE, MODE_BUTTON_SIZE)).move_to(
+            Point::new(PADDING + clock.bounds().width - MODE_BUTTON_SIZE, PADDING),
+        );
+
         let mut node = Node::with_children(
             Size::new(
                 clock.bounds().width + (2.0 * PADDING),
@@ -607,7 +550,13 @@ where
                     + (2.0 * PADDING)
                     + 2.0 * SPACING,
             ),
-            vec![clock, digital_clock, cancel_button, submit_button],
+            vec![
+                clock,
+                digital_clock,
+                cancel_button,
+                submit_button,
+                mode_button,
+            ],
         );
 
         node.center_and_bounce(self.position, bounds);
@@ -629,14 +578,21 @@ where
             return event::Status::Captured;
         }
 
+        if let Event::Mouse(mouse::Event::ButtonPressed(_)) | Event::Touch(_) = event {
+            self.state.typed_digit = None;
+        }
+
         let mut children = layout.children();
 
         // Clock canvas
         let clock_layout = children
             .next()
             .expect("Native: Layout should have a clock canvas layout");
-        let clock_status =
-            self.on_event_clock(&event, clock_layout, cursor, shell, renderer, clipboard);
+        let clock_status = if self.state.digital_mode {
+            event::Status::Ignored
+        } else {
+            on_event_clock(self.state, self.step, &event, clock_layout, cursor)
+        };
 
         // ----------- Digital clock ------------------
         let digital_clock_layout = children
@@ -659,6 +615,8 @@ where
             .next()
             .expect("Native: Layout should have a cancel button layout for a TimePicker");
 
+        let mut fake_messages: Vec<Message> = Vec::new();
+
         let cancel_status = self.cancel_button.on_event(
             &mut self.tree.children[0],
             event.clone(),
@@ -666,19 +624,23 @@ where
             cursor,
             renderer,
             clipboard,
-            shell,
+            &mut Shell::new(&mut fake_messages),
             &layout.bounds(),
         );
 
+        if !fake_messages.is_empty() {
+            self.cancel(shell);
+        }
+
         let submit_button_layout = children
             .next()
             .expect("Native: Layout should have a submit button layout for a TimePicker");
 
-        let mut fake_messages: Vec<Message> = Vec::new();
+        fake_messages.clear();
 
         let submit_status = self.submit_button.on_event(
             &mut self.tree.children[1],
-            event,
+            event.clone(),
             submit_button_layout,
             cursor,
             renderer,
@@ -688,32 +650,28 @@ where
         );
 
         if !fake_messages.is_empty() {
-            let (hour, period) = if self.state.use_24h {
-                (self.state.time.hour(), Period::H24)
-            } else {
-                let (period, hour) = self.state.time.hour12();
-                (hour, if period { Period::Pm } else { Period::Am })
-            };
+            self.submit(shell);
+        }
 
-            let time = if self.state.show_seconds {
-                Time::Hms {
-                    hour,
-                    minute: self.state.time.minute(),
-                    second: self.state.time.second(),
-                    period,
-                }
-            } else {
-                Time::Hm {
-                    hour,
-                    minute: self.state.time.minute(),
-                    period,
-                }
-            };
+        // ----------- Mode button --------------------
+        let mode_button_layout = children
+            .next()
+            .expect("Native: Layout should have a mode button layout for a TimePicker");
 
-            shell.publish((self.on_submit)(time));
-        }
+        let mode_status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(mode_button_layout.bounds()) =>
+            {
+                self.state.digital_mode = !self.state.digital_mode;
+                self.state.clock_cache.clear();
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        };
 
-        clock_status
+        mode_status
+            .merge(clock_status)
             .merge(digital_clock_status)
             .merge(cancel_status)
             .merge(submit_status)
@@ -733,11 +691,12 @@ where
         let clock_layout = children
             .next()
             .expect("Graphics: Layout should have a clock canvas layout");
-        let clock_mouse_interaction = if cursor.is_over(clock_layout.bounds()) {
-            mouse::Interaction::Pointer
-        } else {
-            mouse::Interaction::default()
-        };
+        let clock_mouse_interaction =
+            if !self.state.digital_mode && cursor.is_over(clock_layout.bounds()) {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
 
         // Digital clock
         let digital_clock_layout = children
@@ -803,6 +762,22 @@ where
             mouse::Interaction::default()
         };
 
+        let period_mouse_interaction = if self.state.use_24h {
+            mouse::Interaction::default()
+        } else {
+            let period_layout = digital_clock_children
+                .next()
+                .expect("Graphics: Layout should have a period layout");
+            if period_layout
+                .children()
+                .any(|button| cursor.is_over(button.bounds()))
+            {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            }
+        };
+
         // Buttons
         let cancel_button_layout = children
             .next()
@@ -828,11 +803,22 @@ where
             renderer,
         );
 
+        let mode_button_layout = children
+            .next()
+            .expect("Graphics: Layout should have a mode button layout for a TimePicker");
+        let mode_mouse_interaction = if cursor.is_over(mode_button_layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
+
         mouse_interaction
+            .max(mode_mouse_interaction)
             .max(clock_mouse_interaction)
             .max(hour_mouse_interaction)
             .max(minute_mouse_interaction)
             .max(second_mouse_interaction)
+            .max(period_mouse_interaction)
             .max(cancel_mouse_interaction)
             .max(submit_mouse_interaction)
     }
@@ -848,14 +834,7 @@ where
         let bounds = layout.bounds();
         let mut children = layout.children();
 
-        let mut style_sheet: HashMap<StyleState, Appearance> = HashMap::new();
-        let _ = style_sheet.insert(StyleState::Active, StyleSheet::active(theme, &self.style));
-        let _ = style_sheet.insert(
-            StyleState::Selected,
-            StyleSheet::selected(theme, &self.style),
-        );
-        let _ = style_sheet.insert(StyleState::Hovered, StyleSheet::hovered(theme, &self.style));
-        let _ = style_sheet.insert(StyleState::Focused, StyleSheet::focused(theme, &self.style));
+        let style_sheet = appearances(theme, &self.style);
 
         let mut style_state = StyleState::Active;
         if self.state.focus == Focus::Overlay {
@@ -885,13 +864,29 @@ where
         let clock_layout = children
             .next()
             .expect("Graphics: Layout should have a clock canvas layout");
-        draw_clock(renderer, self, clock_layout, cursor, &style_sheet);
+        if !self.state.digital_mode {
+            draw_clock(
+                renderer,
+                self.state,
+                self.step,
+                clock_layout,
+                cursor,
+                &style_sheet,
+            );
+        }
 
         // ----------- Digital clock ------------------
         let digital_clock_layout = children
             .next()
             .expect("Graphics: Layout should have a digital clock layout");
-        draw_digital_clock(renderer, self, digital_clock_layout, cursor, &style_sheet);
+        draw_digital_clock(
+            renderer,
+            self,
+            theme,
+            digital_clock_layout,
+            cursor,
+            &style_sheet,
+        );
 
         // ----------- Buttons ------------------------
         let cancel_button_layout = children
@@ -960,6 +955,272 @@ where
                 Color::TRANSPARENT,
             );
         }
+
+        // ----------- Mode button --------------------
+        let mode_button_bounds = children
+            .next()
+            .expect("Graphics: Layout should have a mode button layout for a TimePicker")
+            .bounds();
+        let mode_icon = if self.state.digital_mode {
+            Bootstrap::Clock
+        } else {
+            Bootstrap::Keyboard
+        };
+
+        renderer.fill_text(
+            Text {
+                content: &icon_to_string(mode_icon),
+                bounds: mode_button_bounds.size(),
+                size: Pixels(
+                    renderer.default_size().0
+                        + if cursor.is_over(mode_button_bounds) {
+                            1.0
+                        } else {
+                            0.0
+                        },
+                ),
+                font: BOOTSTRAP_FONT,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                line_height: text::LineHeight::Relative(1.3),
+                shaping: text::Shaping::Basic,
+            },
+            Point::new(mode_button_bounds.center_x(), mode_button_bounds.center_y()),
+            style_sheet[&StyleState::Active].text_color,
+            mode_button_bounds,
+        );
+    }
+}
+
+/// Gets the appearances of the [`TimePickerOverlay`] for each style state.
+pub(crate) fn appearances<Theme: StyleSheet>(
+    theme: &Theme,
+    style: &<Theme as StyleSheet>::Style,
+) -> HashMap<StyleState, Appearance> {
+    let mut style_sheet: HashMap<StyleState, Appearance> = HashMap::new();
+    let _ = style_sheet.insert(StyleState::Active, StyleSheet::active(theme, style));
+    let _ = style_sheet.insert(StyleState::Selected, StyleSheet::selected(theme, style));
+    let _ = style_sheet.insert(StyleState::Hovered, StyleSheet::hovered(theme, style));
+    let _ = style_sheet.insert(StyleState::Focused, StyleSheet::focused(theme, style));
+    let _ = style_sheet.insert(StyleState::Disabled, StyleSheet::disabled(theme, style));
+    style_sheet
+}
+
+/// The event handling for the clock.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn on_event_clock(
+    state: &mut State,
+    step: u32,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: Cursor,
+) -> event::Status {
+    if cursor.is_over(layout.bounds()) {
+        state.clock_cache_needs_clearance = true;
+        state.clock_cache.clear();
+    } else if state.clock_cache_needs_clearance {
+        state.clock_cache.clear();
+        state.clock_cache_needs_clearance = false;
+    }
+
+    let clock_bounds = layout.bounds();
+    if cursor.is_over(clock_bounds) {
+        let center = clock_bounds.center();
+        let radius = clock_bounds.width.min(clock_bounds.height) * 0.5;
+
+        let period_radius = radius * PERIOD_PERCENTAGE;
+
+        let (hour_radius, minute_radius, second_radius) = if state.show_seconds {
+            (
+                radius * HOUR_RADIUS_PERCENTAGE,
+                radius * MINUTE_RADIUS_PERCENTAGE,
+                radius * SECOND_RADIUS_PERCENTAGE,
+            )
+        } else {
+            (
+                radius * HOUR_RADIUS_PERCENTAGE_NO_SECONDS,
+                radius * MINUTE_RADIUS_PERCENTAGE_NO_SECONDS,
+                f32::MAX,
+            )
+        };
+
+        let nearest_radius = crate::core::clock::nearest_radius(
+            &if state.show_seconds {
+                vec![
+                    (period_radius, NearestRadius::Period),
+                    (hour_radius, NearestRadius::Hour),
+                    (minute_radius, NearestRadius::Minute),
+                    (second_radius, NearestRadius::Second),
+                ]
+            } else {
+                vec![
+                    (period_radius, NearestRadius::Period),
+                    (hour_radius, NearestRadius::Hour),
+                    (minute_radius, NearestRadius::Minute),
+                ]
+            },
+            cursor.position().unwrap_or_default(),
+            center,
+        );
+
+        let clock_clicked_status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => match nearest_radius {
+                NearestRadius::Period => {
+                    state.set_pm(!state.is_pm());
+                    event::Status::Captured
+                }
+                NearestRadius::Hour => {
+                    state.focus = Focus::DigitalHour;
+                    state.clock_dragged = ClockDragged::Hour;
+                    event::Status::Captured
+                }
+                NearestRadius::Minute => {
+                    let minute_points =
+                        crate::core::clock::circle_points(minute_radius, center, 60);
+                    let nearest_point = crate::core::clock::nearest_point(
+                        &minute_points,
+                        cursor.position().unwrap_or_default(),
+                    );
+
+                    if (nearest_point as u32).is_multiple_of(step) {
+                        state.focus = Focus::DigitalMinute;
+                        state.clock_dragged = ClockDragged::Minute;
+                        event::Status::Captured
+                    } else {
+                        event::Status::Ignored
+                    }
+                }
+                NearestRadius::Second => {
+                    let second_points =
+                        crate::core::clock::circle_points(second_radius, center, 60);
+                    let nearest_point = crate::core::clock::nearest_point(
+                        &second_points,
+                        cursor.position().unwrap_or_default(),
+                    );
+
+                    if (nearest_point as u32).is_multiple_of(step) {
+                        state.focus = Focus::DigitalSecond;
+                        state.clock_dragged = ClockDragged::Second;
+                        event::Status::Captured
+                    } else {
+                        event::Status::Ignored
+                    }
+                }
+                NearestRadius::None => event::Status::Ignored,
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                // Move on to the next hand after picking the hour or minute.
+                match state.clock_dragged {
+                    ClockDragged::Hour => state.focus = Focus::DigitalMinute,
+                    ClockDragged::Minute if state.show_seconds => {
+                        state.focus = Focus::DigitalSecond;
+                    }
+                    _ => {}
+                }
+                state.clock_dragged = ClockDragged::None;
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        };
+
+        let clock_dragged_status = match state.clock_dragged {
+            ClockDragged::Hour => {
+                let hour_points = crate::core::clock::circle_points(hour_radius, center, 12);
+                let nearest_point = crate::core::clock::nearest_point(
+                    &hour_points,
+                    cursor.position().unwrap_or_default(),
+                );
+
+                let (pm, _) = state.time.hour12();
+
+                state.time = state
+                    .time
+                    .with_hour((nearest_point as u32 + if pm { 12 } else { 0 }) % 24)
+                    .expect("New time with hour should be valid");
+                event::Status::Captured
+            }
+            ClockDragged::Minute => {
+                let minute_points = crate::core::clock::circle_points(minute_radius, center, 60);
+                let nearest_point = crate::core::clock::nearest_point(
+                    &minute_points,
+                    cursor.position().unwrap_or_default(),
+                );
+
+                state.time = state
+                    .time
+                    .with_minute(crate::core::clock::snap_to_step(nearest_point as u32, step))
+                    .expect("New time with minute should be valid");
+                event::Status::Captured
+            }
+            ClockDragged::Second => {
+                let second_points = crate::core::clock::circle_points(second_radius, center, 60);
+                let nearest_point = crate::core::clock::nearest_point(
+                    &second_points,
+                    cursor.position().unwrap_or_default(),
+                );
+
+                state.time = state
+                    .time
+                    .with_second(crate::core::clock::snap_to_step(nearest_point as u32, step))
+                    .expect("New time with second should be valid");
+                event::Status::Captured
+            }
+            ClockDragged::None => event::Status::Ignored,
+        };
+
+        clock_clicked_status.merge(clock_dragged_status)
+    } else {
+        match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                state.clock_dragged = ClockDragged::None;
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+}
+
+/// Moves the hour, minute or second of the time, depending on the focus, by one step.
+fn step_time(time: &mut NaiveTime, focus: Focus, step: u32, forward: bool) {
+    match focus {
+        Focus::DigitalHour => {
+            if forward {
+                *time += Duration::hours(1);
+            } else {
+                *time -= Duration::hours(1);
+            }
+        }
+        Focus::DigitalMinute => {
+            let (minute, carry) = clock::step_value(time.minute(), step, forward);
+            *time = time
+                .with_minute(minute)
+                .expect("New time with minute should be valid")
+                + Duration::hours(carry);
+        }
+        Focus::DigitalSecond => {
+            let (second, carry) = clock::step_value(time.second(), step, forward);
+            *time = time
+                .with_second(second)
+                .expect("New time with second should be valid")
+                + Duration::minutes(carry);
+        }
+        _ => {}
+    }
+}
+
+/// Whether a scroll moves the time forward, or `None` if it doesn't move it.
+fn scroll_direction(delta: mouse::ScrollDelta) -> Option<bool> {
+    let (mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. }) = delta;
+
+    if y > 0.0 {
+        Some(true)
+    } else if y < 0.0 {
+        Some(false)
+    } else {
+        None
     }
 }
 
@@ -1074,7 +1335,9 @@ where
         digital_clock_row = digital_clock_row.push(
             Column::new()
                 .height(Length::Shrink)
-                .push(text::Text::new("AM").size(font_size)),
+                .spacing(BUTTON_SPACING)
+                .push(text::Text::new("AM").size(font_size))
+                .push(text::Text::new("PM").size(font_size)),
         );
     }
 
@@ -1101,28 +1364,25 @@ where
 
 /// Draws the analog clock.
 #[allow(clippy::too_many_lines)]
-fn draw_clock<Message, Theme>(
+pub(crate) fn draw_clock(
     renderer: &mut Renderer,
-    time_picker: &TimePickerOverlay<'_, Message, Theme>,
+    state: &State,
+    step: u32,
     layout: Layout<'_>,
     cursor: Cursor,
     style: &HashMap<StyleState, Appearance>,
-) where
-    Message: 'static + Clone,
-    Theme: StyleSheet + button::StyleSheet + text::StyleSheet,
-{
+) {
     let mut clock_style_state = StyleState::Active;
     if cursor.is_over(layout.bounds()) {
         clock_style_state = clock_style_state.max(StyleState::Hovered);
     }
 
-    let geometry = time_picker
-        .state
+    let geometry = state
         .clock_cache
         .draw(renderer, layout.bounds().size(), |frame| {
             let center = frame.center();
             let radius = frame.width().min(frame.height()) * 0.5;
-            let period = if time_picker.state.time.hour12().0 {
+            let period = if state.time.hour12().0 {
                 clock::Period::PM
             } else {
                 clock::Period::AM
@@ -1133,7 +1393,7 @@ fn draw_clock<Message, Theme>(
 
             let period_radius = radius * PERIOD_PERCENTAGE;
 
-            let (hour_radius, minute_radius, second_radius) = if time_picker.state.show_seconds {
+            let (hour_radius, minute_radius, second_radius) = if state.show_seconds {
                 (
                     radius * HOUR_RADIUS_PERCENTAGE,
                     radius * MINUTE_RADIUS_PERCENTAGE,
@@ -1152,7 +1412,7 @@ fn draw_clock<Message, Theme>(
 
             let nearest_radius = if cursor.is_over(layout.bounds()) {
                 crate::core::clock::nearest_radius(
-                    &if time_picker.state.show_seconds {
+                    &if state.show_seconds {
                         vec![
                             (period_radius, NearestRadius::Period),
                             (hour_radius, NearestRadius::Hour),
@@ -1192,6 +1452,16 @@ fn draw_clock<Message, Theme>(
                 ..Stroke::default()
             };
 
+            let seconds_hand_stroke = Stroke {
+                style: Style::Solid(
+                    style
+                        .get(&clock_style_state)
+                        .expect("Style Sheet not found.")
+                        .clock_seconds_hand_color,
+                ),
+                ..hand_stroke.clone()
+            };
+
             match nearest_radius {
                 NearestRadius::Period => {
                     frame.fill(
@@ -1215,28 +1485,34 @@ fn draw_clock<Message, Theme>(
                     );
                 }
                 NearestRadius::Minute => {
-                    let nearest_point = minute_points
-                        [crate::core::clock::nearest_point(&minute_points, internal_cursor)];
+                    let nearest_index =
+                        crate::core::clock::nearest_point(&minute_points, internal_cursor);
+                    let nearest_point = minute_points[nearest_index];
 
-                    frame.fill(
-                        &Path::circle(nearest_point, 5.0),
-                        style
-                            .get(&StyleState::Hovered)
-                            .expect("Style Sheet not found.")
-                            .clock_number_background,
-                    );
+                    if (nearest_index as u32).is_multiple_of(step) {
+                        frame.fill(
+                            &Path::circle(nearest_point, 5.0),
+                            style
+                                .get(&StyleState::Hovered)
+                                .expect("Style Sheet not found.")
+                                .clock_number_background,
+                        );
+                    }
                 }
                 NearestRadius::Second => {
-                    let nearest_point = second_points
-                        [crate::core::clock::nearest_point(&second_points, internal_cursor)];
+                    let nearest_index =
+                        crate::core::clock::nearest_point(&second_points, internal_cursor);
+                    let nearest_point = second_points[nearest_index];
 
-                    frame.fill(
-                        &Path::circle(nearest_point, 5.0),
-                        style
-                            .get(&StyleState::Hovered)
-                            .expect("Style Sheet not found.")
-                            .clock_number_background,
-                    );
+                    if (nearest_index as u32).is_multiple_of(step) {
+                        frame.fill(
+                            &Path::circle(nearest_point, 5.0),
+                            style
+                                .get(&StyleState::Hovered)
+                                .expect("Style Sheet not found.")
+                                .clock_number_background,
+                        );
+                    }
                 }
                 NearestRadius::None => {}
             }
@@ -1259,8 +1535,8 @@ fn draw_clock<Message, Theme>(
 
             hour_points.iter().enumerate().for_each(|(i, p)| {
                 let (pm, selected) = {
-                    let (pm, _) = time_picker.state.time.hour12();
-                    let hour = time_picker.state.time.hour();
+                    let (pm, _) = state.time.hour12();
+                    let hour = state.time.hour();
                     (pm, hour % 12 == i as u32)
                 };
 
@@ -1280,9 +1556,9 @@ fn draw_clock<Message, Theme>(
                 let text = CanvasText {
                     content: format!(
                         "{}",
-                        if pm && time_picker.state.use_24h {
+                        if pm && state.use_24h {
                             i + 12
-                        } else if !time_picker.state.use_24h && i == 0 {
+                        } else if !state.use_24h && i == 0 {
                             12
                         } else {
                             i
@@ -1305,7 +1581,11 @@ fn draw_clock<Message, Theme>(
             });
 
             minute_points.iter().enumerate().for_each(|(i, p)| {
-                let selected = time_picker.state.time.minute() == i as u32;
+                if !(i as u32).is_multiple_of(step) {
+                    return;
+                }
+
+                let selected = state.time.minute() == i as u32;
 
                 let mut style_state = StyleState::Active;
                 if selected {
@@ -1349,13 +1629,17 @@ fn draw_clock<Message, Theme>(
                 }
             });
 
-            if time_picker.state.show_seconds {
+            if state.show_seconds {
                 second_points.iter().enumerate().for_each(|(i, p)| {
-                    let selected = time_picker.state.time.second() == i as u32;
+                    if !(i as u32).is_multiple_of(step) {
+                        return;
+                    }
+
+                    let selected = state.time.second() == i as u32;
 
                     let mut style_state = StyleState::Active;
                     if selected {
-                        frame.stroke(&Path::line(center, *p), hand_stroke.clone());
+                        frame.stroke(&Path::line(center, *p), seconds_hand_stroke.clone());
                         frame.fill(
                             &Path::circle(*p, number_size * 0.6),
                             style
@@ -1408,6 +1692,7 @@ fn draw_clock<Message, Theme>(
 fn draw_digital_clock<Message, Theme>(
     renderer: &mut Renderer,
     time_picker: &TimePickerOverlay<'_, Message, Theme>,
+    theme: &Theme,
     layout: Layout<'_>,
     cursor: Cursor,
     style: &HashMap<StyleState, Appearance>,
@@ -1643,33 +1928,55 @@ fn draw_digital_clock<Message, Theme>(
 
     // Draw period
     if !time_picker.state.use_24h {
-        let period = children
+        let period_layout = children
             .next()
             .expect("Graphics: Layout should have a period layout");
-        renderer.fill_text(
-            Text {
-                content: if time_picker.state.time.hour12().0 {
-                    "PM"
-                } else {
-                    "AM"
+
+        for (button_layout, (label, pm)) in
+            period_layout.children().zip([("AM", false), ("PM", true)])
+        {
+            let appearance = if time_picker.state.is_pm() == pm {
+                StyleSheet::am_pm_button_selected(theme, &time_picker.style)
+            } else {
+                StyleSheet::am_pm_button_active(theme, &time_picker.style)
+            };
+            let bounds = button_layout.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: appearance.border_radius.into(),
+                        width: appearance.border_width,
+                        color: appearance.border_color,
+                    },
+                    shadow: Shadow::default(),
                 },
-                bounds: Size::new(period.bounds().width, period.bounds().height),
-                size: renderer.default_size(),
-                font: renderer.default_font(),
-                horizontal_alignment: Horizontal::Center,
-                vertical_alignment: Vertical::Center,
-                line_height: text::LineHeight::Relative(1.3),
-                shaping: text::Shaping::Basic,
-            },
-            Point::new(period.bounds().center_x(), period.bounds().center_y()),
-            style[&StyleState::Active].text_color,
-            period.bounds(),
-        );
+                appearance.background,
+            );
+
+            renderer.fill_text(
+                Text {
+                    content: label,
+                    bounds: Size::new(bounds.width, bounds.height),
+                    size: renderer.default_size(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    line_height: text::LineHeight::Relative(1.3),
+                    shaping: text::Shaping::Basic,
+                },
+                Point::new(bounds.center_x(), bounds.center_y()),
+                appearance.text_color,
+                bounds,
+            );
+        }
     }
 }
 
 /// The state of the [`TimePickerOverlay`].
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct State {
     /// The selected time of the [`TimePickerOverlay`].
     pub(crate) time: NaiveTime,
@@ -1687,6 +1994,11 @@ pub struct State {
     pub(crate) focus: Focus,
     /// The previously pressed keyboard modifiers.
     pub(crate) keyboard_modifiers: keyboard::Modifiers,
+    /// Whether only the digital clock is shown instead of the clock face.
+    pub(crate) digital_mode: bool,
+    /// The first digit typed into the focused field of the digital clock, if
+    /// a second one can follow.
+    pub(crate) typed_digit: Option<u32>,
 }
 
 impl State {
@@ -1700,6 +2012,81 @@ impl State {
             ..Self::default()
         }
     }
+
+    /// Returns `true` if the selected time is after noon.
+    #[must_use]
+    pub fn is_pm(&self) -> bool {
+        self.time.hour12().0
+    }
+
+    /// Gets the selected time in the format of the clock.
+    pub(crate) fn selected_time(&self) -> Time {
+        let (hour, period) = if self.use_24h {
+            (self.time.hour(), Period::H24)
+        } else {
+            let (period, hour) = self.time.hour12();
+            (hour, if period { Period::Pm } else { Period::Am })
+        };
+
+        if self.show_seconds {
+            Time::Hms {
+                hour,
+                minute: self.time.minute(),
+                second: self.time.second(),
+                period,
+            }
+        } else {
+            Time::Hm {
+                hour,
+                minute: self.time.minute(),
+                period,
+            }
+        }
+    }
+
+    /// Enters a typed digit into the focused field of the digital clock.
+    ///
+    /// The digit follows the previously typed digit if there is one, the value
+    /// is clamped to the range of the field.
+    pub(crate) fn enter_digit(&mut self, digit: u32) {
+        let max = match self.focus {
+            Focus::DigitalHour if self.use_24h => 23,
+            Focus::DigitalHour => 12,
+            Focus::DigitalMinute | Focus::DigitalSecond => 59,
+            _ => return,
+        };
+
+        let value = if let Some(first) = self.typed_digit.take() {
+            (first * 10 + digit).min(max)
+        } else {
+            // A second digit can only follow if it could give a valid value.
+            if digit * 10 <= max {
+                self.typed_digit = Some(digit);
+            }
+            digit
+        };
+
+        self.time = match self.focus {
+            Focus::DigitalHour if self.use_24h => self.time.with_hour(value),
+            Focus::DigitalHour => self
+                .time
+                .with_hour(value % 12 + if self.is_pm() { 12 } else { 0 }),
+            Focus::DigitalMinute => self.time.with_minute(value),
+            _ => self.time.with_second(value),
+        }
+        .expect("New time with the typed value should be valid");
+        self.clock_cache.clear();
+    }
+
+    /// Moves the selected time before or after noon, keeping the hour on the 12 hour clock.
+    pub(crate) fn set_pm(&mut self, pm: bool) {
+        let hour = self.time.hour() % 12 + if pm { 12 } else { 0 };
+        self.time = self
+            .time
+            .with_hour(hour)
+            .expect("New time with hour should be valid");
+        self.clock_cache.clear();
+    }
 }
 
 impl Default for State {
@@ -1711,8 +2098,11 @@ impl Default for State {
             use_24h: false,
             show_seconds: false,
             clock_dragged: ClockDragged::None,
-            focus: Focus::default(),
+            // The keys of the overlay work as soon as it is opened.
+            focus: Focus::DigitalHour,
             keyboard_modifiers: keyboard::Modifiers::default(),
+            digital_mode: false,
+            typed_digit: None,
         }
     }
 }
@@ -1893,3 +2283,53 @@ impl Default for Focus {
         Self::None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Focus, State};
+    use crate::core::time::Time;
+    use chrono::Timelike;
+
+    #[test]
+    fn default_focus_test() {
+        let mut state = State::default();
+        assert_eq!(state.focus, Focus::DigitalHour);
+
+        state.enter_digit(9);
+        assert_eq!(state.time.hour() % 12, 9);
+    }
+
+    #[test]
+    fn enter_digit_test() {
+        let mut state = State::new(Time::now_hm(true), true, true);
+
+        state.focus = Focus::DigitalHour;
+        state.enter_digit(1);
+        state.enter_digit(7);
+        assert_eq!(state.time.hour(), 17);
+        assert_eq!(state.typed_digit, None);
+
+        // Too large values are clamped
+        state.enter_digit(2);
+        state.enter_digit(9);
+        assert_eq!(state.time.hour(), 23);
+
+        // No second digit can follow a digit larger than the first digit of the maximum
+        state.focus = Focus::DigitalMinute;
+        state.enter_digit(7);
+        assert_eq!(state.time.minute(), 7);
+        assert_eq!(state.typed_digit, None);
+        state.enter_digit(4);
+        state.enter_digit(2);
+        assert_eq!(state.time.minute(), 42);
+
+        // The hour keeps the period on the 12 hour clock
+        state.use_24h = false;
+        state.focus = Focus::DigitalHour;
+        state.enter_digit(1);
+        state.enter_digit(2);
+        assert_eq!(state.time.hour(), 12);
+        state.enter_digit(3);
+        assert_eq!(state.time.hour(), 15);
+    }
+}
@@ -0,0 +1,267 @@
+//! Use toasts for showing short notifications on top of the other elements.
+//!
+//! *This API requires the following crate features to be activated: toast*
+use crate::{style::toast::StyleSheet, widgets::toast::ToastPosition};
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        widget::{Operation, Tree},
+        Clipboard, Layout, Overlay, Shell,
+    },
+    event,
+    mouse::{self, Cursor},
+    Border, Element, Event, Point, Rectangle, Shadow, Size,
+};
+
+/// The padding between the border and the content of a toast.
+const TOAST_PADDING: f32 = 10.0;
+
+/// The overlay of the [`ToastContainer`](crate::widgets::toast::ToastContainer).
+#[allow(missing_debug_implementations)]
+pub struct ToastOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The states of the toasts.
+    state: &'b mut [Tree],
+    /// The elements of the toasts.
+    toasts: &'b mut [Element<'a, Message, Theme, Renderer>],
+    /// The styles of the toasts.
+    styles: &'b [<Theme as StyleSheet>::Style],
+    /// The position the toasts are stacked at.
+    position: ToastPosition,
+    /// The width of the toasts.
+    width: f32,
+    /// The spacing between the toasts.
+    spacing: f32,
+    /// The padding between the toasts and the edges of the window.
+    padding: f32,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> ToastOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`ToastOverlay`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        state: &'b mut [Tree],
+        toasts: &'b mut [Element<'a, Message, Theme, Renderer>],
+        styles: &'b [<Theme as StyleSheet>::Style],
+        position: ToastPosition,
+        width: f32,
+        spacing: f32,
+        padding: f32,
+    ) -> Self {
+        ToastOverlay {
+            state,
+            toasts,
+            styles,
+            position,
+            width,
+            spacing,
+            padding,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for ToastOverlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let width = self.width.min(bounds.width - 2.0 * self.padding).max(0.0);
+        let limits = Limits::new(
+            Size::ZERO,
+            Size::new((width - 2.0 * TOAST_PADDING).max(0.0), bounds.height),
+        );
+
+        let mut toasts: Vec<Node> = self
+            .toasts
+            .iter()
+            .zip(self.state.iter_mut())
+            .map(|(toast, state)| {
+                let content = toast
+                    .as_widget()
+                    .layout(state, renderer, &limits)
+                    .move_to(Point::new(TOAST_PADDING, TOAST_PADDING));
+                let height = content.size().height + 2.0 * TOAST_PADDING;
+
+                Node::with_children(Size::new(width, height), vec![content])
+            })
+            .collect();
+
+        let total_height = toasts.iter().map(|toast| toast.size().height).sum::<f32>()
+            + self.spacing * toasts.len().saturating_sub(1) as f32;
+
+        let x = match self.position {
+            ToastPosition::TopLeft | ToastPosition::BottomLeft => self.padding,
+            ToastPosition::TopCenter | ToastPosition::BottomCenter => (bounds.width - width) / 2.0,
+            ToastPosition::TopRight | ToastPosition::BottomRight => {
+                bounds.width - width - self.padding
+            }
+        };
+        let mut y = match self.position {
+            ToastPosition::TopLeft | ToastPosition::TopCenter | ToastPosition::TopRight => {
+                self.padding
+            }
+            ToastPosition::BottomLeft
+            | ToastPosition::BottomCenter
+            | ToastPosition::BottomRight => bounds.height - total_height - self.padding,
+        };
+
+        for toast in &mut toasts {
+            toast.move_to_mut(Point::new(x, y));
+            y += toast.size().height + self.spacing;
+        }
+
+        Node::with_children(bounds, toasts)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        self.toasts
+            .iter_mut()
+            .zip(self.state.iter_mut())
+            .zip(layout.children())
+            .map(|((toast, state), layout)| {
+                toast.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout
+                        .children()
+                        .next()
+                        .expect("Native: Layout should have a content layout."),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &layout.bounds(),
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.toasts
+            .iter()
+            .zip(self.state.iter())
+            .zip(layout.children())
+            .map(|((toast, state), layout)| {
+                toast.as_widget().mouse_interaction(
+                    state,
+                    layout
+                        .children()
+                        .next()
+                        .expect("Native: Layout should have a content layout."),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        for (((toast, state), style), layout) in self
+            .toasts
+            .iter()
+            .zip(self.state.iter())
+            .zip(self.styles)
+            .zip(layout.children())
+        {
+            let bounds = layout.bounds();
+            let style_sheet = theme.active(style);
+
+            // Background
+            if (bounds.width > 0.) && (bounds.height > 0.) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            radius: style_sheet.border_radius.into(),
+                            width: style_sheet.border_width,
+                            color: style_sheet.border_color,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    style_sheet.background,
+                );
+            }
+
+            toast.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style_sheet.text_color,
+                },
+                layout
+                    .children()
+                    .next()
+                    .expect("Native: Layout should have a content layout."),
+                cursor,
+                &bounds,
+            );
+        }
+    }
+
+    fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        layout
+            .children()
+            .any(|layout| layout.bounds().contains(cursor_position))
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        for ((toast, state), layout) in self
+            .toasts
+            .iter()
+            .zip(self.state.iter_mut())
+            .zip(layout.children())
+        {
+            toast.as_widget().operate(
+                state,
+                layout
+                    .children()
+                    .next()
+                    .expect("Native: Layout should have a content layout."),
+                renderer,
+                operation,
+            );
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Use a tooltip for showing a hint next to an element after hovering it for a while.
+//!
+//! *This API requires the following crate features to be activated: `tooltip`*
+use crate::{style::tooltip::StyleSheet, widgets::tooltip::TooltipPosition};
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        widget::Tree,
+        Clipboard, Layout, Overlay, Shell,
+    },
+    event,
+    mouse::{self, Cursor},
+    Border, Element, Event, Padding, Point, Rectangle, Shadow, Size,
+};
+
+/// The overlay of the [`Tooltip`](crate::widgets::tooltip::Tooltip).
+#[allow(missing_debug_implementations)]
+pub struct TooltipOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The state of the tooltip element.
+    state: &'b mut Tree,
+    /// The element shown as the tooltip.
+    tooltip: &'b Element<'a, Message, Theme, Renderer>,
+    /// The position of the tooltip.
+    position: TooltipPosition,
+    /// The bounds of the hovered content.
+    content_bounds: Rectangle,
+    /// The last position of the cursor over the content.
+    cursor_position: Point,
+    /// The gap between the tooltip and the content or the cursor.
+    gap: f32,
+    /// The padding between the border and the element of the tooltip.
+    padding: Padding,
+    /// The style of the tooltip.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> TooltipOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`TooltipOverlay`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        state: &'b mut Tree,
+        tooltip: &'b Element<'a, Message, Theme, Renderer>,
+        position: TooltipPosition,
+        content_bounds: Rectangle,
+        cursor_position: Point,
+        gap: f32,
+        padding: Padding,
+        style: &'b <Theme as StyleSheet>::Style,
+    ) -> Self {
+        TooltipOverlay {
+            state,
+            tooltip,
+            position,
+            content_bounds,
+            cursor_position,
+            gap,
+            padding,
+            style,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for TooltipOverlay<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let limits = Limits::new(Size::ZERO, bounds).shrink(self.padding);
+        let content = self
+            .tooltip
+            .as_widget()
+            .layout(self.state, renderer, &limits)
+            .move_to(Point::new(self.padding.left, self.padding.top));
+        let size = content.size().expand(self.padding);
+
+        let content_bounds = self.content_bounds;
+        let (x, y) = match self.position {
+            TooltipPosition::Top => (
+                content_bounds.center_x() - size.width / 2.0,
+                content_bounds.y - self.gap - size.height,
+            ),
+            TooltipPosition::Bottom => (
+                content_bounds.center_x() - size.width / 2.0,
+                content_bounds.y + content_bounds.height + self.gap,
+            ),
+            TooltipPosition::Left => (
+                content_bounds.x - self.gap - size.width,
+                content_bounds.center_y() - size.height / 2.0,
+            ),
+            TooltipPosition::Right => (
+                content_bounds.x + content_bounds.width + self.gap,
+                content_bounds.center_y() - size.height / 2.0,
+            ),
+            TooltipPosition::FollowCursor => (
+                self.cursor_position.x + self.gap,
+                self.cursor_position.y + self.gap,
+            ),
+        };
+
+        // Keep the tooltip inside of the window.
+        let position = Point::new(
+            x.clamp(0.0, (bounds.width - size.width).max(0.0)),
+            y.clamp(0.0, (bounds.height - size.height).max(0.0)),
+        );
+
+        Node::with_children(size, vec![content]).move_to(position)
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<Message>,
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style_sheet = theme.active(self.style);
+
+        // Background
+        if (bounds.width > 0.) && (bounds.height > 0.) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: style_sheet.border_radius.into(),
+                        width: style_sheet.border_width,
+                        color: style_sheet.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                style_sheet.background,
+            );
+        }
+
+        self.tooltip.as_widget().draw(
+            self.state,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: style_sheet.text_color,
+            },
+            layout
+                .children()
+                .next()
+                .expect("Native: Layout should have a content layout."),
+            cursor,
+            &bounds,
+        );
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        // The tooltip never covers the elements below it for the cursor.
+        false
+    }
+}
@@ -0,0 +1,275 @@
+//! Use a year picker as an input element for picking a year from a scrollable list.
+//!
+//! *This API requires the following crate features to be activated: `date_picker`*
+
+use crate::{
+    core::overlay::Position,
+    style::date_picker::{DayAppearance, StyleSheet},
+    year_picker::{self, YearList},
+};
+
+use iced::{
+    advanced::{
+        layout::Node,
+        overlay, renderer,
+        text::{self, Renderer as _},
+        Clipboard, Layout, Overlay, Renderer as _, Shell,
+    },
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch, Border, Color, Event, Pixels, Point, Rectangle, Renderer, Shadow, Size,
+};
+
+/// The padding around the years.
+const PADDING: f32 = 10.0;
+/// The default minimal distance between the overlay and the edges of the window.
+const DEFAULT_MARGIN: f32 = 8.0;
+
+/// The overlay of the [`YearPicker`](crate::widgets::YearPicker).
+#[allow(missing_debug_implementations)]
+pub struct YearPickerOverlay<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// The state of the [`YearPickerOverlay`].
+    state: &'a mut year_picker::State,
+    /// The message that is send if the [`YearPickerOverlay`] is cancelled.
+    on_cancel: Message,
+    /// The function that produces a message when a year of the [`YearPickerOverlay`] is submitted.
+    on_submit: &'a dyn Fn(i32) -> Message,
+    /// The years that can be picked and the number of shown years.
+    list: YearList,
+    /// The position of the [`YearPickerOverlay`].
+    position: Point,
+    /// The style of the [`YearPickerOverlay`].
+    style: <Theme as StyleSheet>::Style,
+    /// The font size of the [`YearPickerOverlay`].
+    font_size: Pixels,
+}
+
+impl<'a, Message, Theme> YearPickerOverlay<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a + StyleSheet,
+{
+    /// Creates a new [`YearPickerOverlay`] on the given position.
+    pub(crate) fn new(
+        state: &'a mut year_picker::State,
+        on_cancel: Message,
+        on_submit: &'a dyn Fn(i32) -> Message,
+        list: YearList,
+        position: Point,
+        style: <Theme as StyleSheet>::Style,
+        font_size: Pixels,
+    ) -> Self {
+        Self {
+            state,
+            on_cancel,
+            on_submit,
+            list,
+            position,
+            style,
+            font_size,
+        }
+    }
+
+    /// Turn this [`YearPickerOverlay`] into an overlay [`Element`](overlay::Element).
+    #[must_use]
+    pub fn overlay(self) -> overlay::Element<'a, Message, Theme, Renderer> {
+        overlay::Element::new(Box::new(self))
+    }
+
+    /// The size of a year row.
+    fn row_size(&self) -> Size {
+        Size::new(self.font_size.0 * 5.0, self.font_size.0 * 2.0)
+    }
+
+    /// The shown years with their layouts.
+    fn years<'l>(&self, layout: Layout<'l>) -> impl Iterator<Item = (i32, Layout<'l>)> {
+        (self.state.top(self.list)..).zip(layout.children())
+    }
+
+    /// Selects the given year and publishes the submit message.
+    fn submit(&mut self, year: i32, shell: &mut Shell<Message>) {
+        self.state.select(year, self.list);
+        shell.publish((self.on_submit)(self.state.selected));
+    }
+}
+
+impl<'a, Message, Theme> Overlay<Message, Theme, Renderer> for YearPickerOverlay<'a, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: 'a + StyleSheet,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let row = self.row_size();
+
+        #[allow(clippy::cast_precision_loss)]
+        let rows = (0..self.list.len())
+            .map(|index| {
+                Node::new(row).move_to(Point::new(PADDING, PADDING + index as f32 * row.height))
+            })
+            .collect::<Vec<_>>();
+
+        #[allow(clippy::cast_precision_loss)]
+        let mut node = Node::with_children(
+            Size::new(
+                row.width + 2.0 * PADDING,
+                rows.len() as f32 * row.height + 2.0 * PADDING,
+            ),
+            rows,
+        );
+        node.center_and_bounce_with_margin(self.position, bounds, DEFAULT_MARGIN);
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                if !layout.bounds().contains(position) {
+                    shell.publish(self.on_cancel.clone());
+                } else if let Some((year, _)) = self
+                    .years(layout)
+                    .find(|(_, row)| row.bounds().contains(position))
+                {
+                    self.submit(year, shell);
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y.round(),
+                    mouse::ScrollDelta::Pixels { y, .. } => y.signum(),
+                };
+
+                #[allow(clippy::cast_possible_truncation)]
+                self.state.scroll(-y as i32, self.list);
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => match key {
+                keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+                    self.state
+                        .select(self.state.selected.saturating_sub(1), self.list);
+                    event::Status::Captured
+                }
+                keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
+                    self.state
+                        .select(self.state.selected.saturating_add(1), self.list);
+                    event::Status::Captured
+                }
+                keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                    self.submit(self.state.selected, shell);
+                    event::Status::Captured
+                }
+                keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                    shell.publish(self.on_cancel.clone());
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            },
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.children().any(|row| cursor.is_over(row.bounds())) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        if (bounds.width > 0.) && (bounds.height > 0.) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: appearance.border_radius.into(),
+                        width: appearance.border_width,
+                        color: appearance.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.background,
+            );
+        }
+
+        for (year, row) in self.years(layout) {
+            let bounds = row.bounds();
+
+            let year_style: DayAppearance = if cursor.is_over(bounds) {
+                theme.day_hovered(&self.style)
+            } else if year == self.state.selected {
+                theme.day_selected(&self.style)
+            } else {
+                theme.day_active(&self.style)
+            };
+
+            if (bounds.width > 0.) && (bounds.height > 0.) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            radius: year_style.border_radius.min(bounds.height / 2.0).into(),
+                            width: 0.0,
+                            color: Color::TRANSPARENT,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    year_style.background,
+                );
+            }
+
+            renderer.fill_text(
+                iced::advanced::Text {
+                    content: &year.to_string(),
+                    bounds: bounds.size(),
+                    size: self.font_size,
+                    font: renderer.default_font(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    line_height: text::LineHeight::Relative(1.3),
+                    shaping: text::Shaping::Basic,
+                },
+                Point::new(bounds.center_x(), bounds.center_y()),
+                year_style.text_color,
+                bounds,
+            );
+        }
+    }
+}
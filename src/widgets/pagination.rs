@@ -0,0 +1,482 @@
+//! Use a pagination for navigating between the pages of a content.
+//!
+//! *This API requires the following crate features to be activated: `pagination`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::{self, Paragraph as _},
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch, Border, Element, Event, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+use crate::core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT};
+pub use crate::style::pagination::{Appearance, StyleSheet};
+
+/// The horizontal padding of the page numbers inside their buttons.
+const BUTTON_PADDING: f32 = 8.0;
+
+/// A row of buttons for navigating between pages.
+///
+/// The pages are counted from 0 and labeled from 1.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::Pagination;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PageChanged(usize),
+/// }
+///
+/// let pagination = Pagination::new(20, 0, Message::PageChanged).visible_page_buttons(5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Pagination<'a, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The number of pages.
+    total_pages: usize,
+    /// The index of the current page.
+    current_page: usize,
+    /// The function that produces a message when another page is selected.
+    on_page_change: Box<dyn Fn(usize) -> Message + 'a>,
+    /// The number of buttons shown for the pages, including the gaps.
+    visible_page_buttons: usize,
+    /// Whether the buttons for the first and last page are shown.
+    show_first_last: bool,
+    /// Whether the buttons for the previous and next page are shown.
+    show_prev_next: bool,
+    /// The height and minimum width of the buttons.
+    button_size: f32,
+    /// The space between the buttons.
+    spacing: f32,
+    /// The text size of the page numbers, or the default size of the renderer.
+    text_size: Option<Pixels>,
+    /// The style of the [`Pagination`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Pagination<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Pagination`].
+    ///
+    /// It expects:
+    ///     * the number of pages.
+    ///     * the index of the current page.
+    ///     * a function that will be called with the index of the selected page.
+    pub fn new<F>(total_pages: usize, current_page: usize, on_page_change: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        Self {
+            total_pages,
+            current_page: current_page.min(total_pages.saturating_sub(1)),
+            on_page_change: Box::new(on_page_change),
+            visible_page_buttons: 7,
+            show_first_last: true,
+            show_prev_next: true,
+            button_size: 32.0,
+            spacing: 4.0,
+            text_size: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the number of buttons shown for the pages of the [`Pagination`].
+    ///
+    /// If there are more pages, the first and last page stay visible along
+    /// with the pages around the current one, and the skipped pages are shown as `…`.
+    #[must_use]
+    pub fn visible_page_buttons(mut self, visible_page_buttons: usize) -> Self {
+        self.visible_page_buttons = visible_page_buttons;
+        self
+    }
+
+    /// Sets whether the buttons for the first and last page of the [`Pagination`] are shown.
+    #[must_use]
+    pub fn show_first_last(mut self, show_first_last: bool) -> Self {
+        self.show_first_last = show_first_last;
+        self
+    }
+
+    /// Sets whether the buttons for the previous and next page of the [`Pagination`] are shown.
+    #[must_use]
+    pub fn show_prev_next(mut self, show_prev_next: bool) -> Self {
+        self.show_prev_next = show_prev_next;
+        self
+    }
+
+    /// Sets the height and minimum width of the buttons of the [`Pagination`].
+    #[must_use]
+    pub fn button_size(mut self, button_size: f32) -> Self {
+        self.button_size = button_size;
+        self
+    }
+
+    /// Sets the space between the buttons of the [`Pagination`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the page numbers of the [`Pagination`].
+    #[must_use]
+    pub fn text_size<P: Into<Pixels>>(mut self, text_size: P) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the style of the [`Pagination`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The buttons of the [`Pagination`] from left to right.
+    fn buttons(&self) -> Vec<PageButton> {
+        let mut buttons = Vec::new();
+
+        if self.show_first_last {
+            buttons.push(PageButton::First);
+        }
+        if self.show_prev_next {
+            buttons.push(PageButton::Previous);
+        }
+
+        buttons.extend(
+            visible_pages(
+                self.total_pages,
+                self.current_page,
+                self.visible_page_buttons,
+            )
+            .into_iter()
+            .map(|page| page.map_or(PageButton::Gap, PageButton::Page)),
+        );
+
+        if self.show_prev_next {
+            buttons.push(PageButton::Next);
+        }
+        if self.show_first_last {
+            buttons.push(PageButton::Last);
+        }
+
+        buttons
+    }
+
+    /// The page the button leads to, if it can be pressed.
+    fn target(&self, button: PageButton) -> Option<usize> {
+        let has_next = self.current_page + 1 < self.total_pages;
+
+        match button {
+            PageButton::First => (self.current_page > 0).then_some(0),
+            PageButton::Previous => self.current_page.checked_sub(1),
+            PageButton::Page(page) => (page != self.current_page).then_some(page),
+            PageButton::Gap => None,
+            PageButton::Next => has_next.then_some(self.current_page + 1),
+            PageButton::Last => has_next.then_some(self.total_pages - 1),
+        }
+    }
+
+    /// The page of the button that would be pressed at the cursor.
+    fn hovered_target(&self, layout: Layout<'_>, cursor: Cursor) -> Option<usize> {
+        self.buttons()
+            .into_iter()
+            .zip(layout.children())
+            .find(|(_, layout)| cursor.is_over(layout.bounds()))
+            .and_then(|(button, _)| self.target(button))
+    }
+}
+
+/// A button of a [`Pagination`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PageButton {
+    /// The button leading to the first page.
+    First,
+    /// The button leading to the previous page.
+    Previous,
+    /// The button of a page.
+    Page(usize),
+    /// The placeholder for skipped pages.
+    Gap,
+    /// The button leading to the next page.
+    Next,
+    /// The button leading to the last page.
+    Last,
+}
+
+impl PageButton {
+    /// The icon of the button, or its text in the default font.
+    fn content(self) -> (String, Option<iced::Font>) {
+        match self {
+            Self::First => (
+                icon_to_string(Bootstrap::ChevronDoubleLeft),
+                Some(BOOTSTRAP_FONT),
+            ),
+            Self::Previous => (icon_to_string(Bootstrap::ChevronLeft), Some(BOOTSTRAP_FONT)),
+            Self::Page(page) => ((page + 1).to_string(), None),
+            Self::Gap => (String::from("…"), None),
+            Self::Next => (
+                icon_to_string(Bootstrap::ChevronRight),
+                Some(BOOTSTRAP_FONT),
+            ),
+            Self::Last => (
+                icon_to_string(Bootstrap::ChevronDoubleRight),
+                Some(BOOTSTRAP_FONT),
+            ),
+        }
+    }
+}
+
+/// The pages shown for the given number of buttons, with `None` for a gap
+/// of skipped pages.
+fn visible_pages(total: usize, current: usize, visible: usize) -> Vec<Option<usize>> {
+    let visible = visible.max(1);
+
+    if total <= visible {
+        return (0..total).map(Some).collect();
+    }
+
+    // Too few buttons to keep the first and last page, so show a window around the current one.
+    if visible < 5 {
+        let start = current.saturating_sub(visible / 2).min(total - visible);
+        return (start..start + visible).map(Some).collect();
+    }
+
+    let last = total - 1;
+    let mut pages = Vec::with_capacity(visible);
+
+    if current < visible - 3 {
+        pages.extend((0..visible - 2).map(Some));
+        pages.extend([None, Some(last)]);
+    } else if current >= total - (visible - 3) {
+        pages.extend([Some(0), None]);
+        pages.extend((total - (visible - 2)..total).map(Some));
+    } else {
+        let window = visible - 4;
+        let start = current - (window - 1) / 2;
+
+        pages.extend([Some(0), None]);
+        pages.extend((start..start + window).map(Some));
+        pages.extend([None, Some(last)]);
+    }
+
+    pages
+}
+
+/// The state of a [`Pagination`].
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the [`Pagination`] was clicked last, so that it handles the arrow keys.
+    is_focused: bool,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Pagination<'_, Message, Theme>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let mut offset = 0.0;
+        let buttons: Vec<Node> = self
+            .buttons()
+            .into_iter()
+            .map(|button| {
+                let (content, font) = button.content();
+                let text_width = Renderer::Paragraph::with_text(text::Text {
+                    content: &content,
+                    bounds: Size::INFINITY,
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font: font.unwrap_or_else(|| renderer.default_font()),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                })
+                .min_bounds()
+                .width;
+
+                let size = Size::new(
+                    self.button_size.max(text_width + 2.0 * BUTTON_PADDING),
+                    self.button_size,
+                );
+                let node = Node::new(size).move_to(Point::new(offset, 0.0));
+                offset += size.width + self.spacing;
+
+                node
+            })
+            .collect();
+
+        let size = Size::new((offset - self.spacing).max(0.0), self.button_size);
+
+        Node::with_children(
+            limits.resolve(Length::Shrink, Length::Shrink, size),
+            buttons,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = cursor.is_over(layout.bounds());
+
+                if let Some(page) = self.hovered_target(layout, cursor) {
+                    shell.publish((self.on_page_change)(page));
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if state.is_focused => {
+                let page = match key {
+                    keyboard::key::Named::ArrowLeft => self.current_page.checked_sub(1),
+                    keyboard::key::Named::ArrowRight => {
+                        Some(self.current_page + 1).filter(|page| *page < self.total_pages)
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                if let Some(page) = page {
+                    shell.publish((self.on_page_change)(page));
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.hovered_target(layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        for (button, layout) in self.buttons().into_iter().zip(layout.children()) {
+            let bounds = layout.bounds();
+            let is_gap = button == PageButton::Gap;
+
+            let appearance = if button == PageButton::Page(self.current_page) {
+                theme.page_active(&self.style)
+            } else if is_gap {
+                theme.page_inactive(&self.style)
+            } else if self.target(button).is_none() {
+                theme.page_disabled(&self.style)
+            } else if cursor.is_over(bounds) {
+                theme.page_hovered(&self.style)
+            } else {
+                theme.page_inactive(&self.style)
+            };
+
+            if !is_gap {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            radius: appearance.border_radius.into(),
+                            width: appearance.border_width,
+                            color: appearance.border_color,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    appearance.background,
+                );
+            }
+
+            let (content, font) = button.content();
+            renderer.fill_text(
+                text::Text {
+                    content: &content,
+                    bounds: bounds.size(),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font: font.unwrap_or_else(|| renderer.default_font()),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                },
+                bounds.center(),
+                appearance.text_color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Pagination<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(pagination: Pagination<'a, Message, Theme>) -> Self {
+        Element::new(pagination)
+    }
+}
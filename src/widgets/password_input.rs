@@ -0,0 +1,503 @@
+//! Use a password input for entering a password that can be shown and hidden with a click.
+//!
+//! *This API requires the following crate features to be activated: `password_input`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer, text,
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::{text_input, TextInput},
+    Element, Event, Length, Padding, Pixels, Point, Rectangle, Size,
+};
+
+use crate::core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT};
+
+/// The default padding of a [`PasswordInput`].
+const DEFAULT_PADDING: f32 = 5.0;
+
+/// The space between the text and the show/hide button of a [`PasswordInput`].
+const ICON_SPACING: f32 = 5.0;
+
+/// The index of the tree of the icon shown while the password is visible.
+const VISIBLE_ICON: usize = 1;
+
+/// The index of the tree of the icon shown while the password is hidden.
+const HIDDEN_ICON: usize = 2;
+
+/// A text input for a password with a button for showing and hiding it.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::PasswordInput;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PasswordChanged(String),
+///     Login,
+/// }
+///
+/// let password_input = PasswordInput::new("", Message::PasswordChanged)
+///     .placeholder("Password")
+///     .on_submit(Message::Login);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct PasswordInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+    Theme: text_input::StyleSheet,
+{
+    /// The current password.
+    value: String,
+    /// The text shown while the password is empty.
+    placeholder: String,
+    /// The function that produces a message when the password changes.
+    on_change: Box<dyn Fn(String) -> Message + 'a>,
+    /// The message that is produced when the password is submitted.
+    on_submit: Option<Message>,
+    /// The function that produces a message when the password is shown or hidden.
+    on_show_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    /// The icon shown while the password is visible, or an eye with a slash.
+    visible_icon: Option<Element<'a, Message, Theme, Renderer>>,
+    /// The icon shown while the password is hidden, or an eye.
+    hidden_icon: Option<Element<'a, Message, Theme, Renderer>>,
+    /// The width of the [`PasswordInput`].
+    width: Length,
+    /// The padding of the [`PasswordInput`].
+    padding: Padding,
+    /// The text size of the [`PasswordInput`], or the default size of the renderer.
+    size: Option<Pixels>,
+    /// The font of the [`PasswordInput`], or the default font of the renderer.
+    font: Option<Renderer::Font>,
+}
+
+impl<'a, Message, Theme, Renderer> PasswordInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer<Font = iced::Font>,
+    Theme: text_input::StyleSheet,
+{
+    /// Creates a new [`PasswordInput`].
+    ///
+    /// It expects:
+    ///     * the current password.
+    ///     * a function that will be called with the new password when it changes.
+    pub fn new<F>(value: &str, on_change: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        Self {
+            value: value.to_owned(),
+            placeholder: String::new(),
+            on_change: Box::new(on_change),
+            on_submit: None,
+            on_show_toggle: None,
+            visible_icon: None,
+            hidden_icon: None,
+            width: Length::Fill,
+            padding: Padding::from(DEFAULT_PADDING),
+            size: None,
+            font: None,
+        }
+    }
+
+    /// Sets the text shown while the password of the [`PasswordInput`] is empty.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the message that is produced when the password of the
+    /// [`PasswordInput`] is submitted with the enter key.
+    #[must_use]
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets the function that will be called with `true` when the password of
+    /// the [`PasswordInput`] is shown and with `false` when it is hidden.
+    #[must_use]
+    pub fn on_show_toggle<F>(mut self, on_show_toggle: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.on_show_toggle = Some(Box::new(on_show_toggle));
+        self
+    }
+
+    /// Sets the icon of the button while the password of the [`PasswordInput`]
+    /// is visible. Defaults to an eye with a slash.
+    #[must_use]
+    pub fn visible_icon(mut self, icon: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.visible_icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the icon of the button while the password of the [`PasswordInput`]
+    /// is hidden. Defaults to an eye.
+    #[must_use]
+    pub fn hidden_icon(mut self, icon: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.hidden_icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the width of the [`PasswordInput`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the [`PasswordInput`].
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`PasswordInput`] and of its default icons.
+    #[must_use]
+    pub fn size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`PasswordInput`].
+    #[must_use]
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// The underlying text input, hiding the password if `is_secure`, with
+    /// the given space for the button on the right.
+    fn input(&self, is_secure: bool, icon_width: f32) -> TextInput<'_, Message, Theme, Renderer> {
+        let input = TextInput::new(&self.placeholder, &self.value)
+            .on_input(|value| (self.on_change)(value))
+            .secure(is_secure)
+            .width(self.width)
+            .padding(Padding {
+                right: self.padding.right + icon_width + ICON_SPACING,
+                ..self.padding
+            });
+        let input = match &self.on_submit {
+            Some(on_submit) => input.on_submit(on_submit.clone()),
+            None => input,
+        };
+        let input = match self.size {
+            Some(size) => input.size(size),
+            None => input,
+        };
+
+        match self.font {
+            Some(font) => input.font(font),
+            None => input,
+        }
+    }
+
+    /// The custom icon shown in the given state and the index of its tree.
+    fn icon(&self, is_secure: bool) -> (Option<&Element<'a, Message, Theme, Renderer>>, usize) {
+        if is_secure {
+            (self.hidden_icon.as_ref(), HIDDEN_ICON)
+        } else {
+            (self.visible_icon.as_ref(), VISIBLE_ICON)
+        }
+    }
+
+    /// The text size of the [`PasswordInput`].
+    fn text_size(&self, renderer: &Renderer) -> Pixels {
+        self.size.unwrap_or_else(|| renderer.default_size())
+    }
+}
+
+/// The state of a [`PasswordInput`].
+#[derive(Debug)]
+struct State {
+    /// Whether the password is hidden.
+    is_secure: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for PasswordInput<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + text_input::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State { is_secure: true })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        let icon_tree = |icon: &Option<Element<'a, Message, Theme, Renderer>>| {
+            icon.as_ref().map_or_else(Tree::empty, Tree::new)
+        };
+
+        vec![
+            Tree::new(&self.input(true, 0.0) as &dyn Widget<Message, Theme, Renderer>),
+            icon_tree(&self.visible_icon),
+            icon_tree(&self.hidden_icon),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let is_secure = tree.state.downcast_ref::<State>().is_secure;
+        tree.children[0].diff(&self.input(is_secure, 0.0) as &dyn Widget<Message, Theme, Renderer>);
+
+        for (index, icon) in [
+            (VISIBLE_ICON, &self.visible_icon),
+            (HIDDEN_ICON, &self.hidden_icon),
+        ] {
+            match icon {
+                Some(icon) => tree.children[index].diff(icon),
+                None => tree.children[index] = Tree::empty(),
+            }
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let is_secure = tree.state.downcast_ref::<State>().is_secure;
+        let text_size = self.text_size(renderer).0;
+
+        let icon_node = match self.icon(is_secure) {
+            (Some(icon), index) => {
+                icon.as_widget()
+                    .layout(&mut tree.children[index], renderer, &limits.loose())
+            }
+            (None, _) => Node::new(Size::new(text_size, text_size)),
+        };
+        let icon_size = icon_node.size();
+
+        let input = self.input(is_secure, icon_size.width);
+        let input_node = Widget::<Message, Theme, Renderer>::layout(
+            &input,
+            &mut tree.children[0],
+            renderer,
+            limits,
+        );
+        let size = input_node.size();
+
+        let icon_node = icon_node.move_to(Point::new(
+            size.width - self.padding.right - icon_size.width,
+            (size.height - icon_size.height) / 2.0,
+        ));
+
+        Node::with_children(size, vec![input_node, icon_node])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let is_secure = tree.state.downcast_ref::<State>().is_secure;
+        let mut children = layout.children();
+        let input_layout = children
+            .next()
+            .expect("Native: Layout should have a text input layout");
+        let icon_width = children
+            .next()
+            .expect("Native: Layout should have an icon layout")
+            .bounds()
+            .width;
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            Widget::<Message, Theme, Renderer>::operate(
+                &self.input(is_secure, icon_width),
+                &mut tree.children[0],
+                input_layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let mut children = layout.children();
+        let input_layout = children
+            .next()
+            .expect("Native: Layout should have a text input layout");
+        let icon_bounds = children
+            .next()
+            .expect("Native: Layout should have an icon layout")
+            .bounds();
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if cursor.is_over(icon_bounds) {
+                state.is_secure = !state.is_secure;
+                if let Some(on_show_toggle) = &self.on_show_toggle {
+                    shell.publish(on_show_toggle(!state.is_secure));
+                }
+                shell.invalidate_layout();
+                return event::Status::Captured;
+            }
+        }
+
+        let is_secure = state.is_secure;
+        Widget::<Message, Theme, Renderer>::on_event(
+            &mut self.input(is_secure, icon_bounds.width),
+            &mut tree.children[0],
+            event,
+            input_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_secure = tree.state.downcast_ref::<State>().is_secure;
+
+        let mut children = layout.children();
+        let input_layout = children
+            .next()
+            .expect("Graphics: Layout should have a text input layout");
+        let icon_bounds = children
+            .next()
+            .expect("Graphics: Layout should have an icon layout")
+            .bounds();
+
+        if cursor.is_over(icon_bounds) {
+            mouse::Interaction::Pointer
+        } else {
+            Widget::<Message, Theme, Renderer>::mouse_interaction(
+                &self.input(is_secure, icon_bounds.width),
+                &tree.children[0],
+                input_layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let is_secure = tree.state.downcast_ref::<State>().is_secure;
+
+        let mut children = layout.children();
+        let input_layout = children
+            .next()
+            .expect("Graphics: Layout should have a text input layout");
+        let icon_layout = children
+            .next()
+            .expect("Graphics: Layout should have an icon layout");
+        let icon_bounds = icon_layout.bounds();
+
+        Widget::<Message, Theme, Renderer>::draw(
+            &self.input(is_secure, icon_bounds.width),
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            input_layout,
+            cursor,
+            viewport,
+        );
+
+        if let (Some(icon), index) = self.icon(is_secure) {
+            icon.as_widget().draw(
+                &tree.children[index],
+                renderer,
+                theme,
+                style,
+                icon_layout,
+                cursor,
+                viewport,
+            );
+            return;
+        }
+
+        let input_style = <Theme as text_input::StyleSheet>::Style::default();
+        let color = if cursor.is_over(icon_bounds) {
+            theme.value_color(&input_style)
+        } else {
+            theme.active(&input_style).icon_color
+        };
+        let icon = if is_secure {
+            Bootstrap::Eye
+        } else {
+            Bootstrap::EyeSlash
+        };
+
+        renderer.fill_text(
+            iced::advanced::Text {
+                content: &icon_to_string(icon),
+                bounds: icon_bounds.size(),
+                size: self.text_size(renderer),
+                line_height: text::LineHeight::Relative(1.0),
+                font: BOOTSTRAP_FONT,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            },
+            icon_bounds.center(),
+            color,
+            *viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<PasswordInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + text_input::StyleSheet,
+{
+    fn from(password_input: PasswordInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(password_input)
+    }
+}
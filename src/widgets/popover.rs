@@ -0,0 +1,298 @@
+//! Use a popover for showing floating content anchored to an element.
+//!
+//! *This API requires the following crate features to be activated: `popover`*
+
+use super::overlay::popover::PopoverOverlay;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay::{self, Group},
+        renderer,
+        widget::{Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    Element, Event, Length, Padding, Rectangle, Renderer, Size, Vector,
+};
+
+pub use crate::style::popover::{Appearance, StyleSheet};
+
+/// The side of the target a [`Popover`] is shown at.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PopoverPosition {
+    /// The popover is shown above the target.
+    Top,
+    /// The popover is shown below the target.
+    #[default]
+    Bottom,
+    /// The popover is shown left of the target.
+    Left,
+    /// The popover is shown right of the target.
+    Right,
+    /// The popover is shown below the target if it fits, otherwise at the
+    /// side with the most space.
+    Auto,
+}
+
+/// An element that shows floating content next to it while `show` is set.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::{Button, Text};
+/// # use iced_aw::{Popover, PopoverPosition};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggle,
+///     Dismiss,
+/// }
+///
+/// let popover = Popover::new(
+///     Button::new(Text::new("Details")).on_press(Message::Toggle),
+///     Text::new("Some more details"),
+///     true,
+/// )
+/// .position(PopoverPosition::Right)
+/// .show_arrow(true)
+/// .on_dismiss(Message::Dismiss);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Popover<'a, Message, Theme = iced::Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// The element the popover is anchored to.
+    target: Element<'a, Message, Theme, Renderer>,
+    /// The content of the popover.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// Show the popover.
+    show: bool,
+    /// The side of the target the popover is shown at.
+    position: PopoverPosition,
+    /// The gap between the target and the popover.
+    offset: f32,
+    /// Show an arrow pointing from the popover to the target.
+    show_arrow: bool,
+    /// The message that is send when the user clicks outside of the popover.
+    on_dismiss: Option<Message>,
+    /// The padding between the border and the content of the popover.
+    padding: Padding,
+    /// The style of the [`Popover`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Popover<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Popover`].
+    ///
+    /// It expects:
+    ///     * the target [`Element`] the popover is anchored to.
+    ///     * the content [`Element`] of the popover.
+    ///     * if the popover is shown.
+    pub fn new<T, C>(target: T, content: C, show: bool) -> Self
+    where
+        T: Into<Element<'a, Message, Theme, Renderer>>,
+        C: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Popover {
+            target: target.into(),
+            content: content.into(),
+            show,
+            position: PopoverPosition::default(),
+            offset: 5.0,
+            show_arrow: false,
+            on_dismiss: None,
+            padding: Padding::new(10.0),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the side of the target the [`Popover`] is shown at.
+    #[must_use]
+    pub fn position(mut self, position: PopoverPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the gap between the target and the [`Popover`].
+    #[must_use]
+    pub fn offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Shows an arrow pointing from the [`Popover`] to its target.
+    #[must_use]
+    pub fn show_arrow(mut self, show_arrow: bool) -> Self {
+        self.show_arrow = show_arrow;
+        self
+    }
+
+    /// Sets the message that is send when the user clicks outside of the
+    /// [`Popover`] and its target or presses escape.
+    #[must_use]
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the padding between the border and the content of the [`Popover`].
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`Popover`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for Popover<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.target), Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.target, &self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.target.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.target
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.target.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.target.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.target.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.target
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (target_state, content_state) = state.children.split_at_mut(1);
+
+        let mut overlays: Vec<_> = self
+            .target
+            .as_widget_mut()
+            .overlay(&mut target_state[0], layout, renderer, translation)
+            .into_iter()
+            .collect();
+
+        if self.show {
+            overlays.push(overlay::Element::new(Box::new(PopoverOverlay::new(
+                &mut content_state[0],
+                &mut self.content,
+                self.position,
+                layout.bounds() + translation,
+                self.offset,
+                self.show_arrow,
+                self.on_dismiss.as_ref(),
+                self.padding,
+                &self.style,
+            ))));
+        }
+
+        (!overlays.is_empty()).then(|| Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme> From<Popover<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet,
+{
+    fn from(popover: Popover<'a, Message, Theme>) -> Self {
+        Element::new(popover)
+    }
+}
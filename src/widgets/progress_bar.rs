@@ -0,0 +1,286 @@
+//! Use a progress bar for showing the progress of a task, or that a task of unknown length is running.
+//!
+//! *This API requires the following crate features to be activated: `progress_bar`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::Cursor,
+    window, Border, Element, Event, Length, Rectangle, Shadow, Size,
+};
+use std::time::{Duration, Instant};
+
+pub use crate::style::progress_bar::{Appearance, StyleSheet};
+
+/// The time the sliding block of an indeterminate [`ProgressBar`] needs for crossing the bar.
+const CYCLE_DURATION: Duration = Duration::from_millis(1500);
+
+/// The width of the sliding block of an indeterminate [`ProgressBar`] relative to the bar.
+const BLOCK_WIDTH: f32 = 0.25;
+
+/// A bar that is filled according to a progress between 0.0 and 1.0, or shows a
+/// sliding block while the progress is unknown.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::ProgressBar;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Done,
+/// }
+///
+/// let progress_bar = ProgressBar::new(0.5).on_complete(Message::Done);
+/// let loading = ProgressBar::<Message>::new(0.0).indeterminate(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ProgressBar<Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The progress between 0.0 and 1.0.
+    value: f32,
+    /// Whether a sliding block is shown instead of the progress.
+    indeterminate: bool,
+    /// The width of the [`ProgressBar`].
+    width: Length,
+    /// The height of the [`ProgressBar`].
+    height: f32,
+    /// The border radius of the [`ProgressBar`].
+    border_radius: f32,
+    /// The message that is produced when the progress reaches 1.0.
+    on_complete: Option<Message>,
+    /// The style of the [`ProgressBar`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Message, Theme> ProgressBar<Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`ProgressBar`] with the given progress between 0.0 and 1.0.
+    #[must_use]
+    pub fn new(value: f32) -> Self {
+        Self {
+            value: value.clamp(0.0, 1.0),
+            indeterminate: false,
+            width: Length::Fill,
+            height: 30.0,
+            border_radius: 4.0,
+            on_complete: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Shows a block continuously sliding across the [`ProgressBar`] instead of
+    /// the progress, for tasks of unknown length.
+    #[must_use]
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    /// Sets the width of the [`ProgressBar`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`ProgressBar`].
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the border radius of the [`ProgressBar`].
+    #[must_use]
+    pub fn border_radius(mut self, border_radius: f32) -> Self {
+        self.border_radius = border_radius;
+        self
+    }
+
+    /// Sets the message that is produced once when the progress of the
+    /// [`ProgressBar`] reaches 1.0.
+    #[must_use]
+    pub fn on_complete(mut self, message: Message) -> Self {
+        self.on_complete = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`ProgressBar`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Whether the progress of the [`ProgressBar`] is complete.
+    fn is_complete(&self) -> bool {
+        self.value >= 1.0
+    }
+}
+
+/// The state of a [`ProgressBar`].
+#[derive(Debug)]
+struct State {
+    /// The last time the sliding block was moved.
+    last_update: Instant,
+    /// The position of the sliding block between 0.0 and 1.0.
+    t: f32,
+    /// Whether the completion of the progress has been reported.
+    completed: bool,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ProgressBar<Message, Theme>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            last_update: Instant::now(),
+            t: 0.0,
+            completed: self.is_complete(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Fixed(self.height))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::ZERO,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if self.is_complete() != state.completed {
+            state.completed = self.is_complete();
+            if let (true, Some(on_complete)) = (state.completed, &self.on_complete) {
+                shell.publish(on_complete.clone());
+            }
+        }
+
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            if self.indeterminate {
+                let elapsed = now.saturating_duration_since(state.last_update);
+                state.t = (state.t + elapsed.as_secs_f32() / CYCLE_DURATION.as_secs_f32()) % 1.0;
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+
+            state.last_update = now;
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: self.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        if self.indeterminate {
+            let state = tree.state.downcast_ref::<State>();
+            let block_width = bounds.width * BLOCK_WIDTH;
+            let block_bounds = Rectangle {
+                x: bounds.x - block_width + state.t * (bounds.width + block_width),
+                width: block_width,
+                ..bounds
+            };
+
+            // The block slides in and out of the bar, so clip it at the edges.
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: block_bounds,
+                        border: Border {
+                            radius: self.border_radius.into(),
+                            ..Border::default()
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    appearance.bar_color,
+                );
+            });
+        } else if self.value > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: bounds.width * self.value,
+                        ..bounds
+                    },
+                    border: Border {
+                        radius: self.border_radius.into(),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.bar_color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ProgressBar<Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(progress_bar: ProgressBar<Message, Theme>) -> Self {
+        Element::new(progress_bar)
+    }
+}
@@ -0,0 +1,366 @@
+//! Use a range slider for selecting an interval from a range of values.
+//!
+//! *This API requires the following crate features to be activated: `range_slider`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch, Border, Element, Event, Length, Point, Rectangle, Shadow, Size,
+};
+use std::ops::RangeInclusive;
+
+pub use crate::style::range_slider::{Appearance, StyleSheet};
+
+/// A horizontal slider with two handles for selecting an interval of values.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::RangeSlider;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PriceChanged(f32, f32),
+/// }
+///
+/// let range_slider = RangeSlider::new(0.0..=100.0, 20.0, 80.0, Message::PriceChanged).step(5.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The range of selectable values.
+    range: RangeInclusive<f32>,
+    /// The lower end of the selected interval.
+    low: f32,
+    /// The upper end of the selected interval.
+    high: f32,
+    /// The function that produces a message when a handle is moved.
+    on_change: Box<dyn Fn(f32, f32) -> Message + 'a>,
+    /// The step the values snap to.
+    step: f32,
+    /// The width of the [`RangeSlider`].
+    width: Length,
+    /// The height of the [`RangeSlider`].
+    height: f32,
+    /// The style of the [`RangeSlider`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> RangeSlider<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///     * the range of selectable values.
+    ///     * the lower end of the selected interval.
+    ///     * the upper end of the selected interval.
+    ///     * a function that will be called with the new interval when a handle is moved.
+    pub fn new<F>(range: RangeInclusive<f32>, low: f32, high: f32, on_change: F) -> Self
+    where
+        F: 'a + Fn(f32, f32) -> Message,
+    {
+        let low = low.clamp(*range.start(), *range.end());
+        let high = high.clamp(low, *range.end());
+
+        Self {
+            range,
+            low,
+            high,
+            on_change: Box::new(on_change),
+            step: 1.0,
+            width: Length::Fill,
+            height: 22.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the step the values of the [`RangeSlider`] snap to.
+    #[must_use]
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The value at the given position, snapped to the step.
+    fn value_at(&self, bounds: Rectangle, position: Point) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let percent = ((position.x - bounds.x) / bounds.width).clamp(0.0, 1.0);
+        let value = start + percent * (end - start);
+
+        if self.step > 0.0 {
+            (start + ((value - start) / self.step).round() * self.step).clamp(start, end)
+        } else {
+            value
+        }
+    }
+
+    /// Moves the given handle to the value at the position and publishes the new interval.
+    fn drag(
+        &mut self,
+        thumb: Thumb,
+        bounds: Rectangle,
+        position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let value = self.value_at(bounds, position);
+        let (low, high) = match thumb {
+            Thumb::Low => (value.min(self.high), self.high),
+            Thumb::High => (self.low, value.max(self.low)),
+        };
+
+        if (low - self.low).abs() > f32::EPSILON || (high - self.high).abs() > f32::EPSILON {
+            self.low = low;
+            self.high = high;
+            shell.publish((self.on_change)(low, high));
+        }
+    }
+
+    /// The position of the given value between 0.0 and 1.0.
+    fn percent(&self, value: f32) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        if start >= end {
+            0.0
+        } else {
+            (value - start) / (end - start)
+        }
+    }
+}
+
+/// A handle of a [`RangeSlider`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Thumb {
+    /// The handle of the lower end of the interval.
+    Low,
+    /// The handle of the upper end of the interval.
+    High,
+}
+
+/// The state of a [`RangeSlider`].
+#[derive(Debug, Default)]
+struct State {
+    /// The handle that is dragged, if any.
+    dragging: Option<Thumb>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for RangeSlider<'_, Message, Theme>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Fixed(self.height))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::ZERO,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let value = self.value_at(bounds, position);
+                    let to_low = (value - self.low).abs();
+                    let to_high = (value - self.high).abs();
+
+                    // Overlapping handles are pulled apart in the direction of the cursor.
+                    let thumb = if to_low < to_high || (to_low <= to_high && value < self.low) {
+                        Thumb::Low
+                    } else {
+                        Thumb::High
+                    };
+
+                    state.dragging = Some(thumb);
+                    self.drag(thumb, bounds, position, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.dragging.is_some() =>
+            {
+                state.dragging = None;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let (Some(thumb), Some(position)) = (state.dragging, cursor.position()) {
+                    self.drag(thumb, bounds, position, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance = if state.dragging.is_some() {
+            theme.dragging(&self.style)
+        } else if cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        let radius = appearance.handle_radius;
+        let rail_width = (bounds.width - 2.0 * radius).max(0.0);
+        let low_x = bounds.x + radius + rail_width * self.percent(self.low);
+        let high_x = bounds.x + radius + rail_width * self.percent(self.high);
+        let center_y = bounds.center_y();
+
+        let track = |x: f32, width: f32| Rectangle {
+            x,
+            y: center_y - appearance.track_height / 2.0,
+            width,
+            height: appearance.track_height,
+        };
+
+        for (track_bounds, color) in [
+            (track(bounds.x, low_x - bounds.x), appearance.track_color),
+            (track(low_x, high_x - low_x), appearance.fill_color),
+            (
+                track(high_x, bounds.x + bounds.width - high_x),
+                appearance.track_color,
+            ),
+        ] {
+            if track_bounds.width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: track_bounds,
+                        border: Border::with_radius(appearance.track_height / 2.0),
+                        shadow: Shadow::default(),
+                    },
+                    color,
+                );
+            }
+        }
+
+        for x in [low_x, high_x] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - radius,
+                        y: center_y - radius,
+                        width: 2.0 * radius,
+                        height: 2.0 * radius,
+                    },
+                    border: Border {
+                        radius: radius.into(),
+                        width: appearance.handle_border_width,
+                        color: appearance.handle_border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.handle_color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<RangeSlider<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(range_slider: RangeSlider<'a, Message, Theme>) -> Self {
+        Element::new(range_slider)
+    }
+}
@@ -0,0 +1,282 @@
+//! Use a rating for showing or picking a value as a row of stars.
+//!
+//! *This API requires the following crate features to be activated: `rating`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer, text,
+        widget::Tree,
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch, Element, Event, Length, Pixels, Rectangle, Size,
+};
+
+use crate::core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT};
+pub use crate::style::rating::{Appearance, StyleSheet};
+
+/// A row of stars showing a value that can be changed by clicking on a star.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::Rating;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Rated(f32),
+/// }
+///
+/// let rating = Rating::new(3.5, 5, Message::Rated).allow_half(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Rating<'a, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The current value of the [`Rating`].
+    value: f32,
+    /// The number of stars of the [`Rating`].
+    max: u8,
+    /// The function that produces a message when a star is clicked or `None` if read-only.
+    on_change: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    /// Whether half stars can be picked.
+    allow_half: bool,
+    /// The size of each star.
+    icon_size: f32,
+    /// The spacing between the stars.
+    spacing: f32,
+    /// The style of the [`Rating`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Rating<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Rating`].
+    ///
+    /// It expects:
+    ///     * the current value.
+    ///     * the number of stars.
+    ///     * a function that will be called with the value of the clicked star.
+    pub fn new<F>(value: f32, max: u8, on_change: F) -> Self
+    where
+        F: 'a + Fn(f32) -> Message,
+    {
+        Self {
+            on_change: Some(Box::new(on_change)),
+            ..Self::read_only(value, max)
+        }
+    }
+
+    /// Creates a new read-only [`Rating`] that only shows the value.
+    #[must_use]
+    pub fn read_only(value: f32, max: u8) -> Self {
+        Self {
+            value,
+            max,
+            on_change: None,
+            allow_half: false,
+            icon_size: 24.0,
+            spacing: 4.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets whether half stars can be picked on the [`Rating`].
+    #[must_use]
+    pub fn allow_half(mut self, allow_half: bool) -> Self {
+        self.allow_half = allow_half;
+        self
+    }
+
+    /// Sets the size of each star of the [`Rating`].
+    #[must_use]
+    pub fn icon_size(mut self, icon_size: f32) -> Self {
+        self.icon_size = icon_size;
+        self
+    }
+
+    /// Sets the spacing between the stars of the [`Rating`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the style of the [`Rating`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The bounds of the star with the given index.
+    fn star_bounds(&self, bounds: Rectangle, index: u8) -> Rectangle {
+        Rectangle {
+            x: bounds.x + f32::from(index) * (self.icon_size + self.spacing),
+            y: bounds.y,
+            width: self.icon_size,
+            height: self.icon_size,
+        }
+    }
+
+    /// The value that would be picked by clicking at the cursor.
+    fn value_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<f32> {
+        let position = cursor.position_over(bounds)?;
+        let x = position.x - bounds.x;
+        let star = (x / (self.icon_size + self.spacing)).floor();
+        let fraction = (x - star * (self.icon_size + self.spacing)) / self.icon_size;
+
+        let value = if self.allow_half && fraction <= 0.5 {
+            star + 0.5
+        } else {
+            star + 1.0
+        };
+
+        Some(value.min(f32::from(self.max)))
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Rating<'_, Message, Theme>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+    Theme: StyleSheet,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let stars = f32::from(self.max);
+        let width = stars * self.icon_size + (stars - 1.0).max(0.0) * self.spacing;
+
+        Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(width, self.icon_size),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(on_change) = &self.on_change else {
+            return event::Status::Ignored;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => self
+                .value_at(layout.bounds(), cursor)
+                .map_or(event::Status::Ignored, |value| {
+                    shell.publish(on_change(value));
+                    event::Status::Captured
+                }),
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_change.is_some() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+
+        let preview = self
+            .on_change
+            .as_ref()
+            .and_then(|_| self.value_at(bounds, cursor));
+        let (value, filled_color) = preview
+            .map_or((self.value, appearance.filled_color), |preview| {
+                (preview, appearance.hovered_color)
+            });
+
+        let star = icon_to_string(Bootstrap::StarFill);
+
+        for index in 0..self.max {
+            let star_bounds = self.star_bounds(bounds, index);
+            let fill = (((value - f32::from(index)) * 2.0).round() / 2.0).clamp(0.0, 1.0);
+
+            let draw_star = |renderer: &mut Renderer, color, clip_bounds| {
+                renderer.fill_text(
+                    text::Text {
+                        content: &star,
+                        bounds: star_bounds.size(),
+                        size: Pixels(self.icon_size),
+                        line_height: text::LineHeight::Relative(1.0),
+                        font: BOOTSTRAP_FONT,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                    },
+                    star_bounds.center(),
+                    color,
+                    clip_bounds,
+                );
+            };
+
+            if fill < 1.0 {
+                draw_star(renderer, appearance.empty_color, star_bounds);
+            }
+
+            if fill >= 1.0 {
+                draw_star(renderer, filled_color, star_bounds);
+            } else if fill > 0.0 {
+                let filled_bounds = Rectangle {
+                    width: star_bounds.width * fill,
+                    ..star_bounds
+                };
+
+                renderer.with_layer(filled_bounds, |renderer| {
+                    draw_star(renderer, filled_color, filled_bounds);
+                });
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Rating<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(rating: Rating<'a, Message, Theme>) -> Self {
+        Element::new(rating)
+    }
+}
@@ -0,0 +1,552 @@
+//! Use a search input for entering a search query that can be cleared with a click.
+//!
+//! *This API requires the following crate features to be activated: `search_input`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer, text,
+        widget::{
+            operation,
+            tree::{self, Tag},
+            Id, Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::{
+        text_input::{self, Icon, Side},
+        TextInput,
+    },
+    window, Element, Event, Length, Padding, Pixels, Point, Rectangle, Size, Vector,
+};
+use std::{
+    any::Any,
+    time::{Duration, Instant},
+};
+
+use crate::core::icons::{
+    bootstrap::{icon_to_char, icon_to_string},
+    Bootstrap, BOOTSTRAP_FONT,
+};
+
+/// The default padding of a [`SearchInput`].
+const DEFAULT_PADDING: f32 = 5.0;
+
+/// The space between the icons and the text of a [`SearchInput`].
+const ICON_SPACING: f32 = 5.0;
+
+/// A text input with a magnifying glass icon and a button for clearing the query.
+///
+/// The changes can be debounced, so `on_change` is only called once the user
+/// stopped typing for a while.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::SearchInput;
+/// # use std::time::Duration;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Search(String),
+///     Cleared,
+/// }
+///
+/// let search_input = SearchInput::new("", Message::Search)
+///     .placeholder("Search...")
+///     .debounce(Duration::from_millis(300))
+///     .on_clear(Message::Cleared);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SearchInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+    Theme: text_input::StyleSheet,
+{
+    /// The current query.
+    value: String,
+    /// The text shown while the query is empty.
+    placeholder: String,
+    /// The function that produces a message when the query changes.
+    on_change: Box<dyn Fn(String) -> Message + 'a>,
+    /// The message that is produced when the query is cleared.
+    on_clear: Option<Message>,
+    /// The time without input before a change is reported, or `None` for reporting it immediately.
+    debounce: Option<Duration>,
+    /// The width of the [`SearchInput`].
+    width: Length,
+    /// The padding of the [`SearchInput`].
+    padding: Padding,
+    /// The text size of the [`SearchInput`], or the default size of the renderer.
+    size: Option<Pixels>,
+    /// The font of the [`SearchInput`], or the default font of the renderer.
+    font: Option<Renderer::Font>,
+    /// The renderer and theme the [`SearchInput`] is drawn with.
+    _phantom: std::marker::PhantomData<(Theme, Renderer)>,
+}
+
+impl<'a, Message, Theme, Renderer> SearchInput<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+    Theme: text_input::StyleSheet,
+{
+    /// Creates a new [`SearchInput`].
+    ///
+    /// It expects:
+    ///     * the current query.
+    ///     * a function that will be called with the new query when it changes.
+    pub fn new<F>(value: &str, on_change: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        Self {
+            value: value.to_owned(),
+            placeholder: String::new(),
+            on_change: Box::new(on_change),
+            on_clear: None,
+            debounce: None,
+            width: Length::Fill,
+            padding: Padding::from(DEFAULT_PADDING),
+            size: None,
+            font: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the text shown while the query of the [`SearchInput`] is empty.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the message that is produced when the query of the [`SearchInput`]
+    /// is cleared with the clear button.
+    #[must_use]
+    pub fn on_clear(mut self, message: Message) -> Self {
+        self.on_clear = Some(message);
+        self
+    }
+
+    /// Sets the time without further input before a change of the query of the
+    /// [`SearchInput`] is reported.
+    #[must_use]
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = Some(debounce);
+        self
+    }
+
+    /// Sets the width of the [`SearchInput`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the [`SearchInput`].
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`SearchInput`].
+    #[must_use]
+    pub fn size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`SearchInput`].
+    #[must_use]
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// The underlying text input showing the given query.
+    ///
+    /// The text input produces the new query as its message.
+    fn input(&self, value: &str) -> TextInput<'_, String, Theme, Renderer> {
+        let input = TextInput::new(&self.placeholder, value)
+            .on_input(|value| value)
+            .width(self.width)
+            .padding(self.padding)
+            .icon(Icon {
+                font: BOOTSTRAP_FONT,
+                code_point: icon_to_char(Bootstrap::Search),
+                size: self.size,
+                spacing: ICON_SPACING,
+                side: Side::Left,
+            });
+        let input = match self.size {
+            Some(size) => input.size(size),
+            None => input,
+        };
+
+        match self.font {
+            Some(font) => input.font(font),
+            None => input,
+        }
+    }
+
+    /// The underlying text input with space for the clear button on the right.
+    fn padded_input(
+        &self,
+        value: &str,
+        renderer: &Renderer,
+    ) -> TextInput<'_, String, Theme, Renderer> {
+        self.input(value).padding(Padding {
+            right: self.padding.right + self.text_size(renderer).0 + ICON_SPACING,
+            ..self.padding
+        })
+    }
+
+    /// The text size of the [`SearchInput`].
+    fn text_size(&self, renderer: &Renderer) -> Pixels {
+        self.size.unwrap_or_else(|| renderer.default_size())
+    }
+}
+
+/// The state of a [`SearchInput`].
+#[derive(Debug, Default)]
+struct State {
+    /// The query that is typed but not reported yet and the time to report it.
+    pending: Option<(String, Instant)>,
+}
+
+impl State {
+    /// The query shown in the text input.
+    fn value<'b>(&'b self, value: &'b str) -> &'b str {
+        self.pending
+            .as_ref()
+            .map_or(value, |(pending, _)| pending.as_str())
+    }
+}
+
+/// Forwards the operations on the underlying text input of a [`SearchInput`],
+/// which produces the query instead of the messages of the application.
+struct InputOperation<'b, T>(&'b mut dyn Operation<T>);
+
+impl<T> Operation<String> for InputOperation<'_, T> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<String>),
+    ) {
+        self.0.container(id, bounds, &mut |operation| {
+            operate_on_children(&mut InputOperation(operation));
+        });
+    }
+
+    fn focusable(&mut self, state: &mut dyn operation::Focusable, id: Option<&Id>) {
+        self.0.focusable(state, id);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.0.scrollable(state, id, bounds, translation);
+    }
+
+    fn text_input(&mut self, state: &mut dyn operation::TextInput, id: Option<&Id>) {
+        self.0.text_input(state, id);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        self.0.custom(state, id);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for SearchInput<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + text_input::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.input(&self.value) as &dyn Widget<String, Theme, Renderer>
+        )]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let value = tree
+            .state
+            .downcast_ref::<State>()
+            .value(&self.value)
+            .to_owned();
+
+        tree.diff_children(&[&self.input(&value) as &dyn Widget<String, Theme, Renderer>]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let value = tree
+            .state
+            .downcast_ref::<State>()
+            .value(&self.value)
+            .to_owned();
+        let input = self.padded_input(&value, renderer);
+        let input_node = Widget::<String, Theme, Renderer>::layout(
+            &input,
+            &mut tree.children[0],
+            renderer,
+            limits,
+        );
+
+        let size = input_node.size();
+        let icon_size = self.text_size(renderer).0;
+        let clear_node = Node::new(Size::new(icon_size, icon_size)).move_to(Point::new(
+            size.width - self.padding.right - icon_size,
+            (size.height - icon_size) / 2.0,
+        ));
+
+        Node::with_children(size, vec![input_node, clear_node])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let value = tree
+            .state
+            .downcast_ref::<State>()
+            .value(&self.value)
+            .to_owned();
+        let input = self.padded_input(&value, renderer);
+        let input_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a text input layout");
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            Widget::<String, Theme, Renderer>::operate(
+                &input,
+                &mut tree.children[0],
+                input_layout,
+                renderer,
+                &mut InputOperation(operation),
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Report the pending query once no further input arrived in time.
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            if let Some((_, deadline)) = &state.pending {
+                if now >= *deadline {
+                    if let Some((value, _)) = state.pending.take() {
+                        shell.publish((self.on_change)(value));
+                    }
+                } else {
+                    shell.request_redraw(window::RedrawRequest::At(*deadline));
+                }
+            }
+        }
+
+        let mut children = layout.children();
+        let input_layout = children
+            .next()
+            .expect("Native: Layout should have a text input layout");
+        let clear_bounds = children
+            .next()
+            .expect("Native: Layout should have a clear button layout")
+            .bounds();
+
+        let value = state.value(&self.value).to_owned();
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if !value.is_empty() && cursor.is_over(clear_bounds) {
+                state.pending = None;
+                shell.publish((self.on_change)(String::new()));
+                if let Some(on_clear) = &self.on_clear {
+                    shell.publish(on_clear.clone());
+                }
+                return event::Status::Captured;
+            }
+        }
+
+        let mut input = self.padded_input(&value, renderer);
+        let mut values = Vec::new();
+        let mut input_shell = Shell::new(&mut values);
+        let status = Widget::<String, Theme, Renderer>::on_event(
+            &mut input,
+            &mut tree.children[0],
+            event,
+            input_layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            viewport,
+        );
+
+        if input_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if input_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+        if let Some(redraw_request) = input_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        if let Some(value) = values.pop() {
+            let state = tree.state.downcast_mut::<State>();
+
+            match self.debounce {
+                Some(debounce) => {
+                    let deadline = Instant::now() + debounce;
+                    state.pending = Some((value, deadline));
+                    shell.request_redraw(window::RedrawRequest::At(deadline));
+                }
+                None => shell.publish((self.on_change)(value)),
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let value = tree.state.downcast_ref::<State>().value(&self.value);
+
+        let mut children = layout.children();
+        let input_layout = children
+            .next()
+            .expect("Graphics: Layout should have a text input layout");
+        let clear_bounds = children
+            .next()
+            .expect("Graphics: Layout should have a clear button layout")
+            .bounds();
+
+        if !value.is_empty() && cursor.is_over(clear_bounds) {
+            mouse::Interaction::Pointer
+        } else {
+            Widget::<String, Theme, Renderer>::mouse_interaction(
+                &self.padded_input(value, renderer),
+                &tree.children[0],
+                input_layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let value = tree.state.downcast_ref::<State>().value(&self.value);
+
+        let mut children = layout.children();
+        let input_layout = children
+            .next()
+            .expect("Graphics: Layout should have a text input layout");
+        let clear_bounds = children
+            .next()
+            .expect("Graphics: Layout should have a clear button layout")
+            .bounds();
+
+        Widget::<String, Theme, Renderer>::draw(
+            &self.padded_input(value, renderer),
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            input_layout,
+            cursor,
+            viewport,
+        );
+
+        if value.is_empty() {
+            return;
+        }
+
+        let input_style = <Theme as text_input::StyleSheet>::Style::default();
+        let color = if cursor.is_over(clear_bounds) {
+            theme.value_color(&input_style)
+        } else {
+            theme.active(&input_style).icon_color
+        };
+
+        renderer.fill_text(
+            iced::advanced::Text {
+                content: &icon_to_string(Bootstrap::X),
+                bounds: clear_bounds.size(),
+                size: self.text_size(renderer),
+                line_height: text::LineHeight::Relative(1.0),
+                font: BOOTSTRAP_FONT,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            },
+            clear_bounds.center(),
+            color,
+            *viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<SearchInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + text_input::StyleSheet,
+{
+    fn from(search_input: SearchInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(search_input)
+    }
+}
@@ -0,0 +1,379 @@
+//! Use a skeleton as a placeholder for content that is still loading.
+//!
+//! *This API requires the following crate features to be activated: `skeleton`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    gradient::Linear,
+    mouse::Cursor,
+    window, Background, Border, Color, Element, Event, Gradient, Length, Radians, Rectangle,
+    Shadow, Size,
+};
+use std::{f32::consts::FRAC_PI_2, time::Instant};
+
+pub use crate::style::skeleton::{Appearance, StyleSheet};
+
+/// The height of a line of a text [`Skeleton`].
+const LINE_HEIGHT: f32 = 14.0;
+
+/// The spacing between the lines of a text [`Skeleton`].
+const LINE_SPACING: f32 = 8.0;
+
+/// The width of the shimmer relative to the width of the [`Skeleton`].
+const SHIMMER_WIDTH: f32 = 0.4;
+
+/// The shape of a [`Skeleton`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Shape {
+    /// A rectangle with rounded corners.
+    Rectangle,
+    /// A circle.
+    Circle,
+    /// Stacked lines of text with a shorter last line.
+    Text {
+        /// The number of lines.
+        lines: usize,
+        /// The width of the last line relative to the other lines.
+        last_line_width: f32,
+    },
+}
+
+/// A placeholder shown while content is loading, with a shimmer sweeping across it.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::Skeleton;
+/// # use iced::Length;
+/// #
+/// let image = Skeleton::new(Length::Fill, Length::Fixed(120.0));
+/// let avatar = Skeleton::circle(40.0);
+/// let paragraph = Skeleton::text(3, 0.6);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Skeleton<Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The shape of the [`Skeleton`].
+    shape: Shape,
+    /// The width of the [`Skeleton`].
+    width: Length,
+    /// The height of the [`Skeleton`].
+    height: Length,
+    /// The border radius of the rectangle and the lines of the [`Skeleton`].
+    border_radius: f32,
+    /// The style of the [`Skeleton`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Theme> Skeleton<Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new rectangular [`Skeleton`] of the given size.
+    #[must_use]
+    pub fn new(width: Length, height: Length) -> Self {
+        Self {
+            shape: Shape::Rectangle,
+            width,
+            height,
+            border_radius: 4.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Creates a new circular [`Skeleton`] with the given diameter, e.g. for an avatar.
+    #[must_use]
+    pub fn circle(diameter: f32) -> Self {
+        Self {
+            shape: Shape::Circle,
+            ..Self::new(Length::Fixed(diameter), Length::Fixed(diameter))
+        }
+    }
+
+    /// Creates a new [`Skeleton`] of stacked lines for a paragraph of text.
+    ///
+    /// The width of the last line is relative to the other lines, between 0.0 and 1.0.
+    #[must_use]
+    pub fn text(lines: usize, last_line_width: f32) -> Self {
+        let lines = lines.max(1);
+
+        #[allow(clippy::cast_precision_loss)]
+        let height = lines as f32 * LINE_HEIGHT + (lines - 1) as f32 * LINE_SPACING;
+
+        Self {
+            shape: Shape::Text {
+                lines,
+                last_line_width: last_line_width.clamp(0.0, 1.0),
+            },
+            ..Self::new(Length::Fill, Length::Fixed(height))
+        }
+    }
+
+    /// Sets the width of the [`Skeleton`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Skeleton`].
+    ///
+    /// The lines of a text [`Skeleton`] are spread over the height.
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the border radius of the rectangle and the lines of the [`Skeleton`].
+    #[must_use]
+    pub fn border_radius(mut self, border_radius: f32) -> Self {
+        self.border_radius = border_radius;
+        self
+    }
+
+    /// Sets the style of the [`Skeleton`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The bounds and the border radius of the parts of the [`Skeleton`] in the given bounds.
+    fn parts(&self, bounds: Rectangle) -> Vec<(Rectangle, f32)> {
+        match self.shape {
+            Shape::Rectangle => vec![(bounds, self.border_radius)],
+            Shape::Circle => vec![(bounds, bounds.width.min(bounds.height) / 2.0)],
+            Shape::Text {
+                lines,
+                last_line_width,
+            } => {
+                #[allow(clippy::cast_precision_loss)]
+                let line_height =
+                    ((bounds.height - (lines - 1) as f32 * LINE_SPACING) / lines as f32).max(0.0);
+
+                (0..lines)
+                    .map(|line| {
+                        #[allow(clippy::cast_precision_loss)]
+                        let y = bounds.y + line as f32 * (line_height + LINE_SPACING);
+                        let width = if line + 1 == lines {
+                            bounds.width * last_line_width
+                        } else {
+                            bounds.width
+                        };
+
+                        (
+                            Rectangle::new(
+                                iced::Point::new(bounds.x, y),
+                                Size::new(width, line_height),
+                            ),
+                            self.border_radius.min(line_height / 2.0),
+                        )
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// The state of a [`Skeleton`].
+#[derive(Debug)]
+struct State {
+    /// The time the [`Skeleton`] was created.
+    start: Instant,
+    /// The time of the last frame.
+    now: Instant,
+}
+
+/// The shimmer sweeping across a [`Skeleton`].
+#[derive(Clone, Copy, Debug)]
+struct Shimmer {
+    /// The horizontal center of the shimmer.
+    center: f32,
+    /// Half of the width of the shimmer.
+    half_width: f32,
+}
+
+impl Shimmer {
+    /// Creates the [`Shimmer`] at the given progress between 0.0 and 1.0 of a
+    /// sweep across the given bounds.
+    fn new(bounds: Rectangle, progress: f32) -> Self {
+        let half_width = bounds.width * SHIMMER_WIDTH / 2.0;
+
+        Self {
+            center: bounds.x - half_width + progress * (bounds.width + 2.0 * half_width),
+            half_width,
+        }
+    }
+
+    /// The intensity of the shimmer between 0.0 and 1.0 at the given horizontal position.
+    fn intensity(self, x: f32) -> f32 {
+        if self.half_width <= 0.0 {
+            return 0.0;
+        }
+
+        (1.0 - (x - self.center).abs() / self.half_width).max(0.0)
+    }
+
+    /// The background of a part of a [`Skeleton`] in the given bounds.
+    fn background(self, bounds: Rectangle, appearance: &Appearance) -> Background {
+        let color_at = |x: f32| {
+            mix(
+                appearance.base_color,
+                appearance.shimmer_color,
+                self.intensity(x),
+            )
+        };
+
+        let gradient = [
+            self.center - self.half_width,
+            self.center,
+            self.center + self.half_width,
+        ]
+        .into_iter()
+        .filter(|x| *x > bounds.x && *x < bounds.x + bounds.width)
+        .fold(
+            Linear::new(Radians(FRAC_PI_2))
+                .add_stop(0.0, color_at(bounds.x))
+                .add_stop(1.0, color_at(bounds.x + bounds.width)),
+            |gradient, x| gradient.add_stop((x - bounds.x) / bounds.width, color_at(x)),
+        );
+
+        Background::Gradient(Gradient::Linear(gradient))
+    }
+}
+
+/// Mixes the colors `a` and `b` by the given factor between 0.0 and 1.0.
+fn mix(a: Color, b: Color, factor: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * factor,
+        g: a.g + (b.g - a.g) * factor,
+        b: a.b + (b.b - a.b) * factor,
+        a: a.a + (b.a - a.a) * factor,
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Skeleton<Theme>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let now = Instant::now();
+        tree::State::new(State { start: now, now })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::ZERO,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            tree.state.downcast_mut::<State>().now = now;
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let state = tree.state.downcast_ref::<State>();
+
+        let elapsed = state.now.saturating_duration_since(state.start);
+        let shimmer = Shimmer::new(
+            bounds,
+            (elapsed.as_secs_f32() * appearance.animation_speed.max(0.0)) % 1.0,
+        );
+
+        for (bounds, radius) in self.parts(bounds) {
+            if bounds.width <= 0.0 || bounds.height <= 0.0 {
+                continue;
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: radius.into(),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                shimmer.background(bounds, &appearance),
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Skeleton<Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(skeleton: Skeleton<Theme>) -> Self {
+        Element::new(skeleton)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Shimmer;
+    use iced::{Point, Rectangle, Size};
+
+    #[test]
+    fn shimmer_test() {
+        let bounds = Rectangle::new(Point::new(10.0, 0.0), Size::new(100.0, 20.0));
+
+        // The shimmer starts and ends outside of the bounds
+        assert!(Shimmer::new(bounds, 0.0).intensity(bounds.x) <= 0.0);
+        assert!(Shimmer::new(bounds, 1.0).intensity(bounds.x + bounds.width) <= 0.0);
+
+        let shimmer = Shimmer::new(bounds, 0.5);
+        assert!((shimmer.intensity(60.0) - 1.0).abs() < f32::EPSILON);
+        assert!((shimmer.intensity(80.0) - 0.0).abs() < f32::EPSILON);
+        assert!(shimmer.intensity(50.0) > 0.0 && shimmer.intensity(50.0) < 1.0);
+    }
+}
@@ -20,7 +20,10 @@ use std::time::{Duration, Instant};
 
 /// A spinner widget, a circle spinning around the center of the widget.
 #[allow(missing_debug_implementations)]
-pub struct Spinner {
+pub struct Spinner<Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
     /// The width of the [`Spinner`].
     width: Length,
     /// The height of the [`Spinner`].
@@ -29,20 +32,32 @@ pub struct Spinner {
     rate: Duration,
     /// The radius of the spinning circle.
     circle_radius: f32,
+    /// The thickness of the track the circle is spinning on or `None` for the diameter of the circle.
+    bar_height: Option<f32>,
+    /// The style of the [`Spinner`].
+    style: <Theme as StyleSheet>::Style,
 }
 
-impl Default for Spinner {
+impl<Theme> Default for Spinner<Theme>
+where
+    Theme: StyleSheet,
+{
     fn default() -> Self {
         Self {
             width: Length::Fixed(20.0),
             height: Length::Fixed(20.0),
             rate: Duration::from_secs_f32(1.0),
             circle_radius: 2.0,
+            bar_height: None,
+            style: <Theme as StyleSheet>::Style::default(),
         }
     }
 }
 
-impl Spinner {
+impl<Theme> Spinner<Theme>
+where
+    Theme: StyleSheet,
+{
     /// Creates a new [`Spinner`] widget.
     #[must_use]
     pub fn new() -> Self {
@@ -69,6 +84,31 @@ impl Spinner {
         self.circle_radius = radius;
         self
     }
+
+    /// Sets the thickness of the track the circle is spinning on.
+    #[must_use]
+    pub fn bar_height(mut self, height: f32) -> Self {
+        self.bar_height = Some(height);
+        self
+    }
+
+    /// Sets the speed of the [`Spinner`] in rotations per second.
+    #[must_use]
+    pub fn speed(mut self, rotations: f32) -> Self {
+        self.rate = if rotations > 0.0 {
+            Duration::from_secs_f32(1.0 / rotations)
+        } else {
+            Duration::ZERO
+        };
+        self
+    }
+
+    /// Sets the style of the [`Spinner`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
 }
 
 struct SpinnerState {
@@ -107,7 +147,7 @@ fn fill_circle(
     }
 }
 
-impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Spinner
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Spinner<Theme>
 where
     Renderer: renderer::Renderer,
     Theme: StyleSheet,
@@ -128,8 +168,8 @@ where
         &self,
         state: &Tree,
         renderer: &mut Renderer,
-        _theme: &Theme,
-        style: &renderer::Style,
+        theme: &Theme,
+        _style: &renderer::Style,
         layout: Layout<'_>,
         _cursor: Cursor,
         _viewport: &Rectangle,
@@ -146,15 +186,40 @@ where
             bounds.height
         } / 2.0;
         let state = state.state.downcast_ref::<SpinnerState>();
+        let appearance = theme.appearance(&self.style);
         let center = bounds.center();
         let distance_from_center = size - self.circle_radius;
+
+        // Track
+        let bar_height = self.bar_height.unwrap_or(self.circle_radius * 2.0);
+        if bar_height > 0.0 && appearance.background.a > 0.0 {
+            let radius = distance_from_center + bar_height / 2.0;
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center.x - radius,
+                        y: center.y - radius,
+                        width: radius * 2.0,
+                        height: radius * 2.0,
+                    },
+                    border: Border {
+                        radius: radius.into(),
+                        width: bar_height,
+                        color: appearance.background,
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
         let (y, x) = (state.t * std::f32::consts::PI * 2.0).sin_cos();
         let position = Vector::new(
             center.x + x * distance_from_center - self.circle_radius,
             center.y + y * distance_from_center - self.circle_radius,
         );
 
-        fill_circle(renderer, position, self.circle_radius, style.text_color);
+        fill_circle(renderer, position, self.circle_radius, appearance.color);
     }
 
     fn tag(&self) -> Tag {
@@ -212,12 +277,12 @@ where
     }
 }
 
-impl<'a, Message, Theme, Renderer> From<Spinner> for Element<'a, Message, Theme, Renderer>
+impl<'a, Message, Theme, Renderer> From<Spinner<Theme>> for Element<'a, Message, Theme, Renderer>
 where
     Renderer: renderer::Renderer + 'a,
     Theme: 'a + StyleSheet,
 {
-    fn from(spinner: Spinner) -> Self {
+    fn from(spinner: Spinner<Theme>) -> Self {
         Self::new(spinner)
     }
 }
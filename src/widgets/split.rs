@@ -51,6 +51,8 @@ where
     second: Element<'a, Message, Theme, Renderer>,
     /// The position of the divider.
     divider_position: Option<u16>,
+    /// The ratio of the space given to the first element while no divider position is set.
+    ratio: f32,
     /// The axis to split at.
     axis: Axis,
     /// The padding around the elements of the [`Split`].
@@ -83,7 +85,7 @@ where
     /// It expects:
     ///     - The first [`Element`] to display
     ///     - The second [`Element`] to display
-    ///     - The position of the divider. If none, the space will be split in half.
+    ///     - The position of the divider. If none, the space will be split by the [`ratio`](Self::ratio).
     ///     - The [`Axis`] to split at.
     ///     - The message that is send on moving the divider
     pub fn new<A, B, F>(
@@ -108,6 +110,7 @@ where
                 .height(Length::Fill)
                 .into(),
             divider_position,
+            ratio: 0.5,
             axis,
             padding: 0.0,
             spacing: 5.0,
@@ -149,6 +152,16 @@ where
         self
     }
 
+    /// Sets the ratio of the space given to the first element of the [`Split`]
+    /// while no divider position is set.
+    ///
+    /// The ratio is clamped between 0.0 and 1.0 and defaults to 0.5.
+    #[must_use]
+    pub fn ratio(mut self, ratio: f32) -> Self {
+        self.ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
     /// Sets the minimum size of the first element of the [`Split`].
     #[must_use]
     pub fn min_size_first(mut self, size: u16) -> Self {
@@ -573,7 +586,7 @@ where
 
     let divider_position = split
         .divider_position
-        .unwrap_or_else(|| (space.bounds().height / 2.0) as u16)
+        .unwrap_or_else(|| (space.bounds().height * split.ratio) as u16)
         .max((split.spacing / 2.0) as u16);
     let divider_position = (divider_position - (split.spacing / 2.0) as u16).clamp(
         split.min_size_first,
@@ -652,7 +665,7 @@ where
 
     let divider_position = split
         .divider_position
-        .unwrap_or_else(|| (space.bounds().width / 2.0) as u16)
+        .unwrap_or_else(|| (space.bounds().width * split.ratio) as u16)
         .max((split.spacing / 2.0) as u16);
     let divider_position = (divider_position - (split.spacing / 2.0) as u16).clamp(
         split.min_size_first,
@@ -0,0 +1,258 @@
+//! Use a status indicator for showing whether a user is online.
+//!
+//! *This API requires the following crate features to be activated: `status_indicator`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::Cursor,
+    window, Border, Color, Element, Event, Length, Rectangle, Shadow, Size,
+};
+use std::time::{Duration, Instant};
+
+pub use crate::style::status_indicator::{Appearance, StyleSheet};
+
+/// The time the pulse of a [`StatusIndicator`] needs for expanding.
+const PULSE_DURATION: Duration = Duration::from_millis(1500);
+
+/// The radius the pulse of a [`StatusIndicator`] expands to, relative to its radius.
+const PULSE_RADIUS: f32 = 2.0;
+
+/// The opacity of the pulse of a [`StatusIndicator`] when it starts expanding.
+const PULSE_ALPHA: f32 = 0.6;
+
+/// The status shown by a [`StatusIndicator`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    /// The user is online.
+    Online,
+    /// The user is away.
+    Away,
+    /// The user is busy and doesn't want to be disturbed.
+    Busy,
+    /// The user is offline.
+    Offline,
+    /// A status with a custom color.
+    Custom(Color),
+}
+
+impl Status {
+    /// The color of the status in the given [`Appearance`].
+    fn color(self, appearance: &Appearance) -> Color {
+        match self {
+            Self::Online => appearance.online_color,
+            Self::Away => appearance.away_color,
+            Self::Busy => appearance.busy_color,
+            Self::Offline => appearance.offline_color,
+            Self::Custom(color) => color,
+        }
+    }
+}
+
+/// A small dot showing the status of a user, e.g. in a corner of an
+/// [`Avatar`](crate::widgets::Avatar).
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{StatusIndicator, status_indicator::Status};
+/// #
+/// let online = StatusIndicator::new(Status::Online).pulse(true);
+/// let busy = StatusIndicator::new(Status::Busy).size(8.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct StatusIndicator<Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The status of the [`StatusIndicator`].
+    status: Status,
+    /// The diameter of the [`StatusIndicator`].
+    size: f32,
+    /// Whether a ring is pulsing around the [`StatusIndicator`] while it's online.
+    pulse: bool,
+    /// The style of the [`StatusIndicator`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Theme> StatusIndicator<Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`StatusIndicator`] showing the given status.
+    #[must_use]
+    pub fn new(status: Status) -> Self {
+        Self {
+            status,
+            size: 12.0,
+            pulse: false,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the diameter of the [`StatusIndicator`].
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Shows a ring continuously expanding from the [`StatusIndicator`] while the
+    /// status is [`Status::Online`].
+    ///
+    /// The ring is drawn beyond the bounds of the [`StatusIndicator`].
+    #[must_use]
+    pub fn pulse(mut self, pulse: bool) -> Self {
+        self.pulse = pulse;
+        self
+    }
+
+    /// Sets the style of the [`StatusIndicator`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Whether the pulse is shown.
+    fn is_pulsing(&self) -> bool {
+        self.pulse && self.status == Status::Online
+    }
+}
+
+/// The state of a [`StatusIndicator`].
+#[derive(Debug)]
+struct State {
+    /// The last time the pulse was expanded.
+    last_update: Instant,
+    /// The progress of the pulse between 0.0 and 1.0.
+    t: f32,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for StatusIndicator<Theme>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            last_update: Instant::now(),
+            t: 0.0,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.size), Length::Fixed(self.size))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(self.size, self.size, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            if self.is_pulsing() {
+                let elapsed = now.saturating_duration_since(state.last_update);
+                state.t = (state.t + elapsed.as_secs_f32() / PULSE_DURATION.as_secs_f32()) % 1.0;
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+
+            state.last_update = now;
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let color = self.status.color(&appearance);
+        let radius = bounds.width.min(bounds.height) / 2.0;
+
+        if radius <= 0.0 {
+            return;
+        }
+
+        if self.is_pulsing() {
+            let t = tree.state.downcast_ref::<State>().t;
+            let pulse_radius = radius * (1.0 + t * (PULSE_RADIUS - 1.0));
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.center_x() - pulse_radius,
+                        y: bounds.center_y() - pulse_radius,
+                        width: pulse_radius * 2.0,
+                        height: pulse_radius * 2.0,
+                    },
+                    border: Border {
+                        radius: pulse_radius.into(),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color {
+                    a: color.a * PULSE_ALPHA * (1.0 - t),
+                    ..color
+                },
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: radius.into(),
+                    width: appearance.border_width.min(radius),
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            color,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<StatusIndicator<Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(status_indicator: StatusIndicator<Theme>) -> Self {
+        Element::new(status_indicator)
+    }
+}
@@ -0,0 +1,503 @@
+//! Use a stepper for showing the progress through a sequence of steps.
+//!
+//! *This API requires the following crate features to be activated: `stepper`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::{self, Paragraph as _},
+        widget::Tree,
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+use crate::core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT};
+pub use crate::style::stepper::{StepAppearance, StepState, StyleSheet};
+
+/// The space between the circle and the label of a step.
+const LABEL_SPACING: f32 = 4.0;
+
+/// The thickness of the lines connecting the steps.
+const CONNECTOR_WIDTH: f32 = 2.0;
+
+/// The label of a step of a [`Stepper`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StepLabel {
+    /// The text shown next to the circle of the step.
+    label: String,
+    /// Whether the step has failed.
+    error: bool,
+}
+
+impl StepLabel {
+    /// Creates a new [`StepLabel`] with the given text.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            error: false,
+        }
+    }
+
+    /// Marks the step as failed, showing it in the [`StepState::Error`] state.
+    #[must_use]
+    pub fn error(mut self, error: bool) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl From<&str> for StepLabel {
+    fn from(label: &str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<String> for StepLabel {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+/// The direction in which the steps of a [`Stepper`] are laid out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StepperOrientation {
+    /// The steps are laid out from left to right with their labels below.
+    #[default]
+    Horizontal,
+    /// The steps are laid out from top to bottom with their labels on the right.
+    Vertical,
+}
+
+/// A sequence of numbered step circles connected by lines, showing the
+/// progress through the steps.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{stepper::StepLabel, Stepper};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     GoTo(usize),
+/// }
+///
+/// let stepper = Stepper::new(
+///     vec![StepLabel::new("Cart"), StepLabel::new("Shipping"), StepLabel::new("Payment")],
+///     1,
+/// )
+/// .on_step_click(Message::GoTo);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Stepper<'a, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The steps of the [`Stepper`].
+    steps: Vec<StepLabel>,
+    /// The index of the current step.
+    current: usize,
+    /// The function that produces a message when a completed step is clicked.
+    on_step_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The direction in which the steps are laid out.
+    orientation: StepperOrientation,
+    /// The diameter of the step circles.
+    circle_size: f32,
+    /// The length of the lines connecting the steps.
+    spacing: f32,
+    /// The text size of the numbers and labels, or the default size of the renderer.
+    text_size: Option<Pixels>,
+    /// The style of the [`Stepper`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Stepper<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Stepper`].
+    ///
+    /// It expects:
+    ///     * the steps.
+    ///     * the index of the current step. All steps before it are completed,
+    ///       so an index past the last step shows every step as completed.
+    #[must_use]
+    pub fn new(steps: Vec<StepLabel>, current: usize) -> Self {
+        Self {
+            steps,
+            current,
+            on_step_click: None,
+            orientation: StepperOrientation::default(),
+            circle_size: 28.0,
+            spacing: 24.0,
+            text_size: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function that will be called with the index of a clicked
+    /// completed step of the [`Stepper`], allowing to navigate back.
+    #[must_use]
+    pub fn on_step_click<F>(mut self, on_step_click: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_step_click = Some(Box::new(on_step_click));
+        self
+    }
+
+    /// Sets the direction in which the steps of the [`Stepper`] are laid out.
+    #[must_use]
+    pub fn orientation(mut self, orientation: StepperOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the diameter of the step circles of the [`Stepper`].
+    #[must_use]
+    pub fn circle_size(mut self, circle_size: f32) -> Self {
+        self.circle_size = circle_size;
+        self
+    }
+
+    /// Sets the length of the lines connecting the steps of the [`Stepper`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the numbers and labels of the [`Stepper`].
+    #[must_use]
+    pub fn text_size<P: Into<Pixels>>(mut self, text_size: P) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the style of the [`Stepper`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The state of the step with the given index.
+    fn step_state(&self, index: usize) -> StepState {
+        if self.steps[index].error {
+            StepState::Error
+        } else if index < self.current {
+            StepState::Completed
+        } else if index == self.current {
+            StepState::Active
+        } else {
+            StepState::Pending
+        }
+    }
+
+    /// The index of the completed step that would be clicked at the cursor.
+    fn clickable_step(&self, layout: Layout<'_>, cursor: Cursor) -> Option<usize> {
+        self.on_step_click.as_ref().and_then(|_| {
+            layout
+                .children()
+                .take(self.current)
+                .position(|step| cursor.is_over(step.bounds()))
+        })
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Stepper<'_, Message, Theme>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+    Theme: StyleSheet,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let circle = Size::new(self.circle_size, self.circle_size);
+
+        let labels: Vec<Size> = self
+            .steps
+            .iter()
+            .map(|step| {
+                Renderer::Paragraph::with_text(text::Text {
+                    content: &step.label,
+                    bounds: Size::INFINITY,
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                })
+                .min_bounds()
+            })
+            .collect();
+        let max_label_height = labels.iter().map(|label| label.height).fold(0.0, f32::max);
+
+        let mut offset = 0.0;
+        let mut cross = 0.0_f32;
+        let steps: Vec<Node> = labels
+            .into_iter()
+            .map(|label| {
+                let (size, circle_position, label_position) = match self.orientation {
+                    StepperOrientation::Horizontal => {
+                        let size = Size::new(
+                            circle.width.max(label.width),
+                            circle.height + LABEL_SPACING + max_label_height,
+                        );
+                        (
+                            size,
+                            Point::new((size.width - circle.width) / 2.0, 0.0),
+                            Point::new(
+                                (size.width - label.width) / 2.0,
+                                circle.height + LABEL_SPACING,
+                            ),
+                        )
+                    }
+                    StepperOrientation::Vertical => {
+                        let size = Size::new(
+                            circle.width + LABEL_SPACING + label.width,
+                            circle.height.max(label.height),
+                        );
+                        (
+                            size,
+                            Point::new(0.0, (size.height - circle.height) / 2.0),
+                            Point::new(
+                                circle.width + LABEL_SPACING,
+                                (size.height - label.height) / 2.0,
+                            ),
+                        )
+                    }
+                };
+
+                let position = match self.orientation {
+                    StepperOrientation::Horizontal => {
+                        cross = cross.max(size.height);
+                        Point::new(offset, 0.0)
+                    }
+                    StepperOrientation::Vertical => {
+                        cross = cross.max(size.width);
+                        Point::new(0.0, offset)
+                    }
+                };
+                offset += match self.orientation {
+                    StepperOrientation::Horizontal => size.width,
+                    StepperOrientation::Vertical => size.height,
+                } + self.spacing;
+
+                Node::with_children(
+                    size,
+                    vec![
+                        Node::new(circle).move_to(circle_position),
+                        Node::new(label).move_to(label_position),
+                    ],
+                )
+                .move_to(position)
+            })
+            .collect();
+
+        let length = (offset - self.spacing).max(0.0);
+        let size = match self.orientation {
+            StepperOrientation::Horizontal => Size::new(length, cross),
+            StepperOrientation::Vertical => Size::new(cross, length),
+        };
+
+        Node::with_children(limits.resolve(Length::Shrink, Length::Shrink, size), steps)
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match (self.clickable_step(layout, cursor), &self.on_step_click) {
+                    (Some(index), Some(on_step_click)) => {
+                        shell.publish(on_step_click(index));
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.clickable_step(layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let circles: Vec<Rectangle> = layout
+            .children()
+            .map(|step| {
+                step.children()
+                    .next()
+                    .expect("Graphics: Layout should have a circle layout")
+                    .bounds()
+            })
+            .collect();
+
+        // Connectors
+        for (index, pair) in circles.windows(2).enumerate() {
+            let appearance = theme.step(&self.style, self.step_state(index));
+            let (from, to) = (pair[0], pair[1]);
+            let bounds = match self.orientation {
+                StepperOrientation::Horizontal => Rectangle {
+                    x: from.x + from.width,
+                    y: from.center_y() - CONNECTOR_WIDTH / 2.0,
+                    width: to.x - from.x - from.width,
+                    height: CONNECTOR_WIDTH,
+                },
+                StepperOrientation::Vertical => Rectangle {
+                    x: from.center_x() - CONNECTOR_WIDTH / 2.0,
+                    y: from.y + from.height,
+                    width: CONNECTOR_WIDTH,
+                    height: to.y - from.y - from.height,
+                },
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                appearance.connector_color,
+            );
+        }
+
+        // Steps
+        for (index, step) in layout.children().enumerate() {
+            let state = self.step_state(index);
+            let appearance = theme.step(&self.style, state);
+
+            let mut children = step.children();
+            let circle_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a circle layout")
+                .bounds();
+            let label_bounds = children
+                .next()
+                .expect("Graphics: Layout should have a label layout")
+                .bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: circle_bounds,
+                    border: Border {
+                        radius: (circle_bounds.width / 2.0).into(),
+                        width: appearance.circle_border_width,
+                        color: appearance.circle_border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.circle_background,
+            );
+
+            let (content, font) = match state {
+                StepState::Completed => (icon_to_string(Bootstrap::CheckLg), BOOTSTRAP_FONT),
+                StepState::Error => (icon_to_string(Bootstrap::ExclamationLg), BOOTSTRAP_FONT),
+                StepState::Active | StepState::Pending => {
+                    ((index + 1).to_string(), renderer.default_font())
+                }
+            };
+
+            draw_text(
+                renderer,
+                &content,
+                font,
+                text_size,
+                circle_bounds,
+                appearance.circle_text_color,
+                viewport,
+            );
+
+            draw_text(
+                renderer,
+                &self.steps[index].label,
+                renderer.default_font(),
+                text_size,
+                label_bounds,
+                appearance.label_color,
+                viewport,
+            );
+        }
+    }
+}
+
+/// Draws the text centered in the bounds.
+fn draw_text<Renderer>(
+    renderer: &mut Renderer,
+    content: &str,
+    font: iced::Font,
+    size: Pixels,
+    bounds: Rectangle,
+    color: Color,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    renderer.fill_text(
+        text::Text {
+            content,
+            bounds: bounds.size(),
+            size,
+            line_height: text::LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        },
+        bounds.center(),
+        color,
+        *viewport,
+    );
+}
+
+impl<'a, Message, Theme, Renderer> From<Stepper<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(stepper: Stepper<'a, Message, Theme>) -> Self {
+        Element::new(stepper)
+    }
+}
@@ -109,6 +109,8 @@ where
     style: <Theme as StyleSheet>::Style,
     /// Where the icon is placed relative to text
     position: Position,
+    /// Stacks the tabs vertically instead of horizontally.
+    vertical: bool,
     #[allow(clippy::missing_docs_in_private_items)]
     _renderer: PhantomData<Renderer>,
 }
@@ -177,6 +179,7 @@ where
             text_font: None,
             style: <Theme as StyleSheet>::Style::default(),
             position: Position::default(),
+            vertical: false,
             _renderer: PhantomData,
         }
     }
@@ -336,6 +339,16 @@ where
         self.width = width.into();
         self
     }
+
+    /// Stacks the tabs of the [`TabBar`] vertically instead of horizontally.
+    ///
+    /// The tabs of a vertical [`TabBar`] fill its width. If the width is
+    /// [`Length::Shrink`], all tabs are as wide as the widest one.
+    #[must_use]
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self
+    }
 }
 
 impl<Message, TabId, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -388,10 +401,9 @@ where
                 .width(Length::Shrink)
         }
 
-        let row = self
-            .tab_labels
-            .iter()
-            .fold(Row::<Message, Theme, Renderer>::new(), |row, tab_label| {
+        // Creates the tabs with the given width of the tabs and their labels.
+        let tabs = |tab_width: Length, label_width: Length| {
+            self.tab_labels.iter().map(move |tab_label| {
                 let mut label_row = Row::new()
                     .push(
                         match tab_label {
@@ -471,12 +483,12 @@ where
                                 column
                             }
                         }
-                        .width(self.tab_width)
+                        .width(label_width)
                         .height(self.height),
                     )
                     .align_items(Alignment::Center)
                     .padding(self.padding)
-                    .width(self.tab_width);
+                    .width(tab_width);
 
                 if self.on_close.is_some() {
                     label_row = label_row.push(
@@ -487,14 +499,46 @@ where
                     );
                 }
 
-                row.push(label_row)
+                Element::from(label_row)
             })
-            .width(self.width)
-            .height(self.height)
-            .spacing(self.spacing)
-            .align_items(Alignment::Center);
+        };
+
+        let element: Element<Message, Theme, Renderer> = if self.vertical {
+            let (tab_width, label_width) = if self.width == Length::Shrink {
+                // Measure the widest tab first, so all tabs can be as wide as it.
+                let column = Column::with_children(tabs(Length::Shrink, Length::Shrink));
+                let widest = column
+                    .layout(
+                        &mut Tree::new(&column as &dyn Widget<_, _, _>),
+                        renderer,
+                        &limits.loose(),
+                    )
+                    .children()
+                    .iter()
+                    .map(|tab| tab.size().width)
+                    .fold(0.0, f32::max);
+                (Length::Fixed(widest), Length::Fill)
+            } else {
+                (Length::Fill, Length::Fill)
+            };
+
+            Element::new(
+                Column::with_children(tabs(tab_width, label_width))
+                    .width(self.width)
+                    .height(self.height)
+                    .spacing(self.spacing)
+                    .align_items(Alignment::Center),
+            )
+        } else {
+            Element::new(
+                Row::with_children(tabs(self.tab_width, self.tab_width))
+                    .width(self.width)
+                    .height(self.height)
+                    .spacing(self.spacing)
+                    .align_items(Alignment::Center),
+            )
+        };
 
-        let element: Element<Message, Theme, Renderer> = Element::new(row);
         let tab_tree = if let Some(child_tree) = tree.children.get_mut(0) {
             child_tree.diff(element.as_widget());
             child_tree
@@ -235,8 +235,17 @@ where
     }
 
     /// Sets the [`TabBarPosition`] of the [`TabBar`](super::tab_bar::TabBar).
+    ///
+    /// At [`TabBarPosition::Left`] and [`TabBarPosition::Right`] the
+    /// [`TabBar`](super::tab_bar::TabBar) is vertical and shrinks to its widest tab.
+    /// Use [`Tabs::tab_bar_width`] afterwards to give it another width.
     #[must_use]
     pub fn tab_bar_position(mut self, position: TabBarPosition) -> Self {
+        let vertical = matches!(position, TabBarPosition::Left | TabBarPosition::Right);
+        self.tab_bar = self.tab_bar.vertical(vertical);
+        if vertical {
+            self.tab_bar = self.tab_bar.width(Length::Shrink);
+        }
         self.tab_bar_position = position;
         self
     }
@@ -326,7 +335,16 @@ where
     }
 
     fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
-        let tab_bar_limits = limits.width(self.width).height(Length::Shrink);
+        let vertical = matches!(
+            self.tab_bar_position,
+            TabBarPosition::Left | TabBarPosition::Right
+        );
+
+        let tab_bar_limits = if vertical {
+            limits.width(Length::Shrink).height(self.height)
+        } else {
+            limits.width(self.width).height(Length::Shrink)
+        };
         let mut tab_bar_node =
             self.tab_bar
                 .layout(&mut tree.children[0], renderer, &tab_bar_limits);
@@ -334,7 +352,11 @@ where
         let tab_content_limits = limits
             .width(self.width)
             .height(self.height)
-            .shrink([0.0, tab_bar_node.size().height]);
+            .shrink(if vertical {
+                [tab_bar_node.size().width, 0.0]
+            } else {
+                [0.0, tab_bar_node.size().height]
+            });
 
         let mut tab_content_node =
             if let Some(element) = self.tabs.get(self.tab_bar.get_active_tab_idx()) {
@@ -351,33 +373,57 @@ where
             };
 
         let tab_bar_bounds = tab_bar_node.bounds();
-        tab_bar_node = tab_bar_node.move_to(Point::new(
-            tab_bar_bounds.x,
-            tab_bar_bounds.y
-                + match self.tab_bar_position {
-                    TabBarPosition::Top => 0.0,
-                    TabBarPosition::Bottom => tab_content_node.bounds().height,
-                },
-        ));
+        tab_bar_node = tab_bar_node.move_to(match self.tab_bar_position {
+            TabBarPosition::Top | TabBarPosition::Left => {
+                Point::new(tab_bar_bounds.x, tab_bar_bounds.y)
+            }
+            TabBarPosition::Bottom => Point::new(
+                tab_bar_bounds.x,
+                tab_bar_bounds.y + tab_content_node.bounds().height,
+            ),
+            TabBarPosition::Right => Point::new(
+                tab_bar_bounds.x + tab_content_node.bounds().width,
+                tab_bar_bounds.y,
+            ),
+        });
 
         let tab_content_bounds = tab_content_node.bounds();
-        tab_content_node = tab_content_node.move_to(Point::new(
-            tab_content_bounds.x,
-            tab_content_bounds.y
-                + match self.tab_bar_position {
-                    TabBarPosition::Top => tab_bar_node.bounds().height,
-                    TabBarPosition::Bottom => 0.0,
-                },
-        ));
+        tab_content_node = tab_content_node.move_to(match self.tab_bar_position {
+            TabBarPosition::Top => Point::new(
+                tab_content_bounds.x,
+                tab_content_bounds.y + tab_bar_node.bounds().height,
+            ),
+            TabBarPosition::Left => Point::new(
+                tab_content_bounds.x + tab_bar_node.bounds().width,
+                tab_content_bounds.y,
+            ),
+            TabBarPosition::Bottom | TabBarPosition::Right => {
+                Point::new(tab_content_bounds.x, tab_content_bounds.y)
+            }
+        });
 
-        Node::with_children(
+        let size = if vertical {
+            Size::new(
+                tab_bar_node.size().width + tab_content_node.size().width,
+                tab_bar_node
+                    .size()
+                    .height
+                    .max(tab_content_node.size().height),
+            )
+        } else {
             Size::new(
                 tab_content_node.size().width,
                 tab_bar_node.size().height + tab_content_node.size().height,
-            ),
+            )
+        };
+
+        Node::with_children(
+            size,
             match self.tab_bar_position {
-                TabBarPosition::Top => vec![tab_bar_node, tab_content_node],
-                TabBarPosition::Bottom => vec![tab_content_node, tab_bar_node],
+                TabBarPosition::Top | TabBarPosition::Left => vec![tab_bar_node, tab_content_node],
+                TabBarPosition::Bottom | TabBarPosition::Right => {
+                    vec![tab_content_node, tab_bar_node]
+                }
             },
         )
     }
@@ -395,7 +441,7 @@ where
     ) -> event::Status {
         let mut children = layout.children();
         let (tab_bar_layout, tab_content_layout) = match self.tab_bar_position {
-            TabBarPosition::Top => {
+            TabBarPosition::Top | TabBarPosition::Left => {
                 let tab_bar_layout = children
                     .next()
                     .expect("Native: Layout should have a TabBar layout at top position");
@@ -404,7 +450,7 @@ where
                     .expect("Native: Layout should have a tab content layout at top position");
                 (tab_bar_layout, tab_content_layout)
             }
-            TabBarPosition::Bottom => {
+            TabBarPosition::Bottom | TabBarPosition::Right => {
                 let tab_content_layout = children
                     .next()
                     .expect("Native: Layout should have a tab content layout at bottom position");
@@ -456,10 +502,10 @@ where
         // Tab bar
         let mut children = layout.children();
         let tab_bar_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
+            TabBarPosition::Top | TabBarPosition::Left => children
                 .next()
                 .expect("Native: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
+            TabBarPosition::Bottom | TabBarPosition::Right => children
                 .last()
                 .expect("Native: There should be a TabBar at the bottom position"),
         };
@@ -480,10 +526,10 @@ where
         // Tab content
         let mut children = layout.children();
         let tab_content_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
+            TabBarPosition::Top | TabBarPosition::Left => children
                 .last()
                 .expect("Graphics: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
+            TabBarPosition::Bottom | TabBarPosition::Right => children
                 .next()
                 .expect("Graphics: There should be a TabBar at the bottom position"),
         };
@@ -517,10 +563,10 @@ where
     ) {
         let mut children = layout.children();
         let tab_bar_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
+            TabBarPosition::Top | TabBarPosition::Left => children
                 .next()
                 .expect("Native: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
+            TabBarPosition::Bottom | TabBarPosition::Right => children
                 .last()
                 .expect("Native: There should be a TabBar at the bottom position"),
         };
@@ -538,10 +584,10 @@ where
         let mut children = layout.children();
 
         let tab_content_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
+            TabBarPosition::Top | TabBarPosition::Left => children
                 .last()
                 .expect("Graphics: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
+            TabBarPosition::Bottom | TabBarPosition::Right => children
                 .next()
                 .expect("Graphics: There should be a TabBar at the bottom position"),
         };
@@ -568,8 +614,8 @@ where
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         let layout = match self.tab_bar_position {
-            TabBarPosition::Top => layout.children().nth(1),
-            TabBarPosition::Bottom => layout.children().next(),
+            TabBarPosition::Top | TabBarPosition::Left => layout.children().nth(1),
+            TabBarPosition::Bottom | TabBarPosition::Right => layout.children().next(),
         };
 
         layout.and_then(|layout| {
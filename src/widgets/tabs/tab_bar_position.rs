@@ -13,4 +13,12 @@ pub enum TabBarPosition {
     /// A [`TabBarPosition`] for placing the [`TabBar`](crate::native::tab_bar::TabBar)
     ///  on bottom of its content.
     Bottom,
+
+    /// A [`TabBarPosition`] for placing a vertical [`TabBar`](crate::native::tab_bar::TabBar)
+    ///  left of its content.
+    Left,
+
+    /// A [`TabBarPosition`] for placing a vertical [`TabBar`](crate::native::tab_bar::TabBar)
+    ///  right of its content.
+    Right,
 }
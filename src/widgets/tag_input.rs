@@ -0,0 +1,551 @@
+//! Use a tag input for entering several freeform tags.
+//!
+//! *This API requires the following crate features to be activated: `tag_input`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer, text,
+        widget::{
+            operation,
+            tree::{self, Tag},
+            Id, Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event, keyboard,
+    mouse::{self, Cursor},
+    widget::{text_input, TextInput},
+    Alignment, Element, Event, Length, Padding, Pixels, Rectangle, Size, Vector,
+};
+use std::any::Any;
+
+use crate::widgets::{
+    chip::{self, Chip},
+    wrap::Wrap,
+};
+
+/// The default padding of the text input of a [`TagInput`].
+const DEFAULT_PADDING: f32 = 5.0;
+
+/// The default width of the text input of a [`TagInput`].
+const DEFAULT_INPUT_WIDTH: f32 = 150.0;
+
+/// The character separating the tags typed into a [`TagInput`].
+const SEPARATOR: char = ',';
+
+/// A text input showing the entered tags as [`Chip`]s in front of it.
+///
+/// Typing a comma or pressing enter turns the typed text into a new tag,
+/// pressing backspace while no text is typed removes the last tag.
+/// The tags and the text input wrap to multiple lines when they exceed the
+/// available width.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::TagInput;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TagsChanged(Vec<String>),
+/// }
+///
+/// let tags = vec![String::from("rust"), String::from("gui")];
+/// let tag_input = TagInput::new(tags, Message::TagsChanged)
+///     .placeholder("Add a tag...")
+///     .max_tags(5)
+///     .allowed_chars(|c| c.is_alphanumeric() || c == '-');
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TagInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+    Theme: chip::StyleSheet + text_input::StyleSheet,
+{
+    /// The current tags.
+    tags: Vec<String>,
+    /// The function that produces a message when the tags change.
+    on_change: Box<dyn Fn(Vec<String>) -> Message + 'a>,
+    /// The message that is produced when enter is pressed while no text is typed.
+    on_submit: Option<Message>,
+    /// The maximum number of tags, or `None` for no limit.
+    max_tags: Option<usize>,
+    /// The text shown while no text is typed.
+    placeholder: String,
+    /// The function deciding whether a typed character is kept.
+    allowed_chars: Option<Box<dyn Fn(char) -> bool + 'a>>,
+    /// The width of the [`TagInput`].
+    width: Length,
+    /// The width of the text input of the [`TagInput`].
+    input_width: f32,
+    /// The spacing between the tags and the lines of the [`TagInput`].
+    spacing: f32,
+    /// The padding of the text input of the [`TagInput`].
+    padding: Padding,
+    /// The text size of the [`TagInput`], or the default size of the renderer.
+    size: Option<Pixels>,
+    /// The renderer and theme the [`TagInput`] is drawn with.
+    _phantom: std::marker::PhantomData<(Theme, Renderer)>,
+}
+
+impl<'a, Message, Theme, Renderer> TagInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + chip::StyleSheet + text_input::StyleSheet,
+{
+    /// Creates a new [`TagInput`].
+    ///
+    /// It expects:
+    ///     * the current tags.
+    ///     * a function that will be called with the new tags when they change.
+    pub fn new<F>(tags: Vec<String>, on_change: F) -> Self
+    where
+        F: 'a + Fn(Vec<String>) -> Message,
+    {
+        Self {
+            tags,
+            on_change: Box::new(on_change),
+            on_submit: None,
+            max_tags: None,
+            placeholder: String::new(),
+            allowed_chars: None,
+            width: Length::Fill,
+            input_width: DEFAULT_INPUT_WIDTH,
+            spacing: 5.0,
+            padding: Padding::from(DEFAULT_PADDING),
+            size: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the maximum number of tags of the [`TagInput`].
+    #[must_use]
+    pub fn max_tags(mut self, max_tags: usize) -> Self {
+        self.max_tags = Some(max_tags);
+        self
+    }
+
+    /// Sets the text shown while no text is typed into the [`TagInput`].
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the function deciding whether a typed character is kept.
+    ///
+    /// Characters it rejects are dropped from the typed text.
+    #[must_use]
+    pub fn allowed_chars<F>(mut self, allowed_chars: F) -> Self
+    where
+        F: 'a + Fn(char) -> bool,
+    {
+        self.allowed_chars = Some(Box::new(allowed_chars));
+        self
+    }
+
+    /// Sets the message that is produced when enter is pressed while no text
+    /// is typed into the [`TagInput`].
+    #[must_use]
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets the width of the [`TagInput`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the width of the text input of the [`TagInput`].
+    #[must_use]
+    pub fn input_width(mut self, input_width: f32) -> Self {
+        self.input_width = input_width;
+        self
+    }
+
+    /// Sets the spacing between the tags and the lines of the [`TagInput`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding of the text input of the [`TagInput`].
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TagInput`].
+    #[must_use]
+    pub fn size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// The tags followed by the text input showing the given text.
+    fn content(&self, text: &str) -> Element<'_, TagMessage, Theme, Renderer> {
+        let chips = self.tags.iter().enumerate().map(|(index, tag)| {
+            let chip = Chip::new(tag.as_str()).on_delete(TagMessage::Remove(index));
+            match self.size {
+                Some(size) => chip.text_size(size),
+                None => chip,
+            }
+            .into()
+        });
+
+        let input = TextInput::new(&self.placeholder, text)
+            .on_input(TagMessage::Input)
+            .on_submit(TagMessage::Submit)
+            .width(self.input_width)
+            .padding(self.padding);
+        let input = match self.size {
+            Some(size) => input.size(size),
+            None => input,
+        };
+
+        Wrap::with_elements(chips.chain(std::iter::once(input.into())).collect())
+            .width_items(self.width)
+            .spacing(self.spacing)
+            .line_spacing(self.spacing)
+            .align_items(Alignment::Center)
+            .into()
+    }
+
+    /// The tags with the given tags added, as long as there is room for them.
+    fn with_tags(&self, added: Vec<String>) -> Vec<String> {
+        let max_tags = self.max_tags.unwrap_or(usize::MAX);
+        let room = max_tags.saturating_sub(self.tags.len());
+
+        self.tags
+            .iter()
+            .cloned()
+            .chain(added.into_iter().take(room))
+            .collect()
+    }
+
+    /// Whether another tag can be added.
+    fn has_room(&self) -> bool {
+        self.max_tags
+            .is_none_or(|max_tags| self.tags.len() < max_tags)
+    }
+}
+
+/// Splits the typed text at the separators into the completed tags and the
+/// text still being typed, dropping the characters that are not allowed.
+fn split_tags(text: &str, allowed_chars: Option<&dyn Fn(char) -> bool>) -> (Vec<String>, String) {
+    let filtered: String = text
+        .chars()
+        .filter(|c| *c == SEPARATOR || allowed_chars.is_none_or(|allowed| allowed(*c)))
+        .collect();
+
+    let mut parts: Vec<&str> = filtered.split(SEPARATOR).collect();
+    let rest = parts.pop().unwrap_or_default().to_owned();
+    let tags = parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(ToOwned::to_owned)
+        .collect();
+
+    (tags, rest)
+}
+
+/// The messages of the tags and the text input of a [`TagInput`].
+#[derive(Clone, Debug)]
+enum TagMessage {
+    /// The tag with the given index is deleted.
+    Remove(usize),
+    /// The typed text changed.
+    Input(String),
+    /// Enter was pressed in the text input.
+    Submit,
+}
+
+/// The state of a [`TagInput`].
+#[derive(Debug, Default)]
+struct State {
+    /// The text typed into the text input.
+    text: String,
+}
+
+/// The state of the text input in the tree of the content of a [`TagInput`].
+fn input_state<Renderer: text::Renderer>(
+    tree: &Tree,
+) -> Option<&text_input::State<Renderer::Paragraph>> {
+    tree.children[0].children.last().map(|input| {
+        input
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+    })
+}
+
+/// Forwards the operations on the content of a [`TagInput`], which produces
+/// its own messages instead of the messages of the application.
+struct ContentOperation<'b, T>(&'b mut dyn Operation<T>);
+
+impl<T> Operation<TagMessage> for ContentOperation<'_, T> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<TagMessage>),
+    ) {
+        self.0.container(id, bounds, &mut |operation| {
+            operate_on_children(&mut ContentOperation(operation));
+        });
+    }
+
+    fn focusable(&mut self, state: &mut dyn operation::Focusable, id: Option<&Id>) {
+        self.0.focusable(state, id);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.0.scrollable(state, id, bounds, translation);
+    }
+
+    fn text_input(&mut self, state: &mut dyn operation::TextInput, id: Option<&Id>) {
+        self.0.text_input(state, id);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        self.0.custom(state, id);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TagInput<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + chip::StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(self.content(""))]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let text = tree.state.downcast_ref::<State>().text.clone();
+        tree.diff_children(&[&self.content(&text)]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let text = tree.state.downcast_ref::<State>().text.clone();
+        self.content(&text)
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let text = tree.state.downcast_ref::<State>().text.clone();
+        self.content(&text).as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut ContentOperation(operation),
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let text = tree.state.downcast_ref::<State>().text.clone();
+
+        // Remove the last tag with backspace once the typed text is empty.
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::Backspace),
+            ..
+        }) = &event
+        {
+            let focused = input_state::<Renderer>(tree).is_some_and(text_input::State::is_focused);
+            if focused && text.is_empty() && !self.tags.is_empty() {
+                let mut tags = self.tags.clone();
+                let _ = tags.pop();
+                shell.publish((self.on_change)(tags));
+                return event::Status::Captured;
+            }
+        }
+
+        let mut messages = Vec::new();
+        let mut content_shell = Shell::new(&mut messages);
+        let status = self.content(&text).as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut content_shell,
+            viewport,
+        );
+
+        if content_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if content_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+        if let Some(redraw_request) = content_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        for message in messages {
+            let state = tree.state.downcast_mut::<State>();
+
+            match message {
+                TagMessage::Remove(index) => {
+                    let mut tags = self.tags.clone();
+                    if index < tags.len() {
+                        let _ = tags.remove(index);
+                        shell.publish((self.on_change)(tags));
+                    }
+                }
+                TagMessage::Input(value) => {
+                    let (added, rest) = split_tags(&value, self.allowed_chars.as_deref());
+
+                    if added.is_empty() {
+                        state.text = rest;
+                    } else if self.has_room() {
+                        state.text = rest;
+                        shell.publish((self.on_change)(self.with_tags(added)));
+                    }
+                    shell.invalidate_layout();
+                }
+                TagMessage::Submit => {
+                    let tag = state.text.trim().to_owned();
+
+                    if tag.is_empty() {
+                        if let Some(on_submit) = &self.on_submit {
+                            shell.publish(on_submit.clone());
+                        }
+                    } else if self.has_room() {
+                        state.text.clear();
+                        shell.publish((self.on_change)(self.with_tags(vec![tag])));
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let text = &tree.state.downcast_ref::<State>().text;
+        self.content(text).as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let text = &tree.state.downcast_ref::<State>().text;
+        self.content(text).as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TagInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + chip::StyleSheet + text_input::StyleSheet,
+{
+    fn from(tag_input: TagInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tag_input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_tags;
+
+    #[test]
+    fn split_tags_keeps_text_without_separator() {
+        assert_eq!(split_tags("rust", None), (Vec::new(), String::from("rust")));
+    }
+
+    #[test]
+    fn split_tags_at_separators() {
+        assert_eq!(
+            split_tags("rust, gui,,iced", None),
+            (
+                vec![String::from("rust"), String::from("gui")],
+                String::from("iced")
+            )
+        );
+    }
+
+    #[test]
+    fn split_tags_drops_characters_not_allowed() {
+        let allowed = |c: char| c.is_alphanumeric();
+        assert_eq!(
+            split_tags("ru st!,", Some(&allowed)),
+            (vec![String::from("rust")], String::new())
+        );
+    }
+}
@@ -9,7 +9,11 @@ use iced::{
     advanced::{
         layout::{Limits, Node},
         overlay, renderer,
-        widget::tree::{self, Tag, Tree},
+        widget::{
+            self,
+            operation::focusable,
+            tree::{self, Tag, Tree},
+        },
         Clipboard, Layout, Shell, Widget,
     },
     event,
@@ -26,7 +30,7 @@ use iced::{
 };
 
 pub use crate::{
-    core::time::{Period, Time},
+    core::time::{Period, Time, TimeError},
     style::time_picker::{Appearance, StyleSheet},
 };
 
@@ -54,7 +58,7 @@ pub use crate::{
 ///     Message::Submit,
 /// );
 /// ```
-#[allow(missing_debug_implementations)]
+#[allow(missing_debug_implementations, clippy::struct_excessive_bools)]
 pub struct TimePicker<'a, Message, Theme>
 where
     Message: Clone,
@@ -78,6 +82,14 @@ where
     use_24h: bool,
     /// Toggle the use of the seconds of the [`TimePickerOverlay`].
     show_seconds: bool,
+    /// The interval the minutes and seconds of the [`TimePickerOverlay`] snap to.
+    step: u8,
+    /// Show only the digital clock instead of the clock face when the
+    /// [`TimePickerOverlay`] is opened for the first time.
+    digital_mode: bool,
+    /// The id of the widget in the underlay that is focused when the [`TimePickerOverlay`]
+    /// is closed.
+    underlay_id: Option<widget::Id>,
 }
 
 impl<'a, Message, Theme> TimePicker<'a, Message, Theme>
@@ -116,13 +128,36 @@ where
             overlay_state: TimePickerOverlayButtons::default().into(),
             use_24h: false,
             show_seconds: false,
+            step: 1,
+            digital_mode: false,
+            underlay_id: None,
         }
     }
 
-    /// Enables the picker to also pick seconds.
+    /// Toggles if the picker can also pick seconds.
     #[must_use]
-    pub fn show_seconds(mut self) -> Self {
-        self.show_seconds = true;
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    /// Sets the interval in minutes (and seconds, if shown) the [`TimePicker`] snaps to.
+    ///
+    /// Only multiples of the step can be picked, e.g. a step of 15 allows 0, 15, 30 and 45.
+    #[must_use]
+    pub fn step(mut self, step: u8) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Shows only the digital clock instead of the clock face, for typing the
+    /// time into its fields.
+    ///
+    /// The button in the corner of the [`TimePicker`] switches between both modes,
+    /// the last used mode is kept in the [`State`] and restored when it is opened again.
+    #[must_use]
+    pub fn digital_mode(mut self, digital_mode: bool) -> Self {
+        self.digital_mode = digital_mode;
         self
     }
 
@@ -139,13 +174,36 @@ where
         self.use_24h = true;
         self
     }
+
+    /// Toggles the 12 hour format with an AM/PM button.
+    ///
+    /// The submitted [`Time`] always holds the hour in 24 hour format,
+    /// the AM/PM button only sets the offset.
+    #[must_use]
+    pub fn use_12h(mut self, use_12h: bool) -> Self {
+        self.use_24h = !use_12h;
+        self
+    }
+
+    /// Sets the id of the focusable widget in the underlay, e.g. a text input, that gets the
+    /// focus back when the [`TimePicker`] is closed.
+    #[must_use]
+    pub fn underlay_id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.underlay_id = Some(id.into());
+        self
+    }
 }
 
 /// The state of the [`TimePicker`] / [`TimePickerOverlay`].
 #[derive(Debug)]
+#[allow(clippy::struct_field_names)]
 pub struct State {
     /// The state of the overlay.
     pub(crate) overlay_state: time_picker::State,
+    /// Whether the picker was shown during the last event.
+    pub(crate) was_shown: bool,
+    /// The id of the widget in the underlay that is focused when the picker closes.
+    pub(crate) focus_target: Option<widget::Id>,
 }
 
 impl State {
@@ -154,6 +212,8 @@ impl State {
     pub fn now() -> Self {
         Self {
             overlay_state: time_picker::State::default(),
+            was_shown: false,
+            focus_target: None,
         }
     }
 
@@ -162,9 +222,35 @@ impl State {
     pub fn new(time: Time, use_24h: bool, show_seconds: bool) -> Self {
         Self {
             overlay_state: time_picker::State::new(time, use_24h, show_seconds),
+            was_shown: false,
+            focus_target: None,
         }
     }
 
+    /// Creates a new [`State`] with the given time on the 12 hour clock without seconds.
+    #[must_use]
+    pub fn with_time(time: Time) -> Self {
+        Self::new(time, false, false)
+    }
+
+    /// Returns `true` if the selected time is after noon.
+    #[must_use]
+    pub fn is_pm(&self) -> bool {
+        self.overlay_state.is_pm()
+    }
+
+    /// Sets the selected time of the state.
+    pub fn set_time(&mut self, time: Time) {
+        self.overlay_state.clock_cache.clear();
+        self.overlay_state.time = time.into();
+    }
+
+    /// Gets the currently selected time that is not submitted yet.
+    #[must_use]
+    pub fn current_time(&self) -> Time {
+        self.overlay_state.selected_time()
+    }
+
     /// Resets the time of the state to the current time.
     pub fn reset(&mut self) {
         self.overlay_state.clock_cache.clear();
@@ -182,7 +268,9 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new(self.time, self.use_24h, self.show_seconds))
+        let mut state = State::new(self.time, self.use_24h, self.show_seconds);
+        state.overlay_state.digital_mode = self.digital_mode;
+        tree::State::new(state)
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -214,6 +302,26 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        let picker_state: &mut State = state.state.downcast_mut();
+
+        if self.show_picker && !picker_state.was_shown {
+            picker_state.focus_target.clone_from(&self.underlay_id);
+            // The keys of the overlay work right away, also when it is opened again.
+            picker_state.overlay_state.focus = time_picker::Focus::DigitalHour;
+            picker_state.overlay_state.typed_digit = None;
+        }
+        if !self.show_picker && picker_state.was_shown {
+            if let Some(id) = picker_state.focus_target.take() {
+                self.underlay.as_widget().operate(
+                    &mut state.children[0],
+                    layout,
+                    renderer,
+                    &mut focusable::focus(id),
+                );
+            }
+        }
+        picker_state.was_shown = self.show_picker;
+
         self.underlay.as_widget_mut().on_event(
             &mut state.children[0],
             event,
@@ -294,6 +402,7 @@ where
                 self.style.clone(),
                 &mut state.children[1],
             )
+            .step(self.step)
             .overlay(),
         )
     }
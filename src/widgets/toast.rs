@@ -0,0 +1,531 @@
+//! Use toasts for showing short notifications on top of the other elements.
+//!
+//! *This API requires the following crate features to be activated: toast*
+
+use super::overlay::toast::ToastOverlay;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay::{self, Group},
+        renderer, text,
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    widget::{button, Button, Row, Text},
+    window, Alignment, Element, Event, Length, Rectangle, Size, Vector,
+};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    time::{Duration, Instant},
+};
+
+pub use crate::style::toast::StyleSheet;
+
+/// The corner or edge of the window the toasts of a [`ToastContainer`] are stacked at.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ToastPosition {
+    /// The toasts are stacked at the top left corner.
+    TopLeft,
+    /// The toasts are stacked at the top right corner.
+    TopRight,
+    /// The toasts are stacked at the bottom left corner.
+    BottomLeft,
+    /// The toasts are stacked at the bottom right corner.
+    #[default]
+    BottomRight,
+    /// The toasts are stacked at the center of the top edge.
+    TopCenter,
+    /// The toasts are stacked at the center of the bottom edge.
+    BottomCenter,
+}
+
+/// A single notification shown by a [`ToastContainer`].
+#[allow(missing_debug_implementations)]
+pub struct Toast<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The content of the [`Toast`].
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The optional hashed id of the [`Toast`] keeping its timer across updates.
+    id: Option<u64>,
+    /// The optional duration after which the [`Toast`] is dismissed.
+    timeout: Option<Duration>,
+    /// The optional label and message of the action button of the [`Toast`].
+    action: Option<(String, Message)>,
+    /// The style of the [`Toast`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Toast<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Toast`] showing the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Toast {
+            content: content.into(),
+            id: None,
+            timeout: None,
+            action: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the id of the [`Toast`].
+    ///
+    /// The timeout of a [`Toast`] with an id keeps running while the toasts before it
+    /// are added or removed. The timeouts of toasts without an id are restarted
+    /// whenever the number of toasts changes.
+    #[must_use]
+    pub fn id(mut self, id: impl Hash) -> Self {
+        let mut hasher = DefaultHasher::new();
+        id.hash(&mut hasher);
+        self.id = Some(hasher.finish());
+        self
+    }
+
+    /// Sets the duration after which the [`Toast`] is dismissed automatically.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Adds an action button with the given label to the [`Toast`] that
+    /// produces the given message when pressed.
+    #[must_use]
+    pub fn action(mut self, label: impl Into<String>, message: Message) -> Self {
+        self.action = Some((label.into(), message));
+        self
+    }
+
+    /// Sets the style of the [`Toast`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// A container showing a stack of [`Toast`]s as an overlay on top of the underlying element.
+///
+/// # Example
+/// ```ignore
+/// # use std::time::Duration;
+/// # use iced::widget::Text;
+/// # use iced_aw::{Toast, ToastContainer};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Close(usize),
+///     Undo,
+/// }
+///
+/// let toasts = vec![
+///     Toast::new(Text::new("File deleted"))
+///         .timeout(Duration::from_secs(5))
+///         .action("Undo", Message::Undo),
+/// ];
+///
+/// let container = ToastContainer::new(Text::new("Underlay"), toasts, Message::Close);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ToastContainer<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The underlying element.
+    underlay: Element<'a, Message, Theme, Renderer>,
+    /// The elements of the toasts.
+    toasts: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// The hashed ids of the toasts.
+    ids: Vec<Option<u64>>,
+    /// The timeouts of the toasts.
+    timeouts: Vec<Option<Duration>>,
+    /// The styles of the toasts.
+    styles: Vec<<Theme as StyleSheet>::Style>,
+    /// The function that produces a message when a toast timed out.
+    on_close: Box<dyn Fn(usize) -> Message + 'a>,
+    /// The position the toasts are stacked at.
+    position: ToastPosition,
+    /// The width of the toasts.
+    width: f32,
+    /// The spacing between the toasts.
+    spacing: f32,
+    /// The padding between the toasts and the edges of the window.
+    padding: f32,
+}
+
+impl<'a, Message, Theme, Renderer> ToastContainer<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Theme: 'a + StyleSheet + button::StyleSheet + iced::widget::text::StyleSheet,
+{
+    /// Creates a new [`ToastContainer`] wrapping the underlying element to show the toasts
+    /// as an overlay.
+    ///
+    /// It expects:
+    ///     * the underlay [`Element`] on which this [`ToastContainer`] will be wrapped around.
+    ///     * the [`Toast`]s to show.
+    ///     * a function that will be called with the index of a [`Toast`] once its
+    ///         timeout has passed.
+    pub fn new<F>(
+        underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+        toasts: Vec<Toast<'a, Message, Theme, Renderer>>,
+        on_close: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        let mut elements = Vec::with_capacity(toasts.len());
+        let mut ids = Vec::with_capacity(toasts.len());
+        let mut timeouts = Vec::with_capacity(toasts.len());
+        let mut styles = Vec::with_capacity(toasts.len());
+
+        for toast in toasts {
+            let mut row = Row::new()
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .push(toast.content);
+
+            if let Some((label, message)) = toast.action {
+                row = row.push(Button::new(Text::new(label)).on_press(message));
+            }
+
+            elements.push(row.into());
+            ids.push(toast.id);
+            timeouts.push(toast.timeout);
+            styles.push(toast.style);
+        }
+
+        ToastContainer {
+            underlay: underlay.into(),
+            toasts: elements,
+            ids,
+            timeouts,
+            styles,
+            on_close: Box::new(on_close),
+            position: ToastPosition::default(),
+            width: 300.0,
+            spacing: 10.0,
+            padding: 10.0,
+        }
+    }
+
+    /// Sets the position the toasts of the [`ToastContainer`] are stacked at.
+    #[must_use]
+    pub fn position(mut self, position: ToastPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the width of the toasts of the [`ToastContainer`].
+    #[must_use]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the toasts of the [`ToastContainer`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding between the toasts of the [`ToastContainer`] and the edges of the window.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+}
+
+/// The state of the [`ToastContainer`].
+#[derive(Debug, Default)]
+struct State {
+    /// The timers of the toasts.
+    timers: Vec<Timer>,
+}
+
+/// The timer of a single toast of the [`ToastContainer`].
+#[derive(Clone, Copy, Debug)]
+struct Timer {
+    /// The hashed id of the toast the timer belongs to.
+    id: Option<u64>,
+    /// The instant the toast was shown at or `None` if the toast timed out.
+    shown: Option<Instant>,
+}
+
+impl State {
+    /// Matches the timers to the toasts with the given ids.
+    ///
+    /// Toasts with an id keep the timer of the toast with the same id. Toasts without
+    /// an id keep the timer at their position as long as the number of toasts stays the
+    /// same. All other toasts get a new timer.
+    fn update(&mut self, ids: &[Option<u64>]) {
+        let now = Instant::now();
+        let count_changed = self.timers.len() != ids.len();
+        let old = std::mem::take(&mut self.timers);
+
+        self.timers = ids
+            .iter()
+            .enumerate()
+            .map(|(index, id)| {
+                let kept = match id {
+                    Some(_) => old.iter().find(|timer| timer.id == *id),
+                    None if count_changed => None,
+                    None => old.get(index).filter(|timer| timer.id.is_none()),
+                };
+
+                kept.copied().unwrap_or(Timer {
+                    id: *id,
+                    shown: Some(now),
+                })
+            })
+            .collect();
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ToastContainer<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = State::default();
+        state.update(&self.ids);
+        tree::State::new(state)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.underlay)
+            .chain(&self.toasts)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<State>().update(&self.ids);
+
+        let children: Vec<_> = std::iter::once(&self.underlay)
+            .chain(&self.toasts)
+            .collect();
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.underlay
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            let timers = &mut state.state.downcast_mut::<State>().timers;
+
+            // In reverse, so that removing the closed toasts in order keeps the indices valid.
+            for (index, (timer, timeout)) in timers.iter_mut().zip(&self.timeouts).enumerate().rev()
+            {
+                if let (Some(shown), Some(timeout)) = (timer.shown, timeout) {
+                    let deadline = shown + *timeout;
+
+                    if now >= deadline {
+                        timer.shown = None;
+                        shell.publish((self.on_close)(index));
+                    } else {
+                        shell.request_redraw(window::RedrawRequest::At(deadline));
+                    }
+                }
+            }
+        }
+
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.underlay
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (underlay_state, toasts_state) = state.children.split_at_mut(1);
+
+        let mut overlays: Vec<_> = self
+            .underlay
+            .as_widget_mut()
+            .overlay(&mut underlay_state[0], layout, renderer, translation)
+            .into_iter()
+            .collect();
+
+        if !self.toasts.is_empty() {
+            overlays.push(overlay::Element::new(Box::new(ToastOverlay::new(
+                toasts_state,
+                &mut self.toasts,
+                &self.styles,
+                self.position,
+                self.width,
+                self.spacing,
+                self.padding,
+            ))));
+        }
+
+        (!overlays.is_empty()).then(|| Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ToastContainer<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(toast_container: ToastContainer<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(toast_container)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::State;
+    use std::time::Instant;
+
+    #[test]
+    fn keyed_timers_test() {
+        let mut state = State::default();
+        state.update(&[Some(1), Some(2), Some(3)]);
+        let shown: Vec<_> = state.timers.iter().map(|timer| timer.shown).collect();
+
+        // The first toast is closed early by the application.
+        state.update(&[Some(2), Some(3), Some(4)]);
+        assert_eq!(state.timers[0].shown, shown[1]);
+        assert_eq!(state.timers[1].shown, shown[2]);
+        assert_eq!(state.timers[2].id, Some(4));
+        assert!(state.timers[2].shown >= shown[2]);
+    }
+
+    #[test]
+    fn timed_out_toast_kept_test() {
+        let mut state = State::default();
+        state.update(&[Some(1), Some(2)]);
+        state.timers[0].shown = None;
+
+        // The application keeps the timed out toast and adds a new one.
+        state.update(&[Some(1), Some(2), Some(3)]);
+        assert_eq!(state.timers[0].shown, None);
+        assert!(state.timers[1].shown.is_some());
+        assert!(state.timers[2].shown.is_some());
+    }
+
+    #[test]
+    fn unkeyed_timers_test() {
+        let mut state = State::default();
+        state.update(&[None, None]);
+        state.timers[0].shown = None;
+
+        // The same number of toasts keeps the timers.
+        state.update(&[None, None]);
+        assert_eq!(state.timers[0].shown, None);
+
+        // A different number of toasts restarts them.
+        let before = Instant::now();
+        state.update(&[None]);
+        assert!(state.timers[0].shown.is_some_and(|shown| shown >= before));
+    }
+}
@@ -0,0 +1,351 @@
+//! Use a toggle for switching an option on and off.
+//!
+//! *This API requires the following crate features to be activated: `toggle`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::{self, Paragraph as _},
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch, window, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+pub use crate::style::toggle::{Appearance, StyleSheet};
+
+/// The distance the knob slides per frame, so a switch takes two frames.
+const ANIMATION_STEP: f32 = 0.5;
+
+/// The space between the knob and the border of the track.
+const KNOB_PADDING: f32 = 2.0;
+
+/// A pill-shaped switch with a sliding knob for turning an option on and off.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::Toggle;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(bool),
+/// }
+///
+/// let toggle = Toggle::new(true, Message::Toggled).label("Wi-Fi");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Toggle<'a, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Whether the [`Toggle`] is switched on.
+    is_on: bool,
+    /// The function that produces a message when the [`Toggle`] is clicked.
+    on_change: Box<dyn Fn(bool) -> Message + 'a>,
+    /// The width of the switch. The height is half of it.
+    width: f32,
+    /// The label shown to the right of the switch.
+    label: Option<String>,
+    /// Whether the [`Toggle`] ignores clicks.
+    disabled: bool,
+    /// The space between the switch and the label.
+    spacing: f32,
+    /// The text size of the label, or the default size of the renderer.
+    text_size: Option<Pixels>,
+    /// The style of the [`Toggle`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Toggle<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Toggle`].
+    ///
+    /// It expects:
+    ///     * whether the [`Toggle`] is switched on.
+    ///     * a function that will be called with the new state when the [`Toggle`] is clicked.
+    pub fn new<F>(is_on: bool, on_toggle: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        Self {
+            is_on,
+            on_change: Box::new(on_toggle),
+            width: 40.0,
+            label: None,
+            disabled: false,
+            spacing: 10.0,
+            text_size: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the switch of the [`Toggle`]. The height is half of it.
+    #[must_use]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the label shown to the right of the switch of the [`Toggle`].
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets whether the [`Toggle`] is disabled and ignores clicks.
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the space between the switch and the label of the [`Toggle`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Toggle`].
+    #[must_use]
+    pub fn text_size<P: Into<Pixels>>(mut self, text_size: P) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the style of the [`Toggle`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The position of the knob when the [`Toggle`] is not sliding.
+    fn target(&self) -> f32 {
+        if self.is_on {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// The state of a [`Toggle`].
+#[derive(Debug)]
+struct State {
+    /// The position of the knob from 0.0 on the left to 1.0 on the right.
+    knob_position: f32,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Toggle<'_, Message, Theme>
+where
+    Renderer: text::Renderer<Font = iced::Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            knob_position: self.target(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let switch = Size::new(self.width, self.width / 2.0);
+
+        let Some(label) = &self.label else {
+            return Node::with_children(
+                limits.resolve(Length::Shrink, Length::Shrink, switch),
+                vec![Node::new(switch)],
+            );
+        };
+
+        let label = Renderer::Paragraph::with_text(text::Text {
+            content: label,
+            bounds: Size::INFINITY,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: renderer.default_font(),
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        })
+        .min_bounds();
+
+        let height = switch.height.max(label.height);
+        let size = Size::new(switch.width + self.spacing + label.width, height);
+
+        Node::with_children(
+            limits.resolve(Length::Shrink, Length::Shrink, size),
+            vec![
+                Node::new(switch).move_to(Point::new(0.0, (height - switch.height) / 2.0)),
+                Node::new(label).move_to(Point::new(
+                    switch.width + self.spacing,
+                    (height - label.height) / 2.0,
+                )),
+            ],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Window(_id, window::Event::RedrawRequested(_now)) => {
+                let state = tree.state.downcast_mut::<State>();
+                let target = self.target();
+                let distance = target - state.knob_position;
+
+                if distance.abs() > f32::EPSILON {
+                    state.knob_position += distance.clamp(-ANIMATION_STEP, ANIMATION_STEP);
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !self.disabled && cursor.is_over(layout.bounds()) =>
+            {
+                shell.publish((self.on_change)(!self.is_on));
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !cursor.is_over(layout.bounds()) {
+            mouse::Interaction::default()
+        } else if self.disabled {
+            mouse::Interaction::NotAllowed
+        } else {
+            mouse::Interaction::Pointer
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = if self.disabled {
+            theme.disabled_appearance(&self.style, self.is_on)
+        } else if self.is_on {
+            theme.on_appearance(&self.style)
+        } else {
+            theme.off_appearance(&self.style)
+        };
+
+        let mut children = layout.children();
+        let track_bounds = children
+            .next()
+            .expect("Graphics: Layout should have a switch layout")
+            .bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: track_bounds,
+                border: Border {
+                    radius: (track_bounds.height / 2.0).into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        let knob_size = (track_bounds.height - 2.0 * KNOB_PADDING).max(0.0);
+        let knob_bounds = Rectangle {
+            x: track_bounds.x
+                + KNOB_PADDING
+                + state.knob_position * (track_bounds.width - track_bounds.height),
+            y: track_bounds.y + KNOB_PADDING,
+            width: knob_size,
+            height: knob_size,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: knob_bounds,
+                border: Border {
+                    radius: (knob_size / 2.0).into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.knob_color,
+        );
+
+        if let (Some(label), Some(label_layout)) = (&self.label, children.next()) {
+            let label_bounds = label_layout.bounds();
+
+            renderer.fill_text(
+                text::Text {
+                    content: label,
+                    bounds: label_bounds.size(),
+                    size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                },
+                Point::new(label_bounds.x, label_bounds.center_y()),
+                appearance.label_color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Toggle<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(toggle: Toggle<'a, Message, Theme>) -> Self {
+        Element::new(toggle)
+    }
+}
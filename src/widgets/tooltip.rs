@@ -0,0 +1,339 @@
+//! Use a tooltip for showing a hint next to an element after hovering it for a while.
+//!
+//! *This API requires the following crate features to be activated: `tooltip`*
+
+use super::overlay::tooltip::TooltipOverlay;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay::{self, Group},
+        renderer,
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    window, Element, Event, Length, Padding, Point, Rectangle, Size, Vector,
+};
+use std::time::{Duration, Instant};
+
+pub use crate::style::tooltip::StyleSheet;
+
+/// The position of the tooltip of a [`Tooltip`] relative to its content.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TooltipPosition {
+    /// The tooltip is shown above the content.
+    #[default]
+    Top,
+    /// The tooltip is shown below the content.
+    Bottom,
+    /// The tooltip is shown left of the content.
+    Left,
+    /// The tooltip is shown right of the content.
+    Right,
+    /// The tooltip is shown next to the cursor and follows it.
+    FollowCursor,
+}
+
+/// An element that shows a tooltip after it has been hovered for a delay.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::{Button, Text};
+/// # use iced_aw::{Tooltip, TooltipPosition};
+/// # use std::time::Duration;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+/// }
+///
+/// let tooltip = Tooltip::new(
+///     Button::new(Text::new("Save")).on_press(Message::Save),
+///     Text::new("Saves the document"),
+/// )
+/// .delay(Duration::from_millis(300))
+/// .position(TooltipPosition::Bottom);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tooltip<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The content of the [`Tooltip`].
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The element shown as the tooltip.
+    hint: Element<'a, Message, Theme, Renderer>,
+    /// The time the content has to be hovered before the tooltip is shown.
+    delay: Duration,
+    /// The position of the tooltip.
+    position: TooltipPosition,
+    /// The gap between the tooltip and the content or the cursor.
+    gap: f32,
+    /// The padding between the border and the element of the tooltip.
+    padding: Padding,
+    /// The style of the [`Tooltip`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Tooltip<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Tooltip`].
+    ///
+    /// It expects:
+    ///     * the content [`Element`] that shows the tooltip when hovered.
+    ///     * the [`Element`] shown as the tooltip.
+    pub fn new<C, T>(content: C, tooltip: T) -> Self
+    where
+        C: Into<Element<'a, Message, Theme, Renderer>>,
+        T: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Tooltip {
+            content: content.into(),
+            hint: tooltip.into(),
+            delay: Duration::from_millis(500),
+            position: TooltipPosition::default(),
+            gap: 5.0,
+            padding: Padding::new(5.0),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the time the content of the [`Tooltip`] has to be hovered before the tooltip is shown.
+    #[must_use]
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the position of the tooltip of the [`Tooltip`].
+    #[must_use]
+    pub fn position(mut self, position: TooltipPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the gap between the tooltip and the content or the cursor.
+    #[must_use]
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the padding between the border and the element of the tooltip.
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`Tooltip`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// The state of a [`Tooltip`].
+#[derive(Debug, Default)]
+struct State {
+    /// The instant the content started to be hovered.
+    hovered_since: Option<Instant>,
+    /// Whether the tooltip is shown.
+    is_visible: bool,
+    /// The last position of the cursor over the content.
+    cursor_position: Point,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tooltip<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.hint)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content, &self.hint]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let tooltip_state = state.state.downcast_mut::<State>();
+
+        if let Some(position) = cursor.position_over(layout.bounds()) {
+            tooltip_state.cursor_position = position;
+
+            match (tooltip_state.hovered_since, &event) {
+                (None, _) => {
+                    let now = Instant::now();
+                    tooltip_state.hovered_since = Some(now);
+                    shell.request_redraw(window::RedrawRequest::At(now + self.delay));
+                }
+                (Some(since), Event::Window(_id, window::Event::RedrawRequested(now)))
+                    if !tooltip_state.is_visible =>
+                {
+                    let deadline = since + self.delay;
+
+                    if *now >= deadline {
+                        tooltip_state.is_visible = true;
+                    } else {
+                        shell.request_redraw(window::RedrawRequest::At(deadline));
+                    }
+                }
+                _ => {}
+            }
+        } else {
+            tooltip_state.hovered_since = None;
+            tooltip_state.is_visible = false;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let tooltip_state = state.state.downcast_ref::<State>();
+        let is_visible = tooltip_state.is_visible;
+        let cursor_position = tooltip_state.cursor_position + translation;
+
+        let (content_state, tooltip_state) = state.children.split_at_mut(1);
+
+        let mut overlays: Vec<_> = self
+            .content
+            .as_widget_mut()
+            .overlay(&mut content_state[0], layout, renderer, translation)
+            .into_iter()
+            .collect();
+
+        if is_visible {
+            overlays.push(overlay::Element::new(Box::new(TooltipOverlay::new(
+                &mut tooltip_state[0],
+                &self.hint,
+                self.position,
+                layout.bounds() + translation,
+                cursor_position,
+                self.gap,
+                self.padding,
+                &self.style,
+            ))));
+        }
+
+        (!overlays.is_empty()).then(|| Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tooltip<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(tooltip: Tooltip<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tooltip)
+    }
+}
@@ -0,0 +1,693 @@
+//! Use a tree view for showing hierarchical data with expandable nodes.
+//!
+//! *This API requires the following crate features to be activated: `tree_view`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer, text,
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch, Element, Event, Length, Pixels, Point, Rectangle, Size,
+};
+use std::collections::HashSet;
+
+use crate::{
+    core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    Spinner,
+};
+
+/// The index of the spinner shown for loading nodes in the elements.
+const SPINNER: usize = 0;
+
+/// The size of the expand and collapse buttons.
+const TOGGLE_SIZE: f32 = 16.0;
+
+/// The space between the button and the content of a node.
+const TOGGLE_SPACING: f32 = 4.0;
+
+/// The identifier of a node of a [`TreeView`], made of the indices of the
+/// children leading from the root to the node.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(Vec<usize>);
+
+impl NodeId {
+    /// The identifier of the root node.
+    #[must_use]
+    pub fn root() -> Self {
+        Self(Vec::new())
+    }
+
+    /// The indices of the children leading from the root to the node.
+    #[must_use]
+    pub fn path(&self) -> &[usize] {
+        &self.0
+    }
+
+    /// The identifier of the child with the given index.
+    #[must_use]
+    pub fn child(&self, index: usize) -> Self {
+        let mut path = self.0.clone();
+        path.push(index);
+        Self(path)
+    }
+}
+
+/// A node of the hierarchy shown by a [`TreeView`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TreeNode<T> {
+    /// The value shown for the node.
+    pub value: T,
+    /// The children of the node that are known so far.
+    pub children: Vec<Self>,
+    /// Whether the children of the node are still being loaded.
+    pub is_loading: bool,
+}
+
+impl<T> TreeNode<T> {
+    /// Creates a new [`TreeNode`] without children.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            children: Vec::new(),
+            is_loading: false,
+        }
+    }
+
+    /// Sets the children of the [`TreeNode`].
+    #[must_use]
+    pub fn children(mut self, children: Vec<Self>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Sets whether the children of the [`TreeNode`] are still being loaded.
+    ///
+    /// A loading node without children can be expanded and shows a spinner.
+    #[must_use]
+    pub fn loading(mut self, is_loading: bool) -> Self {
+        self.is_loading = is_loading;
+        self
+    }
+
+    /// The descendant of the [`TreeNode`] with the given identifier.
+    #[must_use]
+    pub fn get(&self, id: &NodeId) -> Option<&Self> {
+        id.path()
+            .iter()
+            .try_fold(self, |node, index| node.children.get(*index))
+    }
+
+    /// The mutable descendant of the [`TreeNode`] with the given identifier.
+    pub fn get_mut(&mut self, id: &NodeId) -> Option<&mut Self> {
+        id.path()
+            .iter()
+            .try_fold(self, |node, index| node.children.get_mut(*index))
+    }
+
+    /// Whether the [`TreeNode`] can be expanded.
+    fn is_expandable(&self) -> bool {
+        !self.children.is_empty() || self.is_loading
+    }
+}
+
+/// A node of a [`TreeView`] in the order the nodes are shown.
+#[derive(Clone, Debug)]
+struct FlatNode {
+    /// The identifier of the node.
+    id: NodeId,
+    /// The index of the parent among the flattened nodes.
+    parent: Option<usize>,
+    /// The depth of the node below the root.
+    depth: usize,
+    /// Whether the node can be expanded.
+    is_expandable: bool,
+    /// Whether the node shows a spinner when it's expanded.
+    shows_spinner: bool,
+}
+
+/// A widget showing a hierarchy of [`TreeNode`]s, whose children can be shown
+/// and hidden by expanding and collapsing their parents.
+///
+/// The root is expanded at first. The expanded nodes are kept in the state of
+/// the widget.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::{tree_view::{NodeId, TreeNode}, TreeView};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Expand(NodeId),
+/// }
+///
+/// let root = TreeNode::new("Home").children(vec![
+///     TreeNode::new("Documents").loading(true),
+///     TreeNode::new("Pictures"),
+/// ]);
+///
+/// let tree_view = TreeView::new(root)
+///     .on_expand(Message::Expand)
+///     .render_node(|value| Text::new(*value).into());
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// The root of the hierarchy.
+    root: TreeNode<T>,
+    /// The nodes of the hierarchy in the order they are shown.
+    nodes: Vec<FlatNode>,
+    /// The spinner followed by the contents of the nodes.
+    elements: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// The function that produces a message when a node is expanded.
+    on_expand: Option<Box<dyn Fn(NodeId) -> Message + 'a>>,
+    /// The function that produces a message when a node is collapsed.
+    on_collapse: Option<Box<dyn Fn(NodeId) -> Message + 'a>>,
+    /// The function that produces a message when a node is clicked.
+    on_select: Option<Box<dyn Fn(NodeId) -> Message + 'a>>,
+    /// The horizontal offset of each level of the hierarchy.
+    indent: f32,
+    /// The space between the nodes.
+    spacing: f32,
+}
+
+impl<'a, T, Message, Theme, Renderer> TreeView<'a, T, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + crate::style::spinner::StyleSheet,
+{
+    /// Creates a new [`TreeView`] showing the hierarchy below the given root.
+    ///
+    /// The contents of the nodes are created by [`TreeView::render_node`].
+    pub fn new(root: TreeNode<T>) -> Self {
+        let mut nodes = Vec::new();
+        flatten(&root, &NodeId::root(), None, 0, &mut nodes);
+
+        Self {
+            root,
+            nodes,
+            elements: vec![Spinner::new()
+                .width(Length::Fixed(TOGGLE_SIZE))
+                .height(Length::Fixed(TOGGLE_SIZE))
+                .circle_radius(1.5)
+                .into()],
+            on_expand: None,
+            on_collapse: None,
+            on_select: None,
+            indent: 20.0,
+            spacing: 2.0,
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> TreeView<'a, T, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Sets the function that will be called with the identifier of a node
+    /// of the [`TreeView`] when it's expanded.
+    ///
+    /// This is the moment to load the children of a loading node.
+    #[must_use]
+    pub fn on_expand<F>(mut self, on_expand: F) -> Self
+    where
+        F: 'a + Fn(NodeId) -> Message,
+    {
+        self.on_expand = Some(Box::new(on_expand));
+        self
+    }
+
+    /// Sets the function that will be called with the identifier of a node
+    /// of the [`TreeView`] when it's collapsed.
+    #[must_use]
+    pub fn on_collapse<F>(mut self, on_collapse: F) -> Self
+    where
+        F: 'a + Fn(NodeId) -> Message,
+    {
+        self.on_collapse = Some(Box::new(on_collapse));
+        self
+    }
+
+    /// Sets the function that will be called with the identifier of a node
+    /// of the [`TreeView`] when it's clicked.
+    #[must_use]
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'a + Fn(NodeId) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the function creating the content of each node of the [`TreeView`]
+    /// from its value.
+    #[must_use]
+    pub fn render_node<F>(mut self, render_node: F) -> Self
+    where
+        F: Fn(&T) -> Element<'a, Message, Theme, Renderer>,
+    {
+        let mut contents = Vec::with_capacity(self.nodes.len());
+        render(&self.root, &render_node, &mut contents);
+
+        self.elements.truncate(SPINNER + 1);
+        self.elements.extend(contents);
+        self
+    }
+
+    /// Sets the horizontal offset of each level of the hierarchy of the [`TreeView`].
+    #[must_use]
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the space between the nodes of the [`TreeView`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// The nodes that are shown because all their ancestors are expanded.
+    fn visible_nodes(&self, expanded: &HashSet<NodeId>) -> Vec<usize> {
+        let mut visible = vec![false; self.nodes.len()];
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            visible[index] = node
+                .parent
+                .is_none_or(|parent| visible[parent] && expanded.contains(&self.nodes[parent].id));
+        }
+
+        (0..self.nodes.len())
+            .filter(|index| visible[*index])
+            .collect()
+    }
+
+    /// The content of the node with the given index, if it has been rendered.
+    fn content(&self, index: usize) -> Option<&Element<'a, Message, Theme, Renderer>> {
+        self.elements.get(SPINNER + 1 + index)
+    }
+}
+
+/// Collects the nodes below the given one in the order they are shown.
+fn flatten<T>(
+    node: &TreeNode<T>,
+    id: &NodeId,
+    parent: Option<usize>,
+    depth: usize,
+    nodes: &mut Vec<FlatNode>,
+) {
+    let index = nodes.len();
+    nodes.push(FlatNode {
+        id: id.clone(),
+        parent,
+        depth,
+        is_expandable: node.is_expandable(),
+        shows_spinner: node.children.is_empty() && node.is_loading,
+    });
+
+    for (child_index, child) in node.children.iter().enumerate() {
+        flatten(child, &id.child(child_index), Some(index), depth + 1, nodes);
+    }
+}
+
+/// Renders the contents of the nodes below the given one in the order they are shown.
+fn render<'a, T, Message, Theme, Renderer>(
+    node: &TreeNode<T>,
+    render_node: &dyn Fn(&T) -> Element<'a, Message, Theme, Renderer>,
+    contents: &mut Vec<Element<'a, Message, Theme, Renderer>>,
+) {
+    contents.push(render_node(&node.value));
+
+    for child in &node.children {
+        render(child, render_node, contents);
+    }
+}
+
+/// The state of a [`TreeView`].
+#[derive(Debug)]
+struct State {
+    /// The identifiers of the expanded nodes.
+    expanded: HashSet<NodeId>,
+    /// The indices of the nodes shown in the last layout.
+    rows: Vec<usize>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            expanded: HashSet::from([NodeId::root()]),
+            rows: Vec::new(),
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TreeView<'a, T, Message, Theme, Renderer>
+where
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.elements.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.elements);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<State>();
+        let rows = self.visible_nodes(&state.expanded);
+        let expanded = state.expanded.clone();
+        state.rows.clone_from(&rows);
+
+        let max_width = limits.max().width;
+        let spinner = self.elements[SPINNER].as_widget().layout(
+            &mut tree.children[SPINNER],
+            renderer,
+            &Limits::new(Size::ZERO, Size::new(TOGGLE_SIZE, TOGGLE_SIZE)),
+        );
+
+        let mut y = 0.0;
+        let mut width = 0.0_f32;
+        let nodes: Vec<Node> = rows
+            .into_iter()
+            .map(|index| {
+                let node = &self.nodes[index];
+                let offset = node.depth as f32 * self.indent + TOGGLE_SIZE + TOGGLE_SPACING;
+
+                let content = self.content(index).map_or_else(Node::default, |content| {
+                    content.as_widget().layout(
+                        &mut tree.children[SPINNER + 1 + index],
+                        renderer,
+                        &Limits::new(
+                            Size::ZERO,
+                            Size::new((max_width - offset).max(0.0), f32::INFINITY),
+                        ),
+                    )
+                });
+                let height = content.size().height.max(TOGGLE_SIZE);
+                let mut row_width = offset + content.size().width;
+
+                let mut children = vec![
+                    Node::new(Size::new(TOGGLE_SIZE, TOGGLE_SIZE)).move_to(Point::new(
+                        offset - TOGGLE_SIZE - TOGGLE_SPACING,
+                        (height - TOGGLE_SIZE) / 2.0,
+                    )),
+                    content
+                        .clone()
+                        .move_to(Point::new(offset, (height - content.size().height) / 2.0)),
+                ];
+
+                if node.shows_spinner && expanded.contains(&node.id) {
+                    children.push(spinner.clone().move_to(Point::new(
+                        row_width + TOGGLE_SPACING,
+                        (height - TOGGLE_SIZE) / 2.0,
+                    )));
+                    row_width += TOGGLE_SPACING + TOGGLE_SIZE;
+                }
+
+                let row = Node::with_children(Size::new(row_width, height), children)
+                    .move_to(Point::new(0.0, y));
+                y += height + self.spacing;
+                width = width.max(row_width);
+
+                row
+            })
+            .collect();
+
+        let size = Size::new(width, (y - self.spacing).max(0.0));
+
+        Node::with_children(limits.resolve(Length::Shrink, Length::Shrink, size), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let rows = tree.state.downcast_ref::<State>().rows.clone();
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let toggled = rows.iter().zip(layout.children()).find(|(index, row)| {
+                self.nodes[**index].is_expandable
+                    && row
+                        .children()
+                        .next()
+                        .is_some_and(|toggle| cursor.is_over(toggle.bounds()))
+            });
+
+            if let Some((index, _)) = toggled {
+                let state = tree.state.downcast_mut::<State>();
+                let id = self.nodes[*index].id.clone();
+
+                let callback = if state.expanded.remove(&id) {
+                    &self.on_collapse
+                } else {
+                    let _ = state.expanded.insert(id.clone());
+                    &self.on_expand
+                };
+                if let Some(callback) = callback {
+                    shell.publish(callback(id));
+                }
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+        }
+
+        let mut status = event::Status::Ignored;
+        for (index, row) in rows.iter().zip(layout.children()) {
+            let mut children = row.children().skip(1);
+
+            if let (Some(content), Some(content_layout)) =
+                (self.elements.get_mut(SPINNER + 1 + index), children.next())
+            {
+                status = status.merge(content.as_widget_mut().on_event(
+                    &mut tree.children[SPINNER + 1 + index],
+                    event.clone(),
+                    content_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                ));
+            }
+
+            // The spinner is shared by the loading nodes, so it's animated only once.
+            if let Some(spinner_layout) = children.next() {
+                let _ = self.elements[SPINNER].as_widget_mut().on_event(
+                    &mut tree.children[SPINNER],
+                    event.clone(),
+                    spinner_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+        }
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let (
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }),
+            Some(on_select),
+        ) = (&event, &self.on_select)
+        {
+            let selected = rows
+                .iter()
+                .zip(layout.children())
+                .find(|(_, row)| cursor.is_over(row.bounds()));
+
+            if let Some((index, _)) = selected {
+                shell.publish(on_select(self.nodes[*index].id.clone()));
+
+                return event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let rows = &tree.state.downcast_ref::<State>().rows;
+
+        for (index, row) in rows.iter().zip(layout.children()) {
+            let mut children = row.children();
+            let toggle = children.next();
+            let content_layout = children.next();
+
+            if self.nodes[*index].is_expandable
+                && toggle.is_some_and(|toggle| cursor.is_over(toggle.bounds()))
+            {
+                return mouse::Interaction::Pointer;
+            }
+
+            if let (Some(content), Some(content_layout)) = (self.content(*index), content_layout) {
+                let interaction = content.as_widget().mouse_interaction(
+                    &tree.children[SPINNER + 1 + index],
+                    content_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                );
+                if interaction != mouse::Interaction::default() {
+                    return interaction;
+                }
+            }
+
+            if self.on_select.is_some() && cursor.is_over(row.bounds()) {
+                return mouse::Interaction::Pointer;
+            }
+        }
+
+        mouse::Interaction::default()
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let rows = tree.state.downcast_ref::<State>().rows.clone();
+
+        for (index, row) in rows.iter().zip(layout.children()) {
+            if let (Some(content), Some(content_layout)) =
+                (self.content(*index), row.children().nth(1))
+            {
+                content.as_widget().operate(
+                    &mut tree.children[SPINNER + 1 + index],
+                    content_layout,
+                    renderer,
+                    operation,
+                );
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        for (index, row) in state.rows.iter().zip(layout.children()) {
+            let node = &self.nodes[*index];
+            let mut children = row.children();
+
+            if let Some(toggle) = children.next().filter(|_| node.is_expandable) {
+                let icon = if state.expanded.contains(&node.id) {
+                    Bootstrap::ChevronDown
+                } else {
+                    Bootstrap::ChevronRight
+                };
+                let bounds = toggle.bounds();
+
+                renderer.fill_text(
+                    text::Text {
+                        content: &icon_to_string(icon),
+                        bounds: bounds.size(),
+                        size: Pixels(TOGGLE_SIZE * 0.75),
+                        line_height: text::LineHeight::default(),
+                        font: BOOTSTRAP_FONT,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                    },
+                    bounds.center(),
+                    style.text_color,
+                    *viewport,
+                );
+            }
+
+            if let (Some(content), Some(content_layout)) = (self.content(*index), children.next()) {
+                content.as_widget().draw(
+                    &tree.children[SPINNER + 1 + index],
+                    renderer,
+                    theme,
+                    style,
+                    content_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+
+            if let Some(spinner_layout) = children.next() {
+                self.elements[SPINNER].as_widget().draw(
+                    &tree.children[SPINNER],
+                    renderer,
+                    theme,
+                    style,
+                    spinner_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<TreeView<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a,
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = iced::Font>,
+    Theme: 'a,
+{
+    fn from(tree_view: TreeView<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(tree_view)
+    }
+}
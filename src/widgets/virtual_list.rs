@@ -0,0 +1,395 @@
+//! Use a virtual list for showing a very large number of items of the same height.
+//!
+//! *This API requires the following crate features to be activated: `virtual_list`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor, ScrollDelta},
+    Element, Event, Length, Padding, Point, Rectangle, Size,
+};
+use std::{cell::RefCell, collections::HashMap, ops::Range};
+
+/// The distance scrolled for every line of a mouse wheel.
+const LINE_HEIGHT: f32 = 60.0;
+
+/// The items of a [`VirtualList`] in its viewport, with their indices.
+type VisibleItems<'a, Message, Theme, Renderer> =
+    Vec<(usize, Element<'a, Message, Theme, Renderer>)>;
+
+/// A scrollable list that only creates the items visible in its viewport.
+///
+/// All items have the same height, so the visible items are known without
+/// creating any other item.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::VirtualList;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Scrolled(f32),
+/// }
+///
+/// let virtual_list = VirtualList::new(100_000, 30.0, |index| {
+///     Text::new(format!("Item {index}")).into()
+/// })
+/// .on_scroll(Message::Scrolled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// The number of items.
+    item_count: usize,
+    /// The height of every item.
+    item_height: f32,
+    /// The function creating the item with the given index.
+    render_item: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    /// The function that produces a message when the list is scrolled.
+    on_scroll: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    /// The padding around the items.
+    padding: Padding,
+    /// The width of the [`VirtualList`].
+    width: Length,
+    /// The height of the [`VirtualList`].
+    height: Length,
+    /// The items created by the last layout, with their indices.
+    visible: RefCell<VisibleItems<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`VirtualList`].
+    ///
+    /// It expects:
+    ///     * the number of items.
+    ///     * the height of every item.
+    ///     * a function creating the item with the given index, which is only
+    ///       called for the items in the viewport.
+    pub fn new<F>(item_count: usize, item_height: f32, render_item: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Element<'a, Message, Theme, Renderer>,
+    {
+        Self {
+            item_count,
+            item_height,
+            render_item: Box::new(render_item),
+            on_scroll: None,
+            padding: Padding::ZERO,
+            width: Length::Fill,
+            height: Length::Fill,
+            visible: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Sets the function that will be called with the new scroll offset when
+    /// the [`VirtualList`] is scrolled.
+    #[must_use]
+    pub fn on_scroll<F>(mut self, on_scroll: F) -> Self
+    where
+        F: 'a + Fn(f32) -> Message,
+    {
+        self.on_scroll = Some(Box::new(on_scroll));
+        self
+    }
+
+    /// Sets the padding around the items of the [`VirtualList`].
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`VirtualList`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// The height of all items including the padding.
+    fn content_height(&self) -> f32 {
+        self.item_count as f32 * self.item_height + self.padding.vertical()
+    }
+
+    /// The largest scroll offset for the given viewport height.
+    fn max_offset(&self, viewport_height: f32) -> f32 {
+        (self.content_height() - viewport_height).max(0.0)
+    }
+
+    /// The indices of the items intersecting the viewport.
+    fn visible_range(&self, offset: f32, viewport_height: f32) -> Range<usize> {
+        if self.item_height <= 0.0 {
+            return 0..0;
+        }
+
+        let top = (offset - self.padding.top).max(0.0);
+        let bottom = (offset + viewport_height - self.padding.top).max(0.0);
+
+        let first = ((top / self.item_height).floor() as usize).min(self.item_count);
+        let last = ((bottom / self.item_height).ceil() as usize).min(self.item_count);
+
+        first..last
+    }
+}
+
+/// The state of a [`VirtualList`].
+#[derive(Default)]
+struct State {
+    /// The distance the items are scrolled up.
+    scroll_offset: f32,
+    /// The states of the visible items.
+    items: HashMap<usize, Tree>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + renderer::Renderer,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<State>();
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(0.0, self.content_height()),
+        );
+
+        state.scroll_offset = state.scroll_offset.min(self.max_offset(size.height));
+        let range = self.visible_range(state.scroll_offset, size.height);
+        state.items.retain(|index, _| range.contains(index));
+
+        let item_limits = Limits::new(
+            Size::ZERO,
+            Size::new(
+                (size.width - self.padding.horizontal()).max(0.0),
+                self.item_height,
+            ),
+        );
+
+        let mut visible = Vec::with_capacity(range.len());
+        let nodes = range
+            .map(|index| {
+                let item = (self.render_item)(index);
+                let item_tree = state.items.entry(index).or_insert_with(|| Tree::new(&item));
+                item_tree.diff(&item);
+
+                let node = item
+                    .as_widget()
+                    .layout(item_tree, renderer, &item_limits)
+                    .move_to(Point::new(
+                        self.padding.left,
+                        self.padding.top + index as f32 * self.item_height - state.scroll_offset,
+                    ));
+                visible.push((index, item));
+
+                node
+            })
+            .collect();
+
+        *self.visible.borrow_mut() = visible;
+
+        Node::with_children(size, nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        // The items hidden by the edges of the list don't see the cursor.
+        let item_cursor = if cursor.is_over(bounds) {
+            cursor
+        } else {
+            Cursor::Unavailable
+        };
+        let item_viewport = bounds.intersection(viewport).unwrap_or(bounds);
+
+        let mut status = event::Status::Ignored;
+        for ((index, item), item_layout) in self.visible.get_mut().iter_mut().zip(layout.children())
+        {
+            if let Some(item_tree) = state.items.get_mut(index) {
+                status = status.merge(item.as_widget_mut().on_event(
+                    item_tree,
+                    event.clone(),
+                    item_layout,
+                    item_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &item_viewport,
+                ));
+            }
+        }
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
+            if !cursor.is_over(bounds) {
+                return event::Status::Ignored;
+            }
+
+            let delta = match delta {
+                ScrollDelta::Lines { y, .. } => y * LINE_HEIGHT,
+                ScrollDelta::Pixels { y, .. } => y,
+            };
+            let offset = (state.scroll_offset - delta).clamp(0.0, self.max_offset(bounds.height));
+
+            if (offset - state.scroll_offset).abs() > f32::EPSILON {
+                state.scroll_offset = offset;
+                if let Some(on_scroll) = &self.on_scroll {
+                    shell.publish(on_scroll(offset));
+                }
+                shell.invalidate_layout();
+            }
+
+            return event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !cursor.is_over(layout.bounds()) {
+            return mouse::Interaction::default();
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+
+        self.visible
+            .borrow()
+            .iter()
+            .zip(layout.children())
+            .filter_map(|((index, item), item_layout)| {
+                state.items.get(index).map(|item_tree| {
+                    item.as_widget().mouse_interaction(
+                        item_tree,
+                        item_layout,
+                        cursor,
+                        viewport,
+                        renderer,
+                    )
+                })
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        for ((index, item), item_layout) in self.visible.borrow().iter().zip(layout.children()) {
+            if let Some(item_tree) = state.items.get_mut(index) {
+                item.as_widget()
+                    .operate(item_tree, item_layout, renderer, operation);
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+        let state = tree.state.downcast_ref::<State>();
+        let item_cursor = if cursor.is_over(bounds) {
+            cursor
+        } else {
+            Cursor::Unavailable
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            for ((index, item), item_layout) in self.visible.borrow().iter().zip(layout.children())
+            {
+                if let Some(item_tree) = state.items.get(index) {
+                    item.as_widget().draw(
+                        item_tree,
+                        renderer,
+                        theme,
+                        style,
+                        item_layout,
+                        item_cursor,
+                        &clip,
+                    );
+                }
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<VirtualList<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a,
+{
+    fn from(virtual_list: VirtualList<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(virtual_list)
+    }
+}
@@ -29,8 +29,8 @@ pub struct Wrap<'a, Message, Direction, Theme = iced::Theme, Renderer = iced::Re
     pub max_width: f32,
     /// The maximum height of the [`Wrap`].
     pub max_height: f32,
-    /// The padding of each element of the [`Wrap`].
-    pub padding: f32,
+    /// The padding around the elements of the [`Wrap`].
+    pub padding: Padding,
     /// The spacing between each element of the [`Wrap`].
     pub spacing: f32,
     /// The spacing between each line of the [`Wrap`].
@@ -103,10 +103,10 @@ impl<'a, Message, Renderer, Direction, Theme> Wrap<'a, Message, Direction, Theme
         self
     }
 
-    /// Sets the padding of the elements in the [`Wrap`].
+    /// Sets the padding around the elements of the [`Wrap`].
     #[must_use]
-    pub const fn padding(mut self, units: f32) -> Self {
-        self.padding = units;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -324,7 +324,7 @@ impl<'a, Message, Renderer, Direction, Theme> Default
             height: Length::Shrink,
             max_width: 4_294_967_295.0,
             max_height: 4_294_967_295.0,
-            padding: 0.0,
+            padding: Padding::ZERO,
             spacing: 0.0,
             line_spacing: 0.0,
             line_minimal_length: 10.0,
@@ -349,7 +349,7 @@ where
     #[allow(clippy::inline_always)]
     #[inline(always)]
     fn inner_layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
-        let padding = Padding::from(self.padding);
+        let padding = self.padding;
         let spacing = self.spacing;
         let line_spacing = self.line_spacing;
         #[allow(clippy::cast_precision_loss)] // TODO: possible precision loss
@@ -364,7 +364,7 @@ where
 
         let mut children = tree.children.iter_mut();
         let mut curse = padding.left;
-        let mut deep_curse = padding.left;
+        let mut deep_curse = padding.top;
         let mut current_line_height = line_minimal_length;
         let mut max_main = curse;
         let mut align = vec![];
@@ -420,7 +420,7 @@ where
         }
         let (width, height) = (
             max_main - padding.left,
-            deep_curse - padding.left + current_line_height,
+            deep_curse - padding.top + current_line_height,
         );
         let size = limits.resolve(self.width, self.height, Size::new(width, height));
 
@@ -436,7 +436,7 @@ where
     #[allow(clippy::inline_always)]
     #[inline(always)]
     fn inner_layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
-        let padding = Padding::from(self.padding);
+        let padding = self.padding;
         let spacing = self.spacing;
         let line_spacing = self.line_spacing;
         #[allow(clippy::cast_precision_loss)] // TODO: possible precision loss
@@ -450,7 +450,7 @@ where
         let max_height = limits.max().height;
 
         let mut children = tree.children.iter_mut();
-        let mut curse = padding.left;
+        let mut curse = padding.top;
         let mut wide_curse = padding.left;
         let mut current_line_width = line_minimal_length;
         let mut max_main = curse;
@@ -482,8 +482,8 @@ where
                     start = end;
                     end += 1;
                     current_line_width = line_minimal_length;
-                    node = node.move_to(Point::new(wide_curse, padding.left));
-                    curse = offset_init + padding.left;
+                    node = node.move_to(Point::new(wide_curse, padding.top));
+                    curse = offset_init + padding.top;
                 } else {
                     node = node.move_to(Point::new(wide_curse, curse));
                     end += 1;
@@ -509,7 +509,7 @@ where
 
         let (width, height) = (
             wide_curse - padding.left + current_line_width,
-            max_main - padding.left,
+            max_main - padding.top,
         );
         let size = limits.resolve(self.width, self.height, Size::new(width, height));
 
@@ -0,0 +1,432 @@
+//! Use a year picker as an input element for picking a year from a scrollable list.
+//!
+//! *This API requires the following crate features to be activated: `date_picker`*
+
+use super::overlay::year_picker::YearPickerOverlay;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::Renderer as _,
+        widget::{
+            self,
+            tree::{Tag, Tree},
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    Element,
+    Event,
+    Length,
+    Pixels,
+    Point,
+    Rectangle,
+    Renderer, // the actual type
+    Size,
+    Vector,
+};
+
+pub use crate::{
+    core::date::Date,
+    style::date_picker::{Appearance, DayAppearance, StyleSheet},
+};
+
+/// The default earliest year that can be picked.
+const DEFAULT_MIN_YEAR: i32 = 1;
+/// The default latest year that can be picked.
+const DEFAULT_MAX_YEAR: i32 = 9999;
+/// The default number of shown years.
+const DEFAULT_VISIBLE_ROWS: usize = 7;
+
+//TODO: Remove ignore when Null is updated. Temp fix for Test runs
+/// An input element for picking a year from a scrollable list.
+///
+/// The overlay shows a list of years with the selected year in its center.
+/// The list is scrolled with the mouse wheel, the arrow keys move the selection
+/// and enter or clicking on a year submits it.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::YearPicker;
+/// # use iced::widget::{button, Button, Text};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Open,
+///     Cancel,
+///     Submit(i32),
+/// }
+///
+/// let year_picker = YearPicker::new(
+///     true,
+///     Button::new(Text::new("Pick year"))
+///         .on_press(Message::Open),
+///     Message::Cancel,
+///     Message::Submit,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct YearPicker<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// Show the picker.
+    show_picker: bool,
+    /// The year that is shown as selected when the picker is opened.
+    year: i32,
+    /// The underlying element.
+    underlay: Element<'a, Message, Theme, Renderer>,
+    /// The message that is send if the [`YearPickerOverlay`] is cancelled.
+    on_cancel: Message,
+    /// The function that produces a message when a year of the [`YearPickerOverlay`] is submitted.
+    on_submit: Box<dyn Fn(i32) -> Message>,
+    /// The years that can be picked and the number of shown years.
+    list: YearList,
+    /// The style of the [`YearPickerOverlay`].
+    style: <Theme as StyleSheet>::Style,
+    /// The font size of the [`YearPickerOverlay`] or `None` for the default
+    font_size: Option<Pixels>,
+}
+
+impl<'a, Message, Theme> YearPicker<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet,
+{
+    /// Creates a new [`YearPicker`] wrapping around the given underlay.
+    ///
+    /// It expects:
+    ///     * if the overlay of the year picker is visible.
+    ///     * the underlay [`Element`] on which this [`YearPicker`]
+    ///         will be wrapped around.
+    ///     * a message that will be send when the [`YearPicker`] is cancelled by
+    ///         pressing escape or clicking outside of it.
+    ///     * a function that will be called when a year of the [`YearPicker`]
+    ///         is clicked or submitted with enter, which takes the picked year.
+    pub fn new<U, F>(show_picker: bool, underlay: U, on_cancel: Message, on_submit: F) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        F: 'static + Fn(i32) -> Message,
+    {
+        Self {
+            show_picker,
+            year: Date::today().year,
+            underlay: underlay.into(),
+            on_cancel,
+            on_submit: Box::new(on_submit),
+            list: YearList {
+                min: DEFAULT_MIN_YEAR,
+                max: DEFAULT_MAX_YEAR,
+                rows: DEFAULT_VISIBLE_ROWS,
+            },
+            style: <Theme as StyleSheet>::Style::default(),
+            font_size: None,
+        }
+    }
+
+    /// Sets the year that is shown as selected when the [`YearPicker`] is opened.
+    #[must_use]
+    pub fn year(mut self, year: i32) -> Self {
+        self.year = year;
+        self
+    }
+
+    /// Sets the earliest year that can be picked in the [`YearPicker`].
+    #[must_use]
+    pub fn min_year(mut self, year: i32) -> Self {
+        self.list.min = year;
+        self
+    }
+
+    /// Sets the latest year that can be picked in the [`YearPicker`].
+    #[must_use]
+    pub fn max_year(mut self, year: i32) -> Self {
+        self.list.max = year;
+        self
+    }
+
+    /// Sets the number of years shown in the [`YearPicker`].
+    ///
+    /// Even numbers are rounded up to the next odd number, so that the selected
+    /// year is shown in the center.
+    #[must_use]
+    pub fn visible_rows(mut self, rows: usize) -> Self {
+        self.list.rows = rows | 1;
+        self
+    }
+
+    /// Sets the style of the [`YearPicker`].
+    #[must_use]
+    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the font size of the [`YearPicker`].
+    #[must_use]
+    pub fn font_size<P: Into<Pixels>>(mut self, size: P) -> Self {
+        self.font_size = Some(size.into());
+        self
+    }
+}
+
+/// The years that can be picked and the number of shown years of a [`YearPicker`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct YearList {
+    /// The earliest year that can be picked.
+    pub(crate) min: i32,
+    /// The latest year that can be picked.
+    pub(crate) max: i32,
+    /// The number of shown years.
+    pub(crate) rows: usize,
+}
+
+impl YearList {
+    /// The number of shown years, which is less than the number of rows if
+    /// there are less years that can be picked.
+    pub(crate) fn len(self) -> i32 {
+        i32::try_from(self.rows)
+            .unwrap_or(i32::MAX)
+            .min(self.max.saturating_sub(self.min).saturating_add(1))
+            .max(1)
+    }
+
+    /// Moves the given year into the range of the years that can be picked.
+    pub(crate) fn clamp(self, year: i32) -> i32 {
+        year.min(self.max).max(self.min)
+    }
+
+    /// Moves the given first shown year so that the list doesn't go past the
+    /// earliest or latest year.
+    pub(crate) fn clamp_top(self, top: i32) -> i32 {
+        top.min(self.max.saturating_sub(self.len() - 1))
+            .max(self.min)
+    }
+
+    /// The first shown year of the list showing the given year in its center.
+    pub(crate) fn top_for(self, year: i32) -> i32 {
+        self.clamp_top(year.saturating_sub(self.len() / 2))
+    }
+}
+
+/// The state of the [`YearPicker`] / [`YearPickerOverlay`].
+#[derive(Debug)]
+pub struct State {
+    /// The selected year.
+    pub(crate) selected: i32,
+    /// The first shown year if the list has been scrolled, or `None` if the
+    /// selected year is shown in the center.
+    pub(crate) top: Option<i32>,
+}
+
+impl State {
+    /// Creates a new [`State`] selecting the given year.
+    #[must_use]
+    pub fn new(year: i32) -> Self {
+        Self {
+            selected: year,
+            top: None,
+        }
+    }
+
+    /// Resets the selected year of the state to the current year.
+    pub fn reset(&mut self) {
+        *self = Self::new(Date::today().year);
+    }
+
+    /// The first shown year of the given list.
+    pub(crate) fn top(&self, list: YearList) -> i32 {
+        self.top
+            .map_or_else(|| list.top_for(self.selected), |top| list.clamp_top(top))
+    }
+
+    /// Selects the given year and scrolls it into the center of the list.
+    pub(crate) fn select(&mut self, year: i32, list: YearList) {
+        self.selected = list.clamp(year);
+        self.top = None;
+    }
+
+    /// Scrolls the list by the given number of years.
+    pub(crate) fn scroll(&mut self, years: i32, list: YearList) {
+        self.top = Some(list.clamp_top(self.top(list).saturating_add(years)));
+    }
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for YearPicker<'_, Message, Theme>
+where
+    Message: 'static + Clone,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::new(self.list.clamp(self.year)))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.underlay]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.underlay
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<iced::overlay::Element<'b, Message, Theme, Renderer>> {
+        let picker_state: &mut State = state.state.downcast_mut();
+
+        if !self.show_picker {
+            return self.underlay.as_widget_mut().overlay(
+                &mut state.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        let bounds = layout.bounds();
+        let position = Point::new(bounds.center_x(), bounds.center_y());
+
+        Some(
+            YearPickerOverlay::new(
+                picker_state,
+                self.on_cancel.clone(),
+                &self.on_submit,
+                self.list,
+                position,
+                self.style.clone(),
+                self.font_size.unwrap_or_else(|| renderer.default_size()),
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme> From<YearPicker<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'static + Clone,
+    Theme: 'a + StyleSheet,
+{
+    fn from(year_picker: YearPicker<'a, Message, Theme>) -> Self {
+        Element::new(year_picker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{State, YearList};
+
+    const LIST: YearList = YearList {
+        min: 2000,
+        max: 2030,
+        rows: 7,
+    };
+
+    #[test]
+    fn year_list_test() {
+        assert_eq!(LIST.len(), 7);
+        assert_eq!(LIST.top_for(2015), 2012);
+        assert_eq!(LIST.top_for(2001), 2000);
+        assert_eq!(LIST.top_for(2030), 2024);
+
+        let short = YearList { max: 2002, ..LIST };
+        assert_eq!(short.len(), 3);
+        assert_eq!(short.top_for(2001), 2000);
+    }
+
+    #[test]
+    fn state_test() {
+        let mut state = State::new(2015);
+        assert_eq!(state.top(LIST), 2012);
+
+        state.scroll(-20, LIST);
+        assert_eq!(state.top(LIST), 2000);
+        assert_eq!(state.selected, 2015);
+
+        state.select(2040, LIST);
+        assert_eq!(state.selected, 2030);
+        assert_eq!(state.top(LIST), 2024);
+    }
+}